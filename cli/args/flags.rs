@@ -17,6 +17,7 @@ use std::sync::LazyLock;
 
 use clap::Arg;
 use clap::ArgAction;
+use clap::ArgGroup;
 use clap::ArgMatches;
 use clap::ColorChoice;
 use clap::Command;
@@ -34,6 +35,7 @@ use deno_bundle_runtime::BundleFormat;
 use deno_bundle_runtime::BundlePlatform;
 use deno_bundle_runtime::PackageHandling;
 use deno_bundle_runtime::SourceMapType;
+use deno_config::deno_json::CoverageThresholds;
 use deno_config::deno_json::NewestDependencyDate;
 use deno_config::deno_json::NodeModulesDirMode;
 use deno_config::glob::FilePatterns;
@@ -60,6 +62,7 @@ use deno_semver::package::PackageKind;
 use deno_telemetry::OtelConfig;
 use deno_telemetry::OtelConsoleConfig;
 use deno_telemetry::OtelPropagators;
+use deno_telemetry::OtelSampler;
 use log::Level;
 use log::debug;
 use node_shim::parse_node_options_env_var;
@@ -148,6 +151,14 @@ pub struct BenchFlags {
   pub no_run: bool,
   pub permit_no_files: bool,
   pub watch: Option<WatchFlags>,
+  /// Collect heap allocation and garbage collection statistics.
+  pub memory: bool,
+  /// Name of the baseline to save the results as.
+  pub save_baseline: Option<String>,
+  /// Name of the baseline to compare the results against.
+  pub baseline: Option<String>,
+  /// Slowdown in percent above which a benchmark counts as regressed.
+  pub regression_threshold: Option<u16>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -161,6 +172,8 @@ pub struct CheckFlags {
   pub doc: bool,
   pub doc_only: bool,
   pub check_js: bool,
+  pub sarif: bool,
+  pub github: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -220,6 +233,8 @@ pub enum CoverageType {
   Detailed,
   Lcov,
   Html,
+  Cobertura,
+  Json,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -229,6 +244,9 @@ pub struct CoverageFlags {
   pub include: Vec<String>,
   pub exclude: Vec<String>,
   pub r#type: CoverageType,
+  pub thresholds: CoverageThresholds,
+  /// Only report the coverage of the lines changed since this git ref.
+  pub diff: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -396,6 +414,8 @@ pub struct LintFlags {
   pub permit_no_files: bool,
   pub json: bool,
   pub compact: bool,
+  pub sarif: bool,
+  pub github: bool,
   pub watch: Option<WatchFlags>,
 }
 
@@ -539,6 +559,28 @@ pub enum TestReporterConfig {
   Dot,
   Junit,
   Tap,
+  Json,
+}
+
+/// One of `count` partitions of the test modules, `index` is 1-based.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TestShard {
+  pub index: usize,
+  pub count: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FetchFixturesMode {
+  Record,
+  #[default]
+  Replay,
+  Strict,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FetchFixturesFlags {
+  pub dir: String,
+  pub mode: FetchFixturesMode,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -559,6 +601,12 @@ pub struct TestFlags {
   pub reporter: TestReporterConfig,
   pub junit_path: Option<String>,
   pub hide_stacktraces: bool,
+  pub fetch_fixtures: Option<FetchFixturesFlags>,
+  pub shard: Option<TestShard>,
+  pub shard_durations: Option<String>,
+  pub retries: usize,
+  pub failed: bool,
+  pub changed: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -594,6 +642,46 @@ pub struct CleanFlags {
   pub dry_run: bool,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CronFlags {
+  pub state_path: Option<String>,
+  pub json: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionBrokerFlags {
+  pub socket_path: String,
+  pub rules: String,
+  pub log: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SbomFormat {
+  #[default]
+  CycloneDx,
+  Spdx,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SbomFlags {
+  pub files: Vec<String>,
+  pub format: SbomFormat,
+  pub output: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeLockfileFlags {
+  pub base: String,
+  pub ours: String,
+  pub theirs: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhyFlags {
+  pub package: String,
+  pub json: bool,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BundleFlags {
   pub entrypoints: Vec<String>,
@@ -625,6 +713,7 @@ pub enum DenoSubcommand {
   Compile(CompileFlags),
   Completions(CompletionsFlags),
   Coverage(CoverageFlags),
+  Cron(CronFlags),
   Deploy(DeployFlags),
   Doc(DocFlags),
   Eval(EvalFlags),
@@ -637,15 +726,19 @@ pub enum DenoSubcommand {
   Uninstall(UninstallFlags),
   Lsp,
   Lint(LintFlags),
+  MergeLockfile(MergeLockfileFlags),
   Repl(ReplFlags),
   Run(RunFlags),
   Serve(ServeFlags),
   Task(TaskFlags),
   Test(TestFlags),
   Outdated(OutdatedFlags),
+  PermissionBroker(PermissionBrokerFlags),
+  Sbom(SbomFlags),
   Types,
   Upgrade(UpgradeFlags),
   Vendor,
+  Why(WhyFlags),
   Publish(PublishFlags),
   Help(HelpFlags),
   X(XFlags),
@@ -832,6 +925,26 @@ fn parse_packages_allowed_scripts(s: &str) -> Result<String, AnyError> {
   }
 }
 
+/// Parse --shard from a string like "2/5".
+fn parse_test_shard(s: &str) -> Result<TestShard, String> {
+  let Some((index, count)) = s.split_once('/') else {
+    return Err("Expected a shard in the form of N/M, e.g. 1/3".to_string());
+  };
+  let index = index
+    .parse::<usize>()
+    .map_err(|_| format!("Invalid shard index '{index}'"))?;
+  let count = count
+    .parse::<usize>()
+    .map_err(|_| format!("Invalid shard count '{count}'"))?;
+  if count == 0 {
+    return Err("The shard count must be greater than 0".to_string());
+  }
+  if index == 0 || index > count {
+    return Err(format!("The shard index must be between 1 and {count}"));
+  }
+  Ok(TestShard { index, count })
+}
+
 /// Parse --inspect-publish-uid from a comma-separated string like "stderr,http".
 pub fn parse_inspect_publish_uid(s: &str) -> Result<InspectPublishUid, String> {
   let mut result = InspectPublishUid {
@@ -1241,6 +1354,14 @@ impl Flags {
       HashSet::default()
     };
 
+    let (sampler, warning) = OtelSampler::from_env_values(
+      std::env::var("OTEL_TRACES_SAMPLER").ok().as_deref(),
+      std::env::var("OTEL_TRACES_SAMPLER_ARG").ok().as_deref(),
+    );
+    if let Some(warning) = warning {
+      log::warn!("{warning}");
+    }
+
     OtelConfig {
       tracing_enabled: !disabled
         && otel_var("OTEL_DENO_TRACING").unwrap_or(default),
@@ -1275,6 +1396,7 @@ impl Flags {
         })
         .ok()
         .flatten(),
+      sampler,
     }
   }
 
@@ -1473,6 +1595,21 @@ static ENV_VARS: &[EnvVar] = &[
     description: "Set the directory for collecting code coverage profiles.\nEquivalent to using the --coverage flag.",
     example: None,
   },
+  EnvVar {
+    name: "DENO_CRON_CATCH_UP_MISSED",
+    description: "Run Deno.cron jobs whose persisted deadline passed while the process\nwas not running once on startup. Requires DENO_CRON_STATE_PATH.",
+    example: None,
+  },
+  EnvVar {
+    name: "DENO_CRON_STATE_PATH",
+    description: "Persist local Deno.cron run bookkeeping to the given SQLite file.",
+    example: None,
+  },
+  EnvVar {
+    name: "DENO_CRON_TIMEZONE",
+    description: "IANA time zone that local Deno.cron schedules are evaluated in.",
+    example: Some(r#"(defaults to "UTC", e.g. "Europe/Berlin")"#),
+  },
   EnvVar {
     name: "DENO_DIR",
     description: "Set the cache directory",
@@ -1661,6 +1798,7 @@ static DENO_HELP: &str = cstr!(
     <g>compile</>      Compile the script into a self contained executable
                   <p(245)>deno compile main.ts  |  deno compile --target=x86_64-unknown-linux-gnu</>
     <g>coverage</>     Print coverage reports
+    <g>cron</>         Show the status of locally persisted cron jobs
     <g>deploy</>       Manage and publish applications with Deno Deploy
     <g>doc</>          Generate and show documentation for a module or built-ins
                   <p(245)>deno doc  |  deno doc --json  |  deno doc --html mod.ts</>
@@ -1669,10 +1807,18 @@ static DENO_HELP: &str = cstr!(
     <g>info</>         Show info about cache or info related to source file
     <g>jupyter</>      Deno kernel for Jupyter notebooks
     <g>lint</>         Lint source files
+    <g>merge-lockfile</>
+                 Merge two versions of a lockfile as a git merge driver
+    <g>permission-broker</>
+                 Answer permission requests of Deno processes from a rules file
     <g>init</>         Initialize a new project
     <g>test</>         Run tests
                   <p(245)>deno test  |  deno test test.ts</>
     <g>publish</>      Publish the current working directory's package or workspace
+    <g>sbom</>         Generate a software bill of materials of the dependencies
+                  <p(245)>deno sbom  |  deno sbom --format=spdx main.ts</>
+    <g>why</>          Explain why a package is in the dependency graph
+                  <p(245)>deno why chalk  |  deno why npm:react@18.3.1</>
     <g>upgrade</>      Upgrade deno executable to given version
                   <p(245)>deno upgrade  |  deno upgrade 1.45.0  |  deno upgrade canary</>
 {after-help}
@@ -1884,6 +2030,7 @@ pub fn flags_from_vec_with_initial_cwd(
         "create" => create_parse(&mut flags, &mut m)?,
         "completions" => completions_parse(&mut flags, &mut m, app),
         "coverage" => coverage_parse(&mut flags, &mut m)?,
+        "cron" => cron_parse(&mut flags, &mut m),
         "doc" => doc_parse(&mut flags, &mut m)?,
         "eval" => eval_parse(&mut flags, &mut m)?,
         "fmt" => fmt_parse(&mut flags, &mut m)?,
@@ -1894,9 +2041,12 @@ pub fn flags_from_vec_with_initial_cwd(
         "jupyter" => jupyter_parse(&mut flags, &mut m),
         "lint" => lint_parse(&mut flags, &mut m)?,
         "lsp" => lsp_parse(&mut flags, &mut m),
+        "merge-lockfile" => merge_lockfile_parse(&mut flags, &mut m),
         "outdated" => outdated_parse(&mut flags, &mut m, false)?,
+        "permission-broker" => permission_broker_parse(&mut flags, &mut m),
         "repl" => repl_parse(&mut flags, &mut m)?,
         "run" => run_parse(&mut flags, &mut m, app, false)?,
+        "sbom" => sbom_parse(&mut flags, &mut m)?,
         "serve" => serve_parse(&mut flags, &mut m, app)?,
         "task" => task_parse(&mut flags, &mut m, app)?,
         "test" => test_parse(&mut flags, &mut m)?,
@@ -1905,6 +2055,7 @@ pub fn flags_from_vec_with_initial_cwd(
         "update" => outdated_parse(&mut flags, &mut m, true)?,
         "upgrade" => upgrade_parse(&mut flags, &mut m)?,
         "vendor" => vendor_parse(&mut flags, &mut m),
+        "why" => why_parse(&mut flags, &mut m),
         "publish" => publish_parse(&mut flags, &mut m)?,
         "x" => x_parse(&mut flags, &mut m)?,
         _ => unreachable!(),
@@ -2151,6 +2302,7 @@ pub fn clap_root() -> Command {
         .subcommand(create_subcommand())
         .subcommand(completions_subcommand())
         .subcommand(coverage_subcommand())
+        .subcommand(cron_subcommand())
         .subcommand(doc_subcommand())
         .subcommand(deploy_subcommand())
         .subcommand(sandbox_subcommand())
@@ -2164,16 +2316,20 @@ pub fn clap_root() -> Command {
         .subcommand(approve_scripts_subcommand())
         .subcommand(uninstall_subcommand())
         .subcommand(outdated_subcommand())
+        .subcommand(permission_broker_subcommand())
         .subcommand(lsp_subcommand())
         .subcommand(lint_subcommand())
+        .subcommand(merge_lockfile_subcommand())
         .subcommand(publish_subcommand())
         .subcommand(repl_subcommand())
+        .subcommand(sbom_subcommand())
         .subcommand(task_subcommand())
         .subcommand(test_subcommand())
         .subcommand(types_subcommand())
         .subcommand(update_subcommand())
         .subcommand(upgrade_subcommand())
         .subcommand(vendor_subcommand())
+        .subcommand(why_subcommand())
         .subcommand(x_subcommand());
 
       let help = help_subcommand(&cmd);
@@ -2405,6 +2561,10 @@ Evaluate the given files, run all benches declared with 'Deno.bench()' and repor
 If you specify a directory instead of a file, the path is expanded to all contained files matching the glob <c>{*_,*.,}bench.{js,mjs,ts,mts,jsx,tsx}</>:
   <p(245)>deno bench src/</>
 
+Save the results as a baseline and compare a later run against it, failing if a benchmark got more than 5% slower:
+  <p(245)>deno bench --save-baseline=main</>
+  <p(245)>deno bench --baseline=main --regression-threshold=5</>
+
 <y>Read more:</> <c>https://docs.deno.com/go/bench</>"),
     UnstableArgsConfig::ResolutionAndRuntime,
   )
@@ -2445,6 +2605,38 @@ If you specify a directory instead of a file, the path is expanded to all contai
           .help("Cache bench modules, but don't run benchmarks")
           .action(ArgAction::SetTrue),
       )
+      .arg(
+        Arg::new("memory")
+          .long("memory")
+          .help("Collect heap allocation and garbage collection statistics of each benchmark")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("save-baseline")
+          .long("save-baseline")
+          .value_name("NAME")
+          .require_equals(true)
+          .conflicts_with("watch")
+          .help("Save the benchmark results as a baseline with the given name"),
+      )
+      .arg(
+        Arg::new("baseline")
+          .long("baseline")
+          .value_name("NAME")
+          .require_equals(true)
+          .conflicts_with("watch")
+          .help("Compare the benchmark results against a saved baseline"),
+      )
+      .arg(
+        Arg::new("regression-threshold")
+          .long("regression-threshold")
+          .value_name("PERCENT")
+          .require_equals(true)
+          .requires("baseline")
+          .value_parser(value_parser!(u16))
+          .help(cstr!("Fail if a benchmark is significantly slower than the baseline by more than PERCENT
+  <p(245)>Defaults to 10</>")),
+      )
       .arg(permit_no_files_arg())
       .arg(watch_arg(false))
       .arg(watch_exclude_arg())
@@ -2719,6 +2911,19 @@ Unless --reload is specified, this command will not re-download already cached d
             )
             .action(ArgAction::SetTrue)
         )
+        .arg(
+          Arg::new("sarif")
+            .long("sarif")
+            .help("Output type-check diagnostics in SARIF format, for code scanning tools")
+            .action(ArgAction::SetTrue)
+        )
+        .arg(
+          Arg::new("github")
+            .long("github")
+            .help("Output type-check diagnostics as GitHub Actions annotations")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("sarif")
+        )
         .arg(
           Arg::new("file")
             .num_args(1..)
@@ -2883,6 +3088,12 @@ Write a report using the lcov format:
 Generate html reports from lcov:
   <p(245)>genhtml -o html_cov cov.lcov</>
 
+Fail when less than 80% of the lines are covered:
+  <p(245)>deno coverage --threshold-lines=80 cov_profile/</>
+
+Only report the coverage of the lines changed since the main branch:
+  <p(245)>deno coverage --diff=main cov_profile/</>
+
 <y>Read more:</> <c>https://docs.deno.com/go/coverage</>"),
     UnstableArgsConfig::None,
   )
@@ -2923,13 +3134,30 @@ Generate html reports from lcov:
           .help("Output coverage report in lcov format")
           .action(ArgAction::SetTrue),
       )
+      .arg(
+        Arg::new("cobertura")
+          .long("cobertura")
+          .help("Output coverage report in Cobertura XML format")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("json")
+          .long("json")
+          .help("Output coverage report in the Istanbul coverage-final.json format")
+          .action(ArgAction::SetTrue),
+      )
+      .group(
+        ArgGroup::new("file_report")
+          .args(["lcov", "cobertura", "json"])
+          .multiple(false),
+      )
       .arg(
         Arg::new("output")
-          .requires("lcov")
+          .requires("file_report")
           .long("output")
           .value_parser(value_parser!(String))
           .help(
-            cstr!("Exports the coverage report in lcov format to the given file.
+            cstr!("Exports the coverage report in lcov, Cobertura or JSON format to the given file.
   <p(245)>If no --output arg is specified then the report is written to stdout.</>",
           ))
           .require_equals(true)
@@ -2947,6 +3175,38 @@ Generate html reports from lcov:
           .help("Output coverage report in detailed format in the terminal")
           .action(ArgAction::SetTrue),
       )
+      .arg(
+        Arg::new("threshold-lines")
+          .long("threshold-lines")
+          .value_name("PERCENT")
+          .require_equals(true)
+          .value_parser(value_parser!(u8).range(0..=100))
+          .help("Fail if the line coverage is below PERCENT"),
+      )
+      .arg(
+        Arg::new("threshold-branches")
+          .long("threshold-branches")
+          .value_name("PERCENT")
+          .require_equals(true)
+          .value_parser(value_parser!(u8).range(0..=100))
+          .help("Fail if the branch coverage is below PERCENT"),
+      )
+      .arg(
+        Arg::new("threshold-functions")
+          .long("threshold-functions")
+          .value_name("PERCENT")
+          .require_equals(true)
+          .value_parser(value_parser!(u8).range(0..=100))
+          .help("Fail if the function coverage is below PERCENT"),
+      )
+      .arg(
+        Arg::new("diff")
+          .long("diff")
+          .value_name("GIT_REF")
+          .require_equals(true)
+          .help("Only report the coverage of lines added or modified since GIT_REF")
+          .value_hint(ValueHint::Other),
+      )
       .arg(
         Arg::new("files")
           .num_args(0..)
@@ -2956,6 +3216,36 @@ Generate html reports from lcov:
   })
 }
 
+fn cron_subcommand() -> Command {
+  command(
+    "cron",
+    cstr!("Show the status of cron jobs persisted by the local <c>Deno.cron</> scheduler.
+
+  <p(245)>DENO_CRON_STATE_PATH=crons.db deno run --unstable-cron main.ts</>
+  <p(245)>deno cron --state-path=crons.db</>
+
+Lists every cron recorded in the state file with its schedule, time zone, next deadline,
+last run, last outcome, the number of retries of a failed run and the number of occurrences
+missed while no process was running."),
+    UnstableArgsConfig::None,
+  )
+  .defer(|cmd| {
+    cmd
+      .arg(
+        Arg::new("state-path")
+          .long("state-path")
+          .help(cstr!("Path of the cron state file <p(245)>[default: $DENO_CRON_STATE_PATH]</>"))
+          .value_hint(ValueHint::FilePath),
+      )
+      .arg(
+        Arg::new("json")
+          .long("json")
+          .help("Output the status in JSON format")
+          .action(ArgAction::SetTrue),
+      )
+  })
+}
+
 fn deploy_subcommand() -> Command {
   Command::new("deploy").arg(
     Arg::new("args")
@@ -3475,6 +3765,60 @@ The following information is shown:
       .arg(deny_import_arg())
 }
 
+fn sbom_subcommand() -> Command {
+  command(
+    "sbom",
+    cstr!("Generate a software bill of materials (SBOM) of the npm packages, JSR packages and remote modules in the lockfile.
+
+Output a CycloneDX SBOM of the whole workspace:
+  <p(245)>deno sbom</>
+
+Output an SPDX SBOM of the dependencies of an entrypoint to a file:
+  <p(245)>deno sbom --format=spdx --output=sbom.spdx.json main.ts</>
+
+Licenses are read from the package.json of npm packages and the configuration file of JSR packages."),
+    UnstableArgsConfig::ResolutionOnly,
+  )
+  .defer(|cmd| {
+    cmd
+      .arg(
+        Arg::new("files")
+          .help("Entrypoints to include the dependencies of, defaults to the whole workspace")
+          .num_args(1..)
+          .action(ArgAction::Append)
+          .value_hint(ValueHint::FilePath),
+      )
+      .arg(
+        Arg::new("format")
+          .long("format")
+          .help("The format of the SBOM")
+          .value_parser(["cyclonedx", "spdx"])
+          .default_value("cyclonedx"),
+      )
+      .arg(
+        Arg::new("output")
+          .long("output")
+          .short('o')
+          .help("Write the SBOM to this file instead of stdout")
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath),
+      )
+      .arg(reload_arg())
+      .arg(ca_file_arg())
+      .arg(unsafely_ignore_certificate_errors_arg())
+      .arg(no_config_arg())
+      .arg(no_remote_arg())
+      .arg(no_npm_arg())
+      .args(lock_args())
+      .arg(config_arg())
+      .arg(import_map_arg())
+      .arg(node_modules_dir_arg())
+      .arg(vendor_arg())
+      .arg(allow_import_arg())
+      .arg(deny_import_arg())
+  })
+}
+
 fn install_subcommand() -> Command {
   command("install", cstr!("Installs dependencies either in the local project or globally to a bin directory.
 
@@ -3878,6 +4222,10 @@ fn lint_subcommand() -> Command {
 Print result as JSON:
   <p(245)>deno lint --json</>
 
+Report results to GitHub code scanning or as pull request annotations:
+  <p(245)>deno lint --sarif > deno-lint.sarif</>
+  <p(245)>deno lint --github</>
+
 Read from stdin:
   <p(245)>cat file.ts | deno lint -</>
   <p(245)>cat file.ts | deno lint --json -</>
@@ -3979,6 +4327,22 @@ To ignore linting on an entire file, you can add an ignore comment at the top of
           .conflicts_with("json")
           .help_heading(LINT_HEADING),
       )
+      .arg(
+        Arg::new("sarif")
+          .long("sarif")
+          .help("Output lint result in SARIF format, for code scanning tools")
+          .action(ArgAction::SetTrue)
+          .conflicts_with_all(["json", "compact"])
+          .help_heading(LINT_HEADING),
+      )
+      .arg(
+        Arg::new("github")
+          .long("github")
+          .help("Output lint result as GitHub Actions annotations")
+          .action(ArgAction::SetTrue)
+          .conflicts_with_all(["json", "compact", "sarif"])
+          .help_heading(LINT_HEADING),
+      )
       .arg(
         Arg::new("files")
           .num_args(1..)
@@ -4451,8 +4815,9 @@ or <c>**/__tests__/**</>:
       .arg(
         Arg::new("reporter")
           .long("reporter")
-          .help("Select reporter to use. Default to 'pretty'")
-          .value_parser(["pretty", "dot", "junit", "tap"])
+          .help(cstr!("Select reporter to use. Default to 'pretty'
+  <p(245)>json: stream every test event as a JSON object per line</>"))
+          .value_parser(["pretty", "dot", "junit", "tap", "json"])
           .help_heading(TEST_HEADING)
       )
       .arg(
@@ -4461,6 +4826,77 @@ or <c>**/__tests__/**</>:
           .help("Hide stack traces for errors in failure test results.")
           .action(ArgAction::SetTrue)
       )
+      .arg(
+        Arg::new("fetch-fixtures")
+          .long("fetch-fixtures")
+          .value_name("DIR")
+          .require_equals(true)
+          .value_hint(ValueHint::DirPath)
+          .help("Record fetch() responses into DIR and replay them on later runs")
+          .help_heading(TEST_HEADING)
+      )
+      .arg(
+        Arg::new("fetch-fixtures-mode")
+          .long("fetch-fixtures-mode")
+          .require_equals(true)
+          .requires("fetch-fixtures")
+          .help(cstr!("How to use the fetch fixtures. Defaults to 'replay'
+  <p(245)>record: always send requests and record their responses
+  replay: replay recorded responses and record missing ones
+  strict: replay recorded responses and fail requests that have none</>"))
+          .value_parser(["record", "replay", "strict"])
+          .help_heading(TEST_HEADING)
+      )
+      .arg(
+        Arg::new("retries")
+          .long("retries")
+          .value_name("N")
+          .require_equals(true)
+          .value_parser(value_parser!(usize))
+          .help("Re-run failing tests up to N times. Tests that pass on a retry are reported as flaky")
+          .help_heading(TEST_HEADING)
+      )
+      .arg(
+        Arg::new("shard")
+          .long("shard")
+          .value_name("N/M")
+          .require_equals(true)
+          .value_parser(parse_test_shard)
+          .conflicts_with("watch")
+          .help(cstr!("Only run the N-th of M deterministic partitions of the test modules
+  <p(245)>Use it to split a test suite across multiple machines, e.g. --shard=1/3</>"))
+          .help_heading(TEST_HEADING)
+      )
+      .arg(
+        Arg::new("shard-durations")
+          .long("shard-durations")
+          .value_name("FILE")
+          .require_equals(true)
+          .value_hint(ValueHint::FilePath)
+          .help(cstr!("Record the duration of each test module into FILE
+  <p(245)>When used with --shard, the durations from a previous run balance the shards</>"))
+          .help_heading(TEST_HEADING)
+      )
+      .arg(
+        Arg::new("failed")
+          .long("failed")
+          .action(ArgAction::SetTrue)
+          .conflicts_with("watch")
+          .help("Only run the tests that failed in the previous run")
+          .help_heading(TEST_HEADING)
+      )
+      .arg(
+        Arg::new("changed")
+          .long("changed")
+          .value_name("GIT_REF")
+          .num_args(0..=1)
+          .require_equals(true)
+          .default_missing_value("HEAD")
+          .conflicts_with("watch")
+          .help(cstr!("Only run the test modules that depend on a file changed relative to GIT_REF
+  <p(245)>Uncommitted and untracked files count as changed. Defaults to HEAD</>"))
+          .help_heading(TEST_HEADING)
+      )
       .arg(env_file_arg())
       .arg(executable_ext_arg())
     )
@@ -4599,6 +5035,125 @@ See the Deno 1.x to 2.x Migration Guide for migration instructions: https://docs
     .hide(true)
 }
 
+fn why_subcommand() -> Command {
+  command(
+    "why",
+    cstr!("Explain why a package is in the dependency graph by showing the shortest path from the workspace's configuration files to it through each package that depends on it.
+
+  <p(245)>deno why chalk</>
+  <p(245)>deno why npm:react@18.3.1</>
+  <p(245)>deno why jsr:@std/path --json</>
+
+Dependencies are read from the lockfile. Each path notes when a package was resolved as a peer dependency or by an npm override."),
+    UnstableArgsConfig::ResolutionOnly,
+  )
+  .defer(|cmd| {
+    cmd
+      .arg(
+        Arg::new("package")
+          .help("The package name, optionally with a version requirement")
+          .required(true),
+      )
+      .arg(
+        Arg::new("json")
+          .long("json")
+          .help("Output the paths in JSON format")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(config_arg())
+      .arg(no_config_arg())
+      .args(lock_args())
+  })
+}
+
+fn merge_lockfile_subcommand() -> Command {
+  command(
+    "merge-lockfile",
+    cstr!("Three-way merge two versions of a lockfile, writing the result to the file of the current branch.
+
+This is meant to be used as a git merge driver for deno.lock:
+
+  <p(245)>echo \"deno.lock merge=deno\" >> .gitattributes</>
+  <p(245)>git config merge.deno.driver \"deno merge-lockfile %O %A %B\"</>
+
+Packages, remote modules and redirects from both branches are combined and the result is validated against the configuration file.
+The merge only fails when the integrity of the same package or remote module differs between the branches."),
+    UnstableArgsConfig::ResolutionOnly,
+  )
+  .defer(|cmd| {
+    cmd
+      .arg(
+        Arg::new("base")
+          .help("The lockfile of the common ancestor")
+          .value_hint(ValueHint::FilePath)
+          .required(true),
+      )
+      .arg(
+        Arg::new("ours")
+          .help("The lockfile of the current branch, which the result is written to")
+          .value_hint(ValueHint::FilePath)
+          .required(true),
+      )
+      .arg(
+        Arg::new("theirs")
+          .help("The lockfile of the branch being merged")
+          .value_hint(ValueHint::FilePath)
+          .required(true),
+      )
+      .arg(config_arg())
+      .arg(no_config_arg())
+  })
+}
+
+fn permission_broker_subcommand() -> Command {
+  command(
+    "permission-broker",
+    cstr!("Run a permission broker that answers the permission requests of Deno processes according to a rules file.
+
+  <p(245)>deno permission-broker --rules=rules.json /tmp/broker.sock</>
+  <p(245)>DENO_PERMISSION_BROKER_PATH=/tmp/broker.sock deno run main.ts</>
+
+Rules are evaluated in order and the first rule with a matching pattern decides.
+Within a rule, deny patterns take precedence over allow patterns. <c>*</> matches any sequence of characters:
+
+  <p(245)>{
+    \"default\": \"deny\",
+    \"rules\": [
+      { \"permission\": \"read\", \"allow\": [\"./data/*\"] },
+      { \"permission\": \"env\", \"deny\": [\"*\"], \"reason\": \"No env access.\" },
+      { \"permission\": \"*\", \"pids\": [4242], \"allow\": [\"*\"] }
+    ]
+  }</>
+
+On Windows, the socket path is a named pipe like <c>\\\\.\\pipe\\deno-permission-broker</>."),
+    UnstableArgsConfig::None,
+  )
+  .defer(|cmd| {
+    cmd
+      .arg(
+        Arg::new("socket_path")
+          .help("Path of the Unix socket or Windows named pipe to listen on")
+          .required(true)
+          .value_hint(ValueHint::FilePath),
+      )
+      .arg(
+        Arg::new("rules")
+          .long("rules")
+          .help("Path of the JSON rules file")
+          .value_name("FILE")
+          .required(true)
+          .value_hint(ValueHint::FilePath),
+      )
+      .arg(
+        Arg::new("log")
+          .long("log")
+          .help("Append every decision as a JSON line to this file")
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath),
+      )
+  })
+}
+
 fn publish_subcommand() -> Command {
   command("publish", "Publish the current working directory's package or workspace to JSR", UnstableArgsConfig::ResolutionOnly)
     .defer(|cmd| {
@@ -4763,6 +5318,8 @@ fn permission_args(app: Command, requires: Option<&'static str>) -> Command {
       <g>DENO_AUDIT_PERMISSIONS</>               Environmental variable to audit all permissions accesses. Set to a file path for JSONL output, or "otel" to emit as OpenTelemetry log events via the configured OTel exporter.
                                              <p(245)>DENO_AUDIT_PERMISSIONS=./audit.jsonl deno run main.ts</>
                                              <p(245)>DENO_AUDIT_PERMISSIONS=otel deno run main.ts</>
+      <g>DENO_GENERATE_PERMISSIONS</>            Environmental variable to generate a deno.json permission set from all permission accesses. Set to the file path the config is written to on exit.
+                                             <p(245)>DENO_GENERATE_PERMISSIONS=./permissions.json deno run -A main.ts</>
 "#))
     .arg(
       {
@@ -6112,6 +6669,10 @@ fn bench_parse(
     no_run,
     permit_no_files: permit_no_files_parse(matches),
     watch: watch_arg_parse(matches)?,
+    memory: matches.get_flag("memory"),
+    save_baseline: matches.remove_one::<String>("save-baseline"),
+    baseline: matches.remove_one::<String>("baseline"),
+    regression_threshold: matches.remove_one::<u16>("regression-threshold"),
   });
 
   Ok(())
@@ -6182,6 +6743,8 @@ fn check_parse(
     doc: matches.get_flag("doc"),
     doc_only: matches.get_flag("doc-only"),
     check_js: matches.get_flag("check-js"),
+    sarif: matches.get_flag("sarif"),
+    github: matches.get_flag("github"),
   });
   flags.code_cache_enabled = !matches.get_flag("no-code-cache");
   allow_and_deny_import_parse(flags, matches)?;
@@ -6353,6 +6916,10 @@ fn coverage_parse(
   };
   let r#type = if matches.get_flag("lcov") {
     CoverageType::Lcov
+  } else if matches.get_flag("cobertura") {
+    CoverageType::Cobertura
+  } else if matches.get_flag("json") {
+    CoverageType::Json
   } else if matches.get_flag("html") {
     CoverageType::Html
   } else if matches.get_flag("detailed") {
@@ -6370,11 +6937,32 @@ fn coverage_parse(
     include,
     exclude,
     r#type,
+    thresholds: CoverageThresholds {
+      lines: matches.remove_one::<u8>("threshold-lines"),
+      branches: matches.remove_one::<u8>("threshold-branches"),
+      functions: matches.remove_one::<u8>("threshold-functions"),
+    },
+    diff: matches.remove_one::<String>("diff"),
   });
   Ok(())
 }
 
-fn deploy_parse(
+fn permission_broker_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.subcommand = DenoSubcommand::PermissionBroker(PermissionBrokerFlags {
+    socket_path: matches.remove_one::<String>("socket_path").unwrap(),
+    rules: matches.remove_one::<String>("rules").unwrap(),
+    log: matches.remove_one::<String>("log"),
+  });
+}
+
+fn cron_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.subcommand = DenoSubcommand::Cron(CronFlags {
+    state_path: matches.remove_one::<String>("state-path"),
+    json: matches.get_flag("json"),
+  });
+}
+
+fn deploy_parse(
   flags: &mut Flags,
   matches: &mut ArgMatches,
   sandbox: bool,
@@ -6691,6 +7279,36 @@ fn info_parse(
   Ok(())
 }
 
+fn sbom_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+) -> clap::error::Result<()> {
+  unstable_args_parse(flags, matches, UnstableArgsConfig::ResolutionOnly);
+  reload_arg_parse(flags, matches)?;
+  config_args_parse(flags, matches);
+  import_map_arg_parse(flags, matches);
+  ca_file_arg_parse(flags, matches);
+  unsafely_ignore_certificate_errors_parse(flags, matches)?;
+  node_modules_and_vendor_dir_arg_parse(flags, matches);
+  lock_args_parse(flags, matches);
+  no_remote_arg_parse(flags, matches);
+  no_npm_arg_parse(flags, matches);
+  allow_and_deny_import_parse(flags, matches)?;
+  let format = match matches.remove_one::<String>("format").as_deref() {
+    Some("spdx") => SbomFormat::Spdx,
+    _ => SbomFormat::CycloneDx,
+  };
+  flags.subcommand = DenoSubcommand::Sbom(SbomFlags {
+    files: matches
+      .remove_many::<String>("files")
+      .map(|files| files.collect())
+      .unwrap_or_default(),
+    format,
+    output: matches.remove_one::<String>("output"),
+  });
+  Ok(())
+}
+
 fn install_parse(
   flags: &mut Flags,
   matches: &mut ArgMatches,
@@ -6955,6 +7573,8 @@ fn lint_parse(
 
   let json = matches.get_flag("json");
   let compact = matches.get_flag("compact");
+  let sarif = matches.get_flag("sarif");
+  let github = matches.get_flag("github");
 
   flags.subcommand = DenoSubcommand::Lint(LintFlags {
     files: FileFlags {
@@ -6969,6 +7589,8 @@ fn lint_parse(
     permit_no_files: permit_no_files_parse(matches),
     json,
     compact,
+    sarif,
+    github,
     watch: watch_arg_parse(matches)?,
   });
   Ok(())
@@ -7343,18 +7965,38 @@ fn test_parse(
         "junit" => TestReporterConfig::Junit,
         "dot" => TestReporterConfig::Dot,
         "tap" => TestReporterConfig::Tap,
+        "json" => TestReporterConfig::Json,
         _ => unreachable!(),
       }
     } else {
       TestReporterConfig::Pretty
     };
 
-  if matches!(reporter, TestReporterConfig::Dot | TestReporterConfig::Tap) {
+  if matches!(
+    reporter,
+    TestReporterConfig::Dot
+      | TestReporterConfig::Tap
+      | TestReporterConfig::Json
+  ) {
     flags.log_level = Some(Level::Error);
   }
 
   let hide_stacktraces = matches.get_flag("hide-stacktraces");
 
+  let fetch_fixtures =
+    matches.remove_one::<String>("fetch-fixtures").map(|dir| {
+      let mode = match matches
+        .remove_one::<String>("fetch-fixtures-mode")
+        .as_deref()
+      {
+        Some("record") => FetchFixturesMode::Record,
+        Some("replay") | None => FetchFixturesMode::Replay,
+        Some("strict") => FetchFixturesMode::Strict,
+        _ => unreachable!(),
+      };
+      FetchFixturesFlags { dir, mode }
+    });
+
   flags.subcommand = DenoSubcommand::Test(TestFlags {
     no_run,
     doc,
@@ -7372,6 +8014,12 @@ fn test_parse(
     reporter,
     junit_path,
     hide_stacktraces,
+    fetch_fixtures,
+    shard: matches.remove_one::<TestShard>("shard"),
+    shard_durations: matches.remove_one::<String>("shard-durations"),
+    retries: matches.remove_one::<usize>("retries").unwrap_or(0),
+    failed: matches.get_flag("failed"),
+    changed: matches.remove_one::<String>("changed"),
   });
   Ok(())
 }
@@ -7413,6 +8061,26 @@ fn vendor_parse(flags: &mut Flags, _matches: &mut ArgMatches) {
   flags.subcommand = DenoSubcommand::Vendor
 }
 
+fn merge_lockfile_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  unstable_args_parse(flags, matches, UnstableArgsConfig::ResolutionOnly);
+  config_args_parse(flags, matches);
+  flags.subcommand = DenoSubcommand::MergeLockfile(MergeLockfileFlags {
+    base: matches.remove_one::<String>("base").unwrap(),
+    ours: matches.remove_one::<String>("ours").unwrap(),
+    theirs: matches.remove_one::<String>("theirs").unwrap(),
+  });
+}
+
+fn why_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  unstable_args_parse(flags, matches, UnstableArgsConfig::ResolutionOnly);
+  config_args_parse(flags, matches);
+  lock_args_parse(flags, matches);
+  flags.subcommand = DenoSubcommand::Why(WhyFlags {
+    package: matches.remove_one::<String>("package").unwrap(),
+    json: matches.get_flag("json"),
+  });
+}
+
 fn publish_parse(
   flags: &mut Flags,
   matches: &mut ArgMatches,
@@ -9167,6 +9835,8 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -9198,6 +9868,8 @@ mod tests {
           permit_no_files: true,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Some(Default::default()),
         }),
         permissions: PermissionFlags {
@@ -9232,6 +9904,8 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Some(WatchFlags {
             hmr: false,
             no_clear_screen: true,
@@ -9264,6 +9938,8 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -9287,6 +9963,8 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -9315,6 +9993,8 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -9344,6 +10024,8 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -9367,6 +10049,8 @@ mod tests {
           permit_no_files: false,
           json: true,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -9397,6 +10081,8 @@ mod tests {
           permit_no_files: false,
           json: true,
           compact: false,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
@@ -9428,12 +10114,41 @@ mod tests {
           permit_no_files: false,
           json: false,
           compact: true,
+          sarif: false,
+          github: false,
           watch: Default::default(),
         }),
         config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
         ..Flags::default()
       }
     );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--sarif"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          sarif: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--github"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          github: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--sarif", "--github"]);
+    assert!(r.is_err());
   }
 
   #[test]
@@ -9485,6 +10200,8 @@ mod tests {
           doc: false,
           doc_only: false,
           check_js: false,
+          sarif: false,
+          github: false,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -9501,6 +10218,8 @@ mod tests {
           doc: false,
           doc_only: false,
           check_js: false,
+          sarif: false,
+          github: false,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -9517,6 +10236,8 @@ mod tests {
           doc: true,
           doc_only: false,
           check_js: false,
+          sarif: false,
+          github: false,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -9533,6 +10254,8 @@ mod tests {
           doc: false,
           doc_only: true,
           check_js: false,
+          sarif: false,
+          github: false,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -9563,6 +10286,8 @@ mod tests {
             doc: false,
             doc_only: false,
             check_js: false,
+            sarif: false,
+            github: false,
           }),
           type_check_mode: TypeCheckMode::All,
           code_cache_enabled: true,
@@ -9592,12 +10317,153 @@ mod tests {
           doc: false,
           doc_only: false,
           check_js: true,
+          sarif: false,
+          github: false,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
         ..Flags::default()
       }
     );
+
+    for (flag, sarif, github) in
+      [("--sarif", true, false), ("--github", false, true)]
+    {
+      let r = flags_from_vec(svec!["deno", "check", flag, "script.ts"]);
+      assert_eq!(
+        r.unwrap(),
+        Flags {
+          subcommand: DenoSubcommand::Check(CheckFlags {
+            files: svec!["script.ts"],
+            doc: false,
+            doc_only: false,
+            check_js: false,
+            sarif,
+            github,
+          }),
+          type_check_mode: TypeCheckMode::Local,
+          code_cache_enabled: true,
+          ..Flags::default()
+        }
+      );
+    }
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "check",
+      "--sarif",
+      "--github",
+      "script.ts"
+    ]);
+    assert_eq!(
+      r.unwrap_err().kind(),
+      clap::error::ErrorKind::ArgumentConflict
+    );
+  }
+
+  #[test]
+  fn sbom() {
+    let r = flags_from_vec(svec!["deno", "sbom"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Sbom(SbomFlags {
+          files: vec![],
+          format: SbomFormat::CycloneDx,
+          output: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "sbom",
+      "--format=spdx",
+      "-o",
+      "sbom.json",
+      "--frozen",
+      "main.ts",
+      "other.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Sbom(SbomFlags {
+          files: svec!["main.ts", "other.ts"],
+          format: SbomFormat::Spdx,
+          output: Some("sbom.json".to_string()),
+        }),
+        frozen_lockfile: Some(true),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "sbom", "--format=swid"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn merge_lockfile() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "merge-lockfile",
+      ".merge_file_base",
+      ".merge_file_ours",
+      ".merge_file_theirs"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::MergeLockfile(MergeLockfileFlags {
+          base: ".merge_file_base".to_string(),
+          ours: ".merge_file_ours".to_string(),
+          theirs: ".merge_file_theirs".to_string(),
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "merge-lockfile", "base", "ours"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn why() {
+    let r = flags_from_vec(svec!["deno", "why", "chalk"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Why(WhyFlags {
+          package: "chalk".to_string(),
+          json: false,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "why",
+      "--json",
+      "--config",
+      "deno.jsonc",
+      "npm:react@18"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Why(WhyFlags {
+          package: "npm:react@18".to_string(),
+          json: true,
+        }),
+        config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "why"]);
+    assert!(r.is_err());
   }
 
   #[test]
@@ -11562,6 +12428,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         no_npm: true,
         no_remote: true,
@@ -11669,6 +12541,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         permissions: PermissionFlags {
@@ -11713,6 +12591,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -11797,6 +12681,24 @@ mod tests {
       }
     );
 
+    let r = flags_from_vec(svec!["deno", "test", "--reporter=json"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Test(TestFlags {
+          reporter: TestReporterConfig::Json,
+          ..Default::default()
+        }),
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        type_check_mode: TypeCheckMode::Local,
+        log_level: Some(Level::Error),
+        ..Flags::default()
+      }
+    );
+
     let r = flags_from_vec(svec![
       "deno",
       "test",
@@ -11851,6 +12753,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -11888,6 +12796,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -11924,6 +12838,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -11967,6 +12887,12 @@ mod tests {
           reporter: Default::default(),
           junit_path: None,
           hide_stacktraces: false,
+          fetch_fixtures: None,
+          shard: None,
+          shard_durations: None,
+          retries: 0,
+          failed: false,
+          changed: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         permissions: PermissionFlags {
@@ -12183,6 +13109,154 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_fetch_fixtures() {
+    let r = flags_from_vec(svec!["deno", "test", "--fetch-fixtures=fixtures"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Test(TestFlags {
+          fetch_fixtures: Some(FetchFixturesFlags {
+            dir: "fixtures".to_string(),
+            mode: FetchFixturesMode::Replay,
+          }),
+          ..TestFlags::default()
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "test",
+      "--fetch-fixtures=fixtures",
+      "--fetch-fixtures-mode=strict"
+    ]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Test(TestFlags {
+        fetch_fixtures: Some(FetchFixturesFlags {
+          dir: "fixtures".to_string(),
+          mode: FetchFixturesMode::Strict,
+        }),
+        ..TestFlags::default()
+      })
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "test", "--fetch-fixtures-mode=record"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_shard() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "test",
+      "--shard=2/3",
+      "--shard-durations=durations.json"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Test(TestFlags {
+          shard: Some(TestShard { index: 2, count: 3 }),
+          shard_durations: Some("durations.json".to_string()),
+          ..TestFlags::default()
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        ..Flags::default()
+      }
+    );
+
+    for invalid in ["--shard=0/3", "--shard=4/3", "--shard=1/0", "--shard=1"] {
+      let r = flags_from_vec(svec!["deno", "test", invalid]);
+      assert!(r.is_err(), "{invalid}");
+    }
+    let r = flags_from_vec(svec!["deno", "test", "--shard=1/2", "--watch"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_retries() {
+    let r = flags_from_vec(svec!["deno", "test", "--retries=2"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Test(TestFlags {
+          retries: 2,
+          ..TestFlags::default()
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "test", "--retries=-1"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_failed_and_changed() {
+    let r = flags_from_vec(svec!["deno", "test", "--failed", "--changed"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Test(TestFlags {
+          failed: true,
+          changed: Some("HEAD".to_string()),
+          ..TestFlags::default()
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "test", "--changed=main", "foo_test.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Test(TestFlags {
+          changed: Some("main".to_string()),
+          files: FileFlags {
+            include: vec!["foo_test.ts".to_string()],
+            ignore: vec![],
+          },
+          ..TestFlags::default()
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "test", "--failed", "--watch"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "test", "--changed", "--watch"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn upgrade_with_ca_file() {
     let r = flags_from_vec(svec!["deno", "upgrade", "--cert", "example.crt"]);
@@ -12695,6 +13769,8 @@ mod tests {
           exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
           r#type: CoverageType::Lcov,
           output: Some(String::from("foo.lcov")),
+          thresholds: Default::default(),
+          diff: None,
         }),
         ..Flags::default()
       }
@@ -12721,6 +13797,98 @@ mod tests {
     );
   }
 
+  #[test]
+  fn coverage_with_cobertura_and_thresholds() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "coverage",
+      "--cobertura",
+      "--output=cobertura.xml",
+      "--threshold-lines=80",
+      "--threshold-functions=100",
+      "foo.json"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Coverage(CoverageFlags {
+          files: FileFlags {
+            include: vec!["foo.json".to_string()],
+            ignore: vec![],
+          },
+          include: vec![r"^file:".to_string()],
+          exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
+          r#type: CoverageType::Cobertura,
+          output: Some(String::from("cobertura.xml")),
+          thresholds: CoverageThresholds {
+            lines: Some(80),
+            branches: None,
+            functions: Some(100),
+          },
+          diff: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "coverage", "--json", "foo.json"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Coverage(CoverageFlags {
+        files: FileFlags {
+          include: vec!["foo.json".to_string()],
+          ignore: vec![],
+        },
+        include: vec![r"^file:".to_string()],
+        exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
+        r#type: CoverageType::Json,
+        ..CoverageFlags::default()
+      })
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "coverage", "--threshold-lines=101", "foo"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "coverage", "--lcov", "--json"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "coverage", "--output=foo.xml"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn coverage_with_diff() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "coverage",
+      "--diff=main",
+      "--threshold-lines=90",
+      "--html",
+      "cov"
+    ]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Coverage(CoverageFlags {
+        files: FileFlags {
+          include: vec!["cov".to_string()],
+          ignore: vec![],
+        },
+        include: vec![r"^file:".to_string()],
+        exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
+        r#type: CoverageType::Html,
+        thresholds: CoverageThresholds {
+          lines: Some(90),
+          branches: None,
+          functions: None,
+        },
+        diff: Some("main".to_string()),
+        ..CoverageFlags::default()
+      })
+    );
+
+    let r = flags_from_vec(svec!["deno", "coverage", "--diff", "main"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn location_with_bad_scheme() {
     #[rustfmt::skip]
@@ -13153,6 +14321,10 @@ mod tests {
           },
           watch: Default::default(),
           permit_no_files: false,
+          memory: false,
+          save_baseline: None,
+          baseline: None,
+          regression_threshold: None,
         }),
         no_npm: true,
         no_remote: true,
@@ -13184,7 +14356,8 @@ mod tests {
             ignore: vec![],
           },
           watch: Some(Default::default()),
-          permit_no_files: false
+          permit_no_files: false,
+          ..BenchFlags::default()
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -13211,7 +14384,8 @@ mod tests {
             ignore: vec![],
           },
           watch: None,
-          permit_no_files: true
+          permit_no_files: true,
+          ..BenchFlags::default()
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -13223,6 +14397,45 @@ mod tests {
     );
   }
 
+  #[test]
+  fn bench_memory() {
+    let r = flags_from_vec(svec!["deno", "bench", "--memory", "--json"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Bench(BenchFlags {
+        json: true,
+        memory: true,
+        ..BenchFlags::default()
+      })
+    );
+  }
+
+  #[test]
+  fn bench_baselines() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "bench",
+      "--save-baseline=main",
+      "--baseline=previous",
+      "--regression-threshold=5"
+    ]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Bench(BenchFlags {
+        save_baseline: Some("main".to_string()),
+        baseline: Some("previous".to_string()),
+        regression_threshold: Some(5),
+        ..BenchFlags::default()
+      })
+    );
+
+    let r = flags_from_vec(svec!["deno", "bench", "--regression-threshold=5"]);
+    assert!(r.is_err());
+    let r =
+      flags_from_vec(svec!["deno", "bench", "--watch", "--baseline=main"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn run_with_check() {
     let r = flags_from_vec(svec!["deno", "run", "--check", "script.ts",]);
@@ -14772,6 +15985,60 @@ Usage: deno repl [OPTIONS] [-- [ARGS]...]\n"
     }
   }
 
+  #[test]
+  fn permission_broker_subcommand() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "permission-broker",
+      "--rules=rules.json",
+      "--log",
+      "decisions.jsonl",
+      "/tmp/broker.sock"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::PermissionBroker(PermissionBrokerFlags {
+          socket_path: "/tmp/broker.sock".to_string(),
+          rules: "rules.json".to_string(),
+          log: Some("decisions.jsonl".to_string()),
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "permission-broker", "/tmp/b.sock"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn cron_subcommand() {
+    let r = flags_from_vec(svec!["deno", "cron"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Cron(CronFlags {
+          state_path: None,
+          json: false,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "cron", "--state-path=crons.db", "--json"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Cron(CronFlags {
+          state_path: Some("crons.db".to_string()),
+          json: true,
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
   #[test]
   fn clean_subcommand() {
     let cases = [
@@ -15077,6 +16344,8 @@ Usage: deno repl [OPTIONS] [-- [ARGS]...]\n"
           doc: false,
           doc_only: false,
           check_js: false,
+          sarif: false,
+          github: false,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
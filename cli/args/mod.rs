@@ -48,6 +48,7 @@ use deno_npm_installer::LifecycleScriptsConfig;
 use deno_npm_installer::graph::NpmCachingStrategy;
 use deno_path_util::resolve_url_or_path;
 use deno_resolver::factory::resolve_jsr_url;
+use deno_runtime::deno_fetch::FetchFixtures;
 use deno_runtime::deno_node::ops::ipc::ChildIpcSerialization;
 use deno_runtime::deno_permissions::AllowRunDescriptor;
 use deno_runtime::deno_permissions::PathDescriptor;
@@ -86,6 +87,10 @@ pub struct WorkspaceBenchOptions {
   pub json: bool,
   pub no_run: bool,
   pub permit_no_files: bool,
+  pub memory: bool,
+  pub save_baseline: Option<String>,
+  pub baseline: Option<String>,
+  pub regression_threshold: Option<u16>,
 }
 
 impl WorkspaceBenchOptions {
@@ -95,6 +100,10 @@ impl WorkspaceBenchOptions {
       json: bench_flags.json,
       no_run: bench_flags.no_run,
       permit_no_files: bench_flags.permit_no_files,
+      memory: bench_flags.memory,
+      save_baseline: bench_flags.save_baseline.clone(),
+      baseline: bench_flags.baseline.clone(),
+      regression_threshold: bench_flags.regression_threshold,
     }
   }
 }
@@ -207,6 +216,11 @@ pub struct WorkspaceTestOptions {
   pub reporter: TestReporterConfig,
   pub junit_path: Option<String>,
   pub hide_stacktraces: bool,
+  pub shard: Option<TestShard>,
+  pub shard_durations: Option<String>,
+  pub retries: usize,
+  pub failed: bool,
+  pub changed: Option<String>,
 }
 
 impl WorkspaceTestOptions {
@@ -223,6 +237,11 @@ impl WorkspaceTestOptions {
       reporter: test_flags.reporter,
       junit_path: test_flags.junit_path.clone(),
       hide_stacktraces: test_flags.hide_stacktraces,
+      shard: test_flags.shard,
+      shard_durations: test_flags.shard_durations.clone(),
+      retries: test_flags.retries,
+      failed: test_flags.failed,
+      changed: test_flags.changed.clone(),
     }
   }
 }
@@ -247,6 +266,8 @@ pub enum LintReporterKind {
   Pretty,
   Json,
   Compact,
+  Sarif,
+  Github,
 }
 
 #[derive(Clone, Debug)]
@@ -263,6 +284,10 @@ impl WorkspaceLintOptions {
       Some(LintReporterKind::Json)
     } else if lint_flags.compact {
       Some(LintReporterKind::Compact)
+    } else if lint_flags.sarif {
+      Some(LintReporterKind::Sarif)
+    } else if lint_flags.github {
+      Some(LintReporterKind::Github)
     } else {
       None
     };
@@ -273,6 +298,8 @@ impl WorkspaceLintOptions {
         Some("json") => Some(LintReporterKind::Json),
         Some("compact") => Some(LintReporterKind::Compact),
         Some("pretty") => Some(LintReporterKind::Pretty),
+        Some("sarif") => Some(LintReporterKind::Sarif),
+        Some("github") => Some(LintReporterKind::Github),
         Some(_) => {
           bail!("Invalid lint report type in config file")
         }
@@ -988,6 +1015,24 @@ impl CliOptions {
     }
   }
 
+  pub fn fetch_fixtures(&self) -> Option<FetchFixtures> {
+    match &self.flags.subcommand {
+      DenoSubcommand::Test(TestFlags {
+        fetch_fixtures: Some(flags),
+        ..
+      }) => {
+        use deno_runtime::deno_fetch::FetchFixturesMode as Mode;
+        let mode = match flags.mode {
+          FetchFixturesMode::Record => Mode::Record,
+          FetchFixturesMode::Replay => Mode::Replay,
+          FetchFixturesMode::Strict => Mode::Strict,
+        };
+        Some(FetchFixtures::new(self.initial_cwd.join(&flags.dir), mode))
+      }
+      _ => None,
+    }
+  }
+
   pub fn cpu_prof_dir(&self) -> Option<PathBuf> {
     self.flags.cpu_prof.as_ref().map(|f| {
       f.dir
@@ -14,6 +14,7 @@ use super::fast_check::FAST_CHECK_CACHE_DB;
 use super::incremental::INCREMENTAL_CACHE_DB;
 use super::module_info::MODULE_INFO_CACHE_DB;
 use super::node::NODE_ANALYSIS_CACHE_DB;
+use super::test_results::TEST_RESULTS_CACHE_DB;
 use crate::cache::DenoDirProvider;
 
 pub struct Caches {
@@ -25,6 +26,7 @@ pub struct Caches {
   node_analysis_db: OnceCell<CacheDB>,
   type_checking_cache_db: OnceCell<CacheDB>,
   code_cache_db: OnceCell<CacheDB>,
+  test_results_cache_db: OnceCell<CacheDB>,
 }
 
 impl Caches {
@@ -38,6 +40,7 @@ impl Caches {
       node_analysis_db: Default::default(),
       type_checking_cache_db: Default::default(),
       code_cache_db: Default::default(),
+      test_results_cache_db: Default::default(),
     }
   }
 
@@ -140,4 +143,16 @@ impl Caches {
         .map(|dir| dir.code_cache_db_file_path()),
     )
   }
+
+  pub fn test_results_cache_db(&self) -> CacheDB {
+    Self::make_db(
+      &self.test_results_cache_db,
+      &TEST_RESULTS_CACHE_DB,
+      self
+        .dir_provider
+        .get_or_create()
+        .ok()
+        .map(|dir| dir.test_results_cache_db_file_path()),
+    )
+  }
 }
@@ -8,6 +8,7 @@ mod fast_check;
 mod incremental;
 mod module_info;
 mod node;
+mod test_results;
 
 pub type DenoDir = deno_resolver::cache::DenoDir<CliSys>;
 pub type DenoDirProvider = deno_resolver::cache::DenoDirProvider<CliSys>;
@@ -22,6 +23,7 @@ pub use fast_check::FastCheckCache;
 pub use incremental::IncrementalCache;
 pub use module_info::ModuleInfoCache;
 pub use node::SqliteNodeAnalysisCache;
+pub use test_results::TestResultsCache;
 
 use crate::sys::CliSys;
 
@@ -0,0 +1,153 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use deno_core::ModuleSpecifier;
+use deno_core::error::AnyError;
+use deno_runtime::deno_webstorage::rusqlite::params;
+
+use super::cache_db::CacheDB;
+use super::cache_db::CacheDBConfiguration;
+use super::cache_db::CacheFailure;
+
+pub static TEST_RESULTS_CACHE_DB: CacheDBConfiguration = CacheDBConfiguration {
+  table_initializer: concat!(
+    "CREATE TABLE IF NOT EXISTS failedtests (",
+    "specifier TEXT NOT NULL,",
+    "test_name TEXT NOT NULL,",
+    "PRIMARY KEY (specifier, test_name)",
+    ");"
+  ),
+  on_version_change: "DELETE FROM failedtests;",
+  preheat_queries: &[],
+  on_failure: CacheFailure::Blackhole,
+};
+
+/// Remembers the tests that failed in the last run of each test module, so
+/// that `deno test --failed` can run them again.
+///
+/// An empty test name means that the module itself failed, for example
+/// because of an uncaught error while loading it.
+#[derive(Debug, Clone)]
+pub struct TestResultsCache {
+  conn: CacheDB,
+}
+
+impl TestResultsCache {
+  pub fn new(conn: CacheDB) -> Self {
+    Self { conn }
+  }
+
+  fn ensure_ok<T: Default>(res: Result<T, AnyError>) -> T {
+    match res {
+      Ok(x) => x,
+      Err(err) => {
+        // should never error here, but if it ever does don't fail
+        if cfg!(debug_assertions) {
+          panic!("Error using test results cache: {err:#}");
+        } else {
+          log::debug!("Error using test results cache: {:#}", err);
+        }
+        T::default()
+      }
+    }
+  }
+
+  /// Returns the names of the tests of `specifier` that failed in the last
+  /// run.
+  pub fn failed_tests(&self, specifier: &ModuleSpecifier) -> Vec<String> {
+    Self::ensure_ok(self.failed_tests_result(specifier))
+  }
+
+  pub fn set_failed(&self, specifier: &str, test_name: &str) {
+    Self::ensure_ok(self.set_failed_result(specifier, test_name))
+  }
+
+  pub fn set_passed(&self, specifier: &str, test_name: &str) {
+    Self::ensure_ok(self.set_passed_result(specifier, test_name))
+  }
+
+  /// Forgets the failures of `specifier`, including those of tests that
+  /// were renamed or removed since.
+  pub fn clear(&self, specifier: &str) {
+    Self::ensure_ok(self.clear_result(specifier))
+  }
+
+  fn failed_tests_result(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Result<Vec<String>, AnyError> {
+    self.conn.with_connection(|conn| {
+      let mut stmt = conn.prepare_cached(
+        "SELECT test_name FROM failedtests WHERE specifier=?1",
+      )?;
+      let names = stmt
+        .query_map(params![specifier.as_str()], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+      Ok(names)
+    })
+  }
+
+  fn set_failed_result(
+    &self,
+    specifier: &str,
+    test_name: &str,
+  ) -> Result<(), AnyError> {
+    let sql = "
+      INSERT OR REPLACE INTO
+        failedtests (specifier, test_name)
+      VALUES
+        (?1, ?2)";
+    self.conn.execute(sql, params![specifier, test_name])?;
+    Ok(())
+  }
+
+  fn set_passed_result(
+    &self,
+    specifier: &str,
+    test_name: &str,
+  ) -> Result<(), AnyError> {
+    let sql = "DELETE FROM failedtests WHERE specifier=?1 AND test_name=?2";
+    self.conn.execute(sql, params![specifier, test_name])?;
+    Ok(())
+  }
+
+  fn clear_result(&self, specifier: &str) -> Result<(), AnyError> {
+    let sql = "DELETE FROM failedtests WHERE specifier=?1";
+    self.conn.execute(sql, params![specifier])?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  pub fn test_results_cache_general_use() {
+    let conn = CacheDB::in_memory(&TEST_RESULTS_CACHE_DB, "1.0.0");
+    let cache = TestResultsCache::new(conn);
+    let specifier = ModuleSpecifier::parse("file:///a_test.ts").unwrap();
+    let other = ModuleSpecifier::parse("file:///b_test.ts").unwrap();
+
+    assert!(cache.failed_tests(&specifier).is_empty());
+    cache.set_failed(specifier.as_str(), "one");
+    cache.set_failed(specifier.as_str(), "two");
+    cache.set_failed(specifier.as_str(), "two");
+    cache.set_failed(other.as_str(), "");
+    let mut failed = cache.failed_tests(&specifier);
+    failed.sort();
+    assert_eq!(failed, vec!["one", "two"]);
+    assert_eq!(cache.failed_tests(&other), vec![""]);
+
+    cache.set_passed(specifier.as_str(), "one");
+    assert_eq!(cache.failed_tests(&specifier), vec!["two"]);
+
+    cache.clear(specifier.as_str());
+    assert!(cache.failed_tests(&specifier).is_empty());
+    assert_eq!(cache.failed_tests(&other), vec![""]);
+
+    // changing the cli version clears the cache
+    let conn = cache.conn.recreate_with_version("2.0.0");
+    let cache = TestResultsCache::new(conn);
+    assert!(cache.failed_tests(&specifier).is_empty());
+  }
+}
@@ -639,6 +639,7 @@ impl CliFactory {
             | DenoSubcommand::Compile { .. }
             | DenoSubcommand::Completions { .. }
             | DenoSubcommand::Coverage { .. }
+            | DenoSubcommand::Cron { .. }
             | DenoSubcommand::Deploy { .. }
             | DenoSubcommand::Doc { .. }
             | DenoSubcommand::Eval { .. }
@@ -649,12 +650,16 @@ impl CliFactory {
             | DenoSubcommand::Jupyter { .. }
             | DenoSubcommand::Lsp
             | DenoSubcommand::Lint { .. }
+            | DenoSubcommand::MergeLockfile { .. }
             | DenoSubcommand::Repl { .. }
             | DenoSubcommand::Run { .. }
             | DenoSubcommand::Serve { .. }
             | DenoSubcommand::Task { .. }
             | DenoSubcommand::Test { .. }
             | DenoSubcommand::Outdated { .. }
+            | DenoSubcommand::PermissionBroker { .. }
+            | DenoSubcommand::Sbom { .. }
+            | DenoSubcommand::Why { .. }
             | DenoSubcommand::Types
             | DenoSubcommand::Upgrade { .. }
             | DenoSubcommand::Vendor
@@ -1206,6 +1211,7 @@ impl CliFactory {
       unsafely_ignore_certificate_errors: cli_options
         .unsafely_ignore_certificate_errors()
         .clone(),
+      fetch_fixtures: cli_options.fetch_fixtures(),
       node_ipc_init: cli_options.node_ipc_init()?,
       serve_port: cli_options.serve_port(),
       serve_host: cli_options.serve_host(),
@@ -1410,6 +1416,14 @@ fn new_workspace_factory_options(
           | DenoSubcommand::Uninstall(_)
       ),
     frozen_lockfile: flags.frozen_lockfile,
+    // only a plain `deno install` seeds the lockfile from the one of
+    // another package manager
+    import_npm_lockfile: matches!(
+      flags.subcommand,
+      DenoSubcommand::Install(InstallFlags::Local(
+        InstallFlagsLocal::TopLevel(_)
+      ))
+    ),
     lock_arg: flags.lock.as_ref().map(|l| initial_cwd.join(l)),
     lockfile_skip_write: flags.internal.lockfile_skip_write,
     no_npm: flags.no_npm,
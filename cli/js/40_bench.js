@@ -14,15 +14,18 @@ const {
   op_bench_get_origin,
   op_dispatch_bench_event,
   op_bench_now,
+  op_bench_heap_stats,
 } = core.ops;
 const {
   Array,
   ArrayPrototypeSort,
   ArrayPrototypeSlice,
   Error,
+  Float64Array,
   MathMax,
   MathMin,
   MathCeil,
+  MathSqrt,
   SymbolToStringTag,
   TypeError,
 } = primordials;
@@ -210,6 +213,22 @@ function compareMeasurements(a, b) {
   return 0;
 }
 
+/** Sample standard deviation of the sorted measurements. */
+function standardDeviation(all, allLength) {
+  if (allLength < 2) return 0;
+
+  let sum = 0;
+  for (let i = 0; i < allLength; i++) sum += all[i];
+  const mean = sum / allLength;
+
+  let squares = 0;
+  for (let i = 0; i < allLength; i++) {
+    const diff = all[i] - mean;
+    squares += diff * diff;
+  }
+  return MathSqrt(squares / (allLength - 1));
+}
+
 function benchStats(
   n,
   highPrecision,
@@ -219,6 +238,7 @@ function benchStats(
   max,
   all,
   allLength,
+  heap,
 ) {
   return {
     n,
@@ -229,8 +249,73 @@ function benchStats(
     p995: all[MathCeil(allLength * (99.5 / 100)) - 1],
     p999: all[MathCeil(allLength * (99.9 / 100)) - 1],
     avg: !highPrecision ? (avg / n) : MathCeil(avg / n),
+    sd: standardDeviation(all, allLength),
     highPrecision,
     usedExplicitTimers,
+    heap,
+  };
+}
+
+// [total allocated bytes, used heap size, gc count, gc time in ns]
+const heapStatsBefore = new Float64Array(4);
+const heapStatsAfter = new Float64Array(4);
+
+// cap the untimed iterations used for heap statistics
+const heapMaxIterations = 10_000;
+
+function noop() {}
+async function asyncNoop() {}
+
+/**
+ * Runs `fn` for `iterations` without timing it and returns the allocated
+ * bytes, leaving the heap statistics of the run in `heapStatsBefore` and
+ * `heapStatsAfter`.
+ */
+async function allocatedBytes(fn, async, context, iterations) {
+  op_bench_heap_stats(heapStatsBefore, false);
+  if (!async) {
+    for (let i = 0; i < iterations; i++) {
+      fn(context);
+      currentBenchUserExplicitStart = null;
+      currentBenchUserExplicitEnd = null;
+    }
+  } else {
+    for (let i = 0; i < iterations; i++) {
+      await fn(context);
+      currentBenchUserExplicitStart = null;
+      currentBenchUserExplicitEnd = null;
+    }
+  }
+  op_bench_heap_stats(heapStatsAfter, false);
+  return heapStatsAfter[0] - heapStatsBefore[0];
+}
+
+/**
+ * Heap statistics of a separate, untimed pass over the benchmark. The
+ * allocations of the harness are measured with an empty function and
+ * subtracted. Returns `undefined` unless `deno bench --memory` is used.
+ */
+async function benchHeapStats(fn, desc, context, iterations) {
+  if (!op_bench_heap_stats(heapStatsBefore, true)) {
+    return undefined;
+  }
+  const harnessAllocated = await allocatedBytes(
+    desc.async ? asyncNoop : noop,
+    desc.async,
+    context,
+    iterations,
+  );
+  op_bench_heap_stats(heapStatsBefore, true);
+  const usedHeapSize = heapStatsBefore[1];
+  const allocated = await allocatedBytes(fn, desc.async, context, iterations);
+  const gcCount = heapStatsAfter[2] - heapStatsBefore[2];
+  const gcTime = heapStatsAfter[3] - heapStatsBefore[3];
+  op_bench_heap_stats(heapStatsAfter, true);
+  return {
+    allocatedPerIter: MathMax(0, allocated - harnessAllocated) / iterations,
+    gcCount,
+    gcTime,
+    retained: MathMax(0, heapStatsAfter[1] - usedHeapSize),
   };
 }
 
@@ -400,6 +485,16 @@ async function benchMeasure(fn, desc, context) {
     }
   }
 
+  const calls = wavg > lowPrecisionThresholdInNs
+    ? n
+    : n * lowPrecisionThresholdInNs;
+  const heap = await benchHeapStats(
+    fn,
+    desc,
+    context,
+    MathMin(calls, heapMaxIterations),
+  );
+
   const allLength = MathMin(allMaxLength, n);
   const allSlice = ArrayPrototypeSlice(all, 0, allLength);
   ArrayPrototypeSort(allSlice, compareMeasurements);
@@ -413,6 +508,7 @@ async function benchMeasure(fn, desc, context) {
     max,
     allSlice,
     allLength,
+    heap,
   );
 }
 
@@ -21,6 +21,7 @@ const {
   Error,
   Map,
   NumberIsNaN,
+  NumberIsSafeInteger,
   MapPrototypeGet,
   MapPrototypeSet,
   SafeArrayIterator,
@@ -50,6 +51,7 @@ const DenoNs = globalThis.Deno;
  *   sanitizeResources: boolean,
  *   sanitizeExit: boolean,
  *   permissions: PermissionOptions,
+ *   retry?: number,
  * }} TestDescription
  *
  * @typedef {{
@@ -170,6 +172,10 @@ function wrapOuter(fn, desc) {
       if (desc.ignore) {
         return "ignored";
       }
+      // Forget the steps of a previous attempt when a test is retried.
+      const state = MapPrototypeGet(testStates, desc.id);
+      state.children = [];
+      state.completed = false;
       return await fn(desc) ?? "ok";
     } catch (error) {
       return { failed: { jsError: core.destructureError(error) } };
@@ -344,6 +350,17 @@ function testInner(
   // Delete this prop in case the user passed it. It's used to detect steps.
   delete testDesc.parent;
 
+  // The retry count is passed to `op_register_test` as a 32-bit integer.
+  if (
+    testDesc.retry !== undefined &&
+    (!NumberIsSafeInteger(testDesc.retry) || testDesc.retry < 0 ||
+      testDesc.retry > 0x7fffffff)
+  ) {
+    throw new TypeError(
+      `Invalid 'retry' option: expected an integer between 0 and 2147483647, received ${testDesc.retry}`,
+    );
+  }
+
   if (cachedOrigin == undefined) {
     cachedOrigin = op_test_get_origin();
   }
@@ -367,6 +384,7 @@ function testInner(
     testDesc.location.columnNumber,
     registerTestIdRetBufU8,
     testDesc.sanitizeOnly ?? true,
+    testDesc.retry ?? -1,
   );
   testDesc.id = registerTestIdRetBuf[0];
   testDesc.origin = cachedOrigin;
@@ -188,12 +188,24 @@ async fn run_subcommand(
         Arc::new(flags),
         coverage_flags.files.include,
         coverage_flags.files.ignore,
-        coverage_flags.include,
-        coverage_flags.exclude,
-        coverage_flags.output,
+        tools::coverage::CoverFilesOptions {
+          include: coverage_flags.include,
+          exclude: coverage_flags.exclude,
+          output: coverage_flags.output,
+          thresholds: Some(coverage_flags.thresholds),
+          diff: coverage_flags.diff,
+        },
         &[&*reporter],
       )
     }),
+    DenoSubcommand::Cron(cron_flags) => {
+      spawn_subcommand(async move { tools::cron::cron(cron_flags) })
+    }
+    DenoSubcommand::PermissionBroker(broker_flags) => {
+      spawn_subcommand(async move {
+        tools::permission_broker::permission_broker(broker_flags).await
+      })
+    }
     DenoSubcommand::Fmt(fmt_flags) => spawn_subcommand(async move {
       tools::fmt::format(Arc::new(flags), fmt_flags).await
     }),
@@ -203,6 +215,15 @@ async fn run_subcommand(
     DenoSubcommand::Info(info_flags) => spawn_subcommand(async {
       tools::info::info(Arc::new(flags), info_flags).await
     }),
+    DenoSubcommand::Sbom(sbom_flags) => spawn_subcommand(async {
+      tools::sbom::sbom(Arc::new(flags), sbom_flags).await
+    }),
+    DenoSubcommand::MergeLockfile(merge_flags) => spawn_subcommand(async {
+      tools::merge_lockfile::merge_lockfile(Arc::new(flags), merge_flags).await
+    }),
+    DenoSubcommand::Why(why_flags) => spawn_subcommand(async {
+      tools::why::why(Arc::new(flags), why_flags).await
+    }),
     DenoSubcommand::Install(install_flags) => spawn_subcommand(async {
       tools::installer::install_command(Arc::new(flags), install_flags).await
     }),
@@ -806,6 +827,11 @@ async fn resolve_flags_and_init(
     );
   }
 
+  if let Ok(output) = std::env::var("DENO_GENERATE_PERMISSIONS") {
+    let cwd = resolve_cwd(flags.initial_cwd.as_deref())?.into_owned();
+    tools::permission_audit::init(cwd.join(output), cwd);
+  }
+
   if let Ok(audit_target) = std::env::var("DENO_AUDIT_PERMISSIONS") {
     use deno_runtime::deno_permissions::AuditSink;
 
@@ -261,6 +261,7 @@ pub struct LibMainWorkerOptions {
   pub origin_data_folder_path: Option<PathBuf>,
   pub seed: Option<u64>,
   pub unsafely_ignore_certificate_errors: Option<Vec<String>>,
+  pub fetch_fixtures: Option<deno_runtime::deno_fetch::FetchFixtures>,
   pub skip_op_registration: bool,
   pub node_ipc_init: Option<(i64, ChildIpcSerialization)>,
   pub no_legacy_abort: bool,
@@ -467,6 +468,7 @@ impl<TSys: DenoLibSys> LibWorkerFactorySharedState<TSys> {
           .options
           .unsafely_ignore_certificate_errors
           .clone(),
+        fetch_fixtures: shared.options.fetch_fixtures.clone(),
         seed: shared.options.seed,
         create_web_worker_cb,
         format_js_error_fn: Some(Arc::new(move |a| {
@@ -695,6 +697,7 @@ impl<TSys: DenoLibSys> LibMainWorkerFactory<TSys> {
         .options
         .unsafely_ignore_certificate_errors
         .clone(),
+      fetch_fixtures: shared.options.fetch_fixtures.clone(),
       seed: shared.options.seed,
       format_js_error_fn: Some(Arc::new(move |e| {
         format_js_error(e, maybe_initial_cwd.as_ref())
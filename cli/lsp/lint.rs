@@ -187,6 +187,7 @@ impl LoadPluginsThread {
               lsp_log!("pluggin runner - {}", msg);
             }),
             request.exclude,
+            1,
           )
           .await;
           request.response_tx.send(result).unwrap();
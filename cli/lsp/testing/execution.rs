@@ -331,6 +331,7 @@ impl TestRun {
               filter,
               shuffle: None,
               trace_leaks: false,
+              retries: 0,
             },
           ))
         }
@@ -388,6 +389,10 @@ impl TestRun {
                 let description = tests.read().get(&id).unwrap().clone();
                 match &result {
                   test::TestResult::Ok => summary.passed += 1,
+                  test::TestResult::Flaky(_) => {
+                    summary.passed += 1;
+                    summary.flaky += 1;
+                  }
                   test::TestResult::Ignored => summary.ignored += 1,
                   test::TestResult::Failed(error) => {
                     summary.failed += 1;
@@ -669,7 +674,7 @@ impl LspTestReporter {
   ) {
     self.current_test = None;
     match result {
-      test::TestResult::Ok => {
+      test::TestResult::Ok | test::TestResult::Flaky(_) => {
         let desc = self.tests.get(&desc.id).unwrap();
         self.progress(lsp_custom::TestRunProgressMessage::Passed {
           test: desc.as_test_identifier(&self.tests),
@@ -1,7 +1,13 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::rc::Rc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
 
 use deno_core::ModuleSpecifier;
 use deno_core::OpState;
@@ -30,16 +36,27 @@ deno_core::extension!(deno_bench,
     op_bench_get_origin,
     op_dispatch_bench_event,
     op_bench_now,
+    op_bench_heap_stats,
   ],
   options = {
     sender: UnboundedSender<BenchEvent>,
+    memory: bool,
   },
   state = |state, options| {
     state.put(options.sender);
     state.put(BenchContainer::default());
+    state.put(BenchHeapStatsOptions {
+      enabled: options.memory,
+      gc_callbacks_installed: false,
+    });
   },
 );
 
+struct BenchHeapStatsOptions {
+  enabled: bool,
+  gc_callbacks_installed: bool,
+}
+
 #[op2]
 #[string]
 fn op_bench_get_origin(state: &mut OpState) -> String {
@@ -153,3 +170,76 @@ fn op_bench_now(state: &mut OpState) -> Result<u64, std::num::TryFromIntError> {
   let ns_u64 = u64::try_from(ns)?;
   Ok(ns_u64)
 }
+
+thread_local! {
+  /// Number of garbage collections and the time spent in them, on the
+  /// isolate of the current thread.
+  static GC_STATS: Cell<(u64, Duration)> =
+    const { Cell::new((0, Duration::ZERO)) };
+  static GC_START: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+extern "C" fn gc_prologue_callback(
+  _isolate: v8::UnsafeRawIsolatePtr,
+  _type: v8::GCType,
+  _flags: v8::GCCallbackFlags,
+  _data: *mut c_void,
+) {
+  GC_START.set(Some(Instant::now()));
+}
+
+extern "C" fn gc_epilogue_callback(
+  _isolate: v8::UnsafeRawIsolatePtr,
+  _type: v8::GCType,
+  _flags: v8::GCCallbackFlags,
+  _data: *mut c_void,
+) {
+  if let Some(start) = GC_START.take() {
+    let (count, time) = GC_STATS.get();
+    GC_STATS.set((count + 1, time + start.elapsed()));
+  }
+}
+
+/// Writes the total allocated bytes, the used heap size, the number of
+/// garbage collections and the nanoseconds spent in them to `out`, after a
+/// full garbage collection if `collect_garbage` is set. Returns `false`
+/// without writing anything when `deno bench --memory` isn't used.
+#[op2]
+fn op_bench_heap_stats(
+  state: Rc<RefCell<OpState>>,
+  scope: &mut v8::PinScope<'_, '_>,
+  #[buffer] out: &mut [f64],
+  collect_garbage: bool,
+) -> bool {
+  {
+    let mut state = state.borrow_mut();
+    let options = state.borrow_mut::<BenchHeapStatsOptions>();
+    if !options.enabled {
+      return false;
+    }
+    if !options.gc_callbacks_installed {
+      scope.add_gc_prologue_callback(
+        gc_prologue_callback,
+        std::ptr::null_mut(),
+        v8::GCType::kGCTypeAll,
+      );
+      scope.add_gc_epilogue_callback(
+        gc_epilogue_callback,
+        std::ptr::null_mut(),
+        v8::GCType::kGCTypeAll,
+      );
+      options.gc_callbacks_installed = true;
+    }
+  }
+
+  if collect_garbage {
+    scope.low_memory_notification();
+  }
+  let stats = scope.get_heap_statistics();
+  let (gc_count, gc_time) = GC_STATS.get();
+  out[0] = stats.total_allocated_bytes() as f64;
+  out[1] = stats.used_heap_size() as f64;
+  out[2] = gc_count as f64;
+  out[3] = gc_time.as_nanos() as f64;
+  true
+}
@@ -12,6 +12,7 @@ use deno_runtime::deno_permissions::ChildPermissionsArg;
 use deno_runtime::deno_permissions::PermissionsContainer;
 use uuid::Uuid;
 
+use crate::tools::test::BufferedStepEvents;
 use crate::tools::test::TestContainer;
 use crate::tools::test::TestDescription;
 use crate::tools::test::TestEvent;
@@ -106,6 +107,7 @@ fn op_register_test(
   #[smi] column_number: u32,
   #[buffer] ret_buf: &mut [u8],
   sanitize_only: bool,
+  #[smi] retry: i32,
 ) -> Result<(), JsErrorBox> {
   if ret_buf.len() != 4 {
     return Err(JsErrorBox::type_error(format!(
@@ -123,6 +125,7 @@ fn op_register_test(
     sanitize_only,
     sanitize_ops,
     sanitize_resources,
+    retry: usize::try_from(retry).ok(),
     origin: origin.clone(),
     location: TestLocation {
       file_name,
@@ -184,15 +187,23 @@ fn op_register_test_step(
     root_id,
     root_name,
   };
-  let sender = state.borrow_mut::<TestEventSender>();
-  sender.send(TestEvent::StepRegister(description)).ok();
+  send_step_event(state, TestEvent::StepRegister(description));
   id
 }
 
+/// Sends a step event, unless the steps of the current test attempt are
+/// held back because the test may still be retried.
+fn send_step_event(state: &mut OpState, event: TestEvent) {
+  if let Some(buffered) = state.try_borrow_mut::<BufferedStepEvents>() {
+    buffered.push(event);
+  } else {
+    state.borrow_mut::<TestEventSender>().send(event).ok();
+  }
+}
+
 #[op2(fast)]
 fn op_test_event_step_wait(state: &mut OpState, #[smi] id: usize) {
-  let sender = state.borrow_mut::<TestEventSender>();
-  sender.send(TestEvent::StepWait(id)).ok();
+  send_step_event(state, TestEvent::StepWait(id));
 }
 
 #[op2(fast)]
@@ -201,10 +212,10 @@ fn op_test_event_step_result_ok(
   #[smi] id: usize,
   #[smi] duration: u64,
 ) {
-  let sender = state.borrow_mut::<TestEventSender>();
-  sender
-    .send(TestEvent::StepResult(id, TestStepResult::Ok, duration))
-    .ok();
+  send_step_event(
+    state,
+    TestEvent::StepResult(id, TestStepResult::Ok, duration),
+  );
 }
 
 #[op2(fast)]
@@ -213,10 +224,10 @@ fn op_test_event_step_result_ignored(
   #[smi] id: usize,
   #[smi] duration: u64,
 ) {
-  let sender = state.borrow_mut::<TestEventSender>();
-  sender
-    .send(TestEvent::StepResult(id, TestStepResult::Ignored, duration))
-    .ok();
+  send_step_event(
+    state,
+    TestEvent::StepResult(id, TestStepResult::Ignored, duration),
+  );
 }
 
 #[op2]
@@ -226,12 +237,8 @@ fn op_test_event_step_result_failed(
   #[serde] failure: TestFailure,
   #[smi] duration: u64,
 ) {
-  let sender = state.borrow_mut::<TestEventSender>();
-  sender
-    .send(TestEvent::StepResult(
-      id,
-      TestStepResult::Failed(failure),
-      duration,
-    ))
-    .ok();
+  send_step_event(
+    state,
+    TestEvent::StepResult(id, TestStepResult::Failed(failure), duration),
+  );
 }
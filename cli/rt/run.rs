@@ -1065,6 +1065,7 @@ pub async fn run(
     seed: metadata.seed,
     unsafely_ignore_certificate_errors: metadata
       .unsafely_ignore_certificate_errors,
+    fetch_fixtures: None,
     node_ipc_init: None,
     serve_port: None,
     serve_host: None,
@@ -0,0 +1,390 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Named baselines of benchmark results, saved with `--save-baseline` and
+//! compared against with `--baseline`.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::url::Url;
+use deno_lib::version::DENO_VERSION_INFO;
+use deno_path_util::fs::atomic_write_file_with_retries;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::BenchDescription;
+use super::BenchStats;
+use super::mitata;
+use crate::sys::CliSys;
+
+const BASELINE_VERSION: u8 = 1;
+pub const DEFAULT_REGRESSION_THRESHOLD: u16 = 10;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchBaseline {
+  version: u8,
+  runtime: String,
+  cpu: String,
+  benches: Vec<BaselineBench>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineBench {
+  /// The bench module, relative to the working directory.
+  origin: String,
+  group: Option<String>,
+  name: String,
+  stats: BenchStats,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BaselineOptions {
+  pub dir: PathBuf,
+  pub cwd: PathBuf,
+  /// Name to save the results as.
+  pub save: Option<String>,
+  /// Name and contents of the baseline to compare the results against.
+  pub compare: Option<(String, Arc<BenchBaseline>)>,
+  pub regression_threshold: u16,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineComparison {
+  pub name: String,
+  pub regression_threshold: u16,
+  pub benches: Vec<BenchComparison>,
+}
+
+impl BaselineComparison {
+  pub fn regressions(&self) -> usize {
+    self.benches.iter().filter(|b| b.regressed).count()
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchComparison {
+  pub origin: String,
+  pub group: Option<String>,
+  pub name: String,
+  pub baseline_avg: f64,
+  pub avg: f64,
+  /// Change of the average time in percent. Positive means slower.
+  pub change: f64,
+  /// Whether the change is statistically significant.
+  pub significant: bool,
+  /// Whether the bench got significantly slower by more than the threshold.
+  pub regressed: bool,
+}
+
+/// Ensures that the name can be used as a file name.
+pub fn validate_name(name: &str) -> Result<(), AnyError> {
+  let is_valid = !name.is_empty()
+    && name
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+  if !is_valid {
+    bail!(
+      "Invalid baseline name \"{}\". Only letters, digits, '-', '_' and '.' are allowed.",
+      name
+    );
+  }
+  Ok(())
+}
+
+fn baseline_path(dir: &Path, name: &str) -> Result<PathBuf, AnyError> {
+  validate_name(name)?;
+  Ok(dir.join(format!("{name}.json")))
+}
+
+/// Loads the baseline previously saved as `name`.
+pub fn load(dir: &Path, name: &str) -> Result<BenchBaseline, AnyError> {
+  let path = baseline_path(dir, name)?;
+  let text = match std::fs::read_to_string(&path) {
+    Ok(text) => text,
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+      bail!(
+        "Bench baseline \"{}\" not found. Save it first with --save-baseline={}",
+        name,
+        name
+      );
+    }
+    Err(err) => {
+      return Err(err).with_context(|| {
+        format!("Failed reading bench baseline '{}'", path.display())
+      });
+    }
+  };
+  let baseline: BenchBaseline = serde_json::from_str(&text)
+    .with_context(|| format!("Invalid bench baseline '{}'", path.display()))?;
+  if baseline.version != BASELINE_VERSION {
+    bail!(
+      "Bench baseline \"{}\" was saved by an incompatible version of Deno. Save it again with --save-baseline={}",
+      name,
+      name
+    );
+  }
+  let cpu = mitata::cpu::name();
+  if baseline.cpu != cpu {
+    log::warn!(
+      "{} bench baseline \"{}\" was recorded on a different CPU ({}), so the comparison may not be meaningful.",
+      crate::colors::yellow("Warning"),
+      name,
+      baseline.cpu
+    );
+  }
+  Ok(baseline)
+}
+
+/// Saves the measurements as the baseline `name`, replacing any previous
+/// baseline with that name.
+pub fn save(
+  dir: &Path,
+  name: &str,
+  cwd: &Path,
+  measurements: &[(BenchDescription, BenchStats)],
+) -> Result<(), AnyError> {
+  let path = baseline_path(dir, name)?;
+  let baseline = BenchBaseline {
+    version: BASELINE_VERSION,
+    runtime: format!("{} {}", DENO_VERSION_INFO.user_agent, env!("TARGET")),
+    cpu: mitata::cpu::name(),
+    benches: measurements
+      .iter()
+      .filter(|(desc, _)| !desc.warmup)
+      .map(|(desc, stats)| BaselineBench {
+        origin: relative_origin(&desc.origin, cwd),
+        group: desc.group.clone(),
+        name: desc.name.clone(),
+        stats: stats.clone(),
+      })
+      .collect(),
+  };
+  std::fs::create_dir_all(dir)
+    .with_context(|| format!("Failed creating '{}'", dir.display()))?;
+  atomic_write_file_with_retries(
+    &CliSys::default(),
+    &path,
+    serde_json::to_string_pretty(&baseline)?.as_bytes(),
+    crate::cache::CACHE_PERM,
+  )
+  .with_context(|| format!("Failed writing bench baseline '{}'", name))?;
+  log::info!("Saved bench baseline \"{}\"", name);
+  Ok(())
+}
+
+/// Compares the measurements against the benches of the baseline with the
+/// same module, group and name. Benches that aren't in the baseline are
+/// skipped.
+pub fn compare(
+  name: &str,
+  baseline: &BenchBaseline,
+  cwd: &Path,
+  measurements: &[(BenchDescription, BenchStats)],
+  regression_threshold: u16,
+) -> BaselineComparison {
+  let benches = measurements
+    .iter()
+    .filter(|(desc, _)| !desc.warmup)
+    .filter_map(|(desc, stats)| {
+      let origin = relative_origin(&desc.origin, cwd);
+      let base = baseline.benches.iter().find(|bench| {
+        bench.origin == origin
+          && bench.group == desc.group
+          && bench.name == desc.name
+      })?;
+      let change = if base.stats.avg > 0.0 {
+        (stats.avg - base.stats.avg) / base.stats.avg * 100.0
+      } else {
+        0.0
+      };
+      let significant = is_significant(&base.stats, stats);
+      Some(BenchComparison {
+        origin,
+        group: desc.group.clone(),
+        name: desc.name.clone(),
+        baseline_avg: base.stats.avg,
+        avg: stats.avg,
+        change,
+        significant,
+        regressed: significant && change > regression_threshold as f64,
+      })
+    })
+    .collect();
+  BaselineComparison {
+    name: name.to_string(),
+    regression_threshold,
+    benches,
+  }
+}
+
+fn relative_origin(origin: &str, cwd: &Path) -> String {
+  let Some(path) = Url::parse(origin)
+    .ok()
+    .and_then(|url| url.to_file_path().ok())
+  else {
+    return origin.to_string();
+  };
+  match path.strip_prefix(cwd) {
+    Ok(relative) => relative.to_string_lossy().replace('\\', "/"),
+    Err(_) => origin.to_string(),
+  }
+}
+
+/// Whether the averages differ at the 95% confidence level according to
+/// Welch's t-test.
+fn is_significant(a: &BenchStats, b: &BenchStats) -> bool {
+  if a.n < 2 || b.n < 2 {
+    return false;
+  }
+  let var_a = a.sd * a.sd / a.n as f64;
+  let var_b = b.sd * b.sd / b.n as f64;
+  let std_err = (var_a + var_b).sqrt();
+  if std_err == 0.0 {
+    return a.avg != b.avg;
+  }
+  let t = (a.avg - b.avg).abs() / std_err;
+  let degrees_of_freedom = (var_a + var_b).powi(2)
+    / (var_a * var_a / (a.n - 1) as f64 + var_b * var_b / (b.n - 1) as f64);
+  t > t_critical_95(degrees_of_freedom)
+}
+
+/// Approximates the two-sided 95% critical value of Student's
+/// t-distribution using the Cornish-Fisher expansion.
+fn t_critical_95(degrees_of_freedom: f64) -> f64 {
+  const Z: f64 = 1.959_963_984_540_054;
+  let z3 = Z.powi(3);
+  let z5 = Z.powi(5);
+  let df = degrees_of_freedom;
+  Z + (z3 + Z) / (4.0 * df)
+    + (5.0 * z5 + 16.0 * z3 + 3.0 * Z) / (96.0 * df * df)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn stats(n: u64, avg: f64, sd: f64) -> BenchStats {
+    BenchStats {
+      n,
+      min: avg - sd,
+      max: avg + sd,
+      avg,
+      sd,
+      p75: avg,
+      p99: avg,
+      p995: avg,
+      p999: avg,
+      high_precision: true,
+      used_explicit_timers: false,
+      heap: None,
+    }
+  }
+
+  fn desc(name: &str) -> BenchDescription {
+    BenchDescription {
+      id: 0,
+      name: name.to_string(),
+      origin: "file:///project/a_bench.ts".to_string(),
+      baseline: false,
+      group: None,
+      ignore: false,
+      only: false,
+      warmup: false,
+    }
+  }
+
+  #[test]
+  fn t_critical_values() {
+    // reference values of the t-distribution
+    assert!((t_critical_95(9.0) - 2.262).abs() < 0.01);
+    assert!((t_critical_95(30.0) - 2.042).abs() < 0.01);
+    assert!((t_critical_95(1000.0) - 1.962).abs() < 0.01);
+  }
+
+  #[test]
+  fn significance() {
+    assert!(is_significant(
+      &stats(100, 100.0, 5.0),
+      &stats(100, 110.0, 5.0)
+    ));
+    assert!(!is_significant(
+      &stats(100, 100.0, 50.0),
+      &stats(100, 110.0, 50.0)
+    ));
+    assert!(!is_significant(
+      &stats(1, 100.0, 0.0),
+      &stats(1, 200.0, 0.0)
+    ));
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn compares_against_baseline() {
+    let baseline = BenchBaseline {
+      version: BASELINE_VERSION,
+      runtime: String::new(),
+      cpu: String::new(),
+      benches: vec![
+        BaselineBench {
+          origin: "a_bench.ts".to_string(),
+          group: None,
+          name: "slower".to_string(),
+          stats: stats(100, 100.0, 5.0),
+        },
+        BaselineBench {
+          origin: "a_bench.ts".to_string(),
+          group: None,
+          name: "noisy".to_string(),
+          stats: stats(100, 100.0, 80.0),
+        },
+        BaselineBench {
+          origin: "a_bench.ts".to_string(),
+          group: None,
+          name: "faster".to_string(),
+          stats: stats(100, 100.0, 5.0),
+        },
+      ],
+    };
+    let measurements = vec![
+      (desc("slower"), stats(100, 120.0, 5.0)),
+      (desc("noisy"), stats(100, 120.0, 80.0)),
+      (desc("faster"), stats(100, 80.0, 5.0)),
+      (desc("new"), stats(100, 80.0, 5.0)),
+    ];
+    let comparison =
+      compare("main", &baseline, Path::new("/project"), &measurements, 10);
+    assert_eq!(comparison.benches.len(), 3);
+    assert_eq!(comparison.regressions(), 1);
+
+    let slower = &comparison.benches[0];
+    assert_eq!(slower.origin, "a_bench.ts");
+    assert_eq!(slower.name, "slower");
+    assert_eq!(slower.change, 20.0);
+    assert!(slower.significant && slower.regressed);
+
+    let noisy = &comparison.benches[1];
+    assert!(!noisy.significant && !noisy.regressed);
+
+    let faster = &comparison.benches[2];
+    assert_eq!(faster.change, -20.0);
+    assert!(faster.significant && !faster.regressed);
+  }
+
+  #[test]
+  fn validates_names() {
+    let dir = Path::new("baselines");
+    assert_eq!(
+      baseline_path(dir, "main-1.0_x").unwrap(),
+      dir.join("main-1.0_x.json")
+    );
+    assert!(baseline_path(dir, "").is_err());
+    assert!(baseline_path(dir, "../main").is_err());
+  }
+}
@@ -65,6 +65,22 @@ fn human_readable_decimal_with_fractional(
   }
 }
 
+pub fn fmt_bytes(bytes: f64) -> String {
+  let sign = if bytes < 0.0 { "-" } else { "" };
+  let bytes = bytes.abs();
+  if bytes < 1024.0 {
+    return format!("{}{:.0} B", sign, bytes);
+  }
+  if bytes < 1024.0 * 1024.0 {
+    return format!("{}{:.1} KB", sign, bytes / 1024.0);
+  }
+  if bytes < 1024.0 * 1024.0 * 1024.0 {
+    return format!("{}{:.1} MB", sign, bytes / (1024.0 * 1024.0));
+  }
+
+  format!("{}{:.1} GB", sign, bytes / (1024.0 * 1024.0 * 1024.0))
+}
+
 pub fn fmt_duration(time: f64) -> String {
   if time < 1e0 {
     return format!("{:.1} ps", time * 1e3);
@@ -179,6 +195,14 @@ pub mod reporter {
     pub p995: f64,
   }
 
+  #[derive(Clone, PartialEq)]
+  pub struct HeapStats {
+    pub allocated_per_iter: f64,
+    pub gc_count: u64,
+    pub gc_time: f64,
+    pub retained: f64,
+  }
+
   #[derive(Clone, PartialEq)]
   pub struct GroupBenchmark {
     pub name: String,
@@ -193,6 +217,7 @@ pub mod reporter {
     pub avg: bool,
     pub min_max: bool,
     pub percentiles: bool,
+    pub memory: bool,
   }
 
   impl Options {
@@ -202,6 +227,7 @@ pub mod reporter {
         min_max: true,
         size: size(names),
         percentiles: true,
+        memory: false,
       }
     }
   }
@@ -237,6 +263,14 @@ pub mod reporter {
         "-".repeat(8)
       ));
     }
+    if options.memory {
+      s.push_str(&format!(
+        " {} | {} | {} |",
+        "-".repeat(10),
+        "-".repeat(15),
+        "-".repeat(10)
+      ));
+    }
 
     s
   }
@@ -272,10 +306,30 @@ pub mod reporter {
     if options.percentiles {
       s.push_str(&format!(" {:>8} | {:>8} | {:>8} |", "p75", "p99", "p995"));
     }
+    if options.memory {
+      s.push_str(&format!(
+        " {:>10} | {:>15} | {:>10} |",
+        "alloc/iter", "gc (time)", "retained"
+      ));
+    }
 
     s
   }
 
+  /// The memory columns of a benchmark row.
+  pub fn heap(stats: Option<&HeapStats>) -> String {
+    let Some(stats) = stats else {
+      return format!(" {:>10} | {:>15} | {:>10} |", "-", "-", "-");
+    };
+    let gc = format!("{} ({})", stats.gc_count, fmt_duration(stats.gc_time));
+    format!(
+      " {} | {:>15} | {:>10} |",
+      colors::yellow(format!("{:>10}", fmt_bytes(stats.allocated_per_iter))),
+      gc,
+      fmt_bytes(stats.retained)
+    )
+  }
+
   pub fn benchmark(
     name: &str,
     stats: &BenchmarkStats,
@@ -380,6 +434,15 @@ mod tests {
     assert_eq!(into_decimal_and_fractional_parts(10.99), (11, 0));
   }
 
+  #[test]
+  fn test_fmt_bytes() {
+    assert_eq!(fmt_bytes(0.0), "0 B");
+    assert_eq!(fmt_bytes(56.4), "56 B");
+    assert_eq!(fmt_bytes(2048.0), "2.0 KB");
+    assert_eq!(fmt_bytes(-3.5 * 1024.0 * 1024.0), "-3.5 MB");
+    assert_eq!(fmt_bytes(1024.0 * 1024.0 * 1024.0), "1.0 GB");
+  }
+
   #[test]
   fn test_avg_to_iter_per_s() {
     assert_eq!(avg_to_iter_per_s(55.85), "17,910,000");
@@ -20,6 +20,7 @@ use deno_core::unsync::spawn;
 use deno_core::unsync::spawn_blocking;
 use deno_core::v8;
 use deno_error::JsErrorBox;
+use deno_lib::util::checksum;
 use deno_npm_installer::graph::NpmCachingStrategy;
 use deno_runtime::WorkerExecutionMode;
 use deno_runtime::deno_permissions::Permissions;
@@ -54,9 +55,12 @@ use crate::util::path::matches_pattern_or_exact_path;
 use crate::worker::CliMainWorkerFactory;
 use crate::worker::CreateCustomWorkerError;
 
+mod baseline;
 mod mitata;
 mod reporters;
 
+use baseline::BaselineComparison;
+use baseline::BaselineOptions;
 use reporters::BenchReporter;
 use reporters::ConsoleReporter;
 use reporters::JsonReporter;
@@ -65,7 +69,9 @@ use reporters::JsonReporter;
 struct BenchSpecifierOptions {
   filter: TestFilter,
   json: bool,
+  memory: bool,
   log_level: Option<log::Level>,
+  baseline: BaselineOptions,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
@@ -101,6 +107,7 @@ pub struct BenchReport {
   pub failed: usize,
   pub failures: Vec<(BenchDescription, Box<JsError>)>,
   pub measurements: Vec<(BenchDescription, BenchStats)>,
+  pub baseline: Option<BaselineComparison>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Eq, Hash)]
@@ -122,12 +129,33 @@ pub struct BenchStats {
   pub min: f64,
   pub max: f64,
   pub avg: f64,
+  /// Sample standard deviation of the measurements.
+  #[serde(default)]
+  pub sd: f64,
   pub p75: f64,
   pub p99: f64,
   pub p995: f64,
   pub p999: f64,
   pub high_precision: bool,
   pub used_explicit_timers: bool,
+  /// Only collected with `deno bench --memory`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub heap: Option<BenchHeapStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchHeapStats {
+  /// Bytes allocated on the V8 heap per iteration, without the allocations
+  /// of the harness.
+  pub allocated_per_iter: f64,
+  /// Number of garbage collections while measuring.
+  pub gc_count: u64,
+  /// Nanoseconds spent in garbage collection while measuring.
+  pub gc_time: f64,
+  /// Bytes still reachable on the V8 heap after a garbage collection
+  /// following the measured iterations.
+  pub retained: f64,
 }
 
 impl BenchReport {
@@ -137,6 +165,7 @@ impl BenchReport {
       failed: 0,
       failures: Vec::new(),
       measurements: Vec::new(),
+      baseline: None,
     }
   }
 }
@@ -144,11 +173,12 @@ impl BenchReport {
 fn create_reporter(
   show_output: bool,
   json: bool,
+  memory: bool,
 ) -> Box<dyn BenchReporter + Send> {
   if json {
     return Box::new(JsonReporter::new());
   }
-  Box::new(ConsoleReporter::new(show_output))
+  Box::new(ConsoleReporter::new(show_output, memory))
 }
 
 /// Run a single specifier as an executable bench module.
@@ -159,7 +189,7 @@ async fn bench_specifier(
   preload_modules: Vec<ModuleSpecifier>,
   require_modules: Vec<ModuleSpecifier>,
   sender: UnboundedSender<BenchEvent>,
-  filter: TestFilter,
+  options: BenchSpecifierOptions,
 ) -> Result<(), AnyError> {
   match bench_specifier_inner(
     worker_factory,
@@ -168,7 +198,7 @@ async fn bench_specifier(
     preload_modules,
     require_modules,
     &sender,
-    filter,
+    options,
   )
   .await
   {
@@ -192,7 +222,7 @@ async fn bench_specifier_inner(
   preload_modules: Vec<ModuleSpecifier>,
   require_modules: Vec<ModuleSpecifier>,
   sender: &UnboundedSender<BenchEvent>,
-  filter: TestFilter,
+  options: BenchSpecifierOptions,
 ) -> Result<(), CreateCustomWorkerError> {
   let mut worker = worker_factory
     .create_custom_worker(
@@ -201,7 +231,7 @@ async fn bench_specifier_inner(
       preload_modules,
       require_modules,
       permissions_container,
-      vec![ops::bench::deno_bench::init(sender.clone())],
+      vec![ops::bench::deno_bench::init(sender.clone(), options.memory)],
       Default::default(),
       None,
     )
@@ -231,7 +261,7 @@ async fn bench_specifier_inner(
   let benchmarks = if used_only { only } else { no_only };
   let mut benchmarks = benchmarks
     .into_iter()
-    .filter(|(d, _)| d.warmup || filter.includes(&d.name) && !d.ignore)
+    .filter(|(d, _)| d.warmup || options.filter.includes(&d.name) && !d.ignore)
     .collect::<Vec<_>>();
   let mut groups = IndexSet::<Option<String>>::new();
   // make sure ungrouped benchmarks are placed above grouped
@@ -342,7 +372,7 @@ async fn bench_specifiers(
         preload_modules,
         require_modules,
         sender,
-        options.filter,
+        options,
       );
       create_and_run_current_thread(future)
     })
@@ -356,8 +386,11 @@ async fn bench_specifiers(
     spawn(async move {
       let mut used_only = false;
       let mut report = BenchReport::new();
-      let mut reporter =
-        create_reporter(log_level != Some(Level::Error), options.json);
+      let mut reporter = create_reporter(
+        log_level != Some(Level::Error),
+        options.json,
+        options.memory,
+      );
       let mut benches = IndexMap::new();
 
       while let Some(event) = receiver.recv().await {
@@ -406,6 +439,17 @@ async fn bench_specifiers(
         }
       }
 
+      let baseline_options = &options.baseline;
+      if let Some((name, baseline)) = &baseline_options.compare {
+        report.baseline = Some(baseline::compare(
+          name,
+          baseline,
+          &baseline_options.cwd,
+          &report.measurements,
+          baseline_options.regression_threshold,
+        ));
+      }
+
       reporter.report_end(&report);
 
       if used_only {
@@ -418,6 +462,32 @@ async fn bench_specifiers(
         return Err(anyhow!("Bench failed"));
       }
 
+      if let Some(name) = &baseline_options.save {
+        baseline::save(
+          &baseline_options.dir,
+          name,
+          &baseline_options.cwd,
+          &report.measurements,
+        )?;
+      }
+
+      if let Some(comparison) = &report.baseline {
+        let regressions = comparison.regressions();
+        if regressions > 0 {
+          return Err(anyhow!(
+            "Bench failed because {} {} regressed by more than {}% compared to baseline \"{}\"",
+            regressions,
+            if regressions == 1 {
+              "benchmark"
+            } else {
+              "benchmarks"
+            },
+            comparison.regression_threshold,
+            comparison.name,
+          ));
+        }
+      }
+
       Ok(())
     })
   };
@@ -491,6 +561,23 @@ pub async fn run_benchmarks(
     return Err(anyhow!("No bench modules found"));
   }
 
+  // the deno dir is shared by all projects, so the baselines are kept apart
+  // for each workspace
+  let workspace_root = cli_options.workspace().root_dir_url();
+  let baseline_dir = factory
+    .deno_dir()?
+    .bench_baselines_folder_path()
+    .join(checksum::r#gen(&[workspace_root.as_str().as_bytes()]));
+  if let Some(name) = &workspace_bench_options.save_baseline {
+    baseline::validate_name(name)?;
+  }
+  let compare_baseline = match &workspace_bench_options.baseline {
+    Some(name) => {
+      Some((name.clone(), Arc::new(baseline::load(&baseline_dir, name)?)))
+    }
+    None => None,
+  };
+
   let main_graph_container = factory.main_module_graph_container().await?;
   main_graph_container
     .check_specifiers(
@@ -521,7 +608,17 @@ pub async fn run_benchmarks(
     BenchSpecifierOptions {
       filter: TestFilter::from_flag(&workspace_bench_options.filter),
       json: workspace_bench_options.json,
+      memory: workspace_bench_options.memory,
       log_level,
+      baseline: BaselineOptions {
+        dir: baseline_dir,
+        cwd: cli_options.initial_cwd().to_path_buf(),
+        save: workspace_bench_options.save_baseline.clone(),
+        compare: compare_baseline,
+        regression_threshold: workspace_bench_options
+          .regression_threshold
+          .unwrap_or(baseline::DEFAULT_REGRESSION_THRESHOLD),
+      },
     },
   )
   .await?;
@@ -667,7 +764,10 @@ pub async fn run_benchmarks_with_watch(
           BenchSpecifierOptions {
             filter: TestFilter::from_flag(&workspace_bench_options.filter),
             json: workspace_bench_options.json,
+            memory: workspace_bench_options.memory,
             log_level,
+            // baselines aren't supported in watch mode
+            baseline: BaselineOptions::default(),
           },
         )
         .await?;
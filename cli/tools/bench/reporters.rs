@@ -3,6 +3,8 @@
 use deno_lib::version::DENO_VERSION_INFO;
 use serde::Serialize;
 
+use super::baseline::BaselineComparison;
+use super::baseline::BenchComparison;
 use super::*;
 use crate::tools::test::TestFailureFormatOptions;
 
@@ -25,6 +27,8 @@ struct JsonReporterOutput {
   runtime: String,
   cpu: String,
   benches: Vec<JsonReporterBench>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  baseline: Option<BaselineComparison>,
 }
 
 impl Default for JsonReporterOutput {
@@ -34,6 +38,7 @@ impl Default for JsonReporterOutput {
       runtime: format!("{} {}", DENO_VERSION_INFO.user_agent, env!("TARGET")),
       cpu: mitata::cpu::name(),
       benches: vec![],
+      baseline: None,
     }
   }
 }
@@ -62,7 +67,8 @@ impl BenchReporter for JsonReporter {
   #[cold]
   fn report_plan(&mut self, _plan: &BenchPlan) {}
 
-  fn report_end(&mut self, _report: &BenchReport) {
+  fn report_end(&mut self, report: &BenchReport) {
+    self.0.baseline.clone_from(&report.baseline);
     match write_json_to_stdout(self) {
       Ok(_) => (),
       Err(e) => println!("{}", e),
@@ -106,6 +112,7 @@ impl BenchReporter for JsonReporter {
 pub struct ConsoleReporter {
   name: String,
   show_output: bool,
+  memory: bool,
   group: Option<String>,
   baseline: bool,
   group_measurements: Vec<(BenchDescription, BenchStats)>,
@@ -113,9 +120,10 @@ pub struct ConsoleReporter {
 }
 
 impl ConsoleReporter {
-  pub fn new(show_output: bool) -> Self {
+  pub fn new(show_output: bool, memory: bool) -> Self {
     Self {
       show_output,
+      memory,
       group: None,
       options: None,
       baseline: false,
@@ -146,6 +154,7 @@ impl BenchReporter for ConsoleReporter {
     let options = self.options.as_mut().unwrap();
 
     options.percentiles = true;
+    options.memory = self.memory;
 
     if FIRST_PLAN
       .compare_exchange(true, false, Ordering::SeqCst, Ordering::SeqCst)
@@ -216,21 +225,29 @@ impl BenchReporter for ConsoleReporter {
           desc.baseline = false;
         }
 
-        println!(
-          "{}",
-          mitata::reporter::benchmark(
-            &desc.name,
-            &mitata::reporter::BenchmarkStats {
-              avg: stats.avg,
-              min: stats.min,
-              max: stats.max,
-              p75: stats.p75,
-              p99: stats.p99,
-              p995: stats.p995,
-            },
-            options
-          )
+        let mut row = mitata::reporter::benchmark(
+          &desc.name,
+          &mitata::reporter::BenchmarkStats {
+            avg: stats.avg,
+            min: stats.min,
+            max: stats.max,
+            p75: stats.p75,
+            p99: stats.p99,
+            p995: stats.p995,
+          },
+          options,
         );
+        if options.memory {
+          let heap =
+            stats.heap.as_ref().map(|heap| mitata::reporter::HeapStats {
+              allocated_per_iter: heap.allocated_per_iter,
+              gc_count: heap.gc_count,
+              gc_time: heap.gc_time,
+              retained: heap.retained,
+            });
+          row.push_str(&mitata::reporter::heap(heap.as_ref()));
+        }
+        println!("{}", row);
 
         if !stats.high_precision && stats.used_explicit_timers {
           println!(
@@ -302,8 +319,11 @@ impl BenchReporter for ConsoleReporter {
     self.group_measurements.clear();
   }
 
-  fn report_end(&mut self, _: &BenchReport) {
+  fn report_end(&mut self, report: &BenchReport) {
     self.report_group_summary();
+    if let Some(comparison) = &report.baseline {
+      print_baseline_comparison(comparison);
+    }
   }
 
   fn report_uncaught_error(&mut self, _origin: &str, error: Box<JsError>) {
@@ -321,3 +341,77 @@ impl BenchReporter for ConsoleReporter {
     println!();
   }
 }
+
+#[allow(clippy::print_stdout, reason = "reporter")]
+fn print_baseline_comparison(comparison: &BaselineComparison) {
+  println!(
+    "{}",
+    colors::gray(format!(
+      "Compared to baseline \"{}\" (regression threshold {}%)",
+      comparison.name, comparison.regression_threshold
+    ))
+  );
+  if comparison.benches.is_empty() {
+    println!("No benchmarks found in the baseline\n");
+    return;
+  }
+
+  let display_name = |bench: &BenchComparison| match &bench.group {
+    Some(group) => format!("{} > {}", group, bench.name),
+    None => bench.name.clone(),
+  };
+  let size = comparison
+    .benches
+    .iter()
+    .map(|bench| display_name(bench).chars().count())
+    .max()
+    .unwrap_or(0)
+    .max("benchmark".len());
+  let mut origin = None;
+  for bench in &comparison.benches {
+    if origin != Some(&bench.origin) {
+      println!(
+        "\n{}\n\n| {:<size$} | {:>9} | {:>9} | {:<22} |",
+        colors::gray(&bench.origin),
+        "benchmark",
+        "baseline",
+        "current",
+        "change"
+      );
+      println!(
+        "| {} | {} | {} | {} |",
+        "-".repeat(size),
+        "-".repeat(9),
+        "-".repeat(9),
+        "-".repeat(22)
+      );
+      origin = Some(&bench.origin);
+    }
+    let change = format!("{:+.1}%", bench.change);
+    let change = if bench.regressed {
+      format!("{change} regressed")
+    } else if !bench.significant {
+      format!("{change} not significant")
+    } else {
+      change
+    };
+    let change = format!("{change:<22}");
+    let change = if bench.regressed {
+      colors::red(change).to_string()
+    } else if !bench.significant {
+      colors::gray(change).to_string()
+    } else if bench.change < 0.0 {
+      colors::green(change).to_string()
+    } else {
+      colors::yellow(change).to_string()
+    };
+    println!(
+      "| {:<size$} | {:>9} | {:>9} | {} |",
+      display_name(bench),
+      mitata::fmt_duration(bench.baseline_avg),
+      mitata::fmt_duration(bench.avg),
+      change,
+    );
+  }
+  println!();
+}
@@ -12,6 +12,10 @@ use crate::graph_container::CheckSpecifiersOptions;
 use crate::graph_container::CollectSpecifiersOptions;
 use crate::util::extract;
 
+mod reporters;
+
+pub use reporters::create_reporter;
+
 pub async fn check(
   flags: Arc<Flags>,
   check_flags: CheckFlags,
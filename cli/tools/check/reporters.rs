@@ -0,0 +1,187 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::url::Url;
+
+use crate::args::CheckFlags;
+use crate::tsc::Diagnostic;
+use crate::tsc::DiagnosticCategory;
+use crate::tsc::Position;
+use crate::util::display;
+use crate::util::github_actions::Annotation;
+use crate::util::github_actions::AnnotationLevel;
+use crate::util::github_actions::AnnotationPosition;
+use crate::util::github_actions::annotation_file;
+use crate::util::sarif;
+use crate::util::sarif::SarifRun;
+
+/// Reports type-check diagnostics in a structured format instead of
+/// printing them.
+pub trait CheckReporter {
+  fn visit_diagnostic(&mut self, d: &Diagnostic);
+  fn close(&mut self);
+}
+
+/// Returns the reporter for the format requested by the flags, or `None`
+/// when the diagnostics should be printed as usual.
+pub fn create_reporter(
+  check_flags: &CheckFlags,
+  cwd: &Path,
+) -> Option<Box<dyn CheckReporter>> {
+  if check_flags.sarif {
+    Some(Box::new(SarifCheckReporter::new(cwd)))
+  } else if check_flags.github {
+    Some(Box::new(GithubCheckReporter::new(cwd)))
+  } else {
+    None
+  }
+}
+
+struct SarifCheckReporter {
+  run: SarifRun,
+}
+
+impl SarifCheckReporter {
+  fn new(cwd: &Path) -> SarifCheckReporter {
+    SarifCheckReporter {
+      run: SarifRun::new(
+        "deno check",
+        "https://docs.deno.com/go/check",
+        sarif::ColumnKind::Utf16CodeUnits,
+        cwd,
+      ),
+    }
+  }
+
+  fn location(&self, d: &Diagnostic) -> Option<sarif::Location> {
+    let specifier = Url::parse(d.file_name.as_ref()?).ok()?;
+    let region = diagnostic_range(d).map(|(start, end)| sarif::Region {
+      start_line: start.line,
+      start_column: start.column,
+      end_line: end.map(|end| end.line),
+      end_column: end.map(|end| end.column),
+    });
+    Some(sarif::Location {
+      physical_location: sarif::PhysicalLocation {
+        artifact_location: self.run.artifact_location(&specifier),
+        region,
+      },
+      message: None,
+    })
+  }
+}
+
+impl CheckReporter for SarifCheckReporter {
+  fn visit_diagnostic(&mut self, d: &Diagnostic) {
+    let rule_id = format!("TS{}", d.code);
+    let related_locations = d
+      .related_information
+      .iter()
+      .flatten()
+      .filter_map(|related| {
+        let mut location = self.location(related)?;
+        location.message = Some(sarif::Message::new(message(related)));
+        Some(location)
+      })
+      .collect();
+    let result = sarif::SarifResult {
+      rule_id: rule_id.clone(),
+      rule_index: 0,
+      level: match d.category {
+        DiagnosticCategory::Error => sarif::Level::Error,
+        DiagnosticCategory::Warning => sarif::Level::Warning,
+        DiagnosticCategory::Suggestion | DiagnosticCategory::Message => {
+          sarif::Level::Note
+        }
+      },
+      message: sarif::Message::new(message(d)),
+      locations: self.location(d).into_iter().collect(),
+      related_locations,
+      fixes: Vec::new(),
+    };
+    self.run.add_result(result, || sarif::Rule {
+      id: rule_id,
+      help_uri: None,
+      properties: None,
+    });
+  }
+
+  fn close(&mut self) {
+    display::write_json_to_stdout(&self.run.to_log()).unwrap();
+  }
+}
+
+struct GithubCheckReporter {
+  cwd: PathBuf,
+}
+
+impl GithubCheckReporter {
+  fn new(cwd: &Path) -> GithubCheckReporter {
+    GithubCheckReporter {
+      cwd: cwd.to_path_buf(),
+    }
+  }
+}
+
+impl CheckReporter for GithubCheckReporter {
+  fn visit_diagnostic(&mut self, d: &Diagnostic) {
+    let file = d
+      .file_name
+      .as_ref()
+      .and_then(|file_name| Url::parse(file_name).ok())
+      .and_then(|specifier| annotation_file(&specifier, &self.cwd));
+    let range = diagnostic_range(d);
+    let annotation = Annotation {
+      level: match d.category {
+        DiagnosticCategory::Error => AnnotationLevel::Error,
+        DiagnosticCategory::Warning => AnnotationLevel::Warning,
+        DiagnosticCategory::Suggestion | DiagnosticCategory::Message => {
+          AnnotationLevel::Notice
+        }
+      },
+      file,
+      start: range.map(|(start, _)| start),
+      end: range.and_then(|(_, end)| end),
+      title: Some(format!("TS{}", d.code)),
+      message: message(d),
+    };
+    #[allow(clippy::print_stdout, reason = "reporter")]
+    {
+      println!("{}", annotation.to_workflow_command());
+    }
+  }
+
+  fn close(&mut self) {}
+}
+
+fn message(d: &Diagnostic) -> String {
+  match &d.message_chain {
+    Some(message_chain) => message_chain.format_message(0),
+    None => d.message_text.clone().unwrap_or_default(),
+  }
+}
+
+/// Returns the 1-based start and end of the diagnostic in the original
+/// source.
+fn diagnostic_range(
+  d: &Diagnostic,
+) -> Option<(AnnotationPosition, Option<AnnotationPosition>)> {
+  fn one_based(position: &Position) -> AnnotationPosition {
+    AnnotationPosition {
+      line: position.line as usize + 1,
+      column: position.character as usize + 1,
+    }
+  }
+
+  match &d.original_source_start {
+    // only the start of diagnostics in fast checked modules is mapped back
+    // to the original source
+    Some(start) => Some((one_based(start), None)),
+    None => {
+      let start = one_based(d.start.as_ref()?);
+      Some((start, d.end.as_ref().map(one_based)))
+    }
+  }
+}
@@ -0,0 +1,116 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Narrows coverage reports down to the lines changed since a git ref.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use super::CoverageReport;
+use crate::util::fs::canonicalize_path_maybe_not_exists;
+
+/// Removes the lines, branches and functions that weren't changed from each
+/// report, and drops the reports of files without changed covered lines.
+///
+/// `changed_lines` maps canonicalized file paths to their changed 0-based
+/// line indexes, where `None` means that the whole file is new.
+pub fn retain_changed_lines(
+  file_reports: &mut Vec<(CoverageReport, String)>,
+  changed_lines: &HashMap<PathBuf, Option<HashSet<usize>>>,
+) {
+  file_reports.retain_mut(|(report, _)| {
+    let Ok(path) = report.url.to_file_path() else {
+      return false;
+    };
+    let path = canonicalize_path_maybe_not_exists(&path).unwrap_or(path);
+    let Some(lines) = changed_lines.get(&path) else {
+      return false;
+    };
+    if let Some(lines) = lines {
+      report
+        .found_lines
+        .retain(|(line_index, _)| lines.contains(line_index));
+      report
+        .branches
+        .retain(|branch| lines.contains(&branch.line_index));
+      report
+        .named_functions
+        .retain(|function| lines.contains(&function.line_index));
+    }
+    !report.found_lines.is_empty()
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use deno_core::url::Url;
+
+  use super::*;
+  use crate::tools::coverage::BranchCoverageItem;
+  use crate::tools::coverage::FunctionCoverageItem;
+
+  fn report(path: &str) -> (CoverageReport, String) {
+    let report = CoverageReport {
+      url: Url::from_file_path(path).unwrap(),
+      named_functions: vec![
+        FunctionCoverageItem {
+          name: "a".to_string(),
+          line_index: 0,
+          execution_count: 1,
+        },
+        FunctionCoverageItem {
+          name: "b".to_string(),
+          line_index: 3,
+          execution_count: 0,
+        },
+      ],
+      branches: vec![BranchCoverageItem {
+        line_index: 1,
+        block_number: 0,
+        branch_number: 0,
+        taken: Some(1),
+        is_hit: true,
+      }],
+      found_lines: vec![(0, 1), (1, 1), (2, 1), (3, 0), (4, 0)],
+      output: None,
+    };
+    (report, String::new())
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn retains_changed_lines() {
+    let mut file_reports = vec![
+      report("/project/modified.ts"),
+      report("/project/new.ts"),
+      report("/project/unchanged.ts"),
+      report("/project/comments_only.ts"),
+    ];
+    let changed_lines = HashMap::from([
+      (
+        PathBuf::from("/project/modified.ts"),
+        Some(HashSet::from([3, 4])),
+      ),
+      (PathBuf::from("/project/new.ts"), None),
+      (
+        PathBuf::from("/project/comments_only.ts"),
+        Some(HashSet::from([9])),
+      ),
+    ]);
+    retain_changed_lines(&mut file_reports, &changed_lines);
+
+    assert_eq!(file_reports.len(), 2);
+    let (modified, _) = &file_reports[0];
+    assert_eq!(modified.url.path(), "/project/modified.ts");
+    assert_eq!(modified.found_lines, vec![(3, 0), (4, 0)]);
+    assert!(modified.branches.is_empty());
+    assert_eq!(modified.named_functions.len(), 1);
+    assert_eq!(modified.named_functions[0].name, "b");
+
+    let (new, _) = &file_reports[1];
+    assert_eq!(new.url.path(), "/project/new.ts");
+    assert_eq!(new.found_lines.len(), 5);
+    assert_eq!(new.branches.len(), 1);
+    assert_eq!(new.named_functions.len(), 2);
+  }
+}
@@ -9,6 +9,7 @@ use std::sync::Arc;
 use deno_ast::MediaType;
 use deno_ast::ModuleKind;
 use deno_ast::ModuleSpecifier;
+use deno_config::deno_json::CoverageThresholds;
 use deno_config::glob::FileCollector;
 use deno_config::glob::FilePatterns;
 use deno_config::glob::PathOrPattern;
@@ -39,10 +40,12 @@ use crate::sys::CliSys;
 use crate::tools::test::is_supported_test_path;
 use crate::util::text_encoding::source_map_from_code;
 
+mod diff;
 mod ignore_directives;
 mod merge;
 mod range_tree;
 pub mod reporter;
+mod thresholds;
 mod util;
 use merge::ProcessCoverage;
 
@@ -564,13 +567,25 @@ fn filter_coverages(
     .collect::<Vec<cdp::ScriptCoverage>>()
 }
 
+#[derive(Debug, Default)]
+pub struct CoverFilesOptions {
+  /// Patterns of the covered module urls to include in the report.
+  pub include: Vec<String>,
+  /// Patterns of the covered module urls to exclude from the report.
+  pub exclude: Vec<String>,
+  pub output: Option<String>,
+  /// Thresholds from the command line. When set, the thresholds of the
+  /// config file are checked as well.
+  pub thresholds: Option<CoverageThresholds>,
+  /// Only report the coverage of the lines changed since this git ref.
+  pub diff: Option<String>,
+}
+
 pub fn cover_files(
   flags: Arc<Flags>,
   files_include: Vec<String>,
   files_ignore: Vec<String>,
-  include: Vec<String>,
-  exclude: Vec<String>,
-  output: Option<String>,
+  options: CoverFilesOptions,
   reporters: &[&dyn CoverageReporter],
 ) -> Result<(), AnyError> {
   if files_include.is_empty() {
@@ -597,8 +612,12 @@ pub fn cover_files(
   if script_coverages.is_empty() {
     return Err(anyhow!("No coverage files found"));
   }
-  let script_coverages =
-    filter_coverages(script_coverages, include, exclude, in_npm_pkg_checker);
+  let script_coverages = filter_coverages(
+    script_coverages,
+    options.include,
+    options.exclude,
+    in_npm_pkg_checker,
+  );
   if script_coverages.is_empty() {
     return Err(anyhow!("No covered files included in the report"));
   }
@@ -615,7 +634,7 @@ pub fn cover_files(
     vec![]
   };
 
-  let out_mode = match output {
+  let out_mode = match options.output {
     Some(ref path) => match File::create(path) {
       Ok(_) => Some(PathBuf::from(path)),
       Err(e) => {
@@ -731,9 +750,31 @@ pub fn cover_files(
     return Err(anyhow!("No covered files included in the report"));
   }
 
+  if let Some(git_ref) = &options.diff {
+    let changed_lines =
+      crate::util::git::changed_lines(cli_options.initial_cwd(), git_ref)?;
+    diff::retain_changed_lines(&mut file_reports, &changed_lines);
+    if file_reports.is_empty() {
+      log::info!("No covered lines changed since {}", git_ref);
+      return Ok(());
+    }
+    log::info!("Coverage of the lines changed since {}", git_ref);
+  }
+
   for reporter in reporters {
     reporter.done(&coverage_root, &file_reports);
   }
 
+  if let Some(threshold_flags) = options.thresholds {
+    let mut thresholds = cli_options
+      .start_dir
+      .to_test_config(FilePatterns::new_with_base(
+        cli_options.initial_cwd().to_path_buf(),
+      ))?
+      .coverage_thresholds;
+    thresholds.global = threshold_flags.or(thresholds.global);
+    thresholds::check(&file_reports, &thresholds, cli_options.initial_cwd())?;
+  }
+
   Ok(())
 }
@@ -1,5 +1,6 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
@@ -10,9 +11,12 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::serde_json::json;
 use deno_core::url::Url;
 use deno_lib::version::DENO_VERSION_INFO;
 
+use super::BranchCoverageItem;
 use super::CoverageReport;
 use super::util;
 use crate::args::CoverageType;
@@ -32,6 +36,34 @@ pub struct CoverageStats<'a> {
   pub report: Option<&'a CoverageReport>,
 }
 
+impl CoverageStats<'_> {
+  /// Adds the hits and misses of `report` to these stats.
+  pub fn add(&mut self, report: &CoverageReport) {
+    self.line_hit += report
+      .found_lines
+      .iter()
+      .filter(|(_, count)| *count > 0)
+      .count();
+    self.line_miss += report
+      .found_lines
+      .iter()
+      .filter(|(_, count)| *count == 0)
+      .count();
+    self.branch_hit += report.branches.iter().filter(|b| b.is_hit).count();
+    self.branch_miss += report.branches.iter().filter(|b| !b.is_hit).count();
+    self.fn_hit += report
+      .named_functions
+      .iter()
+      .filter(|f| f.execution_count > 0)
+      .count();
+    self.fn_miss += report
+      .named_functions
+      .iter()
+      .filter(|f| f.execution_count == 0)
+      .count();
+  }
+}
+
 type CoverageSummary<'a> = HashMap<String, CoverageStats<'a>>;
 
 pub fn create(kind: CoverageType) -> Box<dyn CoverageReporter + Send> {
@@ -40,6 +72,8 @@ pub fn create(kind: CoverageType) -> Box<dyn CoverageReporter + Send> {
     CoverageType::Lcov => Box::new(LcovCoverageReporter::new()),
     CoverageType::Detailed => Box::new(DetailedCoverageReporter::new()),
     CoverageType::Html => Box::new(HtmlCoverageReporter::new()),
+    CoverageType::Cobertura => Box::new(CoberturaCoverageReporter::new()),
+    CoverageType::Json => Box::new(JsonCoverageReporter::new()),
   }
 }
 
@@ -86,29 +120,7 @@ pub trait CoverageReporter {
           ..CoverageStats::default()
         });
 
-        stats.line_hit += report
-          .found_lines
-          .iter()
-          .filter(|(_, count)| *count > 0)
-          .count();
-        stats.line_miss += report
-          .found_lines
-          .iter()
-          .filter(|(_, count)| *count == 0)
-          .count();
-        stats.branch_hit += report.branches.iter().filter(|b| b.is_hit).count();
-        stats.branch_miss +=
-          report.branches.iter().filter(|b| !b.is_hit).count();
-        stats.fn_hit += report
-          .named_functions
-          .iter()
-          .filter(|f| f.execution_count > 0)
-          .count();
-        stats.fn_miss += report
-          .named_functions
-          .iter()
-          .filter(|f| f.execution_count == 0)
-          .count();
+        stats.add(report);
 
         file_text = None;
         summary_path = path.parent();
@@ -360,6 +372,322 @@ impl LcovCoverageReporter {
   }
 }
 
+/// Writes a report that covers all files to the `--output` file, or to
+/// stdout if no file is specified.
+fn write_report(
+  name: &str,
+  file_reports: &[(CoverageReport, String)],
+  contents: &str,
+) -> Result<(), AnyError> {
+  match file_reports
+    .first()
+    .and_then(|(report, _)| report.output.as_ref())
+  {
+    Some(output) => {
+      fs::write(output, contents)?;
+      let url = Url::from_file_path(canonicalize_path(output)?).unwrap();
+      log::info!("{} coverage report has been generated at {}", name, url);
+    }
+    None => io::stdout().write_all(contents.as_bytes())?,
+  }
+  Ok(())
+}
+
+/// Returns the path of each report relative to the common root of all
+/// reports, along with that root.
+fn relative_paths(
+  file_reports: &[(CoverageReport, String)],
+) -> (PathBuf, Vec<String>) {
+  let urls = file_reports.iter().map(|(report, _)| &report.url).collect();
+  let root = util::find_root(urls)
+    .and_then(|root| root.to_file_path().ok())
+    .unwrap_or_default();
+  let paths = file_reports
+    .iter()
+    .map(|(report, _)| {
+      report
+        .url
+        .to_file_path()
+        .ok()
+        .and_then(|path| {
+          path
+            .strip_prefix(&root)
+            .ok()
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+        })
+        .unwrap_or_else(|| report.url.to_string())
+    })
+    .collect();
+  (root, paths)
+}
+
+fn escape_xml(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+fn rate(hit: usize, miss: usize) -> String {
+  let (_, percent, _) = util::calc_coverage_display_info(hit, miss);
+  format!("{:.4}", percent / 100.0)
+}
+
+/// Reports coverage in the Cobertura XML format, which is understood by most
+/// CI systems.
+pub struct CoberturaCoverageReporter {}
+
+impl CoverageReporter for CoberturaCoverageReporter {
+  fn done(
+    &self,
+    _coverage_root: &Path,
+    file_reports: &[(CoverageReport, String)],
+  ) {
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    let xml = self.create_xml(file_reports, timestamp);
+    write_report("Cobertura", file_reports, &xml).unwrap();
+  }
+}
+
+impl CoberturaCoverageReporter {
+  pub fn new() -> CoberturaCoverageReporter {
+    CoberturaCoverageReporter {}
+  }
+
+  fn create_xml(
+    &self,
+    file_reports: &[(CoverageReport, String)],
+    timestamp: i64,
+  ) -> String {
+    let (root, paths) = relative_paths(file_reports);
+
+    // group the files by directory
+    let mut packages: BTreeMap<&str, Vec<(&str, &CoverageReport)>> =
+      BTreeMap::new();
+    for ((report, _), path) in file_reports.iter().zip(&paths) {
+      let package = match path.rfind('/') {
+        Some(index) => &path[..index],
+        None => ".",
+      };
+      packages.entry(package).or_default().push((path, report));
+    }
+
+    let mut total = CoverageStats::default();
+    let mut packages_xml = String::new();
+    for (package, files) in packages {
+      let mut package_stats = CoverageStats::default();
+      let mut classes_xml = String::new();
+      for (path, report) in files {
+        let mut stats = CoverageStats::default();
+        stats.add(report);
+        package_stats.add(report);
+        total.add(report);
+        classes_xml.push_str(&self.create_class_xml(path, report, &stats));
+      }
+      packages_xml.push_str(&format!(
+        "    <package name=\"{}\" line-rate=\"{}\" branch-rate=\"{}\" complexity=\"0\">\n      <classes>\n{}      </classes>\n    </package>\n",
+        escape_xml(package),
+        rate(package_stats.line_hit, package_stats.line_miss),
+        rate(package_stats.branch_hit, package_stats.branch_miss),
+        classes_xml,
+      ));
+    }
+
+    format!(
+      "<?xml version=\"1.0\" ?>\n<!DOCTYPE coverage SYSTEM \"http://cobertura.sourceforge.net/xml/coverage-04.dtd\">\n<coverage lines-valid=\"{}\" lines-covered=\"{}\" line-rate=\"{}\" branches-valid=\"{}\" branches-covered=\"{}\" branch-rate=\"{}\" timestamp=\"{}\" complexity=\"0\" version=\"{}\">\n  <sources>\n    <source>{}</source>\n  </sources>\n  <packages>\n{}  </packages>\n</coverage>\n",
+      total.line_hit + total.line_miss,
+      total.line_hit,
+      rate(total.line_hit, total.line_miss),
+      total.branch_hit + total.branch_miss,
+      total.branch_hit,
+      rate(total.branch_hit, total.branch_miss),
+      timestamp,
+      DENO_VERSION_INFO.deno,
+      escape_xml(&root.to_string_lossy()),
+      packages_xml,
+    )
+  }
+
+  fn create_class_xml(
+    &self,
+    path: &str,
+    report: &CoverageReport,
+    stats: &CoverageStats,
+  ) -> String {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let mut methods_xml = String::new();
+    for function in &report.named_functions {
+      methods_xml.push_str(&format!(
+        "            <method name=\"{}\" hits=\"{}\" signature=\"()V\" line-rate=\"{}\" branch-rate=\"1.0000\">\n              <lines>\n                <line number=\"{}\" hits=\"{}\"/>\n              </lines>\n            </method>\n",
+        escape_xml(&function.name),
+        function.execution_count,
+        if function.execution_count > 0 { "1.0000" } else { "0.0000" },
+        function.line_index + 1,
+        function.execution_count,
+      ));
+    }
+
+    let mut lines_xml = String::new();
+    for (line_index, count) in &report.found_lines {
+      let branches = report
+        .branches
+        .iter()
+        .filter(|b| b.line_index == *line_index)
+        .collect::<Vec<_>>();
+      if branches.is_empty() {
+        lines_xml.push_str(&format!(
+          "            <line number=\"{}\" hits=\"{}\" branch=\"false\"/>\n",
+          line_index + 1,
+          count
+        ));
+      } else {
+        let hit = branches.iter().filter(|b| b.is_hit).count();
+        lines_xml.push_str(&format!(
+          "            <line number=\"{}\" hits=\"{}\" branch=\"true\" condition-coverage=\"{}% ({}/{})\"/>\n",
+          line_index + 1,
+          count,
+          hit * 100 / branches.len(),
+          hit,
+          branches.len()
+        ));
+      }
+    }
+
+    format!(
+      "        <class name=\"{}\" filename=\"{}\" line-rate=\"{}\" branch-rate=\"{}\" complexity=\"0\">\n          <methods>\n{}          </methods>\n          <lines>\n{}          </lines>\n        </class>\n",
+      escape_xml(name),
+      escape_xml(path),
+      rate(stats.line_hit, stats.line_miss),
+      rate(stats.branch_hit, stats.branch_miss),
+      methods_xml,
+      lines_xml,
+    )
+  }
+}
+
+/// Reports coverage in the `coverage-final.json` format of Istanbul, which
+/// is understood by tools such as `nyc` and Codecov.
+pub struct JsonCoverageReporter {}
+
+impl CoverageReporter for JsonCoverageReporter {
+  fn done(
+    &self,
+    _coverage_root: &Path,
+    file_reports: &[(CoverageReport, String)],
+  ) {
+    let json = self.create_json(file_reports);
+    let contents = format!("{}\n", serde_json::to_string(&json).unwrap());
+    write_report("JSON", file_reports, &contents).unwrap();
+  }
+}
+
+impl JsonCoverageReporter {
+  pub fn new() -> JsonCoverageReporter {
+    JsonCoverageReporter {}
+  }
+
+  fn create_json(
+    &self,
+    file_reports: &[(CoverageReport, String)],
+  ) -> serde_json::Value {
+    let mut files = serde_json::Map::new();
+    for (report, file_text) in file_reports {
+      let path = report
+        .url
+        .to_file_path()
+        .ok()
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|| report.url.to_string());
+      let lines = file_text.split('\n').collect::<Vec<_>>();
+      // Istanbul locations have 1-based lines and 0-based columns.
+      let line_range = |line_index: usize| {
+        let length = lines
+          .get(line_index)
+          .map(|line| line.trim_end_matches('\r').chars().count())
+          .unwrap_or(0);
+        json!({
+          "start": { "line": line_index + 1, "column": 0 },
+          "end": { "line": line_index + 1, "column": length },
+        })
+      };
+
+      let mut statement_map = serde_json::Map::new();
+      let mut statements = serde_json::Map::new();
+      for (i, (line_index, count)) in report.found_lines.iter().enumerate() {
+        statement_map.insert(i.to_string(), line_range(*line_index));
+        statements.insert(i.to_string(), json!(count));
+      }
+
+      let mut fn_map = serde_json::Map::new();
+      let mut functions = serde_json::Map::new();
+      for (i, function) in report.named_functions.iter().enumerate() {
+        let range = line_range(function.line_index);
+        fn_map.insert(
+          i.to_string(),
+          json!({
+            "name": function.name,
+            "decl": range,
+            "loc": range,
+            "line": function.line_index + 1,
+          }),
+        );
+        functions.insert(i.to_string(), json!(function.execution_count));
+      }
+
+      // each block of branches on a line is one Istanbul branch
+      let mut blocks: Vec<((usize, usize), Vec<&BranchCoverageItem>)> =
+        Vec::new();
+      for branch in &report.branches {
+        let key = (branch.line_index, branch.block_number);
+        match blocks.last_mut() {
+          Some((last_key, branches)) if *last_key == key => {
+            branches.push(branch)
+          }
+          _ => blocks.push((key, vec![branch])),
+        }
+      }
+      let mut branch_map = serde_json::Map::new();
+      let mut branches = serde_json::Map::new();
+      for (i, ((line_index, _), block)) in blocks.iter().enumerate() {
+        let range = line_range(*line_index);
+        branch_map.insert(
+          i.to_string(),
+          json!({
+            "loc": range,
+            "type": "branch",
+            "locations": vec![range.clone(); block.len()],
+            "line": line_index + 1,
+          }),
+        );
+        branches.insert(
+          i.to_string(),
+          json!(
+            block
+              .iter()
+              .map(|b| b.taken.unwrap_or(0))
+              .collect::<Vec<_>>()
+          ),
+        );
+      }
+
+      files.insert(
+        path.clone(),
+        json!({
+          "path": path,
+          "statementMap": statement_map,
+          "fnMap": fn_map,
+          "branchMap": branch_map,
+          "s": statements,
+          "f": functions,
+          "b": branches,
+        }),
+      );
+    }
+    serde_json::Value::Object(files)
+  }
+}
+
 struct DetailedCoverageReporter {}
 
 impl CoverageReporter for DetailedCoverageReporter {
@@ -0,0 +1,181 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Minimum coverage percentages that make `deno coverage` fail.
+
+use std::path::Path;
+
+use deno_config::deno_json::CoverageThresholds;
+use deno_config::deno_json::CoverageThresholdsConfig;
+use deno_config::glob::PathGlobMatch;
+use deno_config::glob::PathOrPattern;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+
+use super::CoverageReport;
+use super::reporter::CoverageStats;
+use super::util;
+
+/// Returns an error listing every threshold that the reports don't meet.
+pub fn check(
+  file_reports: &[(CoverageReport, String)],
+  config: &CoverageThresholdsConfig,
+  cwd: &Path,
+) -> Result<(), AnyError> {
+  let mut failures = Vec::new();
+  if !config.global.is_empty() {
+    let mut stats = CoverageStats::default();
+    for (report, _) in file_reports {
+      stats.add(report);
+    }
+    check_stats("all files", &stats, config.global, &mut failures);
+  }
+
+  for (path, thresholds) in &config.per_path {
+    let mut stats = CoverageStats::default();
+    let mut matched = false;
+    for (report, _) in file_reports {
+      let Ok(file_path) = report.url.to_file_path() else {
+        continue;
+      };
+      if path.matches_path(&file_path) == PathGlobMatch::Matched {
+        stats.add(report);
+        matched = true;
+      }
+    }
+    if matched {
+      let name = display_path(path, cwd);
+      check_stats(&name, &stats, *thresholds, &mut failures);
+    }
+  }
+
+  if !failures.is_empty() {
+    bail!("Coverage thresholds not met:\n{}", failures.join("\n"));
+  }
+  Ok(())
+}
+
+fn check_stats(
+  name: &str,
+  stats: &CoverageStats,
+  thresholds: CoverageThresholds,
+  failures: &mut Vec<String>,
+) {
+  let kinds = [
+    ("Line", thresholds.lines, stats.line_hit, stats.line_miss),
+    (
+      "Branch",
+      thresholds.branches,
+      stats.branch_hit,
+      stats.branch_miss,
+    ),
+    (
+      "Function",
+      thresholds.functions,
+      stats.fn_hit,
+      stats.fn_miss,
+    ),
+  ];
+  for (kind, threshold, hit, miss) in kinds {
+    let Some(threshold) = threshold else {
+      continue;
+    };
+    let (_, percent, _) = util::calc_coverage_display_info(hit, miss);
+    if percent < threshold as f32 {
+      failures.push(format!(
+        "  {kind} coverage of {percent:.1}% is below the threshold of {threshold}% for {name}"
+      ));
+    }
+  }
+}
+
+fn display_path(path: &PathOrPattern, cwd: &Path) -> String {
+  match path {
+    PathOrPattern::Path(path) | PathOrPattern::NegatedPath(path) => path
+      .strip_prefix(cwd)
+      .unwrap_or(path)
+      .to_string_lossy()
+      .replace('\\', "/"),
+    PathOrPattern::RemoteUrl(url) => url.to_string(),
+    PathOrPattern::Pattern(pattern) => pattern.as_str().into_owned(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use deno_core::url::Url;
+
+  use super::*;
+  use crate::tools::coverage::FunctionCoverageItem;
+
+  fn report(path: &str, lines: &[i64], functions: &[i64]) -> CoverageReport {
+    CoverageReport {
+      url: Url::from_file_path(path).unwrap(),
+      named_functions: functions
+        .iter()
+        .enumerate()
+        .map(|(i, count)| FunctionCoverageItem {
+          name: format!("f{i}"),
+          line_index: i,
+          execution_count: *count,
+        })
+        .collect(),
+      branches: vec![],
+      found_lines: lines.iter().copied().enumerate().collect(),
+      output: None,
+    }
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn checks_thresholds() {
+    let cwd = PathBuf::from("/project");
+    let reports = vec![
+      (
+        report("/project/src/a.ts", &[1, 1, 0, 0], &[1]),
+        String::new(),
+      ),
+      (
+        report("/project/lib/b.ts", &[1, 1, 1, 1], &[1, 0]),
+        String::new(),
+      ),
+    ];
+
+    // 6/8 lines, 2/3 functions
+    let config = CoverageThresholdsConfig {
+      global: CoverageThresholds {
+        lines: Some(75),
+        branches: Some(100),
+        functions: Some(60),
+      },
+      per_path: vec![],
+    };
+    assert!(check(&reports, &config, &cwd).is_ok());
+
+    let config = CoverageThresholdsConfig {
+      global: CoverageThresholds {
+        lines: Some(80),
+        branches: None,
+        functions: None,
+      },
+      per_path: vec![(
+        PathOrPattern::Path(PathBuf::from("/project/src")),
+        CoverageThresholds {
+          lines: Some(60),
+          branches: None,
+          functions: Some(100),
+        },
+      )],
+    };
+    let err = check(&reports, &config, &cwd).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      concat!(
+        "Coverage thresholds not met:\n",
+        "  Line coverage of 75.0% is below the threshold of 80% for all files\n",
+        "  Line coverage of 50.0% is below the threshold of 60% for src"
+      )
+    );
+  }
+}
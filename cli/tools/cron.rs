@@ -0,0 +1,118 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::path::PathBuf;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_runtime::deno_cron::store::CronStateStore;
+use deno_runtime::deno_cron::store::PersistedCronState;
+
+use crate::args::CronFlags;
+use crate::colors;
+use crate::util::display;
+
+pub fn cron(cron_flags: CronFlags) -> Result<(), AnyError> {
+  let Some(state_path) = cron_flags
+    .state_path
+    .map(PathBuf::from)
+    .or_else(|| std::env::var_os("DENO_CRON_STATE_PATH").map(PathBuf::from))
+  else {
+    bail!(
+      "No cron state file specified. Pass --state-path or set DENO_CRON_STATE_PATH."
+    );
+  };
+  if !state_path.exists() {
+    bail!("Cron state file not found: {}", state_path.display());
+  }
+
+  // read only, as the file may be in use by a running process
+  let store =
+    CronStateStore::open_read_only(&state_path).with_context(|| {
+      format!("Failed to open cron state file {}", state_path.display())
+    })?;
+  let crons = store.list().with_context(|| {
+    format!("Failed to read cron state file {}", state_path.display())
+  })?;
+
+  if cron_flags.json {
+    return display::write_json_to_stdout(&crons);
+  }
+
+  if crons.is_empty() {
+    log::info!("No crons recorded in {}", state_path.display());
+    return Ok(());
+  }
+  for cron in &crons {
+    log::info!("{}", format_cron(cron));
+  }
+  Ok(())
+}
+
+fn format_cron(cron: &PersistedCronState) -> String {
+  let last_outcome = match cron.last_success {
+    Some(true) => colors::green("ok").to_string(),
+    Some(false) => colors::red("failed").to_string(),
+    None => colors::gray("never run").to_string(),
+  };
+  let mut out = format!(
+    "{}\n  schedule:  {} ({})\n  next run:  {}\n  last run:  {} {}",
+    colors::bold(&cron.name),
+    cron.cron_schedule,
+    cron.timezone,
+    format_timestamp(cron.next_deadline_ms),
+    format_timestamp(cron.last_run_ms),
+    last_outcome,
+  );
+  if cron.current_execution_retries > 0 {
+    out.push_str(&format!(
+      "\n  retries:   {}",
+      colors::yellow(cron.current_execution_retries.to_string())
+    ));
+  }
+  if cron.missed_runs > 0 {
+    out.push_str(&format!(
+      "\n  missed:    {}",
+      colors::yellow(cron.missed_runs.to_string())
+    ));
+  }
+  out
+}
+
+fn format_timestamp(ms: Option<u64>) -> String {
+  ms.and_then(|ms| chrono::DateTime::from_timestamp_millis(ms as i64))
+    .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+    .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_timestamp() {
+    assert_eq!(format_timestamp(None), "-");
+    assert_eq!(
+      format_timestamp(Some(1_700_000_000_000)),
+      "2023-11-14T22:13:20Z"
+    );
+  }
+
+  #[test]
+  fn test_format_cron_retries() {
+    let mut cron = PersistedCronState {
+      name: "a".to_string(),
+      cron_schedule: "* * * * *".to_string(),
+      timezone: "UTC".to_string(),
+      next_deadline_ms: None,
+      last_run_ms: None,
+      last_success: Some(false),
+      missed_runs: 0,
+      current_execution_retries: 0,
+    };
+    assert!(!format_cron(&cron).contains("retries:"));
+    cron.current_execution_retries = 2;
+    let out = test_util::strip_ansi_codes(&format_cron(&cron)).to_string();
+    assert!(out.contains("\n  retries:   2"), "{out}");
+  }
+}
@@ -3,10 +3,12 @@
 //! This module provides file linting utilities using
 //! [`deno_lint`](https://github.com/denoland/deno_lint).
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
 use std::io::Read;
 use std::io::stdin;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -27,9 +29,12 @@ use deno_core::parking_lot::Mutex;
 use deno_core::serde_json;
 use deno_core::unsync::future::LocalFutureExt;
 use deno_core::unsync::future::SharedLocal;
+use deno_graph::GraphKind;
+use deno_graph::Module;
 use deno_graph::ModuleGraph;
 use deno_lib::util::hash::FastInsecureHasher;
 use deno_lint::diagnostic::LintDiagnostic;
+use deno_npm_installer::graph::NpmCachingStrategy;
 use deno_resolver::deno_json::CompilerOptionsResolver;
 use log::debug;
 use reporters::LintReporter;
@@ -112,6 +117,7 @@ pub async fn lint(
       compiler_options_resolver.clone(),
       cli_options.start_dir.clone(),
       &workspace_lint_options,
+      cli_options.initial_cwd(),
     );
     let paths_with_options_batches =
       resolve_paths_with_options_batches(cli_options, &lint_flags)?;
@@ -171,6 +177,7 @@ async fn lint_with_watch_inner(
     factory.compiler_options_resolver()?.clone(),
     cli_options.start_dir.clone(),
     &cli_options.resolve_workspace_lint_options(&lint_flags)?,
+    cli_options.initial_cwd(),
   );
   for paths_with_options in paths_with_options_batches {
     linter
@@ -217,6 +224,56 @@ struct PathsWithOptions {
   options: LintOptions,
 }
 
+/// Hashes the plugin rule configuration and the source of every module in
+/// the plugins' module graph, so that changing a plugin or any of its
+/// dependencies invalidates the incremental cache.
+///
+/// Returns `None` when the graph can't be built without errors.
+async fn plugins_cache_state(
+  module_graph_creator: &ModuleGraphCreator,
+  plugin_specifiers: &[ModuleSpecifier],
+  exclude: Option<&[String]>,
+) -> Option<u64> {
+  let graph = module_graph_creator
+    .create_graph(
+      GraphKind::CodeOnly,
+      plugin_specifiers.to_vec(),
+      NpmCachingStrategy::Eager,
+    )
+    .await
+    .ok()?;
+  if let Err(err) = module_graph_creator.graph_valid(&graph) {
+    debug!("Not caching lint results. Failed to build plugin graph: {err}");
+    return None;
+  }
+
+  let mut hasher = FastInsecureHasher::new_without_deno_version();
+  hasher.write_hashable(plugin_specifiers);
+  let mut exclude = exclude.unwrap_or_default().to_vec();
+  exclude.sort_unstable();
+  hasher.write_hashable(exclude);
+
+  let mut modules = graph.modules().collect::<Vec<_>>();
+  modules.sort_unstable_by(|a, b| a.specifier().cmp(b.specifier()));
+  for module in modules {
+    hasher.write_str(module.specifier().as_str());
+    match module {
+      Module::Js(module) => {
+        hasher.write_str(&module.source.text);
+      }
+      Module::Json(module) => {
+        hasher.write_str(&module.source.text);
+      }
+      Module::Wasm(module) => {
+        hasher.write(&module.source);
+      }
+      // the specifier of npm packages contains the resolved version
+      Module::Npm(_) | Module::Node(_) | Module::External(_) => {}
+    }
+  }
+  Some(hasher.finish())
+}
+
 fn resolve_paths_with_options_batches(
   cli_options: &CliOptions,
   lint_flags: &LintFlags,
@@ -264,9 +321,12 @@ impl WorkspaceLinter {
     compiler_options_resolver: Arc<CompilerOptionsResolver>,
     workspace_dir: Arc<WorkspaceDirectory>,
     workspace_options: &WorkspaceLintOptions,
+    cwd: &Path,
   ) -> Self {
-    let reporter_lock =
-      Arc::new(Mutex::new(create_reporter(workspace_options.reporter_kind)));
+    let reporter_lock = Arc::new(Mutex::new(create_reporter(
+      workspace_options.reporter_kind,
+      cwd,
+    )));
     Self {
       caches,
       lint_rule_provider,
@@ -298,21 +358,32 @@ impl WorkspaceLinter {
 
     let mut maybe_incremental_cache = None;
 
-    // TODO(bartlomieju): for now we don't support incremental caching if plugins are being used.
-    // https://github.com/denoland/deno/issues/28025
-    if lint_rules.supports_incremental_cache() && plugin_specifiers.is_empty() {
+    if lint_rules.supports_incremental_cache() {
       let mut hasher = FastInsecureHasher::new_deno_versioned();
       hasher.write_hashable(lint_rules.incremental_cache_state());
-      if !plugin_specifiers.is_empty() {
-        hasher.write_hashable(&plugin_specifiers);
+      let plugins_state = if plugin_specifiers.is_empty() {
+        Some(None)
+      } else {
+        plugins_cache_state(
+          &self.module_graph_creator,
+          &plugin_specifiers,
+          exclude.as_deref(),
+        )
+        .await
+        .map(Some)
+      };
+      // don't cache when the plugins couldn't be hashed, as a change to
+      // them wouldn't invalidate the cache
+      if let Some(plugins_state) = plugins_state {
+        hasher.write_hashable(plugins_state);
+        let state_hash = hasher.finish();
+
+        maybe_incremental_cache = Some(Arc::new(IncrementalCache::new(
+          self.caches.lint_incremental_cache_db(),
+          CacheDBHash::new(state_hash),
+          &paths,
+        )));
       }
-      let state_hash = hasher.finish();
-
-      maybe_incremental_cache = Some(Arc::new(IncrementalCache::new(
-        self.caches.lint_incremental_cache_db(),
-        CacheDBHash::new(state_hash),
-        &paths,
-      )));
     }
 
     #[allow(clippy::print_stdout, reason = "actually want to output")]
@@ -325,13 +396,46 @@ impl WorkspaceLinter {
       }
     }
 
+    // don't bother rechecking the files that didn't have any diagnostics
+    // before, and keep the text of the others so that they're only read once
+    let mut file_texts = HashMap::new();
+    let lint_paths = match &maybe_incremental_cache {
+      Some(incremental_cache) => paths
+        .iter()
+        .filter(|file_path| match fs::read_to_string(file_path) {
+          Ok(file_text) => {
+            let file_text = deno_ast::strip_bom(file_text);
+            if incremental_cache.is_file_same(file_path, &file_text) {
+              false
+            } else {
+              file_texts.insert((*file_path).clone(), file_text);
+              true
+            }
+          }
+          // report the error when linting the file
+          Err(_) => true,
+        })
+        .cloned()
+        .collect(),
+      None => paths.clone(),
+    };
+    let file_texts = Arc::new(Mutex::new(file_texts));
+
+    // plugins run in a pool of isolates, so files finish in any order
+    let report_in_order = !plugin_specifiers.is_empty();
     let mut plugin_runner = None;
     if !plugin_specifiers.is_empty() {
       let logger = plugins::PluginLogger::new(logger_printer);
+      // lint the files with the plugins in parallel, but don't start more
+      // isolates than there are files to lint
+      let pool_size = crate::args::parallelism_count(true)
+        .get()
+        .min(lint_paths.len());
       let runner = plugins::create_runner_and_load_plugins(
         plugin_specifiers,
         logger,
         exclude,
+        pool_size,
       )
       .await?;
       plugin_runner = Some(Arc::new(runner));
@@ -363,16 +467,16 @@ impl WorkspaceLinter {
     let linter = linter.clone();
     let cli_options = cli_options.clone();
     let fut = async move {
+      let ordered_results =
+        report_in_order.then(|| Arc::new(Mutex::new(Vec::new())));
+      let ordered_results_ = ordered_results.clone();
+      let reporter_lock_ = reporter_lock.clone();
+      let has_error_ = has_error.clone();
       let operation = move |file_path: PathBuf| {
-        let file_text = deno_ast::strip_bom(fs::read_to_string(&file_path)?);
-
-        // don't bother rechecking this file if it didn't have any diagnostics before
-        if let Some(incremental_cache) = &maybe_incremental_cache_
-          && incremental_cache.is_file_same(&file_path, &file_text)
-        {
-          return Ok(());
-        }
-
+        let file_text = match file_texts.lock().remove(&file_path) {
+          Some(file_text) => file_text,
+          None => deno_ast::strip_bom(fs::read_to_string(&file_path)?),
+        };
         let r = linter.lint_file(
           &file_path,
           file_text,
@@ -390,18 +494,42 @@ impl WorkspaceLinter {
           )
         }
 
-        let success = handle_lint_result(
-          &file_path.to_string_lossy(),
-          r,
-          reporter_lock.clone(),
-        );
-        if !success {
-          has_error.raise();
+        match &ordered_results_ {
+          Some(results) => results.lock().push((file_path, r)),
+          None => {
+            let success = handle_lint_result(
+              &file_path.to_string_lossy(),
+              r,
+              reporter_lock_.clone(),
+            );
+            if !success {
+              has_error_.raise();
+            }
+          }
         }
 
         Ok(())
       };
-      run_parallelized(paths, operation).await
+      run_parallelized(lint_paths, operation).await?;
+
+      // report the files linted by plugins in a deterministic order,
+      // regardless of the order in which they were linted
+      if let Some(results) = ordered_results {
+        let mut results = std::mem::take(&mut *results.lock());
+        results.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (file_path, r) in results {
+          let success = handle_lint_result(
+            &file_path.to_string_lossy(),
+            r,
+            reporter_lock.clone(),
+          );
+          if !success {
+            has_error.raise();
+          }
+        }
+      }
+
+      Ok(())
     }
     .boxed_local();
     futures.push(fut);
@@ -587,6 +715,7 @@ fn lint_stdin(
   let start_dir = &cli_options.start_dir;
   let reporter_lock = Arc::new(Mutex::new(create_reporter(
     workspace_lint_options.reporter_kind,
+    cli_options.initial_cwd(),
   )));
   let lint_config = start_dir
     .to_lint_config(FilePatterns::new_with_base(start_dir.dir_path()))?;
@@ -14,6 +14,7 @@ use deno_core::anyhow::bail;
 use deno_core::error::AnyError;
 use deno_core::error::JsError;
 use deno_core::futures::FutureExt;
+use deno_core::futures::future::join_all;
 use deno_core::parking_lot::Mutex;
 use deno_core::v8;
 use deno_lint::diagnostic::LintDiagnostic;
@@ -39,6 +40,8 @@ pub enum PluginHostRequest {
   LoadPlugins {
     specifiers: Vec<ModuleSpecifier>,
     exclude_rules: Option<Vec<String>>,
+    /// Don't print the output of the plugins while loading them.
+    quiet: bool,
     tx: oneshot::Sender<PluginHostResponse>,
   },
   Run {
@@ -100,8 +103,36 @@ v8_static_strings! {
 }
 
 #[derive(Debug)]
-pub struct PluginHostProxy {
+struct PluginHostHandle {
   tx: mpsc::Sender<PluginHostRequest>,
+}
+
+/// The requests sent to a plugin host, ordered by the number of requests
+/// that weren't answered yet and then by the total number of requests.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct PluginHostLoad {
+  pending: usize,
+  total: usize,
+}
+
+/// Marks a request to a plugin host as answered when dropped.
+struct PendingRequestGuard<'a> {
+  loads: &'a Mutex<Vec<PluginHostLoad>>,
+  index: usize,
+}
+
+impl Drop for PendingRequestGuard<'_> {
+  fn drop(&mut self) {
+    self.loads.lock()[self.index].pending -= 1;
+  }
+}
+
+/// A pool of plugin hosts, each running the plugins in its own isolate on
+/// its own thread, so that files can be linted by plugins in parallel.
+#[derive(Debug)]
+pub struct PluginHostProxy {
+  hosts: Vec<PluginHostHandle>,
+  loads: Mutex<Vec<PluginHostLoad>>,
   pub(crate) plugin_info: Arc<Mutex<Vec<PluginInfo>>>,
 }
 
@@ -227,7 +258,7 @@ impl PluginInfo {
 }
 
 impl PluginHost {
-  fn create(logger: PluginLogger) -> Result<PluginHostProxy, AnyError> {
+  fn create(logger: PluginLogger) -> PluginHostHandle {
     let (tx_req, rx_req) = mpsc::channel(10);
 
     let logger_ = logger.clone();
@@ -251,12 +282,7 @@ impl PluginHost {
         tokio_util::create_and_run_current_thread(fut)
       });
 
-    let proxy = PluginHostProxy {
-      tx: tx_req,
-      plugin_info: Arc::new(Mutex::new(vec![])),
-    };
-
-    Ok(proxy)
+    PluginHostHandle { tx: tx_req }
   }
 
   async fn run_loop(mut self) -> Result<(), AnyError> {
@@ -267,9 +293,10 @@ impl PluginHost {
         PluginHostRequest::LoadPlugins {
           specifiers,
           exclude_rules,
+          quiet,
           tx,
         } => {
-          let r = self.load_plugins(specifiers, exclude_rules).await;
+          let r = self.load_plugins(specifiers, exclude_rules, quiet).await;
           let _ = tx.send(PluginHostResponse::LoadPlugin(r));
         }
         PluginHostRequest::Run {
@@ -368,6 +395,25 @@ impl PluginHost {
     &mut self,
     plugin_specifiers: Vec<ModuleSpecifier>,
     exclude: Option<Vec<String>>,
+    quiet: bool,
+  ) -> Result<Vec<PluginInfo>, AnyError> {
+    if !quiet {
+      return self.load_plugins_inner(plugin_specifiers, exclude).await;
+    }
+    let op_state = self.worker.js_runtime.op_state();
+    let logger = op_state.borrow().borrow::<PluginLogger>().clone();
+    op_state
+      .borrow_mut()
+      .put(PluginLogger::new(|_msg, _is_err| {}));
+    let result = self.load_plugins_inner(plugin_specifiers, exclude).await;
+    op_state.borrow_mut().put(logger);
+    result
+  }
+
+  async fn load_plugins_inner(
+    &mut self,
+    plugin_specifiers: Vec<ModuleSpecifier>,
+    exclude: Option<Vec<String>>,
   ) -> Result<Vec<PluginInfo>, AnyError> {
     let mut load_futures = Vec::with_capacity(plugin_specifiers.len());
     for specifier in plugin_specifiers {
@@ -449,18 +495,20 @@ impl PluginHost {
   }
 }
 
-impl PluginHostProxy {
-  pub async fn load_plugins(
+impl PluginHostHandle {
+  async fn load_plugins(
     &self,
     specifiers: Vec<ModuleSpecifier>,
     exclude_rules: Option<Vec<String>>,
-  ) -> Result<(), AnyError> {
+    quiet: bool,
+  ) -> Result<Vec<PluginInfo>, AnyError> {
     let (tx, rx) = oneshot::channel();
     self
       .tx
       .send(PluginHostRequest::LoadPlugins {
         specifiers,
         exclude_rules,
+        quiet,
         tx,
       })
       .await?;
@@ -469,14 +517,12 @@ impl PluginHostProxy {
       let PluginHostResponse::LoadPlugin(result) = val else {
         unreachable!()
       };
-      let infos = result?;
-      *self.plugin_info.lock() = infos;
-      return Ok(());
+      return result;
     }
     bail!("Plugin host has closed")
   }
 
-  pub async fn run_rules(
+  async fn run_rules(
     &self,
     specifier: &Path,
     serialized_ast: Vec<u8>,
@@ -502,6 +548,80 @@ impl PluginHostProxy {
     }
     bail!("Plugin host has closed")
   }
+}
+
+impl PluginHostProxy {
+  /// Starts `pool_size` plugin hosts, at least one.
+  fn create(logger: PluginLogger, pool_size: usize) -> Self {
+    log::debug!("Starting {} lint plugin host(s)", pool_size.max(1));
+    let hosts = (0..pool_size.max(1))
+      .map(|_| PluginHost::create(logger.clone()))
+      .collect::<Vec<_>>();
+    Self {
+      loads: Mutex::new(vec![PluginHostLoad::default(); hosts.len()]),
+      hosts,
+      plugin_info: Arc::new(Mutex::new(vec![])),
+    }
+  }
+
+  pub async fn load_plugins(
+    &self,
+    specifiers: Vec<ModuleSpecifier>,
+    exclude_rules: Option<Vec<String>>,
+  ) -> Result<(), AnyError> {
+    // only the first host prints the output of the plugins while loading,
+    // so that it isn't repeated for every host of the pool
+    let results = join_all(self.hosts.iter().enumerate().map(|(i, host)| {
+      host.load_plugins(specifiers.clone(), exclude_rules.clone(), i > 0)
+    }))
+    .await;
+    let mut results = results.into_iter();
+    let infos = results.next().unwrap()?;
+    for result in results {
+      result?;
+    }
+    *self.plugin_info.lock() = infos;
+    Ok(())
+  }
+
+  /// Runs the plugins on the host with the fewest pending requests,
+  /// preferring the hosts that received fewer requests in total so that
+  /// every host of the pool is used.
+  pub async fn run_rules(
+    &self,
+    specifier: &Path,
+    serialized_ast: Vec<u8>,
+    source_text_info: SourceTextInfo,
+    utf16_map: Utf16Map,
+    maybe_token: Option<CancellationToken>,
+  ) -> Result<Vec<LintDiagnostic>, AnyError> {
+    let index = {
+      let mut loads = self.loads.lock();
+      let (index, load) = loads
+        .iter_mut()
+        .enumerate()
+        .min_by_key(|(_, load)| **load)
+        .unwrap();
+      load.pending += 1;
+      load.total += 1;
+      index
+    };
+    // decrements `pending` even if this future is dropped before the host
+    // answered
+    let _pending = PendingRequestGuard {
+      loads: &self.loads,
+      index,
+    };
+    self.hosts[index]
+      .run_rules(
+        specifier,
+        serialized_ast,
+        source_text_info,
+        utf16_map,
+        maybe_token,
+      )
+      .await
+  }
 
   pub fn serialize_ast(
     &self,
@@ -518,12 +638,15 @@ impl PluginHostProxy {
   }
 }
 
+/// Starts a pool of `pool_size` plugin hosts and loads the plugins in each
+/// of them.
 pub async fn create_runner_and_load_plugins(
   plugin_specifiers: Vec<ModuleSpecifier>,
   logger: PluginLogger,
   exclude: Option<Vec<String>>,
+  pool_size: usize,
 ) -> Result<PluginHostProxy, AnyError> {
-  let host_proxy = PluginHost::create(logger)?;
+  let host_proxy = PluginHostProxy::create(logger, pool_size);
   host_proxy.load_plugins(plugin_specifiers, exclude).await?;
   Ok(host_proxy)
 }
@@ -1,8 +1,15 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_ast::SourceRange;
+use deno_ast::SourceTextInfo;
 use deno_ast::diagnostics::Diagnostic;
 use deno_core::error::AnyError;
 use deno_core::serde_json;
+use deno_core::url::Url;
 use deno_lib::util::result::js_error_downcast_ref;
 use deno_lint::diagnostic::LintDiagnostic;
 use deno_runtime::colors;
@@ -10,15 +17,28 @@ use deno_runtime::fmt_errors::format_js_error;
 use log::info;
 use serde::Serialize;
 
+use super::rules::LintRuleProvider;
 use crate::args::LintReporterKind;
+use crate::util::display;
+use crate::util::github_actions::Annotation;
+use crate::util::github_actions::AnnotationLevel;
+use crate::util::github_actions::AnnotationPosition;
+use crate::util::github_actions::annotation_file;
+use crate::util::sarif;
+use crate::util::sarif::SarifRun;
 
 const JSON_SCHEMA_VERSION: u8 = 1;
 
-pub fn create_reporter(kind: LintReporterKind) -> Box<dyn LintReporter + Send> {
+pub fn create_reporter(
+  kind: LintReporterKind,
+  cwd: &Path,
+) -> Box<dyn LintReporter + Send> {
   match kind {
     LintReporterKind::Pretty => Box::new(PrettyLintReporter::new()),
     LintReporterKind::Json => Box::new(JsonLintReporter::new()),
     LintReporterKind::Compact => Box::new(CompactLintReporter::new()),
+    LintReporterKind::Sarif => Box::new(SarifLintReporter::new(cwd)),
+    LintReporterKind::Github => Box::new(GithubLintReporter::new(cwd)),
   }
 }
 
@@ -288,3 +308,207 @@ fn sort_diagnostics(diagnostics: &mut [JsonLintDiagnostic]) {
     }
   });
 }
+
+struct SarifLintReporter {
+  run: SarifRun,
+  rule_tags: HashMap<&'static str, Vec<String>>,
+}
+
+impl SarifLintReporter {
+  fn new(cwd: &Path) -> SarifLintReporter {
+    let rule_tags = LintRuleProvider::new(None)
+      .all_rules()
+      .iter()
+      .map(|rule| {
+        let tags = rule.tags().iter().map(|t| t.display().to_string());
+        (rule.code(), tags.collect())
+      })
+      .collect();
+    SarifLintReporter {
+      run: SarifRun::new(
+        "deno lint",
+        "https://docs.deno.com/go/lint",
+        sarif::ColumnKind::UnicodeCodePoints,
+        cwd,
+      ),
+      rule_tags,
+    }
+  }
+}
+
+impl LintReporter for SarifLintReporter {
+  fn visit_diagnostic(&mut self, d: &LintDiagnostic) {
+    let artifact_location = self.run.artifact_location(&d.specifier);
+    let fixes = match &d.range {
+      Some(range) => d
+        .details
+        .fixes
+        .iter()
+        .map(|fix| sarif::Fix {
+          description: sarif::Message::new(fix.description.to_string()),
+          artifact_changes: vec![sarif::ArtifactChange {
+            artifact_location: artifact_location.clone(),
+            replacements: fix
+              .changes
+              .iter()
+              .map(|change| sarif::Replacement {
+                deleted_region: sarif_region(&range.text_info, change.range),
+                inserted_content: sarif::ArtifactContent {
+                  text: change.new_text.to_string(),
+                },
+              })
+              .collect(),
+          }],
+        })
+        .collect(),
+      None => Vec::new(),
+    };
+    let mut message = d.message().to_string();
+    if let Some(hint) = d.hint() {
+      message.push_str("\n\n");
+      message.push_str(&hint);
+    }
+    let code = d.code();
+    let result = sarif::SarifResult {
+      rule_id: code.to_string(),
+      rule_index: 0,
+      level: sarif::Level::Error,
+      message: sarif::Message::new(message),
+      locations: vec![sarif::Location {
+        physical_location: sarif::PhysicalLocation {
+          artifact_location,
+          region: d
+            .range
+            .as_ref()
+            .map(|range| sarif_region(&range.text_info, range.range)),
+        },
+        message: None,
+      }],
+      related_locations: Vec::new(),
+      fixes,
+    };
+    self.run.add_result(result, || sarif::Rule {
+      id: code.to_string(),
+      help_uri: d.docs_url().map(|url| url.into_owned()),
+      properties: self
+        .rule_tags
+        .get(code.as_ref())
+        .map(|tags| sarif::RuleProperties { tags: tags.clone() }),
+    });
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    let location =
+      Url::from_file_path(file_path)
+        .ok()
+        .map(|specifier| sarif::Location {
+          physical_location: sarif::PhysicalLocation {
+            artifact_location: self.run.artifact_location(&specifier),
+            region: None,
+          },
+          message: None,
+        });
+    self
+      .run
+      .add_error(format!("Error linting {file_path}: {err}"), location);
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    display::write_json_to_stdout(&self.run.to_log()).unwrap();
+  }
+}
+
+fn sarif_region(
+  text_info: &SourceTextInfo,
+  range: SourceRange,
+) -> sarif::Region {
+  let start = text_info.line_and_column_index(range.start);
+  let end = text_info.line_and_column_index(range.end);
+  sarif::Region {
+    start_line: start.line_index + 1,
+    start_column: start.column_index + 1,
+    end_line: Some(end.line_index + 1),
+    end_column: Some(end.column_index + 1),
+  }
+}
+
+struct GithubLintReporter {
+  cwd: PathBuf,
+  lint_count: u32,
+}
+
+impl GithubLintReporter {
+  fn new(cwd: &Path) -> GithubLintReporter {
+    GithubLintReporter {
+      cwd: cwd.to_path_buf(),
+      lint_count: 0,
+    }
+  }
+}
+
+impl LintReporter for GithubLintReporter {
+  fn visit_diagnostic(&mut self, d: &LintDiagnostic) {
+    self.lint_count += 1;
+
+    let position = |text_info: &SourceTextInfo, pos| {
+      let loc = text_info.line_and_column_index(pos);
+      AnnotationPosition {
+        line: loc.line_index + 1,
+        column: loc.column_index + 1,
+      }
+    };
+    let mut message = d.message().to_string();
+    if let Some(hint) = d.hint() {
+      message.push_str("\n\n");
+      message.push_str(&hint);
+    }
+    let annotation = Annotation {
+      level: AnnotationLevel::Error,
+      file: annotation_file(&d.specifier, &self.cwd),
+      start: d
+        .range
+        .as_ref()
+        .map(|range| position(&range.text_info, range.range.start)),
+      end: d
+        .range
+        .as_ref()
+        .map(|range| position(&range.text_info, range.range.end)),
+      title: Some(d.code().to_string()),
+      message,
+    };
+    #[allow(clippy::print_stdout, reason = "reporter")]
+    {
+      println!("{}", annotation.to_workflow_command());
+    }
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    let annotation = Annotation {
+      level: AnnotationLevel::Error,
+      file: Url::from_file_path(file_path)
+        .ok()
+        .and_then(|specifier| annotation_file(&specifier, &self.cwd)),
+      start: None,
+      end: None,
+      title: Some("Error linting".to_string()),
+      message: err.to_string(),
+    };
+    #[allow(clippy::print_stdout, reason = "reporter")]
+    {
+      println!("{}", annotation.to_workflow_command());
+    }
+  }
+
+  fn close(&mut self, check_count: usize) {
+    match self.lint_count {
+      1 => info!("Found 1 problem"),
+      n if n > 1 => info!("Found {} problems", self.lint_count),
+      _ => (),
+    }
+
+    match check_count {
+      1 => info!("Checked 1 file"),
+      n => info!("Checked {} files", n),
+    }
+  }
+}
@@ -0,0 +1,84 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! A git merge driver for lockfiles, which three-way merges the versions of
+//! the lockfile of two branches instead of letting git merge them as text.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_lockfile::Lockfile;
+use deno_lockfile::NewLockfileOptions;
+use deno_lockfile::NpmPackageInfoProvider;
+
+use crate::args::Flags;
+use crate::args::MergeLockfileFlags;
+use crate::factory::CliFactory;
+
+pub async fn merge_lockfile(
+  flags: Arc<Flags>,
+  merge_flags: MergeLockfileFlags,
+) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  let npm_package_info_provider = factory
+    .npm_installer_factory()?
+    .lockfile_npm_package_info_provider()?;
+
+  let base =
+    read_lockfile(Path::new(&merge_flags.base), npm_package_info_provider)
+      .await?;
+  let mut lockfile =
+    read_lockfile(Path::new(&merge_flags.ours), npm_package_info_provider)
+      .await?;
+  let theirs =
+    read_lockfile(Path::new(&merge_flags.theirs), npm_package_info_provider)
+      .await?;
+  let conflicts = lockfile.merge(&base.content, &theirs.content);
+  // the configuration files of the working tree are still the ones of the
+  // current branch while git runs the driver, so the merged workspace section
+  // is kept as is instead of validating the lockfile against them
+  std::fs::write(&merge_flags.ours, lockfile.as_json_string())
+    .with_context(|| format!("Failed writing '{}'", merge_flags.ours))?;
+
+  if !conflicts.is_empty() {
+    let mut message = format!(
+      "Could not merge '{}' because the integrity of the following differs between the branches:",
+      merge_flags.ours
+    );
+    for conflict in &conflicts {
+      message.push_str(&format!("\n  {conflict}"));
+    }
+    message.push_str(
+      "\n\nThe integrity of the current branch was kept. Verify which one is correct before committing the merge.",
+    );
+    bail!(message);
+  }
+
+  Ok(())
+}
+
+async fn read_lockfile(
+  path: &Path,
+  npm_package_info_provider: &dyn NpmPackageInfoProvider,
+) -> Result<Lockfile, AnyError> {
+  let content = std::fs::read_to_string(path)
+    .with_context(|| format!("Failed reading '{}'", path.display()))?;
+  // the lockfile doesn't exist in the common ancestor when both branches
+  // added it, which git represents as an empty file
+  if content.trim().is_empty() {
+    return Ok(Lockfile::new_empty(PathBuf::from(path), false));
+  }
+  let lockfile = Lockfile::new(
+    NewLockfileOptions {
+      file_path: PathBuf::from(path),
+      content: &content,
+      overwrite: false,
+    },
+    npm_package_info_provider,
+  )
+  .await?;
+  Ok(lockfile)
+}
@@ -6,6 +6,7 @@ pub mod check;
 pub mod clean;
 pub mod compile;
 pub mod coverage;
+pub mod cron;
 pub mod deploy;
 pub mod doc;
 pub mod fmt;
@@ -14,12 +15,17 @@ pub mod init;
 pub mod installer;
 pub mod jupyter;
 pub mod lint;
+pub mod merge_lockfile;
+pub mod permission_audit;
+pub mod permission_broker;
 pub mod pm;
 pub mod publish;
 pub mod repl;
 pub mod run;
+pub mod sbom;
 pub mod serve;
 pub mod task;
 pub mod test;
 pub mod upgrade;
+pub mod why;
 pub mod x;
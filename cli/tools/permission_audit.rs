@@ -0,0 +1,263 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Generates a deno.json permission set from the permission accesses of a
+//! program, enabled with the `DENO_GENERATE_PERMISSIONS` env var.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use deno_config::deno_json::AllowDenyIgnorePermissionConfig;
+use deno_config::deno_json::AllowDenyPermissionConfig;
+use deno_config::deno_json::PermissionConfigValue;
+use deno_config::deno_json::PermissionsObject;
+use deno_core::serde_json;
+use deno_core::serde_json::json;
+use deno_core::url::Url;
+use deno_path_util::normalize_path;
+use deno_runtime::deno_permissions::AUDIT_SINK;
+use deno_runtime::deno_permissions::AuditEntry;
+use deno_runtime::deno_permissions::AuditSink;
+use deno_terminal::colors;
+
+/// Name of the permission set in the generated config.
+const PERMISSION_SET_NAME: &str = "default";
+
+struct GenerateOptions {
+  output: PathBuf,
+  cwd: PathBuf,
+}
+
+static GENERATE_OPTIONS: OnceLock<GenerateOptions> = OnceLock::new();
+
+/// Starts collecting permission accesses. The generated permission set is
+/// written to `output` when the process exits.
+pub fn init(output: PathBuf, cwd: PathBuf) {
+  let sink = AuditSink::Collect(Default::default());
+  if AUDIT_SINK.set(sink).is_err()
+    || GENERATE_OPTIONS
+      .set(GenerateOptions { output, cwd })
+      .is_err()
+  {
+    log::warn!("⚠️  {}", colors::yellow("Audit sink is already set"));
+    return;
+  }
+  deno_signals::before_exit(write_permission_set);
+}
+
+fn write_permission_set() {
+  let (Some(AuditSink::Collect(entries)), Some(options)) =
+    (AUDIT_SINK.get(), GENERATE_OPTIONS.get())
+  else {
+    return;
+  };
+  let entries = entries.lock().iter().cloned().collect::<Vec<_>>();
+  let permissions = generate_permissions_object(&entries, &options.cwd, |p| {
+    std::fs::metadata(p).map(|m| m.is_dir()).unwrap_or(false)
+  });
+  let config = json!({
+    "permissions": {
+      PERMISSION_SET_NAME: permissions,
+    }
+  });
+  let result = serde_json::to_string_pretty(&config)
+    .map_err(std::io::Error::other)
+    .and_then(|text| std::fs::write(&options.output, text + "\n"));
+  match result {
+    Ok(()) => log::info!(
+      "Wrote permission set \"{}\" to {}",
+      PERMISSION_SET_NAME,
+      options.output.display()
+    ),
+    Err(err) => log::error!(
+      "Failed writing permission set to {}: {}",
+      options.output.display(),
+      err
+    ),
+  }
+}
+
+/// Creates the tightest permission set that allows all of the recorded
+/// permission accesses. Accessed files are collapsed to their directories
+/// and network accesses to `host:port`.
+pub fn generate_permissions_object(
+  entries: &[AuditEntry],
+  cwd: &Path,
+  is_dir: impl Fn(&Path) -> bool,
+) -> PermissionsObject {
+  let mut values: BTreeMap<&str, Option<BTreeSet<String>>> = BTreeMap::new();
+  for entry in entries {
+    let slot = values
+      .entry(entry.permission.as_str())
+      .or_insert_with(|| Some(BTreeSet::new()));
+    match &entry.value {
+      Some(value) => {
+        if let Some(set) = slot {
+          set.insert(value.clone());
+        }
+      }
+      // An access to the whole permission, e.g. `Deno.env.toObject()`.
+      None => *slot = None,
+    }
+  }
+
+  let collapse = |permission: &str,
+                  collapse_values: &dyn Fn(BTreeSet<String>) -> Vec<String>|
+   -> Option<PermissionConfigValue> {
+    let value = values.get(permission)?;
+    Some(match value {
+      None => PermissionConfigValue::All,
+      Some(set) => PermissionConfigValue::Some(collapse_values(set.clone())),
+    })
+  };
+  let paths = |values: BTreeSet<String>| collapse_paths(values, cwd, &is_dir);
+  let names = |values: BTreeSet<String>| values.into_iter().collect();
+
+  let allow = |value| AllowDenyPermissionConfig {
+    allow: value,
+    deny: None,
+  };
+  let allow_ignore = |value| AllowDenyIgnorePermissionConfig {
+    allow: value,
+    deny: None,
+    ignore: None,
+  };
+  PermissionsObject {
+    all: None,
+    read: allow_ignore(collapse("read", &paths)),
+    write: allow(collapse("write", &paths)),
+    import: allow(collapse("import", &collapse_hosts)),
+    env: allow_ignore(collapse("env", &names)),
+    net: allow(collapse("net", &collapse_hosts)),
+    run: allow(collapse("run", &names)),
+    ffi: allow(collapse("ffi", &paths)),
+    sys: allow(collapse("sys", &names)),
+  }
+}
+
+/// Replaces files with their parent directory and drops every path that is
+/// already covered by another one. Paths inside `cwd` are made relative.
+fn collapse_paths(
+  values: BTreeSet<String>,
+  cwd: &Path,
+  is_dir: &impl Fn(&Path) -> bool,
+) -> Vec<String> {
+  let mut dirs = values
+    .into_iter()
+    .map(|value| {
+      let path =
+        normalize_path(std::borrow::Cow::Owned(cwd.join(value))).into_owned();
+      if is_dir(&path) {
+        path
+      } else {
+        match path.parent() {
+          Some(parent) => parent.to_path_buf(),
+          None => path,
+        }
+      }
+    })
+    .collect::<Vec<_>>();
+  // Sorting puts ancestors before their descendants.
+  dirs.sort();
+  let mut collapsed: Vec<PathBuf> = Vec::with_capacity(dirs.len());
+  for dir in dirs {
+    if !collapsed.iter().any(|ancestor| dir.starts_with(ancestor)) {
+      collapsed.push(dir);
+    }
+  }
+  collapsed
+    .into_iter()
+    .map(|path| match path.strip_prefix(cwd) {
+      Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+      Ok(relative) => format!("./{}", relative.to_string_lossy()),
+      Err(_) => path.to_string_lossy().into_owned(),
+    })
+    .collect()
+}
+
+/// Normalizes URLs to `host:port` and drops `host:port` entries that are
+/// covered by an entry for the whole host.
+fn collapse_hosts(values: BTreeSet<String>) -> Vec<String> {
+  let hosts = values
+    .into_iter()
+    .map(|value| match Url::parse(&value) {
+      Ok(url) if url.host_str().is_some() => {
+        let host = url.host_str().unwrap();
+        match url.port_or_known_default() {
+          Some(port) => format!("{host}:{port}"),
+          None => host.to_string(),
+        }
+      }
+      _ => value,
+    })
+    .collect::<BTreeSet<_>>();
+  hosts
+    .iter()
+    .filter(|host| match host.rsplit_once(':') {
+      Some((name, port)) if port.parse::<u16>().is_ok() => {
+        !hosts.contains(name)
+      }
+      _ => true,
+    })
+    .cloned()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entry(permission: &str, value: Option<&str>) -> AuditEntry {
+    AuditEntry {
+      permission: permission.to_string(),
+      value: value.map(|v| v.to_string()),
+    }
+  }
+
+  #[test]
+  fn generates_tightest_permission_set() {
+    let cwd = if cfg!(windows) {
+      PathBuf::from("C:\\app")
+    } else {
+      PathBuf::from("/app")
+    };
+    let outside = if cfg!(windows) {
+      "C:\\etc\\hosts"
+    } else {
+      "/etc/hosts"
+    };
+    let entries = vec![
+      entry("read", Some("./data/a.json")),
+      entry("read", Some("./data/nested/b.json")),
+      entry("read", Some("./static")),
+      entry("read", Some(outside)),
+      entry("write", Some("./out/log.txt")),
+      entry("net", Some("https://api.example.com/v1")),
+      entry("net", Some("db.internal:5432")),
+      entry("net", Some("cache.internal")),
+      entry("net", Some("cache.internal:6379")),
+      entry("env", Some("PORT")),
+      entry("env", Some("HOME")),
+      entry("sys", Some("hostname")),
+      entry("run", Some("git")),
+      entry("run", None),
+    ];
+    let object = generate_permissions_object(&entries, &cwd, |path| {
+      path.ends_with("static")
+    });
+    let outside_dir = if cfg!(windows) { "C:\\etc" } else { "/etc" };
+    assert_eq!(
+      serde_json::to_value(&object).unwrap(),
+      json!({
+        "read": ["./data", "./static", outside_dir],
+        "write": ["./out"],
+        "net": ["api.example.com:443", "cache.internal", "db.internal:5432"],
+        "env": ["HOME", "PORT"],
+        "sys": ["hostname"],
+        "run": true,
+      })
+    );
+  }
+}
@@ -0,0 +1,40 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::fs::OpenOptions;
+use std::sync::Arc;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::unsync::spawn_blocking;
+use deno_permission_broker::BrokerListener;
+use deno_permission_broker::PermissionBroker;
+use deno_permission_broker::Policy;
+
+use crate::args::PermissionBrokerFlags;
+
+pub async fn permission_broker(
+  flags: PermissionBrokerFlags,
+) -> Result<(), AnyError> {
+  let rules = std::fs::read_to_string(&flags.rules)
+    .with_context(|| format!("Failed to read rules file {}", flags.rules))?;
+  let policy = Policy::parse(&rules)
+    .with_context(|| format!("Failed to parse rules file {}", flags.rules))?;
+
+  let mut broker = PermissionBroker::new(policy);
+  if let Some(log) = &flags.log {
+    let file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(log)
+      .with_context(|| format!("Failed to open decision log {log}"))?;
+    broker = broker.with_decision_log(Box::new(file));
+  }
+
+  let listener = BrokerListener::bind(&flags.socket_path)
+    .with_context(|| format!("Failed to listen on {}", flags.socket_path))?;
+  log::info!("Permission broker listening on {}", flags.socket_path);
+
+  let broker = Arc::new(broker);
+  spawn_blocking(move || broker.serve(listener)).await??;
+  Ok(())
+}
@@ -0,0 +1,224 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Serializes an SBOM as a CycloneDX 1.5 JSON document.
+//!
+//! https://cyclonedx.org/docs/1.5/json/
+
+use deno_core::serde_json;
+use deno_core::serde_json::json;
+use deno_lib::version::DENO_VERSION_INFO;
+
+use super::Component;
+use super::ComponentKey;
+use super::HashAlgorithm;
+use super::RootComponent;
+use super::Sbom;
+use super::creation_timestamp;
+use super::is_spdx_license_expression;
+
+const ROOT_REF: &str = "root";
+
+pub fn document(sbom: &Sbom) -> serde_json::Value {
+  let components = sbom
+    .components
+    .iter()
+    .map(|(key, component)| component_json(key, component))
+    .collect::<Vec<_>>();
+  let mut dependencies = vec![json!({
+    "ref": ROOT_REF,
+    "dependsOn": sbom
+      .root_dependencies
+      .iter()
+      .map(ComponentKey::bom_ref)
+      .collect::<Vec<_>>(),
+  })];
+  for key in sbom.components.keys() {
+    dependencies.push(json!({
+      "ref": key.bom_ref(),
+      "dependsOn": sbom
+        .dependencies
+        .get(key)
+        .into_iter()
+        .flatten()
+        .map(ComponentKey::bom_ref)
+        .collect::<Vec<_>>(),
+    }));
+  }
+  json!({
+    "$schema": "http://cyclonedx.org/schema/bom-1.5.schema.json",
+    "bomFormat": "CycloneDX",
+    "specVersion": "1.5",
+    "serialNumber": format!("urn:uuid:{}", uuid::Uuid::new_v4()),
+    "version": 1,
+    "metadata": {
+      "timestamp": creation_timestamp(),
+      "tools": {
+        "components": [{
+          "type": "application",
+          "name": "deno",
+          "version": DENO_VERSION_INFO.deno,
+        }],
+      },
+      "component": root_json(&sbom.root),
+    },
+    "components": components,
+    "dependencies": dependencies,
+  })
+}
+
+fn root_json(root: &RootComponent) -> serde_json::Value {
+  let mut value = json!({
+    "type": "application",
+    "bom-ref": ROOT_REF,
+    "name": root.name,
+  });
+  if let Some(version) = &root.version {
+    value["version"] = json!(version);
+  }
+  value
+}
+
+fn component_json(
+  key: &ComponentKey,
+  component: &Component,
+) -> serde_json::Value {
+  let mut value = json!({
+    "type": match key {
+      ComponentKey::Remote(_) => "file",
+      ComponentKey::Npm(_) | ComponentKey::Jsr(_) => "library",
+    },
+    "bom-ref": key.bom_ref(),
+  });
+  match key.name().split_once('/') {
+    Some((scope, name)) if key.name().starts_with('@') => {
+      value["group"] = json!(scope);
+      value["name"] = json!(name);
+    }
+    _ => value["name"] = json!(key.name()),
+  }
+  if let Some(version) = key.version() {
+    value["version"] = json!(version);
+  }
+  if let Some(purl) = key.purl() {
+    value["purl"] = json!(purl);
+  }
+  if !component.hashes.is_empty() {
+    value["hashes"] = component
+      .hashes
+      .iter()
+      .map(|hash| {
+        json!({
+          "alg": match hash.algorithm {
+            HashAlgorithm::Sha1 => "SHA-1",
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha512 => "SHA-512",
+          },
+          "content": hash.digest,
+        })
+      })
+      .collect();
+  }
+  if let Some(license) = &component.license {
+    value["licenses"] = if is_spdx_license_expression(license) {
+      json!([{ "expression": license }])
+    } else {
+      json!([{ "license": { "name": license } }])
+    };
+  }
+  if let Some(url) = &component.download_url {
+    value["externalReferences"] = json!([{
+      "type": "distribution",
+      "url": url,
+    }]);
+  }
+  value
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::tools::sbom::test::test_lockfile;
+  use crate::tools::sbom::test::test_root;
+
+  #[test]
+  fn cyclonedx_document() {
+    let mut sbom = Sbom::from_lockfile(test_root(), &test_lockfile());
+    for (key, component) in &mut sbom.components {
+      if key.name() == "preact" {
+        component.license = Some("MIT".to_string());
+      } else if key.name() == "react" {
+        component.license = Some("SEE LICENSE IN LICENSE".to_string());
+      }
+    }
+    let document = document(&sbom);
+    assert_eq!(document["bomFormat"], "CycloneDX");
+    assert_eq!(
+      document["metadata"]["component"],
+      json!({
+        "type": "application",
+        "bom-ref": "root",
+        "name": "project",
+        "version": "1.0.0",
+      })
+    );
+    let components = document["components"].as_array().unwrap();
+    assert_eq!(components.len(), 6);
+    assert_eq!(
+      components[1],
+      json!({
+        "type": "library",
+        "bom-ref": "pkg:npm/preact@10.0.0",
+        "name": "preact",
+        "version": "10.0.0",
+        "purl": "pkg:npm/preact@10.0.0",
+        "hashes": [{ "alg": "SHA-512", "content": "69a69a" }],
+        "licenses": [{ "expression": "MIT" }],
+        "externalReferences": [{
+          "type": "distribution",
+          "url": "https://registry.npmjs.org/preact/-/preact-10.0.0.tgz",
+        }],
+      })
+    );
+    assert_eq!(
+      components[2]["licenses"],
+      json!([{ "license": { "name": "SEE LICENSE IN LICENSE" } }])
+    );
+    assert_eq!(
+      components[4],
+      json!({
+        "type": "library",
+        "bom-ref": "pkg:jsr/%40std/path@1.0.8",
+        "group": "@std",
+        "name": "path",
+        "version": "1.0.8",
+        "purl": "pkg:jsr/%40std/path@1.0.8",
+        "hashes": [{
+          "alg": "SHA-256",
+          "content": "548fa456bb6a04d3c1a1e7477986b6cffbce95102d0bb447c67c4ee70e0364be",
+        }],
+        "externalReferences": [{
+          "type": "distribution",
+          "url": "https://jsr.io/@std/path/1.0.8",
+        }],
+      })
+    );
+    assert_eq!(components[5]["type"], "file");
+    assert_eq!(components[5]["name"], "https://deno.land/x/mod.ts");
+    assert_eq!(
+      document["dependencies"][0],
+      json!({
+        "ref": "root",
+        "dependsOn": ["pkg:npm/preact@10.0.0", "pkg:jsr/%40std/path@1.0.8"],
+      })
+    );
+    assert_eq!(
+      document["dependencies"][2],
+      json!({
+        "ref": "pkg:npm/preact@10.0.0",
+        "dependsOn": ["pkg:npm/react@18.0.0"],
+      })
+    );
+  }
+}
@@ -0,0 +1,815 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Generates software bills of materials from the lockfile, optionally
+//! narrowed down to the dependencies of some entrypoints.
+
+mod cyclonedx;
+mod spdx;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::url::Url;
+use deno_graph::GraphKind;
+use deno_graph::Module;
+use deno_graph::ModuleGraph;
+use deno_lockfile::Lockfile;
+use deno_npm::NpmPackageId;
+use deno_npm::resolution::DefaultTarballUrlProvider;
+use deno_npm::resolution::NpmRegistryDefaultTarballUrlProvider;
+use deno_npm_installer::PackageCaching;
+use deno_npm_installer::graph::NpmCachingStrategy;
+use deno_path_util::resolve_url_or_path;
+use deno_semver::Version;
+use deno_semver::jsr::JsrDepPackageReq;
+use deno_semver::jsr::JsrPackageReqReference;
+use deno_semver::npm::NpmPackageReqReference;
+use deno_semver::package::PackageKind;
+use deno_semver::package::PackageNv;
+
+use crate::args::Flags;
+use crate::args::SbomFlags;
+use crate::args::SbomFormat;
+use crate::args::jsr_url;
+use crate::factory::CliFactory;
+use crate::file_fetcher::CliFileFetcher;
+use crate::graph_util::graph_exit_integrity_errors;
+use crate::npm::CliManagedNpmResolver;
+use crate::util::display;
+
+pub async fn sbom(
+  flags: Arc<Flags>,
+  sbom_flags: SbomFlags,
+) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  let cli_options = factory.cli_options()?;
+  let Some(lockfile) = factory.maybe_lockfile().await?.cloned() else {
+    bail!(
+      "Generating an SBOM requires a lockfile. Remove --no-lock or enable the lockfile in the configuration file."
+    );
+  };
+
+  let maybe_graph = if sbom_flags.files.is_empty() {
+    if let Some(npm_installer) = factory.npm_installer_if_managed().await? {
+      // licenses are read from the package.json of the cached packages
+      npm_installer
+        .ensure_top_level_package_json_install()
+        .await?;
+      npm_installer.cache_packages(PackageCaching::All).await?;
+    }
+    None
+  } else {
+    let roots = sbom_flags
+      .files
+      .iter()
+      .map(|file| resolve_url_or_path(file, cli_options.initial_cwd()))
+      .collect::<Result<Vec<_>, _>>()?;
+    let module_graph_creator = factory.module_graph_creator().await?;
+    let graph = module_graph_creator
+      .create_graph(GraphKind::All, roots, NpmCachingStrategy::Eager)
+      .await?;
+    graph_exit_integrity_errors(&graph);
+    module_graph_creator.graph_valid(&graph)?;
+    Some(graph)
+  };
+  lockfile.write_if_changed()?;
+
+  let workspace = cli_options.workspace();
+  let root_configs = workspace.root_folder_configs();
+  let root = RootComponent {
+    name: root_configs
+      .pkg_json
+      .as_ref()
+      .and_then(|pkg_json| pkg_json.name.clone())
+      .or_else(|| {
+        root_configs
+          .deno_json
+          .as_ref()
+          .and_then(|deno_json| deno_json.json.name.clone())
+      })
+      .or_else(|| {
+        let root_dir = workspace.root_dir_path();
+        Some(root_dir.file_name()?.to_string_lossy().into_owned())
+      })
+      .unwrap_or_else(|| "root".to_string()),
+    version: root_configs
+      .pkg_json
+      .as_ref()
+      .and_then(|pkg_json| pkg_json.version.clone())
+      .or_else(|| {
+        root_configs
+          .deno_json
+          .as_ref()
+          .and_then(|deno_json| deno_json.json.version.clone())
+      }),
+  };
+
+  let mut sbom = {
+    let lockfile = lockfile.lock();
+    match &maybe_graph {
+      Some(graph) => Sbom::from_graph(root, &lockfile, graph),
+      None => Sbom::from_lockfile(root, &lockfile),
+    }
+  };
+  let npm_resolver = factory.npm_resolver().await?;
+  if let Some(npm_resolver) = npm_resolver.as_managed() {
+    read_npm_licenses(&mut sbom, npm_resolver);
+  }
+  fetch_jsr_licenses(&mut sbom, factory.file_fetcher()?).await;
+
+  let document = match sbom_flags.format {
+    SbomFormat::CycloneDx => cyclonedx::document(&sbom),
+    SbomFormat::Spdx => spdx::document(&sbom),
+  };
+  match &sbom_flags.output {
+    Some(output) => {
+      let path = cli_options.initial_cwd().join(output);
+      let mut text = serde_json::to_string_pretty(&document)?;
+      text.push('\n');
+      std::fs::write(&path, text)
+        .with_context(|| format!("Failed writing '{}'", path.display()))?;
+      log::info!(
+        "Wrote SBOM with {} components to {}",
+        sbom.components.len(),
+        output
+      );
+      Ok(())
+    }
+    None => display::write_json_to_stdout(&document),
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum ComponentKey {
+  Npm(PackageNv),
+  Jsr(PackageNv),
+  Remote(Url),
+}
+
+impl ComponentKey {
+  fn name(&self) -> &str {
+    match self {
+      ComponentKey::Npm(nv) | ComponentKey::Jsr(nv) => &nv.name,
+      ComponentKey::Remote(url) => url.as_str(),
+    }
+  }
+
+  fn version(&self) -> Option<String> {
+    match self {
+      ComponentKey::Npm(nv) | ComponentKey::Jsr(nv) => {
+        Some(nv.version.to_string())
+      }
+      ComponentKey::Remote(_) => None,
+    }
+  }
+
+  /// The package URL, which only exists for packages.
+  fn purl(&self) -> Option<String> {
+    let (kind, nv) = match self {
+      ComponentKey::Npm(nv) => ("npm", nv),
+      ComponentKey::Jsr(nv) => ("jsr", nv),
+      ComponentKey::Remote(_) => return None,
+    };
+    // the scope is the namespace of the purl, which encodes its `@`
+    let name = match nv.name.strip_prefix('@') {
+      Some(name) => format!("%40{name}"),
+      None => nv.name.to_string(),
+    };
+    Some(format!("pkg:{kind}/{name}@{}", nv.version))
+  }
+
+  /// An identifier that's unique within the SBOM.
+  fn bom_ref(&self) -> String {
+    match self {
+      ComponentKey::Remote(url) => url.to_string(),
+      _ => self.purl().unwrap(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HashAlgorithm {
+  Sha1,
+  Sha256,
+  Sha512,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComponentHash {
+  algorithm: HashAlgorithm,
+  /// The hex encoded digest.
+  digest: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Component {
+  hashes: Vec<ComponentHash>,
+  license: Option<String>,
+  download_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RootComponent {
+  name: String,
+  version: Option<String>,
+}
+
+#[derive(Debug)]
+struct Sbom {
+  root: RootComponent,
+  components: BTreeMap<ComponentKey, Component>,
+  root_dependencies: BTreeSet<ComponentKey>,
+  dependencies: BTreeMap<ComponentKey, BTreeSet<ComponentKey>>,
+}
+
+impl Sbom {
+  /// Creates an SBOM of everything in the lockfile, where the root depends
+  /// on the dependencies of the workspace's configuration files.
+  fn from_lockfile(root: RootComponent, lockfile: &Lockfile) -> Self {
+    let mut sbom = Self::new(root, lockfile);
+    let root_dependencies = lockfile
+      .workspace_dep_reqs()
+      .filter_map(|req| resolve_lockfile_req(lockfile, req))
+      .collect::<Vec<_>>();
+    sbom.root_dependencies.extend(root_dependencies);
+    sbom.remove_dangling_dependencies();
+    sbom
+  }
+
+  /// Creates an SBOM of the packages and remote modules that are reachable
+  /// from the roots of the module graph.
+  fn from_graph(
+    root: RootComponent,
+    lockfile: &Lockfile,
+    graph: &ModuleGraph,
+  ) -> Self {
+    let mut sbom = Self::new(root, lockfile);
+    let jsr_package_urls = graph
+      .packages
+      .mappings()
+      .values()
+      .map(|nv| (format!("{}{}/{}/", jsr_url(), nv.name, nv.version), nv))
+      .collect::<Vec<_>>();
+    let resolve_node = |specifier: &Url| -> Option<Option<ComponentKey>> {
+      match specifier.scheme() {
+        "file" => Some(None),
+        "npm" => {
+          let req_ref =
+            NpmPackageReqReference::from_specifier(specifier).ok()?;
+          let req = JsrDepPackageReq::npm(req_ref.into_inner().req);
+          resolve_lockfile_req(lockfile, &req).map(Some)
+        }
+        "jsr" => {
+          let req_ref =
+            JsrPackageReqReference::from_specifier(specifier).ok()?;
+          let nv = graph.packages.mappings().get(req_ref.req())?;
+          Some(Some(ComponentKey::Jsr(nv.clone())))
+        }
+        "http" | "https" => {
+          if let Some((_, nv)) = jsr_package_urls
+            .iter()
+            .find(|(url, _)| specifier.as_str().starts_with(url.as_str()))
+          {
+            Some(Some(ComponentKey::Jsr((*nv).clone())))
+          } else if lockfile.remote().contains_key(specifier.as_str()) {
+            Some(Some(ComponentKey::Remote(specifier.clone())))
+          } else {
+            None
+          }
+        }
+        _ => None,
+      }
+    };
+
+    for module in graph.modules() {
+      let Module::Js(module) = module else {
+        continue;
+      };
+      let Some(dependent) = resolve_node(&module.specifier) else {
+        continue;
+      };
+      let specifiers = module
+        .dependencies
+        .values()
+        .flat_map(|dep| [dep.get_code(), dep.get_type()])
+        .chain(
+          module
+            .maybe_types_dependency
+            .as_ref()
+            .map(|dep| dep.dependency.maybe_specifier()),
+        )
+        .flatten();
+      for specifier in specifiers {
+        let Some(Some(dependency)) = resolve_node(graph.resolve(specifier))
+        else {
+          continue;
+        };
+        match &dependent {
+          None => {
+            sbom.root_dependencies.insert(dependency);
+          }
+          Some(dependent) if *dependent != dependency => {
+            sbom
+              .dependencies
+              .entry(dependent.clone())
+              .or_default()
+              .insert(dependency);
+          }
+          Some(_) => {}
+        }
+      }
+    }
+    sbom.remove_dangling_dependencies();
+    sbom.retain_reachable();
+    sbom
+  }
+
+  fn new(root: RootComponent, lockfile: &Lockfile) -> Self {
+    let mut sbom = Sbom {
+      root,
+      components: Default::default(),
+      root_dependencies: Default::default(),
+      dependencies: Default::default(),
+    };
+    let packages = &lockfile.content.packages;
+    for (id, info) in &packages.npm {
+      let Ok(id) = NpmPackageId::from_serialized(id) else {
+        continue;
+      };
+      let dependencies = info
+        .dependencies
+        .values()
+        .chain(info.optional_dependencies.values())
+        .chain(info.optional_peers.values())
+        .filter_map(|id| NpmPackageId::from_serialized(id).ok())
+        .map(|id| ComponentKey::Npm(id.nv))
+        .collect::<Vec<_>>();
+      let download_url = match &info.tarball {
+        Some(tarball) => tarball.to_string(),
+        None => {
+          NpmRegistryDefaultTarballUrlProvider.default_tarball_url(&id.nv)
+        }
+      };
+      let key = ComponentKey::Npm(id.nv);
+      sbom
+        .dependencies
+        .entry(key.clone())
+        .or_default()
+        .extend(dependencies);
+      if sbom.components.contains_key(&key) {
+        // the same package resolved with other peer dependencies
+        continue;
+      }
+      sbom.components.insert(
+        key,
+        Component {
+          hashes: info
+            .integrity
+            .as_deref()
+            .and_then(npm_integrity_hash)
+            .into_iter()
+            .collect(),
+          license: None,
+          download_url: Some(download_url),
+        },
+      );
+    }
+    for (nv, info) in &packages.jsr {
+      let dependencies = info
+        .dependencies
+        .iter()
+        .filter_map(|req| resolve_lockfile_req(lockfile, req))
+        .collect();
+      let key = ComponentKey::Jsr(nv.clone());
+      sbom.dependencies.insert(key.clone(), dependencies);
+      sbom.components.insert(
+        key,
+        Component {
+          hashes: vec![ComponentHash {
+            algorithm: HashAlgorithm::Sha256,
+            digest: info.integrity.clone(),
+          }],
+          license: None,
+          download_url: Some(format!(
+            "{}{}/{}",
+            jsr_url(),
+            nv.name,
+            nv.version
+          )),
+        },
+      );
+    }
+    for (url, checksum) in lockfile.remote() {
+      let Ok(url) = Url::parse(url) else {
+        continue;
+      };
+      sbom.components.insert(
+        ComponentKey::Remote(url.clone()),
+        Component {
+          hashes: vec![ComponentHash {
+            algorithm: HashAlgorithm::Sha256,
+            digest: checksum.clone(),
+          }],
+          license: None,
+          download_url: Some(url.to_string()),
+        },
+      );
+    }
+    sbom
+  }
+
+  /// Removes the dependencies on packages that aren't in the lockfile.
+  fn remove_dangling_dependencies(&mut self) {
+    let components = &self.components;
+    self
+      .root_dependencies
+      .retain(|key| components.contains_key(key));
+    for dependencies in self.dependencies.values_mut() {
+      dependencies.retain(|key| components.contains_key(key));
+    }
+  }
+
+  fn retain_reachable(&mut self) {
+    let mut reachable = BTreeSet::new();
+    let mut pending = self.root_dependencies.iter().collect::<Vec<_>>();
+    while let Some(key) = pending.pop() {
+      if reachable.insert(key.clone())
+        && let Some(dependencies) = self.dependencies.get(key)
+      {
+        pending.extend(dependencies);
+      }
+    }
+    self.components.retain(|key, _| reachable.contains(key));
+    self.dependencies.retain(|key, _| reachable.contains(key));
+  }
+}
+
+fn resolve_lockfile_req(
+  lockfile: &Lockfile,
+  req: &JsrDepPackageReq,
+) -> Option<ComponentKey> {
+  let version = lockfile.content.packages.specifiers.get(req)?;
+  match req.kind {
+    PackageKind::Jsr => Some(ComponentKey::Jsr(PackageNv {
+      name: req.req.name.clone(),
+      version: Version::parse_standard(version).ok()?,
+    })),
+    PackageKind::Npm => {
+      let id = format!("{}@{}", req.req.name, version);
+      let id = NpmPackageId::from_serialized(&id).ok()?;
+      Some(ComponentKey::Npm(id.nv))
+    }
+  }
+}
+
+/// Converts the integrity of an npm package to a hex encoded hash.
+fn npm_integrity_hash(integrity: &str) -> Option<ComponentHash> {
+  let Some((algorithm, digest)) = integrity.split_once('-') else {
+    // legacy packages only have the hex encoded sha1 of the tarball
+    return Some(ComponentHash {
+      algorithm: HashAlgorithm::Sha1,
+      digest: integrity.to_lowercase(),
+    });
+  };
+  let algorithm = match algorithm {
+    "sha1" => HashAlgorithm::Sha1,
+    "sha256" => HashAlgorithm::Sha256,
+    "sha512" => HashAlgorithm::Sha512,
+    _ => return None,
+  };
+  let digest = BASE64_STANDARD.decode(digest).ok()?;
+  Some(ComponentHash {
+    algorithm,
+    digest: faster_hex::hex_string(&digest),
+  })
+}
+
+/// Gets the license of a package.json or a JSR configuration file.
+fn license_from_config(config: &serde_json::Value) -> Option<String> {
+  fn license_name(value: &serde_json::Value) -> Option<String> {
+    match value {
+      serde_json::Value::String(license) => Some(license.clone()),
+      // deprecated `{ "type": "MIT", "url": "..." }` form
+      serde_json::Value::Object(license) => {
+        license.get("type")?.as_str().map(|s| s.to_string())
+      }
+      _ => None,
+    }
+  }
+
+  if let Some(license) = config.get("license").and_then(license_name) {
+    return Some(license);
+  }
+  // deprecated `licenses` array
+  let licenses = config
+    .get("licenses")?
+    .as_array()?
+    .iter()
+    .filter_map(license_name)
+    .collect::<Vec<_>>();
+  match licenses.len() {
+    0 => None,
+    1 => licenses.into_iter().next(),
+    _ => Some(format!("({})", licenses.join(" OR "))),
+  }
+}
+
+/// Gets whether a license looks like an SPDX license expression, which
+/// excludes ones like `SEE LICENSE IN LICENSE.md` and `UNLICENSED`.
+fn is_spdx_license_expression(license: &str) -> bool {
+  let text = license.replace(['(', ')'], " ");
+  let mut expects_license = true;
+  for token in text.split_whitespace() {
+    let is_operator = matches!(token, "AND" | "OR" | "WITH");
+    if is_operator == expects_license {
+      return false;
+    }
+    if !is_operator
+      && (token == "UNLICENSED"
+        || !token.chars().all(|c| {
+          c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+' | ':')
+        }))
+    {
+      return false;
+    }
+    expects_license = is_operator;
+  }
+  !expects_license
+    && license.matches('(').count() == license.matches(')').count()
+}
+
+fn creation_timestamp() -> String {
+  chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+fn read_npm_licenses(sbom: &mut Sbom, npm_resolver: &CliManagedNpmResolver) {
+  let snapshot = npm_resolver.resolution().snapshot();
+  for package in snapshot.all_packages_for_every_system() {
+    let key = ComponentKey::Npm(package.id.nv.clone());
+    let Some(component) = sbom.components.get_mut(&key) else {
+      continue;
+    };
+    if component.license.is_some() {
+      // the same package resolved with other peer dependencies
+      continue;
+    }
+    let Ok(folder) = npm_resolver.resolve_pkg_folder_from_pkg_id(&package.id)
+    else {
+      continue;
+    };
+    let Ok(text) = std::fs::read_to_string(folder.join("package.json")) else {
+      continue;
+    };
+    component.license = serde_json::from_str(&text)
+      .ok()
+      .and_then(|config: serde_json::Value| license_from_config(&config));
+  }
+}
+
+async fn fetch_jsr_licenses(sbom: &mut Sbom, file_fetcher: &CliFileFetcher) {
+  const CONFIG_FILE_NAMES: [&str; 4] =
+    ["deno.json", "deno.jsonc", "jsr.json", "jsr.jsonc"];
+
+  for (key, component) in &mut sbom.components {
+    let ComponentKey::Jsr(nv) = key else {
+      continue;
+    };
+    let fetch_license = || async {
+      // use the manifest to find the configuration file of the package
+      let meta_url = jsr_url()
+        .join(&format!("{}/{}_meta.json", nv.name, nv.version))
+        .ok()?;
+      let file = file_fetcher
+        .fetch_bypass_permissions(&meta_url)
+        .await
+        .ok()?;
+      let meta =
+        serde_json::from_slice::<serde_json::Value>(&file.source).ok()?;
+      let manifest = meta.get("manifest")?.as_object()?;
+      let file_name = CONFIG_FILE_NAMES
+        .into_iter()
+        .find(|name| manifest.contains_key(&format!("/{name}")))?;
+      let config_url = jsr_url()
+        .join(&format!("{}/{}/{}", nv.name, nv.version, file_name))
+        .ok()?;
+      let file = file_fetcher
+        .fetch_bypass_permissions(&config_url)
+        .await
+        .ok()?;
+      let text = String::from_utf8_lossy(&file.source);
+      let config: serde_json::Value =
+        jsonc_parser::parse_to_serde_value(&text, &Default::default()).ok()?;
+      license_from_config(&config)
+    };
+    component.license = fetch_license().await;
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+
+  use deno_core::futures::FutureExt;
+  use deno_lockfile::NewLockfileOptions;
+  use deno_lockfile::NpmPackageInfoProvider;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  struct NoopNpmPackageInfoProvider;
+
+  #[async_trait::async_trait(?Send)]
+  impl NpmPackageInfoProvider for NoopNpmPackageInfoProvider {
+    async fn get_npm_package_info(
+      &self,
+      _values: &[PackageNv],
+    ) -> Result<
+      Vec<deno_lockfile::Lockfile5NpmInfo>,
+      Box<dyn std::error::Error + Send + Sync>,
+    > {
+      unreachable!()
+    }
+  }
+
+  pub fn test_lockfile() -> Lockfile {
+    let content = r#"{
+  "version": "5",
+  "specifiers": {
+    "jsr:@std/path@1": "1.0.8",
+    "npm:chalk@5": "5.3.0",
+    "npm:preact@10": "10.0.0_react@18.0.0"
+  },
+  "jsr": {
+    "@std/path@1.0.8": {
+      "integrity": "548fa456bb6a04d3c1a1e7477986b6cffbce95102d0bb447c67c4ee70e0364be",
+      "dependencies": ["npm:chalk@5"]
+    }
+  },
+  "npm": {
+    "chalk@5.3.0": {
+      "integrity": "sha512-dLitG79d+GV1Nb/VYcCDFivJeK1hiukt9QjRNVOsUtTy1rR1YJsmpGGTZ3qJos+uw7WmWF4wUwBd9jxjocFC2w=="
+    },
+    "preact@10.0.0_react@18.0.0": {
+      "integrity": "sha512-aaaa",
+      "dependencies": ["react"]
+    },
+    "react@18.0.0": {
+      "integrity": "0123456789ABCDEF0123456789ABCDEF01234567",
+      "tarball": "https://example.com/react.tgz"
+    },
+    "unused@1.0.0": {
+      "integrity": "sha512-bbbb"
+    }
+  },
+  "remote": {
+    "https://deno.land/x/mod.ts": "3118d7a42c03c242c5a49c2ad91c8396110e14acca1324e7aaefd31a999b71a4"
+  },
+  "workspace": {
+    "dependencies": ["jsr:@std/path@1", "npm:preact@10"]
+  }
+}"#;
+    Lockfile::new(
+      NewLockfileOptions {
+        file_path: PathBuf::from("/deno.lock"),
+        content,
+        overwrite: false,
+      },
+      &NoopNpmPackageInfoProvider,
+    )
+    .now_or_never()
+    .unwrap()
+    .unwrap()
+  }
+
+  pub fn test_root() -> RootComponent {
+    RootComponent {
+      name: "project".to_string(),
+      version: Some("1.0.0".to_string()),
+    }
+  }
+
+  fn key(text: &str) -> ComponentKey {
+    if let Some(nv) = text.strip_prefix("npm:") {
+      ComponentKey::Npm(PackageNv::from_str(nv).unwrap())
+    } else if let Some(nv) = text.strip_prefix("jsr:") {
+      ComponentKey::Jsr(PackageNv::from_str(nv).unwrap())
+    } else {
+      ComponentKey::Remote(Url::parse(text).unwrap())
+    }
+  }
+
+  #[test]
+  fn sbom_from_lockfile() {
+    let sbom = Sbom::from_lockfile(test_root(), &test_lockfile());
+    assert_eq!(
+      sbom.components.keys().collect::<Vec<_>>(),
+      vec![
+        &key("npm:chalk@5.3.0"),
+        &key("npm:preact@10.0.0"),
+        &key("npm:react@18.0.0"),
+        &key("npm:unused@1.0.0"),
+        &key("jsr:@std/path@1.0.8"),
+        &key("https://deno.land/x/mod.ts"),
+      ]
+    );
+    assert_eq!(
+      sbom.root_dependencies,
+      BTreeSet::from([key("npm:preact@10.0.0"), key("jsr:@std/path@1.0.8")])
+    );
+    assert_eq!(
+      sbom.dependencies[&key("npm:preact@10.0.0")],
+      BTreeSet::from([key("npm:react@18.0.0")])
+    );
+    assert_eq!(
+      sbom.dependencies[&key("jsr:@std/path@1.0.8")],
+      BTreeSet::from([key("npm:chalk@5.3.0")])
+    );
+
+    let react = &sbom.components[&key("npm:react@18.0.0")];
+    assert_eq!(
+      react.download_url.as_deref(),
+      Some("https://example.com/react.tgz")
+    );
+    assert_eq!(
+      react.hashes,
+      vec![ComponentHash {
+        algorithm: HashAlgorithm::Sha1,
+        digest: "0123456789abcdef0123456789abcdef01234567".to_string(),
+      }]
+    );
+    let chalk = &sbom.components[&key("npm:chalk@5.3.0")];
+    assert_eq!(
+      chalk.download_url.as_deref(),
+      Some("https://registry.npmjs.org/chalk/-/chalk-5.3.0.tgz")
+    );
+    assert_eq!(chalk.hashes[0].algorithm, HashAlgorithm::Sha512);
+    assert_eq!(chalk.hashes[0].digest.len(), 128);
+  }
+
+  #[test]
+  fn retains_reachable_components() {
+    let mut sbom = Sbom::new(test_root(), &test_lockfile());
+    sbom.root_dependencies.insert(key("npm:preact@10.0.0"));
+    sbom.remove_dangling_dependencies();
+    sbom.retain_reachable();
+    assert_eq!(
+      sbom.components.keys().collect::<Vec<_>>(),
+      vec![&key("npm:preact@10.0.0"), &key("npm:react@18.0.0")]
+    );
+  }
+
+  #[test]
+  fn purls() {
+    assert_eq!(
+      key("npm:@scope/pkg@1.0.0").purl().unwrap(),
+      "pkg:npm/%40scope/pkg@1.0.0"
+    );
+    assert_eq!(key("npm:pkg@1.0.0").purl().unwrap(), "pkg:npm/pkg@1.0.0");
+    assert_eq!(
+      key("jsr:@std/path@1.0.8").purl().unwrap(),
+      "pkg:jsr/%40std/path@1.0.8"
+    );
+    assert_eq!(key("https://deno.land/x/mod.ts").purl(), None);
+  }
+
+  #[test]
+  fn spdx_license_expressions() {
+    assert!(is_spdx_license_expression("MIT"));
+    assert!(is_spdx_license_expression("(MIT OR Apache-2.0)"));
+    assert!(is_spdx_license_expression(
+      "GPL-2.0-or-later WITH Classpath-exception-2.0"
+    ));
+    assert!(is_spdx_license_expression("LicenseRef-Custom"));
+    assert!(!is_spdx_license_expression(""));
+    assert!(!is_spdx_license_expression("UNLICENSED"));
+    assert!(!is_spdx_license_expression("SEE LICENSE IN LICENSE.md"));
+    assert!(!is_spdx_license_expression("MIT OR"));
+    assert!(!is_spdx_license_expression("(MIT"));
+  }
+
+  #[test]
+  fn licenses() {
+    let license = |value: serde_json::Value| {
+      license_from_config(&value).unwrap_or_default()
+    };
+    assert_eq!(license(serde_json::json!({ "license": "MIT" })), "MIT");
+    assert_eq!(
+      license(serde_json::json!({ "license": { "type": "ISC" } })),
+      "ISC"
+    );
+    assert_eq!(
+      license(serde_json::json!({
+        "licenses": [{ "type": "MIT" }, { "type": "Apache-2.0" }]
+      })),
+      "(MIT OR Apache-2.0)"
+    );
+    assert_eq!(license(serde_json::json!({})), "");
+  }
+}
@@ -0,0 +1,302 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Serializes an SBOM as an SPDX 2.3 JSON document.
+//!
+//! https://spdx.github.io/spdx-spec/v2.3/
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use deno_core::serde_json;
+use deno_core::serde_json::json;
+use deno_lib::version::DENO_VERSION_INFO;
+
+use super::Component;
+use super::ComponentKey;
+use super::HashAlgorithm;
+use super::RootComponent;
+use super::Sbom;
+use super::creation_timestamp;
+use super::is_spdx_license_expression;
+
+const NO_ASSERTION: &str = "NOASSERTION";
+const ROOT_ID: &str = "SPDXRef-Root";
+
+pub fn document(sbom: &Sbom) -> serde_json::Value {
+  let ids = element_ids(sbom);
+  let mut packages = vec![root_json(&sbom.root)];
+  packages.extend(
+    sbom
+      .components
+      .iter()
+      .map(|(key, component)| component_json(key, component, &ids[key])),
+  );
+
+  let mut relationships = vec![json!({
+    "spdxElementId": "SPDXRef-DOCUMENT",
+    "relationshipType": "DESCRIBES",
+    "relatedSpdxElement": ROOT_ID,
+  })];
+  let depends_on = |dependent: &str, dependency: &ComponentKey| {
+    json!({
+      "spdxElementId": dependent,
+      "relationshipType": "DEPENDS_ON",
+      "relatedSpdxElement": ids[dependency],
+    })
+  };
+  for dependency in &sbom.root_dependencies {
+    relationships.push(depends_on(ROOT_ID, dependency));
+  }
+  for key in sbom.components.keys() {
+    for dependency in sbom.dependencies.get(key).into_iter().flatten() {
+      relationships.push(depends_on(&ids[key], dependency));
+    }
+  }
+
+  json!({
+    "spdxVersion": "SPDX-2.3",
+    "dataLicense": "CC0-1.0",
+    "SPDXID": "SPDXRef-DOCUMENT",
+    "name": sbom.root.name,
+    "documentNamespace": format!(
+      "https://spdx.org/spdxdocs/{}-{}",
+      sanitize_id(&sbom.root.name),
+      uuid::Uuid::new_v4()
+    ),
+    "creationInfo": {
+      "created": creation_timestamp(),
+      "creators": [format!("Tool: deno-{}", DENO_VERSION_INFO.deno)],
+    },
+    "packages": packages,
+    "relationships": relationships,
+  })
+}
+
+/// Gets a unique SPDX identifier for each component.
+fn element_ids(sbom: &Sbom) -> HashMap<&ComponentKey, String> {
+  let mut used = HashSet::from([ROOT_ID.to_string()]);
+  let mut ids = HashMap::with_capacity(sbom.components.len());
+  for key in sbom.components.keys() {
+    let base = match key {
+      ComponentKey::Npm(nv) => {
+        format!("SPDXRef-npm-{}-{}", nv.name, nv.version)
+      }
+      ComponentKey::Jsr(nv) => {
+        format!("SPDXRef-jsr-{}-{}", nv.name, nv.version)
+      }
+      ComponentKey::Remote(url) => format!("SPDXRef-remote-{url}"),
+    };
+    let base = sanitize_id(&base);
+    let mut id = base.clone();
+    let mut counter = 1;
+    while !used.insert(id.clone()) {
+      counter += 1;
+      id = format!("{base}-{counter}");
+    }
+    ids.insert(key, id);
+  }
+  ids
+}
+
+/// Replaces the characters that aren't allowed in SPDX identifiers.
+fn sanitize_id(text: &str) -> String {
+  text
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || matches!(c, '.' | '-') {
+        c
+      } else {
+        '-'
+      }
+    })
+    .collect()
+}
+
+fn root_json(root: &RootComponent) -> serde_json::Value {
+  let mut value = json!({
+    "name": root.name,
+    "SPDXID": ROOT_ID,
+    "downloadLocation": NO_ASSERTION,
+    "filesAnalyzed": false,
+    "licenseConcluded": NO_ASSERTION,
+    "licenseDeclared": NO_ASSERTION,
+    "primaryPackagePurpose": "APPLICATION",
+  });
+  if let Some(version) = &root.version {
+    value["versionInfo"] = json!(version);
+  }
+  value
+}
+
+fn component_json(
+  key: &ComponentKey,
+  component: &Component,
+  id: &str,
+) -> serde_json::Value {
+  let license_declared = component
+    .license
+    .as_deref()
+    .filter(|license| is_spdx_license_expression(license))
+    .unwrap_or(NO_ASSERTION);
+  let mut value = json!({
+    "name": key.name(),
+    "SPDXID": id,
+    "downloadLocation": component
+      .download_url
+      .as_deref()
+      .unwrap_or(NO_ASSERTION),
+    "filesAnalyzed": false,
+    "licenseConcluded": NO_ASSERTION,
+    "licenseDeclared": license_declared,
+    "primaryPackagePurpose": match key {
+      ComponentKey::Remote(_) => "FILE",
+      ComponentKey::Npm(_) | ComponentKey::Jsr(_) => "LIBRARY",
+    },
+  });
+  if let Some(version) = key.version() {
+    value["versionInfo"] = json!(version);
+  }
+  if !component.hashes.is_empty() {
+    value["checksums"] = component
+      .hashes
+      .iter()
+      .map(|hash| {
+        json!({
+          "algorithm": match hash.algorithm {
+            HashAlgorithm::Sha1 => "SHA1",
+            HashAlgorithm::Sha256 => "SHA256",
+            HashAlgorithm::Sha512 => "SHA512",
+          },
+          "checksumValue": hash.digest,
+        })
+      })
+      .collect();
+  }
+  if let Some(purl) = key.purl() {
+    value["externalRefs"] = json!([{
+      "referenceCategory": "PACKAGE-MANAGER",
+      "referenceType": "purl",
+      "referenceLocator": purl,
+    }]);
+  }
+  value
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::tools::sbom::test::test_lockfile;
+  use crate::tools::sbom::test::test_root;
+
+  #[test]
+  fn spdx_document() {
+    let mut sbom = Sbom::from_lockfile(test_root(), &test_lockfile());
+    for (key, component) in &mut sbom.components {
+      if key.name() == "@std/path" {
+        component.license = Some("MIT".to_string());
+      } else if key.name() == "react" {
+        component.license = Some("SEE LICENSE IN LICENSE".to_string());
+      }
+    }
+    let document = document(&sbom);
+    assert_eq!(document["spdxVersion"], "SPDX-2.3");
+    assert_eq!(document["name"], "project");
+    let packages = document["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 7);
+    assert_eq!(
+      packages[0],
+      json!({
+        "name": "project",
+        "SPDXID": "SPDXRef-Root",
+        "versionInfo": "1.0.0",
+        "downloadLocation": "NOASSERTION",
+        "filesAnalyzed": false,
+        "licenseConcluded": "NOASSERTION",
+        "licenseDeclared": "NOASSERTION",
+        "primaryPackagePurpose": "APPLICATION",
+      })
+    );
+    assert_eq!(
+      packages[3],
+      json!({
+        "name": "react",
+        "SPDXID": "SPDXRef-npm-react-18.0.0",
+        "versionInfo": "18.0.0",
+        "downloadLocation": "https://example.com/react.tgz",
+        "filesAnalyzed": false,
+        "licenseConcluded": "NOASSERTION",
+        "licenseDeclared": "NOASSERTION",
+        "primaryPackagePurpose": "LIBRARY",
+        "checksums": [{
+          "algorithm": "SHA1",
+          "checksumValue": "0123456789abcdef0123456789abcdef01234567",
+        }],
+        "externalRefs": [{
+          "referenceCategory": "PACKAGE-MANAGER",
+          "referenceType": "purl",
+          "referenceLocator": "pkg:npm/react@18.0.0",
+        }],
+      })
+    );
+    assert_eq!(packages[5]["SPDXID"], "SPDXRef-jsr--std-path-1.0.8");
+    assert_eq!(packages[5]["licenseDeclared"], "MIT");
+    assert_eq!(
+      packages[6]["SPDXID"],
+      "SPDXRef-remote-https---deno.land-x-mod.ts"
+    );
+    assert_eq!(packages[6]["primaryPackagePurpose"], "FILE");
+    assert_eq!(
+      document["relationships"],
+      json!([
+        {
+          "spdxElementId": "SPDXRef-DOCUMENT",
+          "relationshipType": "DESCRIBES",
+          "relatedSpdxElement": "SPDXRef-Root",
+        },
+        {
+          "spdxElementId": "SPDXRef-Root",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-npm-preact-10.0.0",
+        },
+        {
+          "spdxElementId": "SPDXRef-Root",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-jsr--std-path-1.0.8",
+        },
+        {
+          "spdxElementId": "SPDXRef-npm-preact-10.0.0",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-npm-react-18.0.0",
+        },
+        {
+          "spdxElementId": "SPDXRef-jsr--std-path-1.0.8",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-npm-chalk-5.3.0",
+        },
+      ])
+    );
+  }
+
+  #[test]
+  fn unique_element_ids() {
+    let mut sbom = Sbom::from_lockfile(test_root(), &test_lockfile());
+    let component = sbom.components.values().next().unwrap().clone();
+    for name in ["@a/b-c", "@a-b/c"] {
+      sbom.components.insert(
+        ComponentKey::Npm(
+          deno_semver::package::PackageNv::from_str(&format!("{name}@1.0.0"))
+            .unwrap(),
+        ),
+        component.clone(),
+      );
+    }
+    let ids = element_ids(&sbom);
+    let mut values = ids.values().collect::<Vec<_>>();
+    values.sort();
+    values.dedup();
+    assert_eq!(values.len(), ids.len());
+    assert!(ids.values().any(|id| id == "SPDXRef-npm--a-b-c-1.0.0-2"));
+  }
+}
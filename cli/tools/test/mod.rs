@@ -40,6 +40,7 @@ use deno_core::futures::StreamExt;
 use deno_core::futures::future;
 use deno_core::futures::stream;
 use deno_core::located_script_name;
+use deno_core::parking_lot::Mutex;
 use deno_core::serde_v8;
 use deno_core::unsync::spawn;
 use deno_core::unsync::spawn_blocking;
@@ -70,6 +71,7 @@ use crate::args::CliOptions;
 use crate::args::Flags;
 use crate::args::TestFlags;
 use crate::args::TestReporterConfig;
+use crate::cache::TestResultsCache;
 use crate::colors;
 use crate::display;
 use crate::factory::CliFactory;
@@ -92,6 +94,7 @@ mod channel;
 pub mod fmt;
 pub mod reporters;
 mod sanitizers;
+mod shard;
 
 pub use channel::TestEventReceiver;
 pub use channel::TestEventSender;
@@ -102,11 +105,14 @@ use fmt::format_sanitizer_diff;
 pub use fmt::format_test_error;
 use reporters::CompoundTestReporter;
 use reporters::DotTestReporter;
+use reporters::JsonTestReporter;
 use reporters::JunitTestReporter;
 use reporters::PrettyTestReporter;
+use reporters::ResultsCacheTestReporter;
 use reporters::TapTestReporter;
 use reporters::TestReporter;
 
+use crate::tools::coverage::CoverFilesOptions;
 use crate::tools::coverage::cover_files;
 use crate::tools::coverage::reporter;
 use crate::tools::test::channel::ChannelClosedError;
@@ -299,6 +305,8 @@ pub struct TestDescription {
   pub location: TestLocation,
   pub sanitize_ops: bool,
   pub sanitize_resources: bool,
+  /// Overrides the number of retries of a failing test.
+  pub retry: Option<usize>,
 }
 
 /// May represent a failure of a test or test step.
@@ -485,6 +493,9 @@ pub enum TestResult {
   Ignored,
   Failed(TestFailure),
   Cancelled,
+  /// Passed after failing on previous attempts, with the failures of those
+  /// attempts.
+  Flaky(Vec<TestFailure>),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
@@ -563,6 +574,7 @@ pub struct TestSummary {
   pub passed: usize,
   pub failed: usize,
   pub ignored: usize,
+  pub flaky: usize,
   pub passed_steps: usize,
   pub failed_steps: usize,
   pub ignored_steps: usize,
@@ -580,9 +592,14 @@ struct TestSpecifiersOptions {
   log_level: Option<log::Level>,
   filter: bool,
   specifier: TestSpecifierOptions,
+  /// The names of the tests to run in a module instead of all of them, used
+  /// to only run the tests that failed in the previous run.
+  include_by_specifier: HashMap<ModuleSpecifier, Vec<String>>,
   reporter: TestReporterConfig,
   junit_path: Option<String>,
   hide_stacktraces: bool,
+  durations_path: Option<PathBuf>,
+  test_results_cache: Option<TestResultsCache>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -590,6 +607,7 @@ pub struct TestSpecifierOptions {
   pub shuffle: Option<u64>,
   pub filter: TestFilter,
   pub trace_leaks: bool,
+  pub retries: usize,
 }
 
 impl TestSummary {
@@ -599,6 +617,7 @@ impl TestSummary {
       passed: 0,
       failed: 0,
       ignored: 0,
+      flaky: 0,
       passed_steps: 0,
       failed_steps: 0,
       ignored_steps: 0,
@@ -647,8 +666,16 @@ fn get_test_reporter(options: &TestSpecifiersOptions) -> Box<dyn TestReporter> {
       options.concurrent_jobs > NonZeroUsize::new(1).unwrap(),
       failure_format_options,
     )),
+    TestReporterConfig::Json => Box::new(JsonTestReporter::new(
+      options.cwd.clone(),
+      TestFailureFormatOptions {
+        strip_ascii_color: true,
+        ..failure_format_options
+      },
+    )),
   };
 
+  let mut reporters = vec![reporter];
   if let Some(junit_path) = &options.junit_path {
     let junit = Box::new(JunitTestReporter::new(
       options.cwd.clone(),
@@ -659,10 +686,18 @@ fn get_test_reporter(options: &TestSpecifiersOptions) -> Box<dyn TestReporter> {
         initial_cwd: Some(options.cwd.clone()),
       },
     ));
-    return Box::new(CompoundTestReporter::new(vec![reporter, junit]));
+    reporters.push(junit);
+  }
+
+  if let Some(cache) = &options.test_results_cache {
+    reporters.push(Box::new(ResultsCacheTestReporter::new(cache.clone())));
   }
 
-  reporter
+  if reporters.len() == 1 {
+    reporters.pop().unwrap()
+  } else {
+    Box::new(CompoundTestReporter::new(reporters))
+  }
 }
 
 #[allow(clippy::too_many_arguments, reason = "TODO: cleanup")]
@@ -1068,125 +1103,51 @@ async fn run_tests_for_worker_inner(
     }
     event_tracker.wait(desc)?;
 
-    // Poll event loop once, to allow all ops that are already resolved, but haven't
-    // responded to settle.
-    // TODO(mmastrac): we should provide an API to poll the event loop until no further
-    // progress is made.
-    poll_event_loop(worker).await?;
-
-    // We always capture stats, regardless of sanitization state
-    let before_test_stats = sanitizer_helper.capture_stats();
-
+    let retries = desc.retry.unwrap_or(options.retries);
     let earlier = Instant::now();
-
-    // Execute beforeEach hooks (FIFO order)
-    let mut before_each_hook_errored = false;
-
-    call_hooks(worker, test_hooks.before_each.iter(), |core_error| {
-      match core_error {
-        CoreErrorKind::Js(err) => {
-          before_each_hook_errored = true;
-          let test_result = TestResult::Failed(TestFailure::JsError(err));
-          fail_fast_tracker.add_failure();
-          event_tracker.result(desc, test_result, earlier.elapsed())?;
-          Ok(())
-        }
-        err => Err(err.into_box().into()),
+    let mut failed_attempts = Vec::new();
+    loop {
+      // The steps of an attempt that may still be retried are held back, so
+      // that only the steps of the final attempt are reported.
+      let may_retry = failed_attempts.len() < retries;
+      if may_retry {
+        event_tracker.buffer_steps();
       }
-    })
-    .await?;
-
-    // TODO(bartlomieju): this whole block/binding could be reworked into something better
-    let result = if !before_each_hook_errored {
-      let call = worker.js_runtime.call(&function);
-
-      let slow_test_warning =
-        spawn(slow_test_watchdog(event_tracker.clone(), desc.id));
-
-      let result = worker
-        .js_runtime
-        .with_event_loop_promise(call, PollEventLoopOptions::default())
-        .await;
-      slow_test_warning.abort();
-      let result = match result {
-        Ok(r) => r,
-        Err(error) => match error.into_kind() {
-          CoreErrorKind::Js(js_error) => {
-            event_tracker.uncaught_error(specifier.to_string(), js_error)?;
-            fail_fast_tracker.add_failure();
-            event_tracker.cancelled(desc)?;
-            had_uncaught_error = true;
-            continue;
-          }
-          err => return Err(err.into_box().into()),
-        },
+      let Some(result) = run_test_attempt(
+        worker,
+        specifier,
+        desc,
+        &function,
+        &test_hooks,
+        &sanitizer_helper,
+        event_tracker,
+      )
+      .await?
+      else {
+        event_tracker.flush_steps()?;
+        fail_fast_tracker.add_failure();
+        event_tracker.cancelled(desc)?;
+        had_uncaught_error = true;
+        break;
       };
-
-      // Check the result before we check for leaks
-      deno_core::scope!(scope, &mut worker.js_runtime);
-      let result = v8::Local::new(scope, result);
-      serde_v8::from_v8::<TestResult>(scope, result)?
-    } else {
-      TestResult::Ignored
-    };
-
-    if matches!(result, TestResult::Failed(_)) {
-      fail_fast_tracker.add_failure();
-      event_tracker.result(desc, result.clone(), earlier.elapsed())?;
-    }
-
-    // Execute afterEach hooks (LIFO order)
-    call_hooks(worker, test_hooks.after_each.iter().rev(), |core_error| {
-      match core_error {
-        CoreErrorKind::Js(err) => {
-          let test_result = TestResult::Failed(TestFailure::JsError(err));
-          fail_fast_tracker.add_failure();
-          event_tracker.result(desc, test_result, earlier.elapsed())?;
-          Ok(())
+      let result = match result {
+        TestResult::Failed(failure) if may_retry => {
+          event_tracker.discard_steps();
+          failed_attempts.push(failure);
+          worker_prepare_for_test(worker);
+          continue;
         }
-        err => Err(err.into_box().into()),
-      }
-    })
-    .await?;
-
-    if matches!(result, TestResult::Failed(_)) {
-      continue;
-    }
-
-    // Close idle Node.js HTTP Agent connections to prevent cross-test
-    // pollution and false positive resource leak detection from pooled
-    // keepAlive connections.
-    _ = worker.js_runtime.execute_script(
-      located_script_name!(),
-      "Deno[Deno.internal].node?.closeIdleConnections?.()",
-    );
-
-    // Await activity stabilization
-    if let Some(diff) = sanitizers::wait_for_activity_to_stabilize(
-      worker,
-      &sanitizer_helper,
-      before_test_stats,
-      desc.sanitize_ops,
-      desc.sanitize_resources,
-    )
-    .await?
-    {
-      let (formatted, trailer_notes) = format_sanitizer_diff(diff);
-      if !formatted.is_empty() {
-        let failure = TestFailure::Leaked(formatted, trailer_notes);
+        TestResult::Ok if !failed_attempts.is_empty() => {
+          TestResult::Flaky(failed_attempts)
+        }
+        result => result,
+      };
+      event_tracker.flush_steps()?;
+      if matches!(result, TestResult::Failed(_)) {
         fail_fast_tracker.add_failure();
-        event_tracker.result(
-          desc,
-          TestResult::Failed(failure),
-          earlier.elapsed(),
-        )?;
-        continue;
       }
-    }
-
-    // TODO(bartlomieju): using `before_each_hook_errored` is fishy
-    if !before_each_hook_errored {
       event_tracker.result(desc, result, earlier.elapsed())?;
+      break;
     }
   }
 
@@ -1207,6 +1168,125 @@ async fn run_tests_for_worker_inner(
   Ok(())
 }
 
+/// Runs a test once, together with its `beforeEach` and `afterEach` hooks,
+/// and returns its result. Returns `None` if the test caused an uncaught
+/// error, which has already been reported.
+async fn run_test_attempt(
+  worker: &mut MainWorker,
+  specifier: &ModuleSpecifier,
+  desc: &TestDescription,
+  function: &v8::Global<v8::Function>,
+  test_hooks: &TestHooks,
+  sanitizer_helper: &sanitizers::TestSanitizerHelper,
+  event_tracker: &TestEventTracker,
+) -> Result<Option<TestResult>, RunTestsForWorkerErr> {
+  // Poll event loop once, to allow all ops that are already resolved, but haven't
+  // responded to settle.
+  // TODO(mmastrac): we should provide an API to poll the event loop until no further
+  // progress is made.
+  poll_event_loop(worker).await?;
+
+  // We always capture stats, regardless of sanitization state
+  let before_test_stats = sanitizer_helper.capture_stats();
+
+  // Only the first failure of a test is reported.
+  let mut first_failure = None;
+  let mut test_result = TestResult::Ok;
+
+  // Execute beforeEach hooks (FIFO order)
+  call_hooks(worker, test_hooks.before_each.iter(), |core_error| {
+    match core_error {
+      CoreErrorKind::Js(err) => {
+        first_failure = Some(TestFailure::JsError(err));
+        Ok(())
+      }
+      err => Err(err.into_box().into()),
+    }
+  })
+  .await?;
+
+  if first_failure.is_none() {
+    let call = worker.js_runtime.call(function);
+
+    let slow_test_warning =
+      spawn(slow_test_watchdog(event_tracker.clone(), desc.id));
+
+    let result = worker
+      .js_runtime
+      .with_event_loop_promise(call, PollEventLoopOptions::default())
+      .await;
+    slow_test_warning.abort();
+    let result = match result {
+      Ok(r) => r,
+      Err(error) => match error.into_kind() {
+        CoreErrorKind::Js(js_error) => {
+          event_tracker.uncaught_error(specifier.to_string(), js_error)?;
+          return Ok(None);
+        }
+        err => return Err(err.into_box().into()),
+      },
+    };
+
+    // Check the result before we check for leaks
+    let result = {
+      deno_core::scope!(scope, &mut worker.js_runtime);
+      let result = v8::Local::new(scope, result);
+      serde_v8::from_v8::<TestResult>(scope, result)?
+    };
+    match result {
+      TestResult::Failed(failure) => first_failure = Some(failure),
+      result => test_result = result,
+    }
+  }
+  let test_failed = first_failure.is_some();
+
+  // Execute afterEach hooks (LIFO order)
+  call_hooks(worker, test_hooks.after_each.iter().rev(), |core_error| {
+    match core_error {
+      CoreErrorKind::Js(err) => {
+        first_failure.get_or_insert(TestFailure::JsError(err));
+        Ok(())
+      }
+      err => Err(err.into_box().into()),
+    }
+  })
+  .await?;
+
+  if test_failed {
+    return Ok(first_failure.map(TestResult::Failed));
+  }
+
+  // Close idle Node.js HTTP Agent connections to prevent cross-test
+  // pollution and false positive resource leak detection from pooled
+  // keepAlive connections.
+  _ = worker.js_runtime.execute_script(
+    located_script_name!(),
+    "Deno[Deno.internal].node?.closeIdleConnections?.()",
+  );
+
+  // Await activity stabilization
+  if let Some(diff) = sanitizers::wait_for_activity_to_stabilize(
+    worker,
+    sanitizer_helper,
+    before_test_stats,
+    desc.sanitize_ops,
+    desc.sanitize_resources,
+  )
+  .await?
+  {
+    let (formatted, trailer_notes) = format_sanitizer_diff(diff);
+    if !formatted.is_empty() {
+      first_failure
+        .get_or_insert(TestFailure::Leaked(formatted, trailer_notes));
+    }
+  }
+
+  Ok(Some(match first_failure {
+    Some(failure) => TestResult::Failed(failure),
+    None => test_result,
+  }))
+}
+
 static HAS_TEST_RUN_SIGINT_HANDLER: AtomicBool = AtomicBool::new(false);
 
 /// Test a collection of specifiers with test modes concurrently.
@@ -1240,15 +1320,23 @@ async fn test_specifiers(
   HAS_TEST_RUN_SIGINT_HANDLER.store(true, Ordering::Relaxed);
   let reporter = get_test_reporter(&options);
   let fail_fast_tracker = FailFastTracker::new(options.fail_fast);
+  let module_durations = Arc::new(Mutex::new(Vec::new()));
+  let durations_path = options.durations_path.clone();
+  let cwd = options.cwd.clone();
+  let durations = module_durations.clone();
 
   let join_handles = specifiers.into_iter().map(move |specifier| {
+    let module_durations = durations.clone();
     let worker_factory = worker_factory.clone();
     let specifier_dir = cli_options.workspace().resolve_member_dir(&specifier);
     let preload_modules = preload_modules.clone();
     let require_modules = require_modules.clone();
     let worker_sender = test_event_sender_factory.worker();
     let fail_fast_tracker = fail_fast_tracker.clone();
-    let specifier_options = options.specifier.clone();
+    let mut specifier_options = options.specifier.clone();
+    if let Some(include) = options.include_by_specifier.get(&specifier) {
+      specifier_options.filter.include = Some(include.clone());
+    }
     let cli_options = cli_options.clone();
     let permission_desc_parser = permission_desc_parser.clone();
     spawn_blocking(move || {
@@ -1264,16 +1352,22 @@ async fn test_specifiers(
           &permissions,
         )?,
       );
-      create_and_run_current_thread(test_specifier(
+      let start = Instant::now();
+      let result = create_and_run_current_thread(test_specifier(
         worker_factory,
         permissions_container,
-        specifier,
+        specifier.clone(),
         preload_modules,
         require_modules,
         worker_sender,
-        fail_fast_tracker,
+        fail_fast_tracker.clone(),
         specifier_options,
-      ))
+      ));
+      // Modules cut short by --fail-fast would skew the recorded durations.
+      if result.is_ok() && !fail_fast_tracker.should_stop() {
+        module_durations.lock().push((specifier, start.elapsed()));
+      }
+      result
     })
   });
 
@@ -1289,6 +1383,10 @@ async fn test_specifiers(
   for join_result in join_results {
     join_result??;
   }
+  if let Some(path) = &durations_path {
+    let module_durations = std::mem::take(&mut *module_durations.lock());
+    shard::write_durations(path, &cwd, module_durations)?;
+  }
   result??;
 
   Ok(())
@@ -1613,7 +1711,7 @@ pub async fn run_tests(
 
   let members_with_test_options =
     cli_options.resolve_test_options_for_members(&test_flags)?;
-  let specifiers_with_mode = fetch_specifiers_with_test_mode(
+  let mut specifiers_with_mode = fetch_specifiers_with_test_mode(
     cli_options,
     file_fetcher,
     members_with_test_options.into_iter().map(|(_, v)| v.files),
@@ -1626,12 +1724,90 @@ pub async fn run_tests(
     return Err(anyhow!("No test modules found"));
   }
 
+  if let Some(git_ref) = &workspace_test_options.changed {
+    let changed_paths =
+      crate::util::git::changed_files(cli_options.initial_cwd(), git_ref)?;
+    let graph = factory
+      .module_graph_creator()
+      .await?
+      .create_graph(
+        cli_options.type_check_mode().as_graph_kind(),
+        specifiers_with_mode
+          .iter()
+          .map(|(specifier, _)| specifier.clone())
+          .collect(),
+        NpmCachingStrategy::Eager,
+      )
+      .await?;
+    specifiers_with_mode.retain(|(specifier, _)| {
+      has_graph_root_local_dependent_changed(&graph, specifier, &changed_paths)
+    });
+    if specifiers_with_mode.is_empty() {
+      log::info!("No test modules affected by changes since {}", git_ref);
+      return Ok(());
+    }
+  }
+
+  let cwd =
+    Url::from_directory_path(cli_options.initial_cwd()).map_err(|_| {
+      anyhow!(
+        "Unable to construct URL from the path of cwd: {}",
+        cli_options.initial_cwd().to_string_lossy(),
+      )
+    })?;
+  let durations_path = workspace_test_options
+    .shard_durations
+    .as_ref()
+    .map(|path| cli_options.initial_cwd().join(path));
+
   let doc_tests = get_doc_tests(&specifiers_with_mode, file_fetcher).await?;
   let specifiers_for_typecheck_and_test =
     get_target_specifiers(specifiers_with_mode, &doc_tests);
   for doc_test in doc_tests {
     file_fetcher.insert_memory_files(doc_test);
   }
+  let test_results_cache =
+    TestResultsCache::new(factory.caches()?.test_results_cache_db());
+  let mut include_by_specifier = HashMap::new();
+  let specifiers_for_typecheck_and_test = if workspace_test_options.failed {
+    let specifiers = specifiers_for_typecheck_and_test
+      .into_iter()
+      .filter(|specifier| {
+        let names = test_results_cache.failed_tests(specifier);
+        if names.is_empty() {
+          return false;
+        }
+        // When a whole module failed its tests are unknown, so run all of
+        // them.
+        if !names.iter().any(|name| name.is_empty()) {
+          include_by_specifier.insert(specifier.clone(), names);
+        }
+        true
+      })
+      .collect::<Vec<_>>();
+    if specifiers.is_empty() {
+      log::info!("No tests failed in the previous run");
+      return Ok(());
+    }
+    specifiers
+  } else {
+    specifiers_for_typecheck_and_test
+  };
+  let specifiers_for_typecheck_and_test = match workspace_test_options.shard {
+    Some(test_shard) => {
+      let durations = match &durations_path {
+        Some(path) => shard::read_durations(path)?,
+        None => Default::default(),
+      };
+      shard::partition(
+        specifiers_for_typecheck_and_test,
+        test_shard,
+        &durations,
+        &cwd,
+      )
+    }
+    None => specifiers_for_typecheck_and_test,
+  };
 
   let main_graph_container = factory.main_module_graph_container().await?;
 
@@ -1664,25 +1840,23 @@ pub async fn run_tests(
     preload_modules,
     require_modules,
     TestSpecifiersOptions {
-      cwd: Url::from_directory_path(cli_options.initial_cwd()).map_err(
-        |_| {
-          anyhow!(
-            "Unable to construct URL from the path of cwd: {}",
-            cli_options.initial_cwd().to_string_lossy(),
-          )
-        },
-      )?,
+      cwd,
       concurrent_jobs: workspace_test_options.concurrent_jobs,
       fail_fast: workspace_test_options.fail_fast,
       log_level,
-      filter: workspace_test_options.filter.is_some(),
+      filter: workspace_test_options.filter.is_some()
+        || !include_by_specifier.is_empty(),
       reporter: workspace_test_options.reporter,
       junit_path: workspace_test_options.junit_path,
       hide_stacktraces: workspace_test_options.hide_stacktraces,
+      durations_path,
+      test_results_cache: Some(test_results_cache),
+      include_by_specifier,
       specifier: TestSpecifierOptions {
         filter: TestFilter::from_flag(&workspace_test_options.filter),
         shuffle: workspace_test_options.shuffle,
         trace_leaks: workspace_test_options.trace_leaks,
+        retries: workspace_test_options.retries,
       },
     },
   )
@@ -1702,14 +1876,15 @@ pub async fn run_tests(
       flags,
       vec![coverage.clone()],
       vec![],
-      vec![],
-      vec![],
-      Some(
-        PathBuf::from(coverage)
-          .join("lcov.info")
-          .to_string_lossy()
-          .into_owned(),
-      ),
+      CoverFilesOptions {
+        output: Some(
+          PathBuf::from(coverage)
+            .join("lcov.info")
+            .to_string_lossy()
+            .into_owned(),
+        ),
+        ..Default::default()
+      },
       &reporters,
     ) {
       log::info!("Error generating coverage report: {}", err);
@@ -1904,10 +2079,16 @@ pub async fn run_tests_with_watch(
             reporter: workspace_test_options.reporter,
             junit_path: workspace_test_options.junit_path,
             hide_stacktraces: workspace_test_options.hide_stacktraces,
+            durations_path: None,
+            test_results_cache: Some(TestResultsCache::new(
+              factory.caches()?.test_results_cache_db(),
+            )),
+            include_by_specifier: Default::default(),
             specifier: TestSpecifierOptions {
               filter: TestFilter::from_flag(&workspace_test_options.filter),
               shuffle: workspace_test_options.shuffle,
               trace_leaks: workspace_test_options.trace_leaks,
+              retries: workspace_test_options.retries,
             },
           },
         )
@@ -1954,6 +2135,17 @@ fn get_target_specifiers(
     .collect()
 }
 
+/// Step events of a test attempt that may still be retried. While this is
+/// in the op state, step events are collected here instead of being sent.
+#[derive(Default)]
+pub struct BufferedStepEvents(Vec<TestEvent>);
+
+impl BufferedStepEvents {
+  pub fn push(&mut self, event: TestEvent) {
+    self.0.push(event);
+  }
+}
+
 #[derive(Clone)]
 pub struct TestEventTracker {
   op_state: Rc<RefCell<OpState>>,
@@ -2031,6 +2223,25 @@ impl TestEventTracker {
     ))
   }
 
+  fn buffer_steps(&self) {
+    self
+      .op_state
+      .borrow_mut()
+      .put(BufferedStepEvents::default());
+  }
+
+  fn discard_steps(&self) {
+    self.op_state.borrow_mut().try_take::<BufferedStepEvents>();
+  }
+
+  fn flush_steps(&self) -> Result<(), ChannelClosedError> {
+    let buffered = self.op_state.borrow_mut().try_take::<BufferedStepEvents>();
+    for event in buffered.into_iter().flat_map(|buffered| buffered.0) {
+      self.send_event(event)?;
+    }
+    Ok(())
+  }
+
   pub(crate) fn force_end_report(&self) -> Result<(), ChannelClosedError> {
     self.send_event(TestEvent::ForceEndReport)
   }
@@ -4,6 +4,14 @@ use super::fmt::format_test_error;
 use super::fmt::to_relative_path_or_remote_url;
 use super::*;
 
+pub(super) fn format_flaky_retries(retries: usize) -> String {
+  if retries == 1 {
+    "passed after 1 retry".to_string()
+  } else {
+    format!("passed after {retries} retries")
+  }
+}
+
 pub(super) fn format_test_step_ancestry(
   desc: &TestStepDescription,
   tests: &IndexMap<usize, TestDescription>,
@@ -215,6 +223,10 @@ pub(super) fn report_summary(
     .ok();
   }
 
+  if summary.flaky > 0 {
+    write!(summary_result, " | {} flaky", summary.flaky).ok();
+  }
+
   if summary.measured > 0 {
     write!(summary_result, " | {} measured", summary.measured,).ok();
   }
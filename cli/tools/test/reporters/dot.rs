@@ -64,6 +64,7 @@ impl DotTestReporter {
       TestResult::Ignored => fmt_ignored(),
       TestResult::Failed(_failure) => fmt_failed(),
       TestResult::Cancelled => fmt_cancelled(),
+      TestResult::Flaky(_) => fmt_flaky(),
     };
 
     self.print_status(status);
@@ -86,6 +87,10 @@ fn fmt_cancelled() -> String {
   colors::gray("!").to_string()
 }
 
+fn fmt_flaky() -> String {
+  colors::yellow(".").to_string()
+}
+
 #[allow(clippy::print_stdout, reason = "test reporter")]
 impl TestReporter for DotTestReporter {
   fn report_register(&mut self, _description: &TestDescription) {}
@@ -113,6 +118,10 @@ impl TestReporter for DotTestReporter {
       TestResult::Ok => {
         self.summary.passed += 1;
       }
+      TestResult::Flaky(_) => {
+        self.summary.passed += 1;
+        self.summary.flaky += 1;
+      }
       TestResult::Ignored => {
         self.summary.ignored += 1;
       }
@@ -0,0 +1,458 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use deno_core::serde_json;
+use serde::Serialize;
+
+use super::fmt::to_relative_path_or_remote_url;
+use super::*;
+
+/// A test reporter that streams every test event as a JSON object on its own
+/// line (NDJSON), for consumption by dashboards and editors.
+pub struct JsonTestReporter {
+  cwd: Url,
+  summary: TestSummary,
+  failure_format_options: TestFailureFormatOptions,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum JsonTestEvent<'a> {
+  #[serde(rename_all = "camelCase")]
+  Register {
+    id: usize,
+    name: &'a str,
+    origin: String,
+    location: JsonLocation,
+    ignore: bool,
+    only: bool,
+  },
+  #[serde(rename_all = "camelCase")]
+  Plan {
+    origin: String,
+    total: usize,
+    filtered_out: usize,
+    used_only: bool,
+  },
+  Wait {
+    id: usize,
+  },
+  Slow {
+    id: usize,
+    elapsed: u64,
+  },
+  Output {
+    text: String,
+  },
+  Result {
+    id: usize,
+    name: &'a str,
+    origin: String,
+    status: JsonStatus,
+    duration: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<JsonFailure<'a>>,
+  },
+  UncaughtError {
+    origin: String,
+    error: JsonError,
+  },
+  #[serde(rename_all = "camelCase")]
+  StepRegister {
+    id: usize,
+    name: &'a str,
+    origin: String,
+    location: JsonLocation,
+    level: usize,
+    parent_id: usize,
+    root_id: usize,
+  },
+  StepWait {
+    id: usize,
+  },
+  StepResult {
+    id: usize,
+    name: &'a str,
+    status: JsonStatus,
+    duration: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<JsonFailure<'a>>,
+  },
+  #[serde(rename_all = "camelCase")]
+  Summary {
+    total: usize,
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    flaky: usize,
+    passed_steps: usize,
+    failed_steps: usize,
+    ignored_steps: usize,
+    filtered_out: usize,
+    measured: usize,
+    duration: u64,
+  },
+  Sigint {
+    pending: Vec<usize>,
+  },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum JsonStatus {
+  Ok,
+  Ignored,
+  Failed,
+  Cancelled,
+  Flaky,
+}
+
+#[derive(Serialize)]
+struct JsonLocation {
+  file: String,
+  line: u32,
+  column: u32,
+}
+
+#[derive(Serialize)]
+struct JsonFailure<'a> {
+  #[serde(flatten)]
+  kind: JsonFailureKind<'a>,
+  /// The failure as it is printed by the pretty reporter.
+  message: String,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum JsonFailureKind<'a> {
+  Error {
+    error: JsonError,
+  },
+  FailedSteps {
+    count: usize,
+  },
+  IncompleteSteps,
+  Leaked {
+    details: &'a [String],
+    notes: &'a [String],
+  },
+  Incomplete,
+  OverlapsWithSanitizers {
+    tests: Vec<&'a str>,
+  },
+  HasSanitizersAndOverlaps {
+    tests: Vec<&'a str>,
+  },
+}
+
+#[derive(Serialize)]
+struct JsonError {
+  name: Option<String>,
+  message: Option<String>,
+  stack: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  location: Option<JsonLocation>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  cause: Option<Box<JsonError>>,
+}
+
+#[allow(clippy::print_stdout, reason = "test reporter")]
+impl JsonTestReporter {
+  pub fn new(
+    cwd: Url,
+    failure_format_options: TestFailureFormatOptions,
+  ) -> JsonTestReporter {
+    JsonTestReporter {
+      cwd,
+      summary: TestSummary::new(),
+      failure_format_options,
+    }
+  }
+
+  fn emit(&self, event: JsonTestEvent) {
+    let line =
+      serde_json::to_string(&event).expect("failed to serialize test event");
+    println!("{}", line);
+  }
+
+  fn location(&self, origin: &str, location: &TestLocation) -> JsonLocation {
+    JsonLocation {
+      file: to_relative_path_or_remote_url(&self.cwd, origin),
+      line: location.line_number,
+      column: location.column_number,
+    }
+  }
+
+  fn error(&self, error: &JsError) -> JsonError {
+    // The first frame with a file name is where the error was thrown.
+    let location = error.frames.iter().find_map(|frame| {
+      Some(JsonLocation {
+        file: to_relative_path_or_remote_url(
+          &self.cwd,
+          frame.file_name.as_ref()?,
+        ),
+        line: frame.line_number? as u32,
+        column: frame.column_number? as u32,
+      })
+    });
+    JsonError {
+      name: error.name.clone(),
+      message: error.message.clone(),
+      stack: error.stack.clone(),
+      location,
+      cause: error
+        .cause
+        .as_ref()
+        .map(|cause| Box::new(self.error(cause))),
+    }
+  }
+
+  fn failure<'a>(&self, failure: &'a TestFailure) -> JsonFailure<'a> {
+    let kind = match failure {
+      TestFailure::JsError(error) => JsonFailureKind::Error {
+        error: self.error(error),
+      },
+      TestFailure::FailedSteps(count) => {
+        JsonFailureKind::FailedSteps { count: *count }
+      }
+      TestFailure::IncompleteSteps => JsonFailureKind::IncompleteSteps,
+      TestFailure::Leaked(details, notes) => {
+        JsonFailureKind::Leaked { details, notes }
+      }
+      TestFailure::Incomplete => JsonFailureKind::Incomplete,
+      TestFailure::OverlapsWithSanitizers(tests) => {
+        JsonFailureKind::OverlapsWithSanitizers {
+          tests: tests.iter().map(|s| s.as_str()).collect(),
+        }
+      }
+      TestFailure::HasSanitizersAndOverlaps(tests) => {
+        JsonFailureKind::HasSanitizersAndOverlaps {
+          tests: tests.iter().map(|s| s.as_str()).collect(),
+        }
+      }
+    };
+    JsonFailure {
+      kind,
+      message: failure.format(&self.failure_format_options).into_owned(),
+    }
+  }
+}
+
+impl TestReporter for JsonTestReporter {
+  fn report_register(&mut self, description: &TestDescription) {
+    self.emit(JsonTestEvent::Register {
+      id: description.id,
+      name: &description.name,
+      origin: to_relative_path_or_remote_url(&self.cwd, &description.origin),
+      location: self.location(&description.origin, &description.location),
+      ignore: description.ignore,
+      only: description.only,
+    });
+  }
+
+  fn report_plan(&mut self, plan: &TestPlan) {
+    self.summary.total += plan.total;
+    self.summary.filtered_out += plan.filtered_out;
+    self.emit(JsonTestEvent::Plan {
+      origin: to_relative_path_or_remote_url(&self.cwd, &plan.origin),
+      total: plan.total,
+      filtered_out: plan.filtered_out,
+      used_only: plan.used_only,
+    });
+  }
+
+  fn report_wait(&mut self, description: &TestDescription) {
+    self.emit(JsonTestEvent::Wait { id: description.id });
+  }
+
+  fn report_slow(&mut self, description: &TestDescription, elapsed: u64) {
+    self.emit(JsonTestEvent::Slow {
+      id: description.id,
+      elapsed,
+    });
+  }
+
+  fn report_output(&mut self, output: &[u8]) {
+    self.emit(JsonTestEvent::Output {
+      text: String::from_utf8_lossy(output).into_owned(),
+    });
+  }
+
+  fn report_result(
+    &mut self,
+    description: &TestDescription,
+    result: &TestResult,
+    elapsed: u64,
+  ) {
+    let (status, failures) = match result {
+      TestResult::Ok => {
+        self.summary.passed += 1;
+        (JsonStatus::Ok, vec![])
+      }
+      TestResult::Ignored => {
+        self.summary.ignored += 1;
+        (JsonStatus::Ignored, vec![])
+      }
+      TestResult::Failed(failure) => {
+        self.summary.failed += 1;
+        (JsonStatus::Failed, vec![self.failure(failure)])
+      }
+      TestResult::Cancelled => {
+        self.summary.failed += 1;
+        (JsonStatus::Cancelled, vec![])
+      }
+      TestResult::Flaky(failures) => {
+        self.summary.passed += 1;
+        self.summary.flaky += 1;
+        (
+          JsonStatus::Flaky,
+          failures.iter().map(|f| self.failure(f)).collect(),
+        )
+      }
+    };
+    self.emit(JsonTestEvent::Result {
+      id: description.id,
+      name: &description.name,
+      origin: to_relative_path_or_remote_url(&self.cwd, &description.origin),
+      status,
+      duration: elapsed,
+      failures,
+    });
+  }
+
+  fn report_uncaught_error(&mut self, origin: &str, error: Box<JsError>) {
+    self.summary.failed += 1;
+    self.emit(JsonTestEvent::UncaughtError {
+      origin: to_relative_path_or_remote_url(&self.cwd, origin),
+      error: self.error(&error),
+    });
+  }
+
+  fn report_step_register(&mut self, description: &TestStepDescription) {
+    self.emit(JsonTestEvent::StepRegister {
+      id: description.id,
+      name: &description.name,
+      origin: to_relative_path_or_remote_url(&self.cwd, &description.origin),
+      location: self.location(&description.origin, &description.location),
+      level: description.level,
+      parent_id: description.parent_id,
+      root_id: description.root_id,
+    });
+  }
+
+  fn report_step_wait(&mut self, description: &TestStepDescription) {
+    self.emit(JsonTestEvent::StepWait { id: description.id });
+  }
+
+  fn report_step_result(
+    &mut self,
+    desc: &TestStepDescription,
+    result: &TestStepResult,
+    elapsed: u64,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) {
+    let (status, failures) = match result {
+      TestStepResult::Ok => {
+        self.summary.passed_steps += 1;
+        (JsonStatus::Ok, vec![])
+      }
+      TestStepResult::Ignored => {
+        self.summary.ignored_steps += 1;
+        (JsonStatus::Ignored, vec![])
+      }
+      TestStepResult::Failed(failure) => {
+        self.summary.failed_steps += 1;
+        (JsonStatus::Failed, vec![self.failure(failure)])
+      }
+    };
+    self.emit(JsonTestEvent::StepResult {
+      id: desc.id,
+      name: &desc.name,
+      status,
+      duration: elapsed,
+      failures,
+    });
+  }
+
+  fn report_summary(
+    &mut self,
+    elapsed: &Duration,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) {
+    self.emit(JsonTestEvent::Summary {
+      total: self.summary.total,
+      passed: self.summary.passed,
+      failed: self.summary.failed,
+      ignored: self.summary.ignored,
+      flaky: self.summary.flaky,
+      passed_steps: self.summary.passed_steps,
+      failed_steps: self.summary.failed_steps,
+      ignored_steps: self.summary.ignored_steps,
+      filtered_out: self.summary.filtered_out,
+      measured: self.summary.measured,
+      duration: elapsed.as_millis() as u64,
+    });
+  }
+
+  fn report_sigint(
+    &mut self,
+    tests_pending: &HashSet<usize>,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) {
+    let mut pending = tests_pending.iter().copied().collect::<Vec<_>>();
+    pending.sort();
+    self.emit(JsonTestEvent::Sigint { pending });
+  }
+
+  fn report_completed(&mut self) {}
+
+  fn flush_report(
+    &mut self,
+    _elapsed: &Duration,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serializes_events() {
+    let details = vec!["A timer was started in this test".to_string()];
+    let event = JsonTestEvent::Result {
+      id: 3,
+      name: "leaks",
+      origin: "./main_test.ts".to_string(),
+      status: JsonStatus::Failed,
+      duration: 12,
+      failures: vec![JsonFailure {
+        kind: JsonFailureKind::Leaked {
+          details: &details,
+          notes: &[],
+        },
+        message: "Leaks detected".to_string(),
+      }],
+    };
+    assert_eq!(
+      serde_json::to_string(&event).unwrap(),
+      r#"{"type":"result","id":3,"name":"leaks","origin":"./main_test.ts","status":"failed","duration":12,"failures":[{"kind":"leaked","details":["A timer was started in this test"],"notes":[],"message":"Leaks detected"}]}"#
+    );
+
+    let event = JsonTestEvent::Plan {
+      origin: "./main_test.ts".to_string(),
+      total: 2,
+      filtered_out: 1,
+      used_only: false,
+    };
+    assert_eq!(
+      serde_json::to_string(&event).unwrap(),
+      r#"{"type":"plan","origin":"./main_test.ts","total":2,"filteredOut":1,"usedOnly":false}"#
+    );
+  }
+}
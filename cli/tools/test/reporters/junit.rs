@@ -56,6 +56,19 @@ impl JunitTestReporter {
         description: None,
         reruns: vec![],
       },
+      TestResult::Flaky(failures) => quick_junit::TestCaseStatus::Success {
+        flaky_runs: failures
+          .iter()
+          .map(|failure| {
+            let mut rerun =
+              quick_junit::TestRerun::new(quick_junit::NonSuccessKind::Failure);
+            rerun.message = Some(failure.overview());
+            rerun.description =
+              Some(failure.format(failure_format_options).into_owned());
+            rerun
+          })
+          .collect(),
+      },
     }
   }
 
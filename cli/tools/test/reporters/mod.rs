@@ -5,14 +5,18 @@ use super::*;
 mod common;
 mod compound;
 mod dot;
+mod json;
 mod junit;
 mod pretty;
+mod results_cache;
 mod tap;
 
 pub use compound::CompoundTestReporter;
 pub use dot::DotTestReporter;
+pub use json::JsonTestReporter;
 pub use junit::JunitTestReporter;
 pub use pretty::PrettyTestReporter;
+pub use results_cache::ResultsCacheTestReporter;
 pub use tap::TapTestReporter;
 
 pub trait TestReporter {
@@ -266,6 +266,10 @@ impl TestReporter for PrettyTestReporter {
       TestResult::Ok => {
         self.summary.passed += 1;
       }
+      TestResult::Flaky(_) => {
+        self.summary.passed += 1;
+        self.summary.flaky += 1;
+      }
       TestResult::Ignored => {
         self.summary.ignored += 1;
       }
@@ -315,6 +319,7 @@ impl TestReporter for PrettyTestReporter {
       TestResult::Ignored => colors::yellow("ignored").to_string(),
       TestResult::Failed(failure) => failure.format_label(),
       TestResult::Cancelled => colors::gray("cancelled").to_string(),
+      TestResult::Flaky(_) => colors::yellow("flaky").to_string(),
     };
     write!(&mut self.writer, " {status}").ok();
     if let TestResult::Failed(failure) = result
@@ -322,6 +327,14 @@ impl TestReporter for PrettyTestReporter {
     {
       write!(&mut self.writer, " ({})", inline_summary).ok();
     }
+    if let TestResult::Flaky(failures) = result {
+      write!(
+        &mut self.writer,
+        " ({})",
+        common::format_flaky_retries(failures.len())
+      )
+      .ok();
+    }
     writeln!(
       &mut self.writer,
       " {}",
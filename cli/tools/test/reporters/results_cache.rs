@@ -0,0 +1,94 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use super::*;
+use crate::cache::TestResultsCache;
+
+/// Records which tests failed into the test results cache, so that the next
+/// `deno test --failed` only runs those.
+pub struct ResultsCacheTestReporter {
+  cache: TestResultsCache,
+}
+
+impl ResultsCacheTestReporter {
+  pub fn new(cache: TestResultsCache) -> Self {
+    Self { cache }
+  }
+}
+
+impl TestReporter for ResultsCacheTestReporter {
+  fn report_register(&mut self, _description: &TestDescription) {}
+
+  fn report_plan(&mut self, plan: &TestPlan) {
+    // The module loaded, so any earlier module level failure is resolved,
+    // and the failures of tests that no longer run are stale.
+    self.cache.clear(&plan.origin);
+  }
+
+  fn report_wait(&mut self, _description: &TestDescription) {}
+  fn report_slow(&mut self, _description: &TestDescription, _elapsed: u64) {}
+  fn report_output(&mut self, _output: &[u8]) {}
+
+  fn report_result(
+    &mut self,
+    description: &TestDescription,
+    result: &TestResult,
+    _elapsed: u64,
+  ) {
+    match result {
+      TestResult::Failed(_) | TestResult::Cancelled => {
+        self
+          .cache
+          .set_failed(&description.origin, &description.name);
+      }
+      TestResult::Ok | TestResult::Ignored | TestResult::Flaky(_) => {
+        self
+          .cache
+          .set_passed(&description.origin, &description.name);
+      }
+    }
+  }
+
+  fn report_uncaught_error(&mut self, origin: &str, _error: Box<JsError>) {
+    self.cache.set_failed(origin, "");
+  }
+
+  fn report_step_register(&mut self, _description: &TestStepDescription) {}
+  fn report_step_wait(&mut self, _description: &TestStepDescription) {}
+
+  fn report_step_result(
+    &mut self,
+    _desc: &TestStepDescription,
+    _result: &TestStepResult,
+    _elapsed: u64,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) {
+  }
+
+  fn report_summary(
+    &mut self,
+    _elapsed: &Duration,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) {
+  }
+
+  fn report_sigint(
+    &mut self,
+    _tests_pending: &HashSet<usize>,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) {
+  }
+
+  fn report_completed(&mut self) {}
+
+  fn flush_report(
+    &mut self,
+    _elapsed: &Duration,
+    _tests: &IndexMap<usize, TestDescription>,
+    _test_steps: &IndexMap<usize, TestStepDescription>,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+}
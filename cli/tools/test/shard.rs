@@ -0,0 +1,181 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Splitting the test modules of a run into deterministic shards, so that a
+//! test suite can be spread over multiple machines.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use deno_core::ModuleSpecifier;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::url::Url;
+
+use super::fmt::to_relative_path_or_remote_url;
+use crate::args::TestShard;
+
+/// Durations of test modules in milliseconds, keyed by the module path
+/// relative to the current working directory.
+pub type TestDurations = BTreeMap<String, u64>;
+
+pub fn read_durations(path: &Path) -> Result<TestDurations, AnyError> {
+  match std::fs::read_to_string(path) {
+    Ok(text) => serde_json::from_str(&text).with_context(|| {
+      format!("Failed to parse test durations file '{}'", path.display())
+    }),
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+      Ok(Default::default())
+    }
+    Err(err) => Err(err).with_context(|| {
+      format!("Failed to read test durations file '{}'", path.display())
+    }),
+  }
+}
+
+/// Merges the durations of the modules that ran into the durations file,
+/// keeping the entries of modules that ran on other shards.
+pub fn write_durations(
+  path: &Path,
+  cwd: &Url,
+  module_durations: Vec<(ModuleSpecifier, Duration)>,
+) -> Result<(), AnyError> {
+  let mut durations = read_durations(path)?;
+  for (specifier, duration) in module_durations {
+    durations.insert(
+      to_relative_path_or_remote_url(cwd, specifier.as_str()),
+      duration.as_millis() as u64,
+    );
+  }
+  let text = serde_json::to_string_pretty(&durations)?;
+  std::fs::write(path, format!("{text}\n")).with_context(|| {
+    format!("Failed to write test durations file '{}'", path.display())
+  })
+}
+
+/// Returns the specifiers that belong to `shard`, in their original order.
+///
+/// Modules are assigned greedily, longest first, to the shard with the
+/// smallest total duration. Modules without a recorded duration are assumed
+/// to take the average recorded duration, so without any durations the
+/// modules are spread evenly by count. Ties are broken by the relative path
+/// of the module so that every machine computes the same partitioning.
+pub fn partition(
+  specifiers: Vec<ModuleSpecifier>,
+  shard: TestShard,
+  durations: &TestDurations,
+  cwd: &Url,
+) -> Vec<ModuleSpecifier> {
+  let keys = specifiers
+    .iter()
+    .map(|specifier| to_relative_path_or_remote_url(cwd, specifier.as_str()))
+    .collect::<Vec<_>>();
+  let known = keys
+    .iter()
+    .filter_map(|key| durations.get(key))
+    .copied()
+    .collect::<Vec<_>>();
+  let default_duration = if known.is_empty() {
+    1
+  } else {
+    (known.iter().sum::<u64>() / known.len() as u64).max(1)
+  };
+
+  let mut order = (0..specifiers.len()).collect::<Vec<_>>();
+  let weight =
+    |i: usize| durations.get(&keys[i]).copied().unwrap_or(default_duration);
+  order.sort_by(|&a, &b| {
+    weight(b)
+      .cmp(&weight(a))
+      .then_with(|| keys[a].cmp(&keys[b]))
+  });
+
+  let mut totals = vec![0u64; shard.count];
+  let mut in_shard = vec![false; specifiers.len()];
+  for i in order {
+    let (target, _) = totals
+      .iter()
+      .enumerate()
+      .min_by_key(|(index, total)| (**total, *index))
+      .unwrap();
+    totals[target] += weight(i);
+    in_shard[i] = target == shard.index - 1;
+  }
+
+  specifiers
+    .into_iter()
+    .zip(in_shard)
+    .filter_map(|(specifier, in_shard)| in_shard.then_some(specifier))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn specifiers(names: &[&str]) -> Vec<ModuleSpecifier> {
+    names
+      .iter()
+      .map(|name| Url::parse(&format!("file:///project/{name}")).unwrap())
+      .collect()
+  }
+
+  fn names(specifiers: Vec<ModuleSpecifier>) -> Vec<String> {
+    specifiers
+      .into_iter()
+      .map(|s| s.path().trim_start_matches("/project/").to_string())
+      .collect()
+  }
+
+  #[test]
+  fn partition_by_count() {
+    let cwd = Url::parse("file:///project/").unwrap();
+    let all = specifiers(&["c_test.ts", "a_test.ts", "b_test.ts", "d_test.ts"]);
+    let shard = |index| {
+      names(partition(
+        all.clone(),
+        TestShard { index, count: 3 },
+        &TestDurations::new(),
+        &cwd,
+      ))
+    };
+    assert_eq!(shard(1), vec!["a_test.ts", "d_test.ts"]);
+    assert_eq!(shard(2), vec!["b_test.ts"]);
+    assert_eq!(shard(3), vec!["c_test.ts"]);
+  }
+
+  #[test]
+  fn partition_by_duration() {
+    let cwd = Url::parse("file:///project/").unwrap();
+    let all = specifiers(&["a_test.ts", "b_test.ts", "c_test.ts", "d_test.ts"]);
+    let durations = TestDurations::from([
+      ("./a_test.ts".to_string(), 900),
+      ("./b_test.ts".to_string(), 300),
+      ("./c_test.ts".to_string(), 300),
+    ]);
+    let shard = |index| {
+      names(partition(
+        all.clone(),
+        TestShard { index, count: 2 },
+        &durations,
+        &cwd,
+      ))
+    };
+    // d_test.ts has no recorded duration and counts as the average (500ms).
+    assert_eq!(shard(1), vec!["a_test.ts"]);
+    assert_eq!(shard(2), vec!["b_test.ts", "c_test.ts", "d_test.ts"]);
+  }
+
+  #[test]
+  fn partition_single_shard() {
+    let cwd = Url::parse("file:///project/").unwrap();
+    let all = specifiers(&["b_test.ts", "a_test.ts"]);
+    let result = partition(
+      all.clone(),
+      TestShard { index: 1, count: 1 },
+      &TestDurations::new(),
+      &cwd,
+    );
+    assert_eq!(result, all);
+  }
+}
@@ -0,0 +1,795 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Explains why a package is in the dependency graph by finding the paths
+//! from the dependencies of the workspace's configuration files to it in the
+//! lockfile.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_lockfile::Lockfile;
+use deno_npm::NpmPackageId;
+use deno_npm::resolution::NpmOverrides;
+use deno_resolver::factory::npm_overrides_from_workspace;
+use deno_semver::StackString;
+use deno_semver::Version;
+use deno_semver::VersionReq;
+use deno_semver::jsr::JsrDepPackageReq;
+use deno_semver::package::PackageKind;
+use deno_semver::package::PackageNv;
+use deno_semver::package::PackageReq;
+use deno_terminal::colors;
+use serde::Serialize;
+
+use crate::args::Flags;
+use crate::args::WhyFlags;
+use crate::factory::CliFactory;
+use crate::util::display;
+
+pub async fn why(
+  flags: Arc<Flags>,
+  why_flags: WhyFlags,
+) -> Result<(), AnyError> {
+  let query = PackageQuery::parse(&why_flags.package)?;
+  let factory = CliFactory::from_flags(flags);
+  let cli_options = factory.cli_options()?;
+  let Some(lockfile) = factory.maybe_lockfile().await?.cloned() else {
+    bail!(
+      "Explaining why a package is a dependency requires a lockfile. Remove --no-lock or enable the lockfile in the configuration file."
+    );
+  };
+  let overrides =
+    Rc::new(npm_overrides_from_workspace(cli_options.workspace()));
+
+  let graph = DependencyGraph::from_lockfile(&lockfile.lock());
+  let packages = graph.explain(&query, &overrides);
+  if packages.is_empty() {
+    bail!(
+      "No package matching '{}' was found in the lockfile. Run `deno install` if it was recently added.",
+      why_flags.package
+    );
+  }
+
+  if why_flags.json {
+    display::write_json_to_stdout(&WhyOutput {
+      version: 1,
+      packages: packages
+        .iter()
+        .map(|package| package.to_json(&graph))
+        .collect(),
+    })
+  } else {
+    print_packages(&graph, &packages);
+    Ok(())
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct PackageQuery {
+  kind: Option<PackageKind>,
+  req: PackageReq,
+}
+
+impl PackageQuery {
+  /// Parses a package name with an optional version requirement and
+  /// `npm:` or `jsr:` prefix.
+  fn parse(text: &str) -> Result<Self, AnyError> {
+    let (kind, req) = if let Some(req) = text.strip_prefix("npm:") {
+      (Some(PackageKind::Npm), req)
+    } else if let Some(req) = text.strip_prefix("jsr:") {
+      (Some(PackageKind::Jsr), req)
+    } else {
+      (None, text)
+    };
+    let req = PackageReq::from_str(req)
+      .with_context(|| format!("Invalid package '{}'", text))?;
+    Ok(Self { kind, req })
+  }
+
+  fn matches(&self, node: &Node) -> bool {
+    let nv = node.nv();
+    self.kind.is_none_or(|kind| kind == node.kind())
+      && nv.name == self.req.name
+      && self.req.version_req.matches(&nv.version)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Node {
+  Jsr(PackageNv),
+  /// The npm package with the peer dependencies it was resolved with.
+  Npm(NpmPackageId),
+}
+
+impl Node {
+  fn nv(&self) -> &PackageNv {
+    match self {
+      Node::Jsr(nv) => nv,
+      Node::Npm(id) => &id.nv,
+    }
+  }
+
+  fn kind(&self) -> PackageKind {
+    match self {
+      Node::Jsr(_) => PackageKind::Jsr,
+      Node::Npm(_) => PackageKind::Npm,
+    }
+  }
+
+  fn display(&self) -> String {
+    match self {
+      Node::Jsr(nv) => format!("jsr:{nv}"),
+      Node::Npm(id) => format!("npm:{}", id.nv),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum DependencyKind {
+  Dependency,
+  Optional,
+  Peer,
+  OptionalPeer,
+}
+
+impl DependencyKind {
+  fn description(&self) -> Option<&'static str> {
+    match self {
+      DependencyKind::Dependency => None,
+      DependencyKind::Optional => Some("optional dependency"),
+      DependencyKind::Peer => Some("peer dependency"),
+      DependencyKind::OptionalPeer => Some("optional peer dependency"),
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Edge {
+  /// The name the package refers to the dependency as, which is different
+  /// from the dependency's name for aliases.
+  name: StackString,
+  to: Node,
+  kind: DependencyKind,
+}
+
+/// A dependency of a workspace member's configuration file.
+#[derive(Debug)]
+struct Root {
+  config_file: String,
+  req: JsrDepPackageReq,
+  to: Node,
+}
+
+struct DependencyGraph {
+  roots: Vec<Root>,
+  edges: HashMap<Node, Vec<Edge>>,
+}
+
+impl DependencyGraph {
+  fn from_lockfile(lockfile: &Lockfile) -> Self {
+    let mut roots = Vec::new();
+    for (member_path, member) in lockfile.workspace_members() {
+      let config_files = [
+        ("deno.json", member.dependencies),
+        ("package.json", member.package_json_deps),
+      ];
+      for (file_name, reqs) in config_files {
+        let config_file = if member_path.is_empty() {
+          file_name.to_string()
+        } else {
+          format!("{member_path}/{file_name}")
+        };
+        let mut reqs = reqs.into_iter().collect::<Vec<_>>();
+        reqs.sort_by_cached_key(|req| req.to_string_normalized());
+        for req in reqs {
+          if let Some(to) = resolve_lockfile_req(lockfile, &req) {
+            roots.push(Root {
+              config_file: config_file.clone(),
+              req,
+              to,
+            });
+          }
+        }
+      }
+    }
+    roots.sort_by(|a, b| a.config_file.cmp(&b.config_file));
+
+    let packages = &lockfile.content.packages;
+    let mut edges = HashMap::with_capacity(packages.npm.len());
+    for (id, info) in &packages.npm {
+      let Ok(id) = NpmPackageId::from_serialized(id) else {
+        continue;
+      };
+      let dependencies = info
+        .dependencies
+        .iter()
+        .map(|(name, dep_id)| {
+          let kind = if info.optional_dependencies.contains_key(name) {
+            DependencyKind::Optional
+          } else {
+            DependencyKind::Dependency
+          };
+          (name, dep_id, kind)
+        })
+        .chain(
+          info
+            .optional_dependencies
+            .iter()
+            .filter(|(name, _)| !info.dependencies.contains_key(*name))
+            .map(|(name, dep_id)| (name, dep_id, DependencyKind::Optional)),
+        )
+        .chain(
+          info
+            .optional_peers
+            .iter()
+            .map(|(name, dep_id)| (name, dep_id, DependencyKind::OptionalPeer)),
+        )
+        .filter_map(|(name, dep_id, kind)| {
+          let dep_id = NpmPackageId::from_serialized(dep_id).ok()?;
+          let is_peer =
+            id.peer_dependencies.iter().any(|peer| peer.nv == dep_id.nv);
+          let kind = match kind {
+            DependencyKind::Dependency if is_peer => DependencyKind::Peer,
+            kind => kind,
+          };
+          Some(Edge {
+            name: name.clone(),
+            to: Node::Npm(dep_id),
+            kind,
+          })
+        })
+        .collect();
+      edges.insert(Node::Npm(id), dependencies);
+    }
+    for (nv, info) in &packages.jsr {
+      let mut reqs = info.dependencies.iter().collect::<Vec<_>>();
+      reqs.sort_by_cached_key(|req| req.to_string_normalized());
+      let dependencies = reqs
+        .into_iter()
+        .filter_map(|req| {
+          Some(Edge {
+            name: req.req.name.clone(),
+            to: resolve_lockfile_req(lockfile, req)?,
+            kind: DependencyKind::Dependency,
+          })
+        })
+        .collect();
+      edges.insert(Node::Jsr(nv.clone()), dependencies);
+    }
+    Self { roots, edges }
+  }
+
+  /// Finds the shortest path from each root through each package that
+  /// depends on a package matching the query, grouped by the package.
+  fn explain(
+    &self,
+    query: &PackageQuery,
+    overrides: &Rc<NpmOverrides>,
+  ) -> Vec<ExplainedPackage> {
+    let targets = self
+      .edges
+      .keys()
+      .filter(|node| query.matches(node))
+      .collect::<HashSet<_>>();
+    if targets.is_empty() {
+      return Vec::new();
+    }
+    // group the copies of npm packages resolved with other peer dependencies
+    let mut packages = BTreeMap::new();
+    for target in &targets {
+      packages
+        .entry((target.kind(), target.nv().clone()))
+        .or_insert_with(Vec::new);
+    }
+
+    let mut finder = PathFinder {
+      graph: self,
+      can_reach_target: self.can_reach(&targets),
+      targets,
+      root_overrides: overrides.clone(),
+      paths: Vec::new(),
+    };
+    for root_index in 0..self.roots.len() {
+      finder.visit_root(root_index);
+    }
+    for path in finder.paths {
+      let node = &path.steps.last().unwrap().node;
+      packages
+        .get_mut(&(node.kind(), node.nv().clone()))
+        .unwrap()
+        .push(path);
+    }
+    packages
+      .into_iter()
+      .map(|((kind, nv), paths)| ExplainedPackage {
+        package: match kind {
+          PackageKind::Jsr => format!("jsr:{nv}"),
+          PackageKind::Npm => format!("npm:{nv}"),
+        },
+        paths,
+      })
+      .collect()
+  }
+
+  /// Gets the packages that depend directly or transitively on one of the
+  /// targets, including the targets.
+  fn can_reach<'a>(&'a self, targets: &HashSet<&'a Node>) -> HashSet<&'a Node> {
+    let mut dependents: HashMap<&Node, Vec<&Node>> = HashMap::new();
+    for (node, edges) in &self.edges {
+      for edge in edges {
+        dependents.entry(&edge.to).or_default().push(node);
+      }
+    }
+    let mut reached = targets.clone();
+    let mut pending = targets.iter().copied().collect::<VecDeque<_>>();
+    while let Some(node) = pending.pop_front() {
+      for dependent in dependents.get(node).into_iter().flatten() {
+        if reached.insert(*dependent) {
+          pending.push_back(*dependent);
+        }
+      }
+    }
+    reached
+  }
+}
+
+/// Gets the package a dependency was resolved to in the lockfile.
+fn resolve_lockfile_req(
+  lockfile: &Lockfile,
+  req: &JsrDepPackageReq,
+) -> Option<Node> {
+  let version = lockfile.content.packages.specifiers.get(req)?;
+  match req.kind {
+    PackageKind::Jsr => Some(Node::Jsr(PackageNv {
+      name: req.req.name.clone(),
+      version: Version::parse_standard(version).ok()?,
+    })),
+    PackageKind::Npm => {
+      let id = format!("{}@{}", req.req.name, version);
+      Some(Node::Npm(NpmPackageId::from_serialized(&id).ok()?))
+    }
+  }
+}
+
+#[derive(Clone)]
+struct PathStep {
+  node: Node,
+  kind: DependencyKind,
+  /// The version requirement of the npm override that applied.
+  override_req: Option<VersionReq>,
+}
+
+struct DependencyPath {
+  root_index: usize,
+  steps: Vec<PathStep>,
+}
+
+/// Finds the shortest path from a root to each package that directly depends
+/// on a target. Enumerating every path instead grows combinatorially in npm
+/// graphs where many packages share dependencies.
+struct PathFinder<'a> {
+  graph: &'a DependencyGraph,
+  targets: HashSet<&'a Node>,
+  can_reach_target: HashSet<&'a Node>,
+  /// The overrides of the root package.json, which also apply to the npm
+  /// dependencies of JSR packages.
+  root_overrides: Rc<NpmOverrides>,
+  paths: Vec<DependencyPath>,
+}
+
+impl<'a> PathFinder<'a> {
+  fn visit_root(&mut self, root_index: usize) {
+    let graph = self.graph;
+    let root = &graph.roots[root_index];
+    if !self.can_reach_target.contains(&root.to) {
+      return;
+    }
+    // the package and edge each package was first reached from, which is
+    // the end of a shortest path to it
+    let mut reached: HashMap<&'a Node, Option<(&'a Node, &'a Edge)>> =
+      HashMap::from([(&root.to, None)]);
+    let mut pending = VecDeque::from([&root.to]);
+    if self.targets.contains(&root.to) {
+      self.push_path(root_index, &reached, &root.to, None);
+    }
+    while let Some(node) = pending.pop_front() {
+      for edge in graph.edges.get(node).into_iter().flatten() {
+        if !self.can_reach_target.contains(&edge.to) {
+          continue;
+        }
+        if self.targets.contains(&edge.to) {
+          self.push_path(root_index, &reached, node, Some(edge));
+        }
+        if !reached.contains_key(&edge.to) {
+          reached.insert(&edge.to, Some((node, edge)));
+          pending.push_back(&edge.to);
+        }
+      }
+    }
+  }
+
+  fn push_path(
+    &mut self,
+    root_index: usize,
+    reached: &HashMap<&'a Node, Option<(&'a Node, &'a Edge)>>,
+    parent: &'a Node,
+    edge: Option<&'a Edge>,
+  ) {
+    let mut edges = Vec::new();
+    let mut node = parent;
+    while let Some((from, edge)) = reached[node] {
+      edges.push(edge);
+      node = from;
+    }
+    edges.reverse();
+    edges.extend(edge);
+
+    let root = &self.graph.roots[root_index];
+    let nodes = std::iter::once((
+      &root.to,
+      &root.req.req.name,
+      DependencyKind::Dependency,
+    ))
+    .chain(edges.iter().map(|edge| (&edge.to, &edge.name, edge.kind)))
+    .collect::<Vec<_>>();
+    let (target, ..) = nodes[nodes.len() - 1];
+    // a path through the target is already reported as a path to it
+    if nodes[..nodes.len() - 1]
+      .iter()
+      .any(|(node, ..)| *node == target)
+    {
+      return;
+    }
+
+    // the npm overrides are scoped to the subtree of the package they're
+    // nested in, the same way as when resolving
+    let mut active_overrides = self.root_overrides.clone();
+    let mut steps = Vec::with_capacity(nodes.len());
+    for (node, name, kind) in nodes {
+      let override_req = match node {
+        Node::Npm(id) => {
+          let override_req = active_overrides
+            .get_override_for(name, Some(&id.nv.version))
+            .cloned();
+          active_overrides =
+            active_overrides.for_child(&id.nv.name, &id.nv.version);
+          override_req
+        }
+        Node::Jsr(_) => {
+          active_overrides = self.root_overrides.clone();
+          None
+        }
+      };
+      steps.push(PathStep {
+        node: node.clone(),
+        kind,
+        override_req,
+      });
+    }
+    self.paths.push(DependencyPath { root_index, steps });
+  }
+}
+
+struct ExplainedPackage {
+  package: String,
+  paths: Vec<DependencyPath>,
+}
+
+impl ExplainedPackage {
+  fn to_json(&self, graph: &DependencyGraph) -> PackageJson {
+    PackageJson {
+      package: self.package.clone(),
+      paths: self
+        .paths
+        .iter()
+        .map(|path| {
+          let root = &graph.roots[path.root_index];
+          PathJson {
+            config_file: root.config_file.clone(),
+            requested: root.req.to_string_normalized().to_string(),
+            steps: path
+              .steps
+              .iter()
+              .map(|step| StepJson {
+                package: step.node.display(),
+                kind: step.kind,
+                peer_dependencies: match &step.node {
+                  Node::Npm(id) => id
+                    .peer_dependencies
+                    .iter()
+                    .map(|peer| format!("npm:{}", peer.nv))
+                    .collect(),
+                  Node::Jsr(_) => Vec::new(),
+                },
+                override_req: step
+                  .override_req
+                  .as_ref()
+                  .map(|req| req.to_string()),
+              })
+              .collect(),
+          }
+        })
+        .collect(),
+    }
+  }
+}
+
+#[derive(Serialize)]
+struct WhyOutput {
+  version: u32,
+  packages: Vec<PackageJson>,
+}
+
+#[derive(Serialize)]
+struct PackageJson {
+  package: String,
+  paths: Vec<PathJson>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PathJson {
+  config_file: String,
+  requested: String,
+  steps: Vec<StepJson>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StepJson {
+  package: String,
+  kind: DependencyKind,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  peer_dependencies: Vec<String>,
+  #[serde(rename = "override", skip_serializing_if = "Option::is_none")]
+  override_req: Option<String>,
+}
+
+#[allow(clippy::print_stdout, reason = "print method")]
+fn print_packages(graph: &DependencyGraph, packages: &[ExplainedPackage]) {
+  for (index, package) in packages.iter().enumerate() {
+    if index > 0 {
+      println!();
+    }
+    println!("{}", colors::bold(&package.package));
+    if package.paths.is_empty() {
+      println!(
+        "  {}",
+        colors::gray("not a dependency of the workspace's configuration files")
+      );
+    }
+    for path in &package.paths {
+      let root = &graph.roots[path.root_index];
+      println!("  {}", root.config_file);
+      for (depth, step) in path.steps.iter().enumerate() {
+        let mut reasons = Vec::new();
+        if depth == 0 {
+          reasons
+            .push(format!("requested as {}", root.req.to_string_normalized()));
+        }
+        if let Some(description) = step.kind.description() {
+          reasons.push(description.to_string());
+        }
+        if let Node::Npm(id) = &step.node
+          && !id.peer_dependencies.is_empty()
+        {
+          let peers = id
+            .peer_dependencies
+            .iter()
+            .map(|peer| peer.nv.to_string())
+            .collect::<Vec<_>>();
+          reasons.push(format!("with peers {}", peers.join(", ")));
+        }
+        if let Some(req) = &step.override_req {
+          reasons.push(format!("overridden to {}", req));
+        }
+        let reasons = if reasons.is_empty() {
+          String::new()
+        } else {
+          format!(" {}", colors::gray(format!("({})", reasons.join("; "))))
+        };
+        println!(
+          "  {}└─ {}{}",
+          "   ".repeat(depth),
+          step.node.display(),
+          reasons
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+
+  use deno_core::futures::FutureExt;
+  use deno_core::serde_json;
+  use deno_lockfile::NewLockfileOptions;
+  use deno_lockfile::NpmPackageInfoProvider;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  struct NoopNpmPackageInfoProvider;
+
+  #[async_trait::async_trait(?Send)]
+  impl NpmPackageInfoProvider for NoopNpmPackageInfoProvider {
+    async fn get_npm_package_info(
+      &self,
+      _values: &[PackageNv],
+    ) -> Result<
+      Vec<deno_lockfile::Lockfile5NpmInfo>,
+      Box<dyn std::error::Error + Send + Sync>,
+    > {
+      unreachable!()
+    }
+  }
+
+  fn test_lockfile() -> Lockfile {
+    let content = r#"{
+  "version": "5",
+  "specifiers": {
+    "jsr:@std/path@1": "1.0.8",
+    "npm:chalk@5": "5.3.0",
+    "npm:preact@10": "10.0.0_react@18.0.0",
+    "npm:react@18": "18.0.0"
+  },
+  "jsr": {
+    "@std/path@1.0.8": {
+      "integrity": "548fa456bb6a04d3c1a1e7477986b6cffbce95102d0bb447c67c4ee70e0364be",
+      "dependencies": ["npm:chalk@5"]
+    }
+  },
+  "npm": {
+    "chalk@5.3.0": {
+      "integrity": "sha512-chalk"
+    },
+    "preact@10.0.0_react@18.0.0": {
+      "integrity": "sha512-preact",
+      "dependencies": ["react", "scheduler"]
+    },
+    "react@18.0.0": {
+      "integrity": "sha512-react",
+      "dependencies": ["scheduler"]
+    },
+    "scheduler@0.23.0": {
+      "integrity": "sha512-scheduler"
+    }
+  },
+  "workspace": {
+    "dependencies": ["jsr:@std/path@1"],
+    "packageJson": {
+      "dependencies": ["npm:preact@10"]
+    },
+    "members": {
+      "packages/a": {
+        "packageJson": {
+          "dependencies": ["npm:react@18"]
+        }
+      }
+    }
+  }
+}"#;
+    Lockfile::new(
+      NewLockfileOptions {
+        file_path: PathBuf::from("/deno.lock"),
+        content,
+        overwrite: false,
+      },
+      &NoopNpmPackageInfoProvider,
+    )
+    .now_or_never()
+    .unwrap()
+    .unwrap()
+  }
+
+  fn explain(
+    query: &str,
+    overrides: serde_json::Value,
+  ) -> Vec<(String, Vec<String>)> {
+    let graph = DependencyGraph::from_lockfile(&test_lockfile());
+    let overrides = Rc::new(
+      NpmOverrides::from_value(overrides, &Default::default()).unwrap(),
+    );
+    graph
+      .explain(&PackageQuery::parse(query).unwrap(), &overrides)
+      .iter()
+      .map(|package| {
+        let json = package.to_json(&graph);
+        (
+          json.package,
+          json
+            .paths
+            .into_iter()
+            .map(|path| {
+              let mut parts = vec![path.config_file];
+              parts.extend(path.steps.into_iter().map(|step| {
+                let mut text = step.package;
+                if step.kind != DependencyKind::Dependency {
+                  text.push_str(&format!(" [{:?}]", step.kind));
+                }
+                if let Some(req) = step.override_req {
+                  text.push_str(&format!(" [override {req}]"));
+                }
+                text
+              }));
+              parts.join(" > ")
+            })
+            .collect(),
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn parses_query() {
+    assert_eq!(
+      PackageQuery::parse("npm:react@18").unwrap(),
+      PackageQuery {
+        kind: Some(PackageKind::Npm),
+        req: PackageReq::from_str("react@18").unwrap(),
+      }
+    );
+    assert_eq!(
+      PackageQuery::parse("@std/path").unwrap(),
+      PackageQuery {
+        kind: None,
+        req: PackageReq::from_str("@std/path").unwrap(),
+      }
+    );
+    assert!(PackageQuery::parse("npm:").is_err());
+  }
+
+  #[test]
+  fn explains_paths() {
+    assert_eq!(
+      explain("scheduler", serde_json::json!({})),
+      vec![(
+        "npm:scheduler@0.23.0".to_string(),
+        vec![
+          "package.json > npm:preact@10.0.0 > npm:scheduler@0.23.0".to_string(),
+          "package.json > npm:preact@10.0.0 > npm:react@18.0.0 [Peer] > npm:scheduler@0.23.0".to_string(),
+          "packages/a/package.json > npm:react@18.0.0 > npm:scheduler@0.23.0".to_string(),
+        ]
+      )]
+    );
+    assert_eq!(
+      explain("npm:chalk@^5.1", serde_json::json!({})),
+      vec![(
+        "npm:chalk@5.3.0".to_string(),
+        vec!["deno.json > jsr:@std/path@1.0.8 > npm:chalk@5.3.0".to_string()]
+      )]
+    );
+    assert_eq!(explain("jsr:chalk", serde_json::json!({})), vec![]);
+    assert_eq!(explain("chalk@4", serde_json::json!({})), vec![]);
+  }
+
+  #[test]
+  fn explains_overrides() {
+    assert_eq!(
+      explain(
+        "scheduler",
+        serde_json::json!({
+          "react": { "scheduler": "0.23.0" },
+        })
+      ),
+      vec![(
+        "npm:scheduler@0.23.0".to_string(),
+        vec![
+          "package.json > npm:preact@10.0.0 > npm:scheduler@0.23.0".to_string(),
+          "package.json > npm:preact@10.0.0 > npm:react@18.0.0 [Peer] > npm:scheduler@0.23.0 [override 0.23.0]".to_string(),
+          "packages/a/package.json > npm:react@18.0.0 > npm:scheduler@0.23.0 [override 0.23.0]".to_string(),
+        ]
+      )]
+    );
+  }
+}
@@ -852,6 +852,11 @@ declare namespace Deno {
      *
      * @default {"inherit"} */
     permissions?: PermissionOptions;
+    /** Number of times to re-run the test when it fails. A test that passes
+     * on a retry is reported as flaky instead of failed.
+     *
+     * Defaults to the value of the `--retries` flag, or `0`. */
+    retry?: number;
   }
 
   /** Register a test which will be run when `deno test` is used on the command
@@ -5426,6 +5431,40 @@ declare namespace Deno {
 
     /** The callback which is called when the server starts listening. */
     onListen?: (localAddr: Addr) => void;
+
+    /** Configures automatic compression of response bodies. Set to `false` to
+     * disable compression entirely.
+     *
+     * By default responses are compressed with the first of `br`, `zstd` and
+     * `gzip` accepted by the client. List `zstd` first in
+     * {@linkcode ServeCompressionOptions.encodings} to prefer it.
+     */
+    compression?: ServeCompressionOptions | false;
+  }
+
+  /** Options for the automatic response body compression of
+   * {@linkcode Deno.serve}.
+   *
+   * @category HTTP Server
+   */
+  export interface ServeCompressionOptions {
+    /** The encodings the server may use, in order of preference. When the
+     * client accepts several of them with the same quality value, the first
+     * one in this list is used.
+     *
+     * @default {["br", "zstd", "gzip"]}
+     */
+    encodings?: ("zstd" | "br" | "gzip")[];
+
+    /** Compression levels per encoding. */
+    levels?: {
+      /** An integer between 0 and 9. @default {1} */
+      gzip?: number;
+      /** An integer between 0 and 11. @default {6} */
+      br?: number;
+      /** An integer between 1 and 22. @default {3} */
+      zstd?: number;
+    };
   }
 
   /**
@@ -5469,6 +5508,18 @@ declare namespace Deno {
      *
      * @default {511} */
     tcpBacklog?: number;
+
+    /** **UNSTABLE**: New API, yet to be vetted.
+     *
+     * Also serve HTTP/3 on a QUIC endpoint bound to the UDP port with the same
+     * number as the TCP listener. Responses sent over HTTP/1.1 and HTTP/2
+     * advertise the HTTP/3 endpoint with an `Alt-Svc` header.
+     *
+     * Requires `cert` and `key` to be set and the `--unstable-net` flag.
+     *
+     * @experimental
+     * @default {false} */
+    http3?: boolean;
   }
 
   /**
@@ -6542,6 +6593,36 @@ declare namespace Deno {
     allowHost?: boolean;
     /** Sets the local address where the socket will connect from. */
     localAddress?: string;
+    /** **UNSTABLE**: New API, yet to be vetted.
+     *
+     * Caches responses to `GET` requests made with this client in a private
+     * HTTP cache following RFC 9111. `Cache-Control`, `Expires`, `Vary`, and
+     * revalidation with `ETag` and `Last-Modified` are honored. Responses are
+     * stored in the same storage as the Cache API.
+     *
+     * `true` uses the `"deno:fetch"` cache, and `false` disables caching for
+     * this client even when `--unstable-fetch-cache` is set. Defaults to the
+     * `--unstable-fetch-cache` setting.
+     *
+     * @experimental
+     */
+    cache?: boolean | HttpCacheOptions;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for the HTTP cache of a {@linkcode Deno.HttpClient}.
+   *
+   * @category Fetch
+   * @experimental
+   */
+  export interface HttpCacheOptions {
+    /** The name of the cache that stores the responses, as used with
+     * `caches.open()`.
+     *
+     * @default {"deno:fetch"}
+     */
+    name?: string;
   }
 
   /**
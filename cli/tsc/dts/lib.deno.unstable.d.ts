@@ -438,7 +438,9 @@ declare namespace Deno {
    *
    * `schedule` can be a string in the Unix cron format or in JSON format
    * as specified by interface {@linkcode CronSchedule}, where time is specified
-   * using UTC time zone.
+   * using UTC time zone. When running locally, the `DENO_CRON_TIMEZONE`
+   * environment variable can be set to an IANA time zone name to evaluate
+   * schedules in that time zone instead.
    *
    * @category Cloud
    * @experimental
@@ -464,7 +466,9 @@ declare namespace Deno {
    *
    * `schedule` can be a string in the Unix cron format or in JSON format
    * as specified by interface {@linkcode CronSchedule}, where time is specified
-   * using UTC time zone.
+   * using UTC time zone. When running locally, the `DENO_CRON_TIMEZONE`
+   * environment variable can be set to an IANA time zone name to evaluate
+   * schedules in that time zone instead.
    *
    * `backoffSchedule` option can be used to specify the retry policy for failed
    * executions. Each element in the array represents the number of milliseconds
@@ -483,6 +487,72 @@ declare namespace Deno {
     handler: () => Promise<void> | void,
   ): Promise<void>;
 
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * The status of a cron job registered with {@linkcode Deno.cron}, as
+   * returned by {@linkcode Deno.cron.list}.
+   *
+   * @category Cloud
+   * @experimental
+   */
+  export interface CronStatus {
+    /** The name the cron job was registered with. */
+    name: string;
+    /** The schedule in the Unix cron format. */
+    schedule: string;
+    /** The retry policy for failed executions, if one was specified. */
+    backoffSchedule: number[] | null;
+    /** Unix timestamp in milliseconds of the next execution, if known. When
+     * crons are scheduled by an external service this is always `null`. */
+    nextDeadline: number | null;
+    /** Number of retries performed for the currently failing execution. */
+    currentExecutionRetries: number;
+    /** Whether the handler is currently being executed. */
+    running: boolean;
+    /** Unix timestamp in milliseconds of the start of the last execution. */
+    lastRun: number | null;
+    /** Whether the last execution succeeded. */
+    lastSuccess: boolean | null;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category Cloud
+   * @experimental
+   */
+  export namespace cron {
+    /** **UNSTABLE**: New API, yet to be vetted.
+     *
+     * Returns the status of all cron jobs registered in the current process,
+     * sorted by name.
+     *
+     * ```ts
+     * for (const status of Deno.cron.list()) {
+     *   console.log(status.name, status.nextDeadline, status.lastSuccess);
+     * }
+     * ```
+     *
+     * @category Cloud
+     * @experimental
+     */
+    export function list(): CronStatus[];
+
+    /** **UNSTABLE**: New API, yet to be vetted.
+     *
+     * Executes the cron job with the given name as soon as possible,
+     * regardless of its schedule. This is intended for testing handlers.
+     * Afterwards the cron job continues with its regular schedule.
+     *
+     * Throws a `Deno.errors.NotFound` error if no cron job with that name is
+     * registered, and a `Deno.errors.Busy` error if it is currently running
+     * or an invocation of it is already pending.
+     *
+     * @category Cloud
+     * @experimental
+     */
+    export function trigger(name: string): void;
+  }
+
   /** **UNSTABLE**: New API, yet to be vetted.
    *
    * A key to be persisted in a {@linkcode Deno.Kv}. A key is a sequence
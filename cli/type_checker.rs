@@ -43,6 +43,7 @@ use crate::node::CliPackageJsonResolver;
 use crate::npm::CliNpmResolver;
 use crate::sys::CliSys;
 use crate::tsc;
+use crate::tsc::DiagnosticCategory;
 use crate::tsc::Diagnostics;
 use crate::tsc::TypeCheckingCjsTracker;
 
@@ -155,15 +156,37 @@ impl TypeChecker {
     options: CheckOptions,
   ) -> Result<Arc<ModuleGraph>, CheckError> {
     let mut diagnostics = self.check_diagnostics(graph, options)?;
+    let mut maybe_reporter = match self.cli_options.sub_command() {
+      DenoSubcommand::Check(check_flags) => {
+        crate::tools::check::create_reporter(
+          check_flags,
+          self.cli_options.initial_cwd(),
+        )
+      }
+      _ => None,
+    };
     let mut failed = false;
     for result in diagnostics.by_ref() {
       let mut diagnostics = result?;
-      diagnostics.emit_warnings();
+      match &mut maybe_reporter {
+        Some(reporter) => {
+          for diagnostic in diagnostics.iter() {
+            reporter.visit_diagnostic(diagnostic);
+          }
+          diagnostics.retain(|d| d.category != DiagnosticCategory::Warning);
+        }
+        None => diagnostics.emit_warnings(),
+      }
       if diagnostics.has_diagnostic() {
         failed = true;
-        log::error!("{}\n", diagnostics);
+        if maybe_reporter.is_none() {
+          log::error!("{}\n", diagnostics);
+        }
       }
     }
+    if let Some(reporter) = &mut maybe_reporter {
+      reporter.close();
+    }
     if failed {
       Err(
         FailedTypeCheckingError {
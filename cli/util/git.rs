@@ -0,0 +1,211 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+
+use super::fs::canonicalize_path_maybe_not_exists;
+
+/// Runs git in `cwd` and returns its stdout.
+pub fn run_git(cwd: &Path, args: &[&str]) -> Result<String, AnyError> {
+  let output = Command::new("git")
+    .current_dir(cwd)
+    .args(args)
+    .output()
+    .context("Failed to run git. Is it installed and on the PATH?")?;
+  if !output.status.success() {
+    bail!(
+      "git {} failed: {}",
+      args.join(" "),
+      String::from_utf8_lossy(&output.stderr).trim()
+    );
+  }
+  Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Returns the root directory of the git repository containing `cwd`.
+pub fn repository_root(cwd: &Path) -> Result<PathBuf, AnyError> {
+  let stdout = run_git(cwd, &["rev-parse", "--show-toplevel"])?;
+  Ok(PathBuf::from(stdout.trim()))
+}
+
+/// Returns the canonicalized paths of the files that differ from `git_ref`,
+/// including uncommitted changes and untracked files.
+pub fn changed_files(
+  cwd: &Path,
+  git_ref: &str,
+) -> Result<HashSet<PathBuf>, AnyError> {
+  let root = repository_root(cwd)?;
+  let diff = run_git(
+    &root,
+    &[
+      "diff",
+      "--name-only",
+      "--no-renames",
+      // the ref is user input and must not be read as an option
+      "--end-of-options",
+      git_ref,
+      "--",
+    ],
+  )?;
+  let untracked =
+    run_git(&root, &["ls-files", "--others", "--exclude-standard"])?;
+  Ok(
+    parse_file_list(&root, &diff)
+      .chain(parse_file_list(&root, &untracked))
+      .map(|path| canonicalize_path_maybe_not_exists(&path).unwrap_or(path))
+      .collect(),
+  )
+}
+
+/// Returns the changed lines of each file that differs from `git_ref`, keyed
+/// by canonicalized path. Line indexes are 0-based. Untracked files map to
+/// `None` as all of their lines are new.
+pub fn changed_lines(
+  cwd: &Path,
+  git_ref: &str,
+) -> Result<HashMap<PathBuf, Option<HashSet<usize>>>, AnyError> {
+  let root = repository_root(cwd)?;
+  let diff = run_git(
+    &root,
+    &[
+      "-c",
+      "core.quotePath=false",
+      "diff",
+      "--unified=0",
+      "--no-renames",
+      "--no-color",
+      "--no-ext-diff",
+      // parse_diff_hunks relies on the default prefixes, which can be
+      // changed with diff.noprefix or diff.mnemonicPrefix
+      "--src-prefix=a/",
+      "--dst-prefix=b/",
+      "--end-of-options",
+      git_ref,
+      "--",
+    ],
+  )?;
+  let untracked =
+    run_git(&root, &["ls-files", "--others", "--exclude-standard"])?;
+  let canonicalize =
+    |path: PathBuf| canonicalize_path_maybe_not_exists(&path).unwrap_or(path);
+  let mut result = HashMap::new();
+  for (path, lines) in parse_diff_hunks(&diff) {
+    result.insert(canonicalize(root.join(path)), Some(lines));
+  }
+  for path in parse_file_list(&root, &untracked) {
+    result.insert(canonicalize(path), None);
+  }
+  Ok(result)
+}
+
+/// Parses the output of `git diff --unified=0` into the added or modified
+/// line indexes of each file. Deleted files are skipped.
+fn parse_diff_hunks(diff: &str) -> Vec<(String, HashSet<usize>)> {
+  let mut files = Vec::new();
+  let mut current: Option<(String, HashSet<usize>)> = None;
+  let mut in_header = false;
+  for line in diff.lines() {
+    if line.starts_with("diff --git ") {
+      files.extend(current.take());
+      in_header = true;
+    } else if in_header && let Some(path) = line.strip_prefix("+++ ") {
+      current = path
+        .strip_prefix("b/")
+        .map(|path| (path.to_string(), HashSet::new()));
+    } else if let Some(hunk) = line.strip_prefix("@@ ") {
+      in_header = false;
+      let Some((_, lines)) = &mut current else {
+        continue;
+      };
+      // @@ -start[,count] +start[,count] @@
+      let Some(range) = hunk.split(' ').find_map(|part| part.strip_prefix('+'))
+      else {
+        continue;
+      };
+      let (start, count) = range.split_once(',').unwrap_or((range, "1"));
+      if let (Ok(start), Ok(count)) =
+        (start.parse::<usize>(), count.parse::<usize>())
+      {
+        // a count of 0 means the hunk only removes lines
+        lines.extend((start..start + count).map(|line| line.saturating_sub(1)));
+      }
+    }
+  }
+  files.extend(current);
+  files
+}
+
+fn parse_file_list<'a>(
+  root: &'a Path,
+  stdout: &'a str,
+) -> impl Iterator<Item = PathBuf> + 'a {
+  stdout
+    .lines()
+    .filter(|line| !line.is_empty())
+    .map(|line| root.join(line))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parses_file_list() {
+    let root = Path::new("/repo");
+    let paths = parse_file_list(root, "src/mod.ts\n\nsrc/mod_test.ts\n")
+      .collect::<Vec<_>>();
+    assert_eq!(
+      paths,
+      vec![
+        PathBuf::from("/repo/src/mod.ts"),
+        PathBuf::from("/repo/src/mod_test.ts")
+      ]
+    );
+  }
+
+  #[test]
+  fn parses_diff_hunks() {
+    let diff = r#"diff --git a/src/mod.ts b/src/mod.ts
+index 1111111..2222222 100644
+--- a/src/mod.ts
++++ b/src/mod.ts
+@@ -3 +3 @@ export function a() {
+-  return 1;
++  return 2;
+@@ -10,0 +11,3 @@ export function b() {
++++ this added line starts with pluses
++  c();
++}
+@@ -20,2 +23,0 @@ export function c() {
+-  d();
+-}
+diff --git a/old.ts b/old.ts
+deleted file mode 100644
+--- a/old.ts
++++ /dev/null
+@@ -1 +0,0 @@
+-old();
+diff --git a/new.ts b/new.ts
+new file mode 100644
+--- /dev/null
++++ b/new.ts
+@@ -0,0 +1,2 @@
++a();
++b();
+"#;
+    assert_eq!(
+      parse_diff_hunks(diff),
+      vec![
+        ("src/mod.ts".to_string(), HashSet::from([2, 10, 11, 12])),
+        ("new.ts".to_string(), HashSet::from([0, 1])),
+      ]
+    );
+  }
+}
@@ -0,0 +1,159 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Workflow commands that create annotations in GitHub Actions.
+//!
+//! See https://docs.github.com/actions/reference/workflow-commands-for-github-actions
+
+use std::path::Path;
+
+use deno_core::url::Url;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationLevel {
+  Error,
+  Warning,
+  Notice,
+}
+
+impl AnnotationLevel {
+  fn as_str(&self) -> &'static str {
+    match self {
+      AnnotationLevel::Error => "error",
+      AnnotationLevel::Warning => "warning",
+      AnnotationLevel::Notice => "notice",
+    }
+  }
+}
+
+/// A 1-based position in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnnotationPosition {
+  pub line: usize,
+  pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotation {
+  pub level: AnnotationLevel,
+  /// The path of the file, relative to the working directory.
+  pub file: Option<String>,
+  pub start: Option<AnnotationPosition>,
+  pub end: Option<AnnotationPosition>,
+  pub title: Option<String>,
+  pub message: String,
+}
+
+impl Annotation {
+  /// Formats the annotation as a workflow command.
+  pub fn to_workflow_command(&self) -> String {
+    let mut properties = Vec::new();
+    if let Some(file) = &self.file {
+      properties.push(format!("file={}", escape_property(file)));
+      if let Some(start) = self.start {
+        properties.push(format!("line={}", start.line));
+        properties.push(format!("col={}", start.column));
+      }
+      if let Some(end) = self.end {
+        properties.push(format!("endLine={}", end.line));
+        // GitHub ignores the columns of annotations spanning several lines
+        if self.start.is_some_and(|start| start.line == end.line) {
+          properties.push(format!("endColumn={}", end.column));
+        }
+      }
+    }
+    if let Some(title) = &self.title {
+      properties.push(format!("title={}", escape_property(title)));
+    }
+    let mut command = format!("::{}", self.level.as_str());
+    if !properties.is_empty() {
+      command.push(' ');
+      command.push_str(&properties.join(","));
+    }
+    command.push_str("::");
+    command.push_str(&escape_data(&self.message));
+    command
+  }
+}
+
+/// Returns the path of a local module for the `file` property, relative to
+/// the working directory when it's inside of it.
+pub fn annotation_file(specifier: &Url, cwd: &Path) -> Option<String> {
+  if specifier.scheme() != "file" {
+    return None;
+  }
+  let path = specifier.to_file_path().ok()?;
+  let path = path.strip_prefix(cwd).unwrap_or(&path);
+  Some(path.to_string_lossy().replace('\\', "/"))
+}
+
+fn escape_data(value: &str) -> String {
+  value
+    .replace('%', "%25")
+    .replace('\r', "%0D")
+    .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+  escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn formats_workflow_commands() {
+    let annotation = Annotation {
+      level: AnnotationLevel::Error,
+      file: Some("src/a,b.ts".to_string()),
+      start: Some(AnnotationPosition { line: 1, column: 7 }),
+      end: Some(AnnotationPosition { line: 1, column: 9 }),
+      title: Some("no-unused-vars".to_string()),
+      message: "`_a` is never used\n\n100% sure".to_string(),
+    };
+    assert_eq!(
+      annotation.to_workflow_command(),
+      "::error file=src/a%2Cb.ts,line=1,col=7,endLine=1,endColumn=9,title=no-unused-vars::`_a` is never used%0A%0A100%25 sure"
+    );
+
+    let annotation = Annotation {
+      level: AnnotationLevel::Warning,
+      file: Some("a.ts".to_string()),
+      start: Some(AnnotationPosition { line: 1, column: 7 }),
+      end: Some(AnnotationPosition { line: 3, column: 1 }),
+      title: Some("TS2322".to_string()),
+      message: "message".to_string(),
+    };
+    assert_eq!(
+      annotation.to_workflow_command(),
+      "::warning file=a.ts,line=1,col=7,endLine=3,title=TS2322::message"
+    );
+
+    let annotation = Annotation {
+      level: AnnotationLevel::Notice,
+      file: None,
+      start: None,
+      end: None,
+      title: None,
+      message: "message".to_string(),
+    };
+    assert_eq!(annotation.to_workflow_command(), "::notice::message");
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn annotation_files() {
+    let cwd = Path::new("/project");
+    let file =
+      |specifier: &str| annotation_file(&Url::parse(specifier).unwrap(), cwd);
+    assert_eq!(
+      file("file:///project/src/mod.ts"),
+      Some("src/mod.ts".to_string())
+    );
+    assert_eq!(
+      file("file:///other/mod.ts"),
+      Some("/other/mod.ts".to_string())
+    );
+    assert_eq!(file("https://deno.land/x/mod.ts"), None);
+  }
+}
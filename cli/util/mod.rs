@@ -9,9 +9,12 @@ pub mod env;
 pub mod extract;
 pub mod file_watcher;
 pub mod fs;
+pub mod git;
+pub mod github_actions;
 pub mod path;
 pub mod progress_bar;
 pub mod retry;
+pub mod sarif;
 pub mod sync;
 pub mod temp;
 pub mod text_encoding;
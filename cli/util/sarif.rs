@@ -0,0 +1,433 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! A subset of the SARIF 2.1.0 format, used to report diagnostics to code
+//! scanning tools.
+//!
+//! See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+
+use deno_core::url::Url;
+use deno_lib::version::DENO_VERSION_INFO;
+use serde::Serialize;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+/// Base id of the URIs that are relative to the working directory.
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Level {
+  Error,
+  Warning,
+  Note,
+}
+
+/// The unit of the columns in regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnKind {
+  Utf16CodeUnits,
+  UnicodeCodePoints,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Message {
+  pub text: String,
+}
+
+impl Message {
+  pub fn new(text: impl Into<String>) -> Self {
+    Self { text: text.into() }
+  }
+}
+
+/// Metadata of a rule, a "reportingDescriptor" in SARIF terms.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+  pub id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help_uri: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub properties: Option<RuleProperties>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleProperties {
+  pub tags: Vec<String>,
+}
+
+/// A 1-based region of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+  pub start_line: usize,
+  pub start_column: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end_line: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end_column: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+  pub uri: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub uri_base_id: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+  pub artifact_location: ArtifactLocation,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub region: Option<Region>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+  pub physical_location: PhysicalLocation,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<Message>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fix {
+  pub description: Message,
+  pub artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactChange {
+  pub artifact_location: ArtifactLocation,
+  pub replacements: Vec<Replacement>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Replacement {
+  pub deleted_region: Region,
+  pub inserted_content: ArtifactContent,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtifactContent {
+  pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+  pub rule_id: String,
+  /// Set when printing the run.
+  pub rule_index: usize,
+  pub level: Level,
+  pub message: Message,
+  pub locations: Vec<Location>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub related_locations: Vec<Location>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub fixes: Vec<Fix>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Notification {
+  level: Level,
+  message: Message,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  locations: Vec<Location>,
+}
+
+/// The results of running a tool, which are printed as a SARIF log.
+///
+/// Results and rules are sorted when printing, so the output doesn't depend
+/// on the order in which files were checked.
+pub struct SarifRun {
+  tool_name: &'static str,
+  information_uri: &'static str,
+  column_kind: ColumnKind,
+  cwd_url: Option<Url>,
+  rules: BTreeMap<String, Rule>,
+  results: Vec<SarifResult>,
+  notifications: Vec<Notification>,
+}
+
+impl SarifRun {
+  pub fn new(
+    tool_name: &'static str,
+    information_uri: &'static str,
+    column_kind: ColumnKind,
+    cwd: &Path,
+  ) -> Self {
+    Self {
+      tool_name,
+      information_uri,
+      column_kind,
+      cwd_url: Url::from_directory_path(cwd).ok(),
+      rules: BTreeMap::new(),
+      results: Vec::new(),
+      notifications: Vec::new(),
+    }
+  }
+
+  /// Returns the location of a module, relative to the working directory
+  /// when it's inside of it.
+  pub fn artifact_location(&self, specifier: &Url) -> ArtifactLocation {
+    if specifier.scheme() == "file"
+      && let Some(cwd_url) = &self.cwd_url
+      && let Some(relative) = cwd_url.make_relative(specifier)
+      && !relative.starts_with("../")
+    {
+      return ArtifactLocation {
+        uri: relative,
+        uri_base_id: Some(SRCROOT),
+      };
+    }
+    ArtifactLocation {
+      uri: specifier.to_string(),
+      uri_base_id: None,
+    }
+  }
+
+  /// Adds the result, along with its rule unless it was added before.
+  pub fn add_result(
+    &mut self,
+    result: SarifResult,
+    create_rule: impl FnOnce() -> Rule,
+  ) {
+    if !self.rules.contains_key(&result.rule_id) {
+      self.rules.insert(result.rule_id.clone(), create_rule());
+    }
+    self.results.push(result);
+  }
+
+  /// Adds an error that prevented the tool from checking a file.
+  pub fn add_error(&mut self, message: String, location: Option<Location>) {
+    self.notifications.push(Notification {
+      level: Level::Error,
+      message: Message::new(message),
+      locations: location.into_iter().collect(),
+    });
+  }
+
+  /// Returns the SARIF log to print.
+  pub fn to_log(&self) -> SarifLog<'_> {
+    let original_uri_base_ids = match &self.cwd_url {
+      Some(cwd_url) => BTreeMap::from([(
+        SRCROOT,
+        ArtifactLocation {
+          uri: cwd_url.to_string(),
+          uri_base_id: None,
+        },
+      )]),
+      None => BTreeMap::new(),
+    };
+    let rule_indexes = self
+      .rules
+      .keys()
+      .enumerate()
+      .map(|(index, id)| (id.as_str(), index))
+      .collect::<HashMap<_, _>>();
+    let mut results = self.results.clone();
+    for result in &mut results {
+      result.rule_index = rule_indexes[result.rule_id.as_str()];
+    }
+    results.sort_by_cached_key(|result| {
+      let location = result
+        .locations
+        .first()
+        .map(|location| &location.physical_location);
+      let region = location.and_then(|location| location.region.as_ref());
+      (
+        location.map(|location| location.artifact_location.uri.clone()),
+        region.map(|region| (region.start_line, region.start_column)),
+        result.rule_id.clone(),
+        result.message.text.clone(),
+      )
+    });
+    let mut notifications = self.notifications.clone();
+    notifications.sort_by(|a, b| a.message.text.cmp(&b.message.text));
+    SarifLog {
+      schema: SARIF_SCHEMA,
+      version: SARIF_VERSION,
+      runs: [RunLog {
+        tool: Tool {
+          driver: Driver {
+            name: self.tool_name,
+            version: DENO_VERSION_INFO.deno,
+            information_uri: self.information_uri,
+            rules: self.rules.values().collect(),
+          },
+        },
+        original_uri_base_ids,
+        column_kind: self.column_kind,
+        results,
+        invocations: [Invocation {
+          execution_successful: notifications.is_empty(),
+          tool_execution_notifications: notifications,
+        }],
+      }],
+    }
+  }
+}
+
+#[derive(Serialize)]
+pub struct SarifLog<'a> {
+  #[serde(rename = "$schema")]
+  schema: &'static str,
+  version: &'static str,
+  runs: [RunLog<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RunLog<'a> {
+  tool: Tool<'a>,
+  original_uri_base_ids: BTreeMap<&'static str, ArtifactLocation>,
+  column_kind: ColumnKind,
+  results: Vec<SarifResult>,
+  invocations: [Invocation; 1],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+  driver: Driver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver<'a> {
+  name: &'a str,
+  version: &'a str,
+  information_uri: &'a str,
+  rules: Vec<&'a Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Invocation {
+  execution_successful: bool,
+  tool_execution_notifications: Vec<Notification>,
+}
+
+#[cfg(test)]
+mod test {
+  use deno_core::serde_json;
+
+  use super::*;
+
+  #[cfg(not(windows))]
+  #[test]
+  fn relative_artifact_locations() {
+    let run = SarifRun::new(
+      "deno lint",
+      "https://docs.deno.com/go/lint",
+      ColumnKind::UnicodeCodePoints,
+      Path::new("/project"),
+    );
+    let location =
+      |specifier: &str| run.artifact_location(&Url::parse(specifier).unwrap());
+    assert_eq!(
+      location("file:///project/src/my%20mod.ts"),
+      ArtifactLocation {
+        uri: "src/my%20mod.ts".to_string(),
+        uri_base_id: Some(SRCROOT),
+      }
+    );
+    assert_eq!(
+      location("file:///other/mod.ts"),
+      ArtifactLocation {
+        uri: "file:///other/mod.ts".to_string(),
+        uri_base_id: None,
+      }
+    );
+    assert_eq!(
+      location("https://deno.land/x/mod.ts"),
+      ArtifactLocation {
+        uri: "https://deno.land/x/mod.ts".to_string(),
+        uri_base_id: None,
+      }
+    );
+  }
+
+  #[test]
+  fn sorts_results_and_rules() {
+    let mut run = SarifRun::new(
+      "deno check",
+      "https://docs.deno.com/go/check",
+      ColumnKind::Utf16CodeUnits,
+      Path::new("/project"),
+    );
+    let result = |rule_id: &str, uri: &str, start_line: usize| SarifResult {
+      rule_id: rule_id.to_string(),
+      rule_index: 0,
+      level: Level::Error,
+      message: Message::new("message"),
+      locations: vec![Location {
+        physical_location: PhysicalLocation {
+          artifact_location: ArtifactLocation {
+            uri: uri.to_string(),
+            uri_base_id: Some(SRCROOT),
+          },
+          region: Some(Region {
+            start_line,
+            start_column: 1,
+            end_line: None,
+            end_column: None,
+          }),
+        },
+        message: None,
+      }],
+      related_locations: vec![],
+      fixes: vec![],
+    };
+    let rule = |id: &str| Rule {
+      id: id.to_string(),
+      help_uri: None,
+      properties: None,
+    };
+    run.add_result(result("TS2345", "b.ts", 1), || rule("TS2345"));
+    run.add_result(result("TS2322", "a.ts", 10), || rule("TS2322"));
+    run.add_result(result("TS2345", "a.ts", 2), || unreachable!());
+
+    let json = serde_json::to_value(run.to_log()).unwrap();
+    let run = &json["runs"][0];
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["id"], "TS2322");
+    assert_eq!(rules[1]["id"], "TS2345");
+    let results = run["results"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|result| {
+        let location = &result["locations"][0]["physicalLocation"];
+        (
+          result["ruleIndex"].as_u64().unwrap(),
+          location["artifactLocation"]["uri"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+          location["region"]["startLine"].as_u64().unwrap(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      results,
+      vec![
+        (1, "a.ts".to_string(), 2),
+        (0, "a.ts".to_string(), 10),
+        (1, "b.ts".to_string(), 1),
+      ]
+    );
+  }
+}
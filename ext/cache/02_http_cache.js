@@ -0,0 +1,134 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+// Private HTTP cache for `fetch()` (RFC 9111). The caching rules live in
+// `http_cache.rs`; this module only moves requests and response bodies
+// between `fetch()` and the Cache API storage.
+
+import { primordials } from "ext:core/mod.js";
+import {
+  op_cache_http_invalidate,
+  op_cache_http_match,
+  op_cache_http_put,
+  op_cache_http_storable,
+  op_cache_http_update_headers,
+  op_cache_storage_open,
+} from "ext:core/ops";
+const {
+  PromisePrototypeCatch,
+  SafeMap,
+  StringPrototypeIndexOf,
+  StringPrototypeSlice,
+} = primordials;
+
+import {
+  getReadableStreamResourceBacking,
+  resourceForReadableStream,
+} from "ext:deno_web/06_streams.js";
+
+/** @type {Map<string, Promise<number>>} */
+const cacheIds = new SafeMap();
+
+/**
+ * @param {string} cacheName
+ * @returns {Promise<number>}
+ */
+function openCache(cacheName) {
+  let cacheId = cacheIds.get(cacheName);
+  if (cacheId === undefined) {
+    cacheId = op_cache_storage_open(cacheName);
+    cacheIds.set(cacheName, cacheId);
+    PromisePrototypeCatch(cacheId, () => cacheIds.delete(cacheName));
+  }
+  return cacheId;
+}
+
+/**
+ * @param {string} url
+ * @returns {string}
+ */
+function stripFragment(url) {
+  const index = StringPrototypeIndexOf(url, "#");
+  return index === -1 ? url : StringPrototypeSlice(url, 0, index);
+}
+
+/**
+ * Looks up a stored response that is fresh, or that can be revalidated with
+ * the returned conditional headers.
+ * @param {string} cacheName
+ * @param {InnerRequest} req
+ */
+async function match(cacheName, req) {
+  return await op_cache_http_match({
+    cacheId: await openCache(cacheName),
+    requestUrl: stripFragment(req.currentUrl()),
+    requestHeaders: req.headerList,
+  });
+}
+
+/**
+ * @param {InnerRequest} req
+ * @param {number} status
+ * @param {[string, string][]} headerList
+ * @returns {boolean}
+ */
+function isStorable(req, status, headerList) {
+  return op_cache_http_storable({
+    method: req.method,
+    requestHeaders: req.headerList,
+    responseStatus: status,
+    responseHeaders: headerList,
+  });
+}
+
+/**
+ * Stores a response. The body stream is consumed by the cache.
+ * @param {string} cacheName
+ * @param {InnerRequest} req
+ * @param {number} status
+ * @param {string} statusText
+ * @param {[string, string][]} headerList
+ * @param {ReadableStream<Uint8Array> | null} stream
+ */
+async function put(cacheName, req, status, statusText, headerList, stream) {
+  let rid = null;
+  if (stream !== null) {
+    rid = getReadableStreamResourceBacking(stream)?.rid ??
+      resourceForReadableStream(stream);
+  }
+  await op_cache_http_put({
+    cacheId: await openCache(cacheName),
+    requestUrl: stripFragment(req.currentUrl()),
+    requestHeaders: req.headerList,
+    responseHeaders: headerList,
+    responseStatus: status,
+    responseStatusText: statusText,
+    responseRid: rid,
+  });
+}
+
+/**
+ * Merges the headers of a `304 Not Modified` response into the stored ones.
+ * @param {[string, string][]} storedHeaders
+ * @param {[string, string][]} notModifiedHeaders
+ * @returns {[string, string][]}
+ */
+function updateHeaders(storedHeaders, notModifiedHeaders) {
+  return op_cache_http_update_headers(storedHeaders, notModifiedHeaders);
+}
+
+/**
+ * Drops the stored response for a URL after it was changed by an unsafe
+ * request.
+ * @param {string} cacheName
+ * @param {string} url
+ */
+async function invalidate(cacheName, url) {
+  await op_cache_http_invalidate({
+    cacheId: await openCache(cacheName),
+    requestUrl: stripFragment(url),
+  });
+}
+
+const httpCache = { invalidate, isStorable, match, put, updateHeaders };
+
+export { httpCache };
@@ -0,0 +1,743 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! A private HTTP cache for `fetch()` following RFC 9111, stored in the same
+//! backends as the Cache API.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use deno_core::ByteString;
+use deno_core::OpState;
+use deno_core::ResourceId;
+use deno_core::op2;
+use deno_core::serde::Deserialize;
+use deno_core::serde::Serialize;
+
+use crate::Cache;
+use crate::CacheDeleteRequest;
+use crate::CacheError;
+use crate::CacheMatchRequest;
+use crate::CachePutRequest;
+use crate::get_cache;
+use crate::get_header;
+
+/// Records when a response was stored, in seconds since the Unix epoch. It is
+/// needed to compute the age of a stored response and never leaves the cache.
+const STORED_AT_HEADER: &str = "x-deno-cache-stored-at";
+
+/// Status codes that are cacheable by default, i.e. that may be given a
+/// heuristic freshness lifetime (RFC 9110, section 15.1).
+const HEURISTICALLY_CACHEABLE_STATUSES: &[u16] =
+  &[200, 203, 204, 300, 301, 308, 404, 405, 410, 414, 501];
+
+/// Fields of a `304 Not Modified` response that must not replace the ones of
+/// the stored response (RFC 9111, section 3.2).
+const EXCLUDED_UPDATE_HEADERS: &[&str] = &[
+  "content-length",
+  "content-encoding",
+  "transfer-encoding",
+  "connection",
+  "keep-alive",
+  STORED_AT_HEADER,
+];
+
+type Headers = [(ByteString, ByteString)];
+
+/// The `Cache-Control` directives relevant to a private cache.
+#[derive(Debug, Default, PartialEq)]
+struct CacheControl {
+  no_store: bool,
+  no_cache: bool,
+  must_revalidate: bool,
+  public: bool,
+  max_age: Option<u64>,
+  /// Only used to tell whether a response to a request with credentials may
+  /// be stored, as a private cache doesn't apply `s-maxage` otherwise.
+  s_maxage: Option<u64>,
+  /// `Some(None)` when `max-stale` is given without a value, meaning any
+  /// staleness is acceptable.
+  max_stale: Option<Option<u64>>,
+  min_fresh: Option<u64>,
+}
+
+impl CacheControl {
+  fn parse(headers: &Headers) -> Self {
+    let mut cache_control = Self::default();
+    let mut has_cache_control = false;
+    for (name, value) in headers {
+      if !name.eq_ignore_ascii_case(b"cache-control") {
+        continue;
+      }
+      has_cache_control = true;
+      let Ok(value) = std::str::from_utf8(value) else {
+        continue;
+      };
+      for directive in value.split(',') {
+        let (name, argument) = match directive.split_once('=') {
+          Some((name, argument)) => {
+            (name.trim(), Some(argument.trim().trim_matches('"')))
+          }
+          None => (directive.trim(), None),
+        };
+        let seconds = argument.and_then(|argument| argument.parse().ok());
+        match name.to_ascii_lowercase().as_str() {
+          "no-store" => cache_control.no_store = true,
+          "no-cache" => cache_control.no_cache = true,
+          "must-revalidate" | "proxy-revalidate" => {
+            cache_control.must_revalidate = true
+          }
+          "public" => cache_control.public = true,
+          // Invalid values make the response stale (RFC 9111, section
+          // 4.2.1).
+          "max-age" => cache_control.max_age = Some(seconds.unwrap_or(0)),
+          "s-maxage" => cache_control.s_maxage = Some(seconds.unwrap_or(0)),
+          "max-stale" => cache_control.max_stale = Some(seconds),
+          "min-fresh" => cache_control.min_fresh = seconds,
+          _ => {}
+        }
+      }
+    }
+    // `Pragma: no-cache` is only honored without `Cache-Control` (RFC 9111,
+    // section 5.4).
+    if !has_cache_control
+      && get_header("pragma", headers)
+        .is_some_and(|value| value.eq_ignore_ascii_case(b"no-cache"))
+    {
+      cache_control.no_cache = true;
+    }
+    cache_control
+  }
+}
+
+fn header_str<'a>(name: &str, headers: &'a Headers) -> Option<&'a str> {
+  headers
+    .iter()
+    .find(|(k, _)| k.eq_ignore_ascii_case(name.as_bytes()))
+    .and_then(|(_, v)| std::str::from_utf8(v).ok())
+}
+
+fn header_date(name: &str, headers: &Headers) -> Option<u64> {
+  let date = chrono::DateTime::parse_from_rfc2822(header_str(name, headers)?)
+    .ok()?
+    .timestamp();
+  u64::try_from(date).ok()
+}
+
+fn header_seconds(name: &str, headers: &Headers) -> Option<u64> {
+  header_str(name, headers)?.trim().parse().ok()
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs())
+    .unwrap_or_default()
+}
+
+/// Conditional and range requests are passed through to the origin, as their
+/// responses depend on what the client already has.
+fn is_conditional_or_range(request_headers: &Headers) -> bool {
+  ["range", "if-none-match", "if-modified-since"]
+    .iter()
+    .any(|name| get_header(name, request_headers).is_some())
+}
+
+/// Whether a response to a request may be stored (RFC 9111, section 3).
+pub fn is_storable(
+  method: &str,
+  status: u16,
+  request_headers: &Headers,
+  response_headers: &Headers,
+) -> bool {
+  if method != "GET" || !(200..600).contains(&status) || status == 206 {
+    return false;
+  }
+  if is_conditional_or_range(request_headers) {
+    return false;
+  }
+  if get_header("content-encoding", response_headers).is_some() {
+    return false;
+  }
+  if get_header("vary", response_headers).is_some_and(|vary| {
+    vary.split(|b| *b == b',').any(|v| v.trim_ascii() == b"*")
+  }) {
+    return false;
+  }
+  let request_cache_control = CacheControl::parse(request_headers);
+  let response_cache_control = CacheControl::parse(response_headers);
+  if request_cache_control.no_store || response_cache_control.no_store {
+    return false;
+  }
+  // The cache is shared by every caller of `fetch()` in the process, so a
+  // response to a request with credentials must not be served to others
+  // unless it explicitly allows that (RFC 9111, section 3.5).
+  if get_header("authorization", request_headers).is_some()
+    && !response_cache_control.public
+    && !response_cache_control.must_revalidate
+    && response_cache_control.s_maxage.is_none()
+  {
+    return false;
+  }
+  // Without explicit freshness information, a response is only worth
+  // storing if it can be revalidated later.
+  response_cache_control.public
+    || response_cache_control.max_age.is_some()
+    || get_header("expires", response_headers).is_some()
+    || (HEURISTICALLY_CACHEABLE_STATUSES.contains(&status)
+      && (get_header("etag", response_headers).is_some()
+        || get_header("last-modified", response_headers).is_some()))
+}
+
+/// How long a response stays fresh after it was generated, in seconds (RFC
+/// 9111, section 4.2.1).
+fn freshness_lifetime(
+  status: u16,
+  response_headers: &Headers,
+  stored_at: u64,
+) -> u64 {
+  let cache_control = CacheControl::parse(response_headers);
+  if let Some(max_age) = cache_control.max_age {
+    return max_age;
+  }
+  let date = header_date("date", response_headers).unwrap_or(stored_at);
+  if get_header("expires", response_headers).is_some() {
+    // An invalid `Expires` means the response is already expired.
+    return header_date("expires", response_headers)
+      .map(|expires| expires.saturating_sub(date))
+      .unwrap_or(0);
+  }
+  // Heuristic freshness: 10% of the time since the last modification (RFC
+  // 9111, section 4.2.2).
+  if HEURISTICALLY_CACHEABLE_STATUSES.contains(&status)
+    && let Some(last_modified) = header_date("last-modified", response_headers)
+  {
+    return date.saturating_sub(last_modified) / 10;
+  }
+  0
+}
+
+/// The age of a stored response, in seconds (RFC 9111, section 4.2.3).
+fn current_age(response_headers: &Headers, stored_at: u64, now: u64) -> u64 {
+  let apparent_age = header_date("date", response_headers)
+    .map(|date| stored_at.saturating_sub(date))
+    .unwrap_or(0);
+  let age = header_seconds("age", response_headers).unwrap_or(0);
+  apparent_age.max(age) + now.saturating_sub(stored_at)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HttpCacheDecision {
+  /// The stored response can be used without contacting the origin.
+  Fresh,
+  /// The stored response must be validated with these conditional request
+  /// headers first.
+  Revalidate(Vec<(ByteString, ByteString)>),
+  /// The stored response can't be used.
+  Miss,
+}
+
+/// Decides whether a stored response can satisfy a request (RFC 9111,
+/// section 4).
+pub fn evaluate(
+  request_headers: &Headers,
+  status: u16,
+  response_headers: &Headers,
+  now: u64,
+) -> HttpCacheDecision {
+  let Some(stored_at) = header_seconds(STORED_AT_HEADER, response_headers)
+  else {
+    return HttpCacheDecision::Miss;
+  };
+  let request_cache_control = CacheControl::parse(request_headers);
+  let response_cache_control = CacheControl::parse(response_headers);
+  if request_cache_control.no_store || is_conditional_or_range(request_headers)
+  {
+    return HttpCacheDecision::Miss;
+  }
+
+  if !request_cache_control.no_cache && !response_cache_control.no_cache {
+    let mut lifetime = freshness_lifetime(status, response_headers, stored_at);
+    if let Some(max_age) = request_cache_control.max_age {
+      lifetime = lifetime.min(max_age);
+    }
+    let age = current_age(response_headers, stored_at, now)
+      + request_cache_control.min_fresh.unwrap_or(0);
+    if age < lifetime {
+      return HttpCacheDecision::Fresh;
+    }
+    if !response_cache_control.must_revalidate {
+      match request_cache_control.max_stale {
+        Some(None) => return HttpCacheDecision::Fresh,
+        Some(Some(max_stale)) if age < lifetime + max_stale => {
+          return HttpCacheDecision::Fresh;
+        }
+        _ => {}
+      }
+    }
+  }
+
+  let mut conditional_headers = Vec::new();
+  if let Some(etag) = get_header("etag", response_headers) {
+    conditional_headers.push((ByteString::from("if-none-match"), etag));
+  }
+  if let Some(last_modified) = get_header("last-modified", response_headers) {
+    conditional_headers
+      .push((ByteString::from("if-modified-since"), last_modified));
+  }
+  if conditional_headers.is_empty() {
+    HttpCacheDecision::Miss
+  } else {
+    HttpCacheDecision::Revalidate(conditional_headers)
+  }
+}
+
+/// Updates the headers of a stored response with those of a `304 Not
+/// Modified` response that validated it (RFC 9111, section 4.3.4).
+pub fn update_headers(
+  stored_headers: &Headers,
+  not_modified_headers: &Headers,
+) -> Vec<(ByteString, ByteString)> {
+  let is_update = |name: &ByteString| {
+    not_modified_headers
+      .iter()
+      .any(|(k, _)| k.eq_ignore_ascii_case(name))
+      && !EXCLUDED_UPDATE_HEADERS
+        .iter()
+        .any(|excluded| name.eq_ignore_ascii_case(excluded.as_bytes()))
+  };
+  let mut headers = stored_headers
+    .iter()
+    .filter(|(name, _)| !is_update(name))
+    .cloned()
+    .collect::<Vec<_>>();
+  headers.extend(
+    not_modified_headers
+      .iter()
+      .filter(|(name, _)| is_update(name))
+      .cloned(),
+  );
+  headers
+}
+
+fn without_stored_at(
+  headers: Vec<(ByteString, ByteString)>,
+) -> Vec<(ByteString, ByteString)> {
+  headers
+    .into_iter()
+    .filter(|(name, _)| !name.eq_ignore_ascii_case(STORED_AT_HEADER.as_bytes()))
+    .collect()
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpCacheMatchRequest {
+  pub cache_id: i64,
+  pub request_url: String,
+  pub request_headers: Vec<(ByteString, ByteString)>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpCacheMatch {
+  pub fresh: bool,
+  pub conditional_headers: Vec<(ByteString, ByteString)>,
+  pub response_status: u16,
+  pub response_status_text: String,
+  pub response_headers: Vec<(ByteString, ByteString)>,
+  pub response_rid: Option<ResourceId>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpCacheStorableRequest {
+  pub method: String,
+  pub request_headers: Vec<(ByteString, ByteString)>,
+  pub response_status: u16,
+  pub response_headers: Vec<(ByteString, ByteString)>,
+}
+
+/// Looks up a stored response that can be used for a request, either
+/// directly or after revalidation.
+#[op2]
+#[serde]
+pub async fn op_cache_http_match(
+  state: Rc<RefCell<OpState>>,
+  #[serde] request: HttpCacheMatchRequest,
+) -> Result<Option<HttpCacheMatch>, CacheError> {
+  let cache = get_cache(&state)?;
+  let request_headers = request.request_headers.clone();
+  let Some((meta, resource)) = cache
+    .r#match(CacheMatchRequest {
+      cache_id: request.cache_id,
+      request_url: request.request_url,
+      request_headers: request.request_headers,
+    })
+    .await?
+  else {
+    return Ok(None);
+  };
+  let (fresh, conditional_headers) = match evaluate(
+    &request_headers,
+    meta.response_status,
+    &meta.response_headers,
+    now(),
+  ) {
+    HttpCacheDecision::Fresh => (true, vec![]),
+    HttpCacheDecision::Revalidate(headers) => (false, headers),
+    HttpCacheDecision::Miss => return Ok(None),
+  };
+  let response_rid =
+    resource.map(|resource| state.borrow_mut().resource_table.add(resource));
+  Ok(Some(HttpCacheMatch {
+    fresh,
+    conditional_headers,
+    response_status: meta.response_status,
+    response_status_text: meta.response_status_text,
+    response_headers: without_stored_at(meta.response_headers),
+    response_rid,
+  }))
+}
+
+#[op2]
+pub fn op_cache_http_storable(
+  #[serde] request: HttpCacheStorableRequest,
+) -> bool {
+  is_storable(
+    &request.method,
+    request.response_status,
+    &request.request_headers,
+    &request.response_headers,
+  )
+}
+
+/// Stores a response, recording the time it was received.
+#[op2]
+pub async fn op_cache_http_put(
+  state: Rc<RefCell<OpState>>,
+  #[serde] mut request_response: CachePutRequest,
+) -> Result<(), CacheError> {
+  request_response.response_headers =
+    without_stored_at(request_response.response_headers);
+  request_response.response_headers.push((
+    ByteString::from(STORED_AT_HEADER),
+    ByteString::from(now().to_string()),
+  ));
+  let cache = get_cache(&state)?;
+  let resource = match request_response.response_rid {
+    Some(rid) => Some(
+      state
+        .borrow_mut()
+        .resource_table
+        .take_any(rid)
+        .map_err(CacheError::Resource)?,
+    ),
+    None => None,
+  };
+  cache.put(request_response, resource).await
+}
+
+#[op2]
+#[serde]
+pub fn op_cache_http_update_headers(
+  #[serde] stored_headers: Vec<(ByteString, ByteString)>,
+  #[serde] not_modified_headers: Vec<(ByteString, ByteString)>,
+) -> Vec<(ByteString, ByteString)> {
+  update_headers(&stored_headers, &not_modified_headers)
+}
+
+/// Invalidates the stored response for a URL after an unsafe request to it
+/// succeeded (RFC 9111, section 4.4).
+#[op2]
+pub async fn op_cache_http_invalidate(
+  state: Rc<RefCell<OpState>>,
+  #[serde] request: CacheDeleteRequest,
+) -> Result<bool, CacheError> {
+  let cache = get_cache(&state)?;
+  cache.delete(request).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn headers(headers: &[(&str, &str)]) -> Vec<(ByteString, ByteString)> {
+    headers
+      .iter()
+      .map(|(name, value)| (ByteString::from(*name), ByteString::from(*value)))
+      .collect()
+  }
+
+  fn stored(
+    response_headers: &[(&str, &str)],
+    stored_at: u64,
+  ) -> Vec<(ByteString, ByteString)> {
+    let mut headers = headers(response_headers);
+    headers.push((
+      ByteString::from(STORED_AT_HEADER),
+      ByteString::from(stored_at.to_string()),
+    ));
+    headers
+  }
+
+  #[test]
+  fn test_cache_control_parse() {
+    let cache_control = CacheControl::parse(&headers(&[(
+      "Cache-Control",
+      "no-cache, max-age=\"60\", max-stale, MIN-FRESH=5",
+    )]));
+    assert_eq!(
+      cache_control,
+      CacheControl {
+        no_cache: true,
+        max_age: Some(60),
+        max_stale: Some(None),
+        min_fresh: Some(5),
+        ..Default::default()
+      }
+    );
+    assert!(CacheControl::parse(&headers(&[("pragma", "no-cache")])).no_cache);
+    assert!(
+      !CacheControl::parse(&headers(&[
+        ("pragma", "no-cache"),
+        ("cache-control", "max-age=10")
+      ]))
+      .no_cache
+    );
+    assert_eq!(
+      CacheControl::parse(&headers(&[("cache-control", "max-age=abc")]))
+        .max_age,
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn test_is_storable() {
+    let max_age = headers(&[("cache-control", "max-age=60")]);
+    let etag = headers(&[("etag", "\"v1\"")]);
+    assert!(is_storable("GET", 200, &[], &max_age));
+    assert!(!is_storable("GET", 200, &[], &[]));
+    assert!(is_storable("GET", 200, &[], &etag));
+    assert!(is_storable("GET", 404, &[], &etag));
+    assert!(!is_storable("GET", 500, &[], &etag));
+    assert!(is_storable("GET", 500, &[], &max_age));
+    assert!(!is_storable("POST", 200, &[], &max_age));
+    assert!(!is_storable("GET", 206, &[], &max_age));
+    assert!(!is_storable(
+      "GET",
+      200,
+      &[],
+      &headers(&[("cache-control", "max-age=60, no-store")])
+    ));
+    assert!(!is_storable(
+      "GET",
+      200,
+      &headers(&[("cache-control", "no-store")]),
+      &max_age
+    ));
+    assert!(!is_storable(
+      "GET",
+      200,
+      &headers(&[("range", "bytes=0-10")]),
+      &max_age
+    ));
+    assert!(!is_storable(
+      "GET",
+      200,
+      &[],
+      &headers(&[("cache-control", "max-age=60"), ("vary", "accept, *")])
+    ));
+    let authorization = headers(&[("authorization", "Bearer secret")]);
+    assert!(!is_storable("GET", 200, &authorization, &max_age));
+    assert!(!is_storable("GET", 200, &authorization, &etag));
+    for cache_control in [
+      "public, max-age=60",
+      "max-age=60, must-revalidate",
+      "max-age=60, s-maxage=60",
+    ] {
+      assert!(is_storable(
+        "GET",
+        200,
+        &authorization,
+        &headers(&[("cache-control", cache_control)])
+      ));
+    }
+  }
+
+  #[test]
+  fn test_freshness_lifetime() {
+    let now = 1_700_000_000;
+    assert_eq!(
+      freshness_lifetime(
+        200,
+        &headers(&[("cache-control", "max-age=60"), ("expires", "invalid")]),
+        now
+      ),
+      60
+    );
+    assert_eq!(
+      freshness_lifetime(
+        200,
+        &headers(&[
+          ("date", "Tue, 14 Nov 2023 22:13:20 GMT"),
+          ("expires", "Tue, 14 Nov 2023 22:15:20 GMT"),
+        ]),
+        now
+      ),
+      120
+    );
+    assert_eq!(
+      freshness_lifetime(200, &headers(&[("expires", "0")]), now),
+      0
+    );
+    assert_eq!(
+      freshness_lifetime(
+        200,
+        &headers(&[
+          ("date", "Tue, 14 Nov 2023 22:13:20 GMT"),
+          ("last-modified", "Tue, 14 Nov 2023 22:03:20 GMT"),
+        ]),
+        now
+      ),
+      60
+    );
+    assert_eq!(freshness_lifetime(200, &[], now), 0);
+  }
+
+  #[test]
+  fn test_evaluate() {
+    let now = 1_700_000_000;
+    let response = stored(&[("cache-control", "max-age=60")], now - 10);
+    assert_eq!(evaluate(&[], 200, &response, now), HttpCacheDecision::Fresh);
+    assert_eq!(
+      evaluate(
+        &headers(&[("cache-control", "max-age=5")]),
+        200,
+        &response,
+        now
+      ),
+      HttpCacheDecision::Miss
+    );
+    assert_eq!(
+      evaluate(
+        &headers(&[("cache-control", "no-store")]),
+        200,
+        &response,
+        now
+      ),
+      HttpCacheDecision::Miss
+    );
+    assert_eq!(
+      evaluate(&headers(&[("range", "bytes=0-1")]), 200, &response, now),
+      HttpCacheDecision::Miss
+    );
+    // Entries not stored by the HTTP cache are never used.
+    assert_eq!(
+      evaluate(&[], 200, &headers(&[("cache-control", "max-age=60")]), now),
+      HttpCacheDecision::Miss
+    );
+
+    let response =
+      stored(&[("cache-control", "max-age=60"), ("age", "55")], now - 10);
+    assert_eq!(evaluate(&[], 200, &response, now), HttpCacheDecision::Miss);
+
+    let response = stored(
+      &[
+        ("cache-control", "max-age=60"),
+        ("etag", "\"v1\""),
+        ("last-modified", "Tue, 14 Nov 2023 22:03:20 GMT"),
+      ],
+      now - 120,
+    );
+    assert_eq!(
+      evaluate(&[], 200, &response, now),
+      HttpCacheDecision::Revalidate(headers(&[
+        ("if-none-match", "\"v1\""),
+        ("if-modified-since", "Tue, 14 Nov 2023 22:03:20 GMT"),
+      ]))
+    );
+    assert_eq!(
+      evaluate(
+        &headers(&[("cache-control", "max-stale")]),
+        200,
+        &response,
+        now
+      ),
+      HttpCacheDecision::Fresh
+    );
+    assert_eq!(
+      evaluate(
+        &headers(&[("cache-control", "max-stale=30")]),
+        200,
+        &response,
+        now
+      ),
+      HttpCacheDecision::Revalidate(headers(&[
+        ("if-none-match", "\"v1\""),
+        ("if-modified-since", "Tue, 14 Nov 2023 22:03:20 GMT"),
+      ]))
+    );
+
+    let response = stored(
+      &[
+        ("cache-control", "no-cache, max-age=60"),
+        ("etag", "\"v1\""),
+      ],
+      now,
+    );
+    assert_eq!(
+      evaluate(&[], 200, &response, now),
+      HttpCacheDecision::Revalidate(headers(&[("if-none-match", "\"v1\"")]))
+    );
+
+    let response = stored(
+      &[
+        ("cache-control", "max-age=60, must-revalidate"),
+        ("etag", "\"v1\""),
+      ],
+      now - 120,
+    );
+    assert_eq!(
+      evaluate(
+        &headers(&[("cache-control", "max-stale")]),
+        200,
+        &response,
+        now
+      ),
+      HttpCacheDecision::Revalidate(headers(&[("if-none-match", "\"v1\"")]))
+    );
+  }
+
+  #[test]
+  fn test_update_headers() {
+    let stored = stored(
+      &[
+        ("content-type", "text/plain"),
+        ("content-length", "5"),
+        ("etag", "\"v1\""),
+        ("cache-control", "max-age=60"),
+      ],
+      1,
+    );
+    let updated = update_headers(
+      &stored,
+      &headers(&[
+        ("content-length", "0"),
+        ("cache-control", "max-age=120"),
+        ("x-request-id", "2"),
+      ]),
+    );
+    assert_eq!(
+      updated,
+      headers(&[
+        ("content-type", "text/plain"),
+        ("content-length", "5"),
+        ("etag", "\"v1\""),
+        (STORED_AT_HEADER, "1"),
+        ("cache-control", "max-age=120"),
+        ("x-request-id", "2"),
+      ])
+    );
+  }
+}
@@ -23,6 +23,7 @@ use futures::Stream;
 use tokio::io::AsyncRead;
 use tokio::io::AsyncReadExt;
 
+mod http_cache;
 mod lsc_shard;
 mod lscache;
 mod sqlite;
@@ -106,8 +107,13 @@ deno_core::extension!(deno_cache,
     op_cache_put,
     op_cache_match,
     op_cache_delete,
+    http_cache::op_cache_http_match,
+    http_cache::op_cache_http_storable,
+    http_cache::op_cache_http_put,
+    http_cache::op_cache_http_update_headers,
+    http_cache::op_cache_http_invalidate,
   ],
-  esm = [ "01_cache.js" ],
+  esm = [ "01_cache.js", "02_http_cache.js" ],
   options = {
     maybe_create_cache: Option<CreateCache>,
   },
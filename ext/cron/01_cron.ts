@@ -1,7 +1,12 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
 import { core, internals, primordials } from "ext:core/mod.js";
-import { op_cron_create, op_cron_next } from "ext:core/ops";
+import {
+  op_cron_create,
+  op_cron_list,
+  op_cron_next,
+  op_cron_trigger,
+} from "ext:core/ops";
 const {
   ArrayPrototypeJoin,
   NumberPrototypeToString,
@@ -219,6 +224,17 @@ function cron(
   })();
 }
 
+function list(): Deno.CronStatus[] {
+  return op_cron_list();
+}
+
+function trigger(name: string) {
+  op_cron_trigger(name);
+}
+
+cron.list = list;
+cron.trigger = trigger;
+
 // For testing
 internals.formatToCronSchedule = formatToCronSchedule;
 internals.parseScheduleToString = parseScheduleToString;
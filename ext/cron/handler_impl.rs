@@ -7,8 +7,10 @@ use crate::CronHandle;
 use crate::CronHandler;
 use crate::CronNextResult;
 use crate::CronSpec;
+use crate::CronStatus;
 use crate::local::CronExecutionHandle;
 use crate::local::LocalCronHandler;
+use crate::local::LocalCronHandlerOptions;
 use crate::socket::SocketCronHandle;
 use crate::socket::SocketCronHandler;
 
@@ -21,7 +23,9 @@ impl CronHandlerImpl {
   pub fn create_from_env() -> Self {
     match std::env::var("DENO_UNSTABLE_CRON_SOCK") {
       Ok(socket_addr) => Self::Socket(SocketCronHandler::new(socket_addr)),
-      Err(_) => Self::Local(LocalCronHandler::new()),
+      Err(_) => Self::Local(LocalCronHandler::with_options(
+        LocalCronHandlerOptions::from_env(),
+      )),
     }
   }
 
@@ -69,6 +73,20 @@ impl CronHandler for CronHandlerImpl {
       Self::Socket(h) => h.create(spec).map(CronHandleImpl::Socket),
     }
   }
+
+  fn list(&self) -> Vec<CronStatus> {
+    match self {
+      Self::Local(h) => h.list(),
+      Self::Socket(h) => h.list(),
+    }
+  }
+
+  fn trigger(&self, name: &str) -> Result<(), CronError> {
+    match self {
+      Self::Local(h) => h.trigger(name),
+      Self::Socket(h) => h.trigger(name),
+    }
+  }
 }
 
 pub enum CronHandleImpl {
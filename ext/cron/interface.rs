@@ -12,10 +12,37 @@ pub struct CronNextResult {
   pub traceparent: Traceparent,
 }
 
+/// Point-in-time view of a registered cron, as reported by
+/// [`CronHandler::list`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CronStatus {
+  pub name: String,
+  pub schedule: String,
+  pub backoff_schedule: Option<Vec<u32>>,
+  /// Unix timestamp in milliseconds of the next invocation, if it is known
+  /// to this process.
+  pub next_deadline: Option<u64>,
+  /// Number of retries performed for the current failed invocation.
+  pub current_execution_retries: u32,
+  /// Whether the handler is currently being executed.
+  pub running: bool,
+  /// Unix timestamp in milliseconds of the start of the last invocation.
+  pub last_run: Option<u64>,
+  pub last_success: Option<bool>,
+}
+
 pub trait CronHandler {
   type EH: CronHandle + 'static;
 
   fn create(&self, spec: CronSpec) -> Result<Self::EH, CronError>;
+
+  /// Returns the status of all crons registered through this handler, sorted
+  /// by name.
+  fn list(&self) -> Vec<CronStatus>;
+
+  /// Invokes the named cron as soon as possible, outside of its schedule.
+  fn trigger(&self, name: &str) -> Result<(), CronError>;
 }
 
 #[async_trait(?Send)]
@@ -4,6 +4,7 @@ mod handler_impl;
 mod interface;
 pub mod local;
 mod socket;
+pub mod store;
 
 use std::borrow::Cow;
 use std::cell::RefCell;
@@ -31,6 +32,8 @@ deno_core::extension!(deno_cron,
   ops = [
     op_cron_create<C>,
     op_cron_next<C>,
+    op_cron_list<C>,
+    op_cron_trigger<C>,
   ],
   esm = [ "01_cron.ts" ],
   options = {
@@ -80,6 +83,21 @@ pub enum CronError {
   #[class(type)]
   #[error("Invalid backoff schedule")]
   InvalidBackoff,
+  #[class("NotFound")]
+  #[error("Cron not found: {0}")]
+  NotFound(String),
+  #[class("Busy")]
+  #[error("Cron is already running: {0}")]
+  AlreadyRunning(String),
+  #[class("Busy")]
+  #[error("Cron invocation is already pending: {0}")]
+  InvocationPending(String),
+  #[class(type)]
+  #[error("Invalid cron time zone: {0}")]
+  InvalidTimezone(String),
+  #[class(generic)]
+  #[error("Cron state store error: {0}")]
+  Store(#[from] rusqlite::Error),
   #[class(generic)]
   #[error(transparent)]
   AcquireError(#[from] tokio::sync::AcquireError),
@@ -161,6 +179,32 @@ where
   cron_handler.next(prev_success).await
 }
 
+#[op2]
+#[serde]
+fn op_cron_list<C>(state: &mut OpState) -> Vec<CronStatus>
+where
+  C: CronHandler + 'static,
+{
+  state
+    .borrow::<Arc<FeatureChecker>>()
+    .check_or_exit(UNSTABLE_FEATURE_NAME, "Deno.cron.list");
+  state.borrow::<Rc<C>>().list()
+}
+
+#[op2(fast)]
+fn op_cron_trigger<C>(
+  state: &mut OpState,
+  #[string] name: &str,
+) -> Result<(), CronError>
+where
+  C: CronHandler + 'static,
+{
+  state
+    .borrow::<Arc<FeatureChecker>>()
+    .check_or_exit(UNSTABLE_FEATURE_NAME, "Deno.cron.trigger");
+  state.borrow::<Rc<C>>().trigger(name)
+}
+
 fn validate_cron_name(name: &str) -> Result<(), CronError> {
   if name.len() > 64 {
     return Err(CronError::NameExceeded(name.len()));
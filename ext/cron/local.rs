@@ -5,15 +5,22 @@ use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::env;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::rc::Weak;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono::Utc;
+use chrono_tz::Tz;
 use deno_core::futures;
 use deno_core::futures::FutureExt;
 use deno_core::unsync::JoinHandle;
 use deno_core::unsync::spawn;
+use tokio::sync::Notify;
 use tokio::sync::OwnedSemaphorePermit;
 use tokio::sync::Semaphore;
 use tokio::sync::mpsc;
@@ -24,29 +31,70 @@ use crate::CronHandle;
 use crate::CronHandler;
 use crate::CronNextResult;
 use crate::CronSpec;
+use crate::CronStatus;
+use crate::store::CronStateStore;
 
 const MAX_CRONS: usize = 100;
 const DISPATCH_CONCURRENCY_LIMIT: usize = 50;
 const MAX_BACKOFF_MS: u32 = 60 * 60 * 1_000; // 1 hour
 const MAX_BACKOFF_COUNT: usize = 5;
 const DEFAULT_BACKOFF_SCHEDULE: [u32; 5] = [100, 1_000, 5_000, 30_000, 60_000];
+// Upper bound on the number of missed occurrences counted after a restart.
+const MAX_MISSED_RUNS_COUNT: u64 = 10_000;
+
+#[derive(Debug, Clone, Default)]
+pub struct LocalCronHandlerOptions {
+  /// IANA time zone name (e.g. `Europe/Berlin`) that cron schedules are
+  /// evaluated in. Defaults to UTC.
+  pub timezone: Option<String>,
+  /// Path of a SQLite database used to persist last-run and missed-run
+  /// bookkeeping across restarts. State is kept in memory only if unset.
+  pub state_path: Option<PathBuf>,
+  /// When set, a cron whose persisted deadline passed while the process was
+  /// not running is invoked once right after it is registered, instead of
+  /// waiting for its next scheduled occurrence.
+  pub catch_up_missed: bool,
+}
+
+impl LocalCronHandlerOptions {
+  pub fn from_env() -> Self {
+    Self {
+      timezone: env::var("DENO_CRON_TIMEZONE").ok(),
+      state_path: env::var_os("DENO_CRON_STATE_PATH").map(PathBuf::from),
+      catch_up_missed: env::var("DENO_CRON_CATCH_UP_MISSED")
+        .is_ok_and(|v| v == "1" || v == "true"),
+    }
+  }
+}
 
 pub struct LocalCronHandler {
   cron_schedule_tx: OnceCell<mpsc::Sender<(String, bool)>>,
   concurrency_limiter: Arc<Semaphore>,
   cron_loop_join_handle: OnceCell<JoinHandle<()>>,
   runtime_state: Rc<RefCell<RuntimeState>>,
+  reschedule_notify: Rc<Notify>,
+  options: LocalCronHandlerOptions,
 }
 
 struct RuntimeState {
   crons: HashMap<String, Cron>,
   scheduled_deadlines: BTreeMap<u64, Vec<String>>,
+  store: Option<CronStateStore>,
 }
 
 struct Cron {
   spec: CronSpec,
+  timezone: Tz,
   next_tx: mpsc::WeakSender<()>,
   current_execution_retries: u32,
+  /// Deadline to use for the next scheduling instead of the computed one,
+  /// either restored from persisted state or requested by a manual trigger.
+  override_deadline: Option<u64>,
+  next_deadline: Option<u64>,
+  /// Time of the in-flight invocation, if any.
+  dispatched_at: Option<u64>,
+  last_run: Option<u64>,
+  last_success: Option<bool>,
 }
 
 impl Cron {
@@ -67,6 +115,10 @@ impl Default for LocalCronHandler {
 
 impl LocalCronHandler {
   pub fn new() -> Self {
+    Self::with_options(LocalCronHandlerOptions::default())
+  }
+
+  pub fn with_options(options: LocalCronHandlerOptions) -> Self {
     Self {
       cron_schedule_tx: OnceCell::new(),
       concurrency_limiter: Arc::new(Semaphore::new(DISPATCH_CONCURRENCY_LIMIT)),
@@ -74,12 +126,20 @@ impl LocalCronHandler {
       runtime_state: Rc::new(RefCell::new(RuntimeState {
         crons: HashMap::new(),
         scheduled_deadlines: BTreeMap::new(),
+        store: None,
       })),
+      reschedule_notify: Rc::new(Notify::new()),
+      options,
     }
   }
 
+  pub fn options(&self) -> &LocalCronHandlerOptions {
+    &self.options
+  }
+
   async fn cron_loop(
     runtime_state: Rc<RefCell<RuntimeState>>,
+    reschedule_notify: Rc<Notify>,
     mut cron_schedule_rx: mpsc::Receiver<(String, bool)>,
   ) -> Result<(), CronError> {
     loop {
@@ -103,6 +163,7 @@ impl LocalCronHandler {
 
       let cron_to_schedule = tokio::select! {
         _ = sleep_fut => None,
+        _ = reschedule_notify.notified() => None,
         x = cron_schedule_rx.recv() => {
           if x.is_none() {
             return Ok(());
@@ -114,21 +175,44 @@ impl LocalCronHandler {
       // Schedule next execution of the cron if needed.
       if let Some((name, prev_success)) = cron_to_schedule {
         let mut runtime_state = runtime_state.borrow_mut();
+        let runtime_state = &mut *runtime_state;
         if let Some(cron) = runtime_state.crons.get_mut(&name) {
+          let dispatched_at = cron.dispatched_at.take();
+          if dispatched_at.is_some() {
+            cron.last_run = dispatched_at;
+            cron.last_success = Some(prev_success);
+          }
           let backoff_schedule = cron.backoff_schedule();
-          let next_deadline = if !prev_success
-            && cron.current_execution_retries < backoff_schedule.len() as u32
-          {
-            let backoff_ms =
-              backoff_schedule[cron.current_execution_retries as usize];
-            let now = chrono::Utc::now().timestamp_millis() as u64;
-            cron.current_execution_retries += 1;
-            now + backoff_ms as u64
-          } else {
-            let next_ts = compute_next_deadline(&cron.spec.cron_schedule)?;
-            cron.current_execution_retries = 0;
-            next_ts
-          };
+          let next_deadline =
+            if let Some(deadline) = cron.override_deadline.take() {
+              deadline
+            } else if !prev_success
+              && cron.current_execution_retries < backoff_schedule.len() as u32
+            {
+              let backoff_ms =
+                backoff_schedule[cron.current_execution_retries as usize];
+              let now = chrono::Utc::now().timestamp_millis() as u64;
+              cron.current_execution_retries += 1;
+              now + backoff_ms as u64
+            } else {
+              let next_ts =
+                compute_next_deadline(&cron.spec.cron_schedule, cron.timezone)?;
+              cron.current_execution_retries = 0;
+              next_ts
+            };
+          // Persisting bookkeeping is best effort: a failing write must not
+          // stop crons from being dispatched.
+          if let Some(store) = &runtime_state.store {
+            if let Some(dispatched_at) = dispatched_at {
+              let _ = store.record_run(&name, dispatched_at, prev_success);
+            }
+            let _ = store.set_next_deadline(
+              &name,
+              next_deadline,
+              cron.current_execution_retries,
+            );
+          }
+          cron.next_deadline = Some(next_deadline);
           runtime_state
             .scheduled_deadlines
             .entry(next_deadline)
@@ -175,10 +259,11 @@ impl RuntimeState {
             .collect::<Vec<_>>()
         })
         .filter_map(|(_, name)| {
-          self
-            .crons
-            .get(&name)
-            .map(|c| (name.clone(), c.next_tx.clone()))
+          self.crons.get_mut(&name).map(|c| {
+            c.next_deadline = None;
+            c.dispatched_at = Some(now);
+            (name.clone(), c.next_tx.clone())
+          })
         })
         .collect::<Vec<_>>()
     };
@@ -198,15 +283,28 @@ impl CronHandler for LocalCronHandler {
         mpsc::channel::<(String, bool)>(1);
       self.cron_schedule_tx.set(cron_schedule_tx).unwrap();
       let runtime_state = self.runtime_state.clone();
+      let reschedule_notify = self.reschedule_notify.clone();
       spawn(async move {
-        LocalCronHandler::cron_loop(runtime_state, cron_schedule_rx)
-          .await
-          .unwrap();
+        LocalCronHandler::cron_loop(
+          runtime_state,
+          reschedule_notify,
+          cron_schedule_rx,
+        )
+        .await
+        .unwrap();
       })
     });
 
+    let timezone = parse_timezone(self.options.timezone.as_deref())?;
+
     let mut runtime_state = self.runtime_state.borrow_mut();
 
+    if runtime_state.store.is_none()
+      && let Some(state_path) = &self.options.state_path
+    {
+      runtime_state.store = Some(CronStateStore::open(state_path)?);
+    }
+
     if runtime_state.crons.len() > MAX_CRONS {
       return Err(CronError::TooManyCrons);
     }
@@ -225,11 +323,47 @@ impl CronHandler for LocalCronHandler {
       validate_backoff_schedule(backoff_schedule)?;
     }
 
+    let (override_deadline, current_execution_retries, last_run, last_success) =
+      match &runtime_state.store {
+        Some(store) => {
+          let deadline = restore_deadline(
+            store,
+            &spec,
+            timezone,
+            self.options.catch_up_missed,
+          )?;
+          let persisted = store.get(&spec.name)?;
+          // keep retrying with the backoff schedule when the retry scheduled
+          // by a previous process is still pending
+          let current_execution_retries = match (deadline, &persisted) {
+            (Some(deadline), Some(persisted))
+              if persisted.next_deadline_ms == Some(deadline) =>
+            {
+              persisted.current_execution_retries
+            }
+            _ => 0,
+          };
+          (
+            deadline,
+            current_execution_retries,
+            persisted.as_ref().and_then(|p| p.last_run_ms),
+            persisted.and_then(|p| p.last_success),
+          )
+        }
+        None => (None, 0, None, None),
+      };
+
     let (next_tx, next_rx) = mpsc::channel::<()>(1);
     let cron = Cron {
       spec: spec.clone(),
+      timezone,
       next_tx: next_tx.downgrade(),
-      current_execution_retries: 0,
+      current_execution_retries,
+      override_deadline,
+      next_deadline: None,
+      dispatched_at: None,
+      last_run,
+      last_success,
     };
     runtime_state.crons.insert(spec.name.clone(), cron);
 
@@ -245,6 +379,63 @@ impl CronHandler for LocalCronHandler {
       }),
     })
   }
+
+  fn list(&self) -> Vec<CronStatus> {
+    let runtime_state = self.runtime_state.borrow();
+    let mut crons = runtime_state
+      .crons
+      .values()
+      .map(|cron| CronStatus {
+        name: cron.spec.name.clone(),
+        schedule: cron.spec.cron_schedule.clone(),
+        backoff_schedule: cron.spec.backoff_schedule.clone(),
+        next_deadline: cron.next_deadline,
+        current_execution_retries: cron.current_execution_retries,
+        running: cron.dispatched_at.is_some(),
+        last_run: cron.last_run,
+        last_success: cron.last_success,
+      })
+      .collect::<Vec<_>>();
+    crons.sort_by(|a, b| a.name.cmp(&b.name));
+    crons
+  }
+
+  /// Moves the next invocation of the named cron to now. After the triggered
+  /// invocation completes the cron resumes its regular schedule.
+  fn trigger(&self, name: &str) -> Result<(), CronError> {
+    let mut runtime_state = self.runtime_state.borrow_mut();
+    let runtime_state = &mut *runtime_state;
+    let Some(cron) = runtime_state.crons.get_mut(name) else {
+      return Err(CronError::NotFound(name.to_string()));
+    };
+    if cron.dispatched_at.is_some() {
+      return Err(CronError::AlreadyRunning(name.to_string()));
+    }
+
+    let now = chrono::Utc::now().timestamp_millis() as u64;
+    match cron.next_deadline.take() {
+      Some(deadline) => {
+        if let Some(names) =
+          runtime_state.scheduled_deadlines.get_mut(&deadline)
+        {
+          names.retain(|n| n != name);
+          if names.is_empty() {
+            runtime_state.scheduled_deadlines.remove(&deadline);
+          }
+        }
+        cron.next_deadline = Some(now);
+        runtime_state
+          .scheduled_deadlines
+          .entry(now)
+          .or_default()
+          .push(name.to_string());
+        self.reschedule_notify.notify_one();
+      }
+      // Not scheduled yet, the first call to `next` picks this up.
+      None => cron.override_deadline = Some(now),
+    }
+    Ok(())
+  }
 }
 
 impl Drop for LocalCronHandler {
@@ -323,7 +514,63 @@ impl CronHandle for CronExecutionHandle {
   }
 }
 
-fn compute_next_deadline(cron_expression: &str) -> Result<u64, CronError> {
+fn parse_timezone(timezone: Option<&str>) -> Result<Tz, CronError> {
+  match timezone {
+    Some(name) => name
+      .parse::<Tz>()
+      .map_err(|_| CronError::InvalidTimezone(name.to_string())),
+    None => Ok(Tz::UTC),
+  }
+}
+
+/// Registers the cron in the state store and returns the deadline it should
+/// first be scheduled at, if one was persisted by a previous process.
+///
+/// Occurrences that passed while no process was running are either skipped
+/// and added to the persisted missed-run counter, or, with `catch_up_missed`,
+/// collapsed into a single immediate invocation.
+fn restore_deadline(
+  store: &CronStateStore,
+  spec: &CronSpec,
+  timezone: Tz,
+  catch_up_missed: bool,
+) -> Result<Option<u64>, CronError> {
+  store.register(&spec.name, &spec.cron_schedule, timezone.name())?;
+  let Some(next_deadline_ms) = store
+    .get(&spec.name)?
+    .and_then(|state| state.next_deadline_ms)
+  else {
+    return Ok(None);
+  };
+
+  let now = Utc::now();
+  let now_ms = now.timestamp_millis() as u64;
+  if next_deadline_ms > now_ms {
+    return Ok(Some(next_deadline_ms));
+  }
+  if catch_up_missed {
+    return Ok(Some(now_ms));
+  }
+
+  let mut missed = 0;
+  let mut deadline_ms = next_deadline_ms;
+  while deadline_ms <= now_ms && missed < MAX_MISSED_RUNS_COUNT {
+    missed += 1;
+    let Some(after) = DateTime::from_timestamp_millis(deadline_ms as i64)
+    else {
+      break;
+    };
+    deadline_ms = next_deadline_after(&spec.cron_schedule, timezone, after)?;
+  }
+  store.add_missed_runs(&spec.name, missed)?;
+
+  Ok(None)
+}
+
+fn compute_next_deadline(
+  cron_expression: &str,
+  timezone: Tz,
+) -> Result<u64, CronError> {
   let now = chrono::Utc::now();
 
   if let Ok(test_schedule) = env::var("DENO_CRON_TEST_SCHEDULE_OFFSET")
@@ -332,13 +579,84 @@ fn compute_next_deadline(cron_expression: &str) -> Result<u64, CronError> {
     return Ok(now.timestamp_millis() as u64 + offset);
   }
 
+  next_deadline_after(cron_expression, timezone, now)
+}
+
+/// Computes the first instant strictly after `after` at which the wall clock
+/// in `timezone` matches `cron_expression`.
+///
+/// DST transitions are resolved like Temporal's "compatible" disambiguation
+/// for skipped times: wall-clock times skipped by a forward transition fire
+/// shifted forward by the transition's length. Wall-clock times repeated by
+/// a backward transition fire at both of their instants.
+fn next_deadline_after(
+  cron_expression: &str,
+  timezone: Tz,
+  after: DateTime<Utc>,
+) -> Result<u64, CronError> {
   let cron = cron_expression
     .parse::<saffron::Cron>()
     .map_err(|_| CronError::InvalidCron)?;
-  let Some(next_deadline) = cron.next_after(now) else {
-    return Err(CronError::InvalidCron);
-  };
-  Ok(next_deadline.timestamp_millis() as u64)
+
+  // saffron matches fields against the `DateTime<Utc>` it is given, so it is
+  // fed local wall-clock time disguised as UTC.
+  let mut local = after.with_timezone(&timezone).naive_local();
+  // If `after` is the earlier instant of a repeated wall-clock time, the
+  // wall-clock times before it repeat as well, so the search starts early
+  // enough to find their later instants.
+  if let chrono::LocalResult::Ambiguous(earliest, latest) =
+    timezone.from_local_datetime(&local)
+    && earliest.with_timezone(&Utc) == after
+  {
+    local -= latest.signed_duration_since(earliest);
+  }
+  let mut local = local.and_utc();
+
+  let mut next: Option<DateTime<Utc>> = None;
+  // Bounded so that a schedule that only matches non-existent local times
+  // cannot loop forever.
+  for _ in 0..1_000 {
+    let Some(next_local) = cron.next_after(local) else {
+      return Err(CronError::InvalidCron);
+    };
+    let naive = next_local.naive_utc();
+    let result = timezone.from_local_datetime(&naive);
+    let candidates = match result {
+      chrono::LocalResult::Single(dt) => [Some(dt.with_timezone(&Utc)), None],
+      chrono::LocalResult::Ambiguous(earliest, latest) => [
+        Some(earliest.with_timezone(&Utc)),
+        Some(latest.with_timezone(&Utc)),
+      ],
+      chrono::LocalResult::None => {
+        // Interpret the skipped time using the offset in effect before the
+        // transition, which moves it past the gap.
+        let before = naive - chrono::Duration::days(1);
+        let shifted =
+          timezone.from_local_datetime(&before).earliest().map(|dt| {
+            let offset = dt.offset().fix();
+            (naive - offset).and_utc()
+          });
+        [shifted, None]
+      }
+    };
+    for candidate in candidates.into_iter().flatten() {
+      if candidate > after {
+        next = Some(next.map_or(candidate, |next| next.min(candidate)));
+      }
+    }
+    // The later instants of repeated wall-clock times come after the
+    // earlier instants of the following ones, so all of the repeated times
+    // are considered before settling on the first instant.
+    if let Some(next) = next
+      && !matches!(result, chrono::LocalResult::Ambiguous(..))
+    {
+      return Ok(next.timestamp_millis() as u64);
+    }
+    local = next_local;
+  }
+  next
+    .map(|next| next.timestamp_millis() as u64)
+    .ok_or(CronError::InvalidCron)
 }
 
 fn validate_backoff_schedule(
@@ -360,10 +678,106 @@ mod tests {
   #[test]
   fn test_compute_next_deadline() {
     let now = chrono::Utc::now().timestamp_millis() as u64;
-    assert!(compute_next_deadline("*/1 * * * *").unwrap() > now);
-    assert!(compute_next_deadline("* * * * *").unwrap() > now);
-    assert!(compute_next_deadline("bogus").is_err());
-    assert!(compute_next_deadline("* * * * * *").is_err());
-    assert!(compute_next_deadline("* * *").is_err());
+    assert!(compute_next_deadline("*/1 * * * *", Tz::UTC).unwrap() > now);
+    assert!(compute_next_deadline("* * * * *", Tz::UTC).unwrap() > now);
+    assert!(compute_next_deadline("bogus", Tz::UTC).is_err());
+    assert!(compute_next_deadline("* * * * * *", Tz::UTC).is_err());
+    assert!(compute_next_deadline("* * *", Tz::UTC).is_err());
+  }
+
+  fn utc(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+  }
+
+  fn next(expr: &str, tz: Tz, after: &str) -> DateTime<Utc> {
+    let ms = next_deadline_after(expr, tz, utc(after)).unwrap();
+    DateTime::from_timestamp_millis(ms as i64).unwrap()
+  }
+
+  #[test]
+  fn test_next_deadline_in_timezone() {
+    let tz: Tz = "America/New_York".parse().unwrap();
+    // 09:00 EST is 14:00 UTC.
+    assert_eq!(
+      next("0 9 * * *", tz, "2024-01-15T12:00:00Z"),
+      utc("2024-01-15T14:00:00Z")
+    );
+    // 09:00 EDT is 13:00 UTC.
+    assert_eq!(
+      next("0 9 * * *", tz, "2024-07-15T12:00:00Z"),
+      utc("2024-07-15T13:00:00Z")
+    );
+  }
+
+  #[test]
+  fn test_next_deadline_dst_transitions() {
+    let tz: Tz = "America/New_York".parse().unwrap();
+    // 02:30 does not exist on 2024-03-10; it fires at 03:30 EDT instead.
+    assert_eq!(
+      next("30 2 * * *", tz, "2024-03-10T05:00:00Z"),
+      utc("2024-03-10T07:30:00Z")
+    );
+    // 01:30 happens twice on 2024-11-03, and fires at both instants.
+    assert_eq!(
+      next("30 1 * * *", tz, "2024-11-03T04:00:00Z"),
+      utc("2024-11-03T05:30:00Z")
+    );
+    assert_eq!(
+      next("30 1 * * *", tz, "2024-11-03T05:30:00Z"),
+      utc("2024-11-03T06:30:00Z")
+    );
+    assert_eq!(
+      next("30 1 * * *", tz, "2024-11-03T06:30:00Z"),
+      utc("2024-11-04T06:30:00Z")
+    );
+    // Every minute of the repeated hour from 01:00 to 02:00 fires twice.
+    assert_eq!(
+      next("* * * * *", tz, "2024-11-03T05:30:00Z"),
+      utc("2024-11-03T05:31:00Z")
+    );
+    assert_eq!(
+      next("* * * * *", tz, "2024-11-03T05:59:00Z"),
+      utc("2024-11-03T06:00:00Z")
+    );
+    assert_eq!(
+      next("* * * * *", tz, "2024-11-03T06:59:00Z"),
+      utc("2024-11-03T07:00:00Z")
+    );
+  }
+
+  #[test]
+  fn test_restore_deadline() {
+    let store = CronStateStore::open_in_memory().unwrap();
+    let spec = CronSpec {
+      name: "a".to_string(),
+      cron_schedule: "* * * * *".to_string(),
+      backoff_schedule: None,
+    };
+    assert_eq!(
+      restore_deadline(&store, &spec, Tz::UTC, true).unwrap(),
+      None
+    );
+
+    let now = chrono::Utc::now().timestamp_millis() as u64;
+    store.set_next_deadline("a", now + 60_000, 0).unwrap();
+    assert_eq!(
+      restore_deadline(&store, &spec, Tz::UTC, false).unwrap(),
+      Some(now + 60_000)
+    );
+
+    // At least three minutes worth of missed occurrences.
+    store.set_next_deadline("a", now - 3 * 60_000, 0).unwrap();
+    assert_eq!(
+      restore_deadline(&store, &spec, Tz::UTC, false).unwrap(),
+      None
+    );
+    let missed_runs = store.get("a").unwrap().unwrap().missed_runs;
+    assert!(missed_runs >= 3);
+    // Caught up occurrences aren't counted as missed.
+    let restored = restore_deadline(&store, &spec, Tz::UTC, true)
+      .unwrap()
+      .unwrap();
+    assert!(restored >= now);
+    assert_eq!(store.get("a").unwrap().unwrap().missed_runs, missed_runs);
   }
 }
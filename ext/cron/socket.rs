@@ -1,5 +1,7 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -19,6 +21,7 @@ use crate::CronHandle;
 use crate::CronHandler;
 use crate::CronNextResult;
 use crate::CronSpec;
+use crate::CronStatus;
 use crate::Traceparent;
 
 pub struct SocketCronHandler {
@@ -27,13 +30,32 @@ pub struct SocketCronHandler {
   socket_task_handle: deno_core::unsync::JoinHandle<()>,
   socket_task_exit_error: Rc<OnceCell<CronError>>,
   reject_reason: Rc<OnceCell<String>>,
+  crons: Rc<RefCell<BTreeMap<String, SocketCronEntry>>>,
+}
+
+/// Locally observed state of a cron. Scheduling is owned by the remote end of
+/// the socket, so the next deadline is not known here.
+struct SocketCronEntry {
+  spec: CronSpec,
+  invocation_tx: mpsc::Sender<Invocation>,
+  current_execution_retries: u32,
+  running: bool,
+  last_run: Option<u64>,
+  last_success: Option<bool>,
+}
+
+pub(crate) struct Invocation {
+  traceparent: Traceparent,
+  /// Invocations requested through `CronHandler::trigger` are not reported
+  /// back over the socket.
+  manual: bool,
 }
 
 // Commands sent to the socket task
 pub(crate) enum SocketTaskCommand {
   RegisterCron {
     spec: CronSpec,
-    invocation_tx: mpsc::Sender<Traceparent>,
+    invocation_tx: mpsc::Sender<Invocation>,
   },
   SendResult {
     name: String,
@@ -89,6 +111,7 @@ impl SocketCronHandler {
       socket_task_handle,
       socket_task_exit_error,
       reject_reason,
+      crons: Rc::new(RefCell::new(BTreeMap::new())),
     }
   }
 
@@ -103,7 +126,7 @@ impl SocketCronHandler {
     exit_error: Rc<OnceCell<CronError>>,
     reject_reason: Rc<OnceCell<String>>,
   ) {
-    let mut invocation_txs: HashMap<String, mpsc::Sender<Traceparent>> =
+    let mut invocation_txs: HashMap<String, mpsc::Sender<Invocation>> =
       HashMap::new();
     let stream = match connect_to_socket(&socket_addr).await {
       Ok(s) => s,
@@ -188,24 +211,35 @@ impl Drop for SocketCronHandler {
 
 pub struct SocketCronHandle {
   spec: CronSpec,
-  invocation_rx: std::cell::RefCell<Option<mpsc::Receiver<Traceparent>>>,
+  invocation_rx: std::cell::RefCell<Option<mpsc::Receiver<Invocation>>>,
   socket_task_tx: mpsc::Sender<SocketTaskCommand>,
+  crons: Rc<RefCell<BTreeMap<String, SocketCronEntry>>>,
   closed: std::cell::Cell<bool>,
   first_call: std::cell::Cell<bool>,
+  manual_invocation: std::cell::Cell<bool>,
 }
 
 impl SocketCronHandle {
-  pub(crate) fn new(
+  fn new(
     spec: CronSpec,
-    invocation_rx: mpsc::Receiver<Traceparent>,
+    invocation_rx: mpsc::Receiver<Invocation>,
     socket_task_tx: mpsc::Sender<SocketTaskCommand>,
+    crons: Rc<RefCell<BTreeMap<String, SocketCronEntry>>>,
   ) -> Self {
     Self {
       spec,
       invocation_rx: std::cell::RefCell::new(Some(invocation_rx)),
       socket_task_tx,
+      crons,
       closed: std::cell::Cell::new(false),
       first_call: std::cell::Cell::new(true),
+      manual_invocation: std::cell::Cell::new(false),
+    }
+  }
+
+  fn update_entry(&self, f: impl FnOnce(&mut SocketCronEntry)) {
+    if let Some(entry) = self.crons.borrow_mut().get_mut(&self.spec.name) {
+      f(entry);
     }
   }
 }
@@ -224,13 +258,24 @@ impl CronHandle for SocketCronHandle {
     }
 
     if !self.first_call.replace(false) {
-      let _ = self
-        .socket_task_tx
-        .send(SocketTaskCommand::SendResult {
-          name: self.spec.name.clone(),
-          success: prev_success,
-        })
-        .await;
+      self.update_entry(|entry| {
+        entry.running = false;
+        entry.last_success = Some(prev_success);
+        if prev_success {
+          entry.current_execution_retries = 0;
+        } else {
+          entry.current_execution_retries += 1;
+        }
+      });
+      if !self.manual_invocation.get() {
+        let _ = self
+          .socket_task_tx
+          .send(SocketTaskCommand::SendResult {
+            name: self.spec.name.clone(),
+            success: prev_success,
+          })
+          .await;
+      }
     }
 
     let mut invocation_rx = self
@@ -238,10 +283,20 @@ impl CronHandle for SocketCronHandle {
       .take()
       .expect("calls to CronHandle::next should be serialized");
     let r = match invocation_rx.recv().await {
-      Some(traceparent) => Ok(CronNextResult {
-        active: true,
+      Some(Invocation {
         traceparent,
-      }),
+        manual,
+      }) => {
+        self.manual_invocation.set(manual);
+        self.update_entry(|entry| {
+          entry.running = true;
+          entry.last_run = Some(chrono::Utc::now().timestamp_millis() as u64);
+        });
+        Ok(CronNextResult {
+          active: true,
+          traceparent,
+        })
+      }
       None => {
         self.closed.set(true);
         Ok(CronNextResult {
@@ -256,6 +311,7 @@ impl CronHandle for SocketCronHandle {
 
   fn close(&self) {
     self.closed.set(true);
+    self.crons.borrow_mut().remove(&self.spec.name);
   }
 }
 
@@ -460,7 +516,7 @@ async fn send_reject_ack(
 
 fn handle_inbound_messages(
   line: &str,
-  invocation_txs: &HashMap<String, mpsc::Sender<Traceparent>>,
+  invocation_txs: &HashMap<String, mpsc::Sender<Invocation>>,
   reject_reason: &Rc<OnceCell<String>>,
   socket_task_tx: &mpsc::Sender<SocketTaskCommand>,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -468,8 +524,20 @@ fn handle_inbound_messages(
 
   match msg {
     InboundMessage::Invoke { name, traceparent } => {
-      if let Some(tx) = invocation_txs.get(&name) {
-        let _ = tx.try_send(traceparent);
+      if let Some(tx) = invocation_txs.get(&name)
+        && let Err(mpsc::error::TrySendError::Full(_)) =
+          tx.try_send(Invocation {
+            traceparent,
+            manual: false,
+          })
+      {
+        // Another invocation, e.g. a manually triggered one, is still
+        // pending, so the invocation is reported as failed instead of
+        // leaving the remote waiting for a result.
+        let _ = socket_task_tx.try_send(SocketTaskCommand::SendResult {
+          name,
+          success: false,
+        });
       }
     }
     InboundMessage::RejectNewCrons { reason } => {
@@ -495,14 +563,14 @@ impl CronHandler for SocketCronHandler {
       .parse::<saffron::Cron>()
       .map_err(|_| CronError::InvalidCron)?;
 
-    let (invocation_tx, invocation_rx) = mpsc::channel::<Traceparent>(1);
+    let (invocation_tx, invocation_rx) = mpsc::channel::<Invocation>(1);
     let socket_task_tx = self.socket_task_tx.clone();
     let socket_task_exit_error = self.socket_task_exit_error.clone();
 
     socket_task_tx
       .try_send(SocketTaskCommand::RegisterCron {
         spec: spec.clone(),
-        invocation_tx,
+        invocation_tx: invocation_tx.clone(),
       })
       .map_err(|_| {
         if let Some(err) = socket_task_exit_error.get() {
@@ -512,6 +580,65 @@ impl CronHandler for SocketCronHandler {
         }
       })?;
 
-    Ok(SocketCronHandle::new(spec, invocation_rx, socket_task_tx))
+    self.crons.borrow_mut().insert(
+      spec.name.clone(),
+      SocketCronEntry {
+        spec: spec.clone(),
+        invocation_tx,
+        current_execution_retries: 0,
+        running: false,
+        last_run: None,
+        last_success: None,
+      },
+    );
+
+    Ok(SocketCronHandle::new(
+      spec,
+      invocation_rx,
+      socket_task_tx,
+      self.crons.clone(),
+    ))
+  }
+
+  fn list(&self) -> Vec<CronStatus> {
+    self
+      .crons
+      .borrow()
+      .values()
+      .map(|entry| CronStatus {
+        name: entry.spec.name.clone(),
+        schedule: entry.spec.cron_schedule.clone(),
+        backoff_schedule: entry.spec.backoff_schedule.clone(),
+        next_deadline: None,
+        current_execution_retries: entry.current_execution_retries,
+        running: entry.running,
+        last_run: entry.last_run,
+        last_success: entry.last_success,
+      })
+      .collect()
+  }
+
+  fn trigger(&self, name: &str) -> Result<(), CronError> {
+    let crons = self.crons.borrow();
+    let Some(entry) = crons.get(name) else {
+      return Err(CronError::NotFound(name.to_string()));
+    };
+    if entry.running {
+      return Err(CronError::AlreadyRunning(name.to_string()));
+    }
+    entry
+      .invocation_tx
+      .try_send(Invocation {
+        traceparent: None,
+        manual: true,
+      })
+      .map_err(|err| match err {
+        mpsc::error::TrySendError::Full(_) => {
+          CronError::InvocationPending(name.to_string())
+        }
+        mpsc::error::TrySendError::Closed(_) => {
+          CronError::NotFound(name.to_string())
+        }
+      })
   }
 }
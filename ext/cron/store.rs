@@ -0,0 +1,233 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+// NOTE to all: use **cached** prepared statements when interfacing with SQLite.
+
+use std::path::Path;
+
+use rusqlite::Connection;
+use rusqlite::OpenFlags;
+use rusqlite::OptionalExtension;
+use rusqlite::params;
+
+/// Bookkeeping persisted for a single cron across process restarts.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedCronState {
+  pub name: String,
+  pub cron_schedule: String,
+  pub timezone: String,
+  pub next_deadline_ms: Option<u64>,
+  pub last_run_ms: Option<u64>,
+  pub last_success: Option<bool>,
+  pub missed_runs: u64,
+  /// Number of retries of a failed execution that were scheduled with the
+  /// backoff schedule, reset when the cron runs on its schedule again.
+  pub current_execution_retries: u32,
+}
+
+/// SQLite backed store for the local cron handler.
+pub struct CronStateStore {
+  conn: Connection,
+}
+
+impl CronStateStore {
+  pub fn open(path: &Path) -> Result<Self, rusqlite::Error> {
+    let conn = Connection::open(path)?;
+    Self::init(conn)
+  }
+
+  /// Opens an existing store for reading only, without creating or migrating
+  /// anything, so that it can be inspected while a process is using it.
+  pub fn open_read_only(path: &Path) -> Result<Self, rusqlite::Error> {
+    let conn = Connection::open_with_flags(
+      path,
+      OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    Ok(Self { conn })
+  }
+
+  pub fn open_in_memory() -> Result<Self, rusqlite::Error> {
+    Self::init(Connection::open_in_memory()?)
+  }
+
+  fn init(conn: Connection) -> Result<Self, rusqlite::Error> {
+    conn.execute_batch(
+      "
+      PRAGMA journal_mode=WAL;
+      PRAGMA synchronous=NORMAL;
+      CREATE TABLE IF NOT EXISTS cron_state (
+        name TEXT PRIMARY KEY,
+        cron_schedule TEXT NOT NULL,
+        timezone TEXT NOT NULL,
+        next_deadline_ms INTEGER,
+        last_run_ms INTEGER,
+        last_success INTEGER,
+        missed_runs INTEGER NOT NULL DEFAULT 0,
+        current_execution_retries INTEGER NOT NULL DEFAULT 0
+      );
+      ",
+    )?;
+    Ok(Self { conn })
+  }
+
+  pub fn get(
+    &self,
+    name: &str,
+  ) -> Result<Option<PersistedCronState>, rusqlite::Error> {
+    let mut stmt = self.conn.prepare_cached(
+      "SELECT name, cron_schedule, timezone, next_deadline_ms, last_run_ms, last_success, missed_runs, current_execution_retries FROM cron_state WHERE name = ?",
+    )?;
+    stmt.query_row(params![name], read_row).optional()
+  }
+
+  pub fn list(&self) -> Result<Vec<PersistedCronState>, rusqlite::Error> {
+    let mut stmt = self.conn.prepare_cached(
+      "SELECT name, cron_schedule, timezone, next_deadline_ms, last_run_ms, last_success, missed_runs, current_execution_retries FROM cron_state ORDER BY name",
+    )?;
+    stmt.query_map(params![], read_row)?.collect()
+  }
+
+  /// Registers a cron, resetting its bookkeeping if the schedule or time zone
+  /// changed since it was last persisted.
+  pub fn register(
+    &self,
+    name: &str,
+    cron_schedule: &str,
+    timezone: &str,
+  ) -> Result<(), rusqlite::Error> {
+    let mut stmt = self.conn.prepare_cached(
+      "INSERT INTO cron_state (name, cron_schedule, timezone) VALUES (?1, ?2, ?3)
+       ON CONFLICT(name) DO UPDATE SET
+         cron_schedule = excluded.cron_schedule,
+         timezone = excluded.timezone,
+         next_deadline_ms = NULL,
+         missed_runs = 0,
+         current_execution_retries = 0
+       WHERE cron_schedule != excluded.cron_schedule
+         OR timezone != excluded.timezone",
+    )?;
+    stmt.execute(params![name, cron_schedule, timezone])?;
+    Ok(())
+  }
+
+  /// Persists the next deadline along with the number of retries of a
+  /// failed execution that led to it.
+  pub fn set_next_deadline(
+    &self,
+    name: &str,
+    next_deadline_ms: u64,
+    current_execution_retries: u32,
+  ) -> Result<(), rusqlite::Error> {
+    let mut stmt = self.conn.prepare_cached(
+      "UPDATE cron_state SET next_deadline_ms = ?, current_execution_retries = ? WHERE name = ?",
+    )?;
+    stmt.execute(params![
+      next_deadline_ms as i64,
+      current_execution_retries,
+      name
+    ])?;
+    Ok(())
+  }
+
+  pub fn record_run(
+    &self,
+    name: &str,
+    run_ms: u64,
+    success: bool,
+  ) -> Result<(), rusqlite::Error> {
+    let mut stmt = self.conn.prepare_cached(
+      "UPDATE cron_state SET last_run_ms = ?, last_success = ? WHERE name = ?",
+    )?;
+    stmt.execute(params![run_ms as i64, success, name])?;
+    Ok(())
+  }
+
+  pub fn add_missed_runs(
+    &self,
+    name: &str,
+    count: u64,
+  ) -> Result<(), rusqlite::Error> {
+    let mut stmt = self.conn.prepare_cached(
+      "UPDATE cron_state SET missed_runs = missed_runs + ? WHERE name = ?",
+    )?;
+    stmt.execute(params![count as i64, name])?;
+    Ok(())
+  }
+}
+
+fn read_row(
+  row: &rusqlite::Row<'_>,
+) -> Result<PersistedCronState, rusqlite::Error> {
+  Ok(PersistedCronState {
+    name: row.get(0)?,
+    cron_schedule: row.get(1)?,
+    timezone: row.get(2)?,
+    next_deadline_ms: row.get::<_, Option<i64>>(3)?.map(|v| v as u64),
+    last_run_ms: row.get::<_, Option<i64>>(4)?.map(|v| v as u64),
+    last_success: row.get(5)?,
+    missed_runs: row.get::<_, i64>(6)? as u64,
+    current_execution_retries: row.get(7)?,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_register_resets_on_schedule_change() {
+    let store = CronStateStore::open_in_memory().unwrap();
+    store.register("a", "* * * * *", "UTC").unwrap();
+    store.set_next_deadline("a", 1_000, 2).unwrap();
+    store.add_missed_runs("a", 3).unwrap();
+    store.record_run("a", 500, true).unwrap();
+
+    // Same schedule keeps bookkeeping.
+    store.register("a", "* * * * *", "UTC").unwrap();
+    let state = store.get("a").unwrap().unwrap();
+    assert_eq!(state.next_deadline_ms, Some(1_000));
+    assert_eq!(state.missed_runs, 3);
+    assert_eq!(state.current_execution_retries, 2);
+    assert_eq!(state.last_success, Some(true));
+
+    // Changed time zone resets the deadline and missed counter.
+    store.register("a", "* * * * *", "Europe/Berlin").unwrap();
+    let state = store.get("a").unwrap().unwrap();
+    assert_eq!(state.next_deadline_ms, None);
+    assert_eq!(state.missed_runs, 0);
+    assert_eq!(state.current_execution_retries, 0);
+    assert_eq!(state.last_run_ms, Some(500));
+    assert!(store.get("b").unwrap().is_none());
+
+    store.register("b", "0 * * * *", "UTC").unwrap();
+    let names = store
+      .list()
+      .unwrap()
+      .into_iter()
+      .map(|s| s.name)
+      .collect::<Vec<_>>();
+    assert_eq!(names, vec!["a", "b"]);
+  }
+
+  #[test]
+  fn test_open_read_only() {
+    let dir = std::env::temp_dir()
+      .join(format!("deno_cron_store_read_only_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("cron.db");
+
+    let store = CronStateStore::open(&path).unwrap();
+    store.register("a", "* * * * *", "UTC").unwrap();
+    store.set_next_deadline("a", 1_000, 1).unwrap();
+
+    let read_only = CronStateStore::open_read_only(&path).unwrap();
+    let state = read_only.get("a").unwrap().unwrap();
+    assert_eq!(state.next_deadline_ms, Some(1_000));
+    assert_eq!(state.current_execution_retries, 1);
+    assert!(read_only.register("b", "* * * * *", "UTC").is_err());
+
+    drop(read_only);
+    drop(store);
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+}
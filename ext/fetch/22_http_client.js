@@ -20,10 +20,45 @@ const {
   ObjectDefineProperty,
   ObjectHasOwn,
   StringPrototypeStartsWith,
+  Symbol,
   SymbolDispose,
   TypeError,
 } = primordials;
 
+/** Name of the cache used by the `fetch()` HTTP cache unless one is given. */
+const DEFAULT_HTTP_CACHE_NAME = "deno:fetch";
+const httpCacheNameSymbol = Symbol("httpCacheName");
+
+/**
+ * Resolves the `cache` option of `Deno.createHttpClient()` to the name of the
+ * cache to use, `null` if caching is disabled for the client, or `undefined`
+ * to follow the global `--unstable-fetch-cache` setting.
+ * @param {boolean | { name?: string } | undefined} cache
+ * @returns {string | null | undefined}
+ */
+function httpCacheName(cache) {
+  if (cache === undefined) {
+    return undefined;
+  }
+  if (typeof cache === "boolean") {
+    return cache ? DEFAULT_HTTP_CACHE_NAME : null;
+  }
+  if (cache !== null && typeof cache === "object") {
+    if (cache.name === undefined) {
+      return DEFAULT_HTTP_CACHE_NAME;
+    }
+    if (typeof cache.name === "string" && cache.name !== "") {
+      return cache.name;
+    }
+    throw new TypeError(
+      `Invalid value for 'cache.name' option: ${JSONStringify(cache.name)}`,
+    );
+  }
+  throw new TypeError(
+    `Invalid value for 'cache' option: ${JSONStringify(cache)}`,
+  );
+}
+
 /**
  * @param {Deno.CreateHttpClientOptions} options
  * @returns {HttpClient}
@@ -91,17 +126,22 @@ function createHttpClient(options) {
       options.proxy.transport = "http";
     }
   }
+  const cacheName = httpCacheName(options.cache);
   const keyPair = loadTlsKeyPair("Deno.createHttpClient", options);
-  return new HttpClient(
+  const client = new HttpClient(
     op_fetch_custom_client(
       options,
       keyPair,
     ),
   );
+  client[httpCacheNameSymbol] = cacheName;
+  return client;
 }
 
 class HttpClient {
   #rid;
+  /** @type {string | null | undefined} */
+  [httpCacheNameSymbol] = undefined;
 
   /**
    * @param {number} rid
@@ -125,4 +165,10 @@ class HttpClient {
 }
 const HttpClientPrototype = HttpClient.prototype;
 
-export { createHttpClient, HttpClient, HttpClientPrototype };
+export {
+  createHttpClient,
+  DEFAULT_HTTP_CACHE_NAME,
+  HttpClient,
+  httpCacheNameSymbol,
+  HttpClientPrototype,
+};
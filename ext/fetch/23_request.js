@@ -39,7 +39,10 @@ import {
   headerListFromHeaders,
   headersFromHeaderList,
 } from "ext:deno_fetch/20_headers.js";
-import { HttpClientPrototype } from "ext:deno_fetch/22_http_client.js";
+import {
+  HttpClientPrototype,
+  httpCacheNameSymbol,
+} from "ext:deno_fetch/22_http_client.js";
 import {
   createDependentAbortSignal,
   newSignal,
@@ -85,6 +88,7 @@ function processUrlList(urlList, urlListProcessed) {
  * @property {(() => string)[]} urlList
  * @property {string[]} urlListProcessed
  * @property {number | null} clientRid NOTE: non standard extension for `Deno.HttpClient`.
+ * @property {string | null | undefined} httpCacheName NOTE: non standard extension for `Deno.HttpClient`.
  * @property {Blob | null} blobUrlEntry
  */
 
@@ -133,6 +137,7 @@ function newInnerRequest(method, url, headerList, body, maybeBlob) {
     urlList: [typeof url === "string" ? () => url : url],
     urlListProcessed: [],
     clientRid: null,
+    httpCacheName: undefined,
     blobUrlEntry,
     url() {
       if (this.urlListProcessed[0] === undefined) {
@@ -184,6 +189,7 @@ function cloneInnerRequest(request, skipBody = false) {
     urlList: [() => request.url()],
     urlListProcessed: [request.url()],
     clientRid: request.clientRid,
+    httpCacheName: request.httpCacheName,
     blobUrlEntry: request.blobUrlEntry,
     url() {
       if (this.urlListProcessed[0] === undefined) {
@@ -394,6 +400,7 @@ class Request {
         );
       }
       request.clientRid = init.client?.[internalRidSymbol] ?? null;
+      request.httpCacheName = init.client?.[httpCacheNameSymbol];
     }
 
     // 28.
@@ -13,6 +13,7 @@
 import { core, primordials } from "ext:core/mod.js";
 import {
   op_fetch,
+  op_fetch_check_net,
   op_fetch_promise_is_settled,
   op_fetch_send,
   op_wasm_streaming_feed,
@@ -48,6 +49,7 @@ import {
   resourceForReadableStream,
 } from "ext:deno_web/06_streams.js";
 import { extractBody, InnerBody } from "ext:deno_fetch/22_body.js";
+import { DEFAULT_HTTP_CACHE_NAME } from "ext:deno_fetch/22_http_client.js";
 import { processUrlList, toInnerRequest } from "ext:deno_fetch/23_request.js";
 import {
   abortedNetworkError,
@@ -85,6 +87,69 @@ const REDIRECT_SENSITIVE_HEADER_NAMES = [
   "cookie",
 ];
 
+const SAFE_METHODS = ["GET", "HEAD", "OPTIONS", "TRACE"];
+
+/**
+ * The HTTP cache from `ext:deno_cache`, if the runtime provides one.
+ * @type {typeof import("ext:deno_cache/02_http_cache.js").httpCache | null}
+ */
+let httpCache = null;
+/** Whether requests made without a client `cache` option are cached. */
+let httpCacheByDefault = false;
+
+/**
+ * @param {typeof import("ext:deno_cache/02_http_cache.js").httpCache} cache
+ * @param {boolean} enabledByDefault
+ */
+function setHttpCache(cache, enabledByDefault) {
+  httpCache = cache;
+  httpCacheByDefault = enabledByDefault;
+}
+
+/**
+ * @param {InnerRequest} req
+ * @returns {string | null}
+ */
+function httpCacheNameForRequest(req) {
+  if (httpCache === null) {
+    return null;
+  }
+  if (req.httpCacheName === undefined) {
+    return httpCacheByDefault ? DEFAULT_HTTP_CACHE_NAME : null;
+  }
+  return req.httpCacheName;
+}
+
+/**
+ * Hands a copy of the response body to the HTTP cache, if the response may
+ * be stored.
+ * @param {string} cacheName
+ * @param {InnerRequest} req
+ * @param {InnerResponse} response
+ */
+function storeInHttpCache(cacheName, req, response) {
+  if (!httpCache.isStorable(req, response.status, response.headerList)) {
+    return;
+  }
+  let stream = null;
+  if (response.body !== null) {
+    const { 0: body, 1: cacheBody } = response.body.stream.tee();
+    response.body = new InnerBody(body);
+    stream = cacheBody;
+  }
+  PromisePrototypeCatch(
+    httpCache.put(
+      cacheName,
+      req,
+      response.status,
+      response.statusMessage,
+      response.headerList,
+      stream,
+    ),
+    () => {},
+  );
+}
+
 /**
  * @param {number} rid
  * @returns {Promise<{ status: number, statusText: string, headers: [string, string][], url: string, responseRid: number, error: [string, string]? }>}
@@ -171,10 +236,34 @@ async function mainFetch(req, recursive, terminator) {
     }
   }
 
+  const cacheName = httpCacheNameForRequest(req);
+  let cached = null;
+  let headerList = req.headerList;
+  if (cacheName !== null && req.method === "GET") {
+    // Stored responses are only served to code that may fetch them.
+    op_fetch_check_net(req.currentUrl());
+    try {
+      cached = await httpCache.match(cacheName, req);
+    } catch {
+      // The cache storage is unavailable, e.g. in contexts without the Cache
+      // API, so the request goes to the network.
+      cached = null;
+    }
+    if (cached?.fresh) {
+      return cachedResponse(req, cached, recursive, terminator);
+    }
+    if (cached !== null) {
+      headerList = [
+        ...new SafeArrayIterator(req.headerList),
+        ...new SafeArrayIterator(cached.conditionalHeaders),
+      ];
+    }
+  }
+
   const { requestRid, cancelHandleRid } = op_fetch(
     req.method,
     req.currentUrl(),
-    req.headerList,
+    headerList,
     req.clientRid,
     reqBody !== null || reqRid !== null,
     reqBody,
@@ -191,6 +280,9 @@ async function mainFetch(req, recursive, terminator) {
   try {
     resp = await opFetchSend(requestRid);
   } catch (err) {
+    if (cached !== null && cached.responseRid !== null) {
+      core.tryClose(cached.responseRid);
+    }
     if (terminator.aborted) return abortedNetworkError();
     throw err;
   } finally {
@@ -198,6 +290,21 @@ async function mainFetch(req, recursive, terminator) {
       core.tryClose(cancelHandleRid);
     }
   }
+  if (cached !== null) {
+    if (resp.status === 304 && resp.error === null && !terminator.aborted) {
+      // The stored response was validated: serve it with refreshed headers
+      // and store those.
+      core.close(resp.responseRid);
+      cached.responseHeaders = httpCache.updateHeaders(
+        cached.responseHeaders,
+        resp.headers,
+      );
+      const response = cachedResponse(req, cached, recursive, terminator);
+      storeInHttpCache(cacheName, req, response);
+      return response;
+    }
+    if (cached.responseRid !== null) core.tryClose(cached.responseRid);
+  }
   // Re-throw any body errors
   if (resp.error !== null) {
     const { 0: message, 1: cause } = resp.error;
@@ -205,6 +312,17 @@ async function mainFetch(req, recursive, terminator) {
   }
   if (terminator.aborted) return abortedNetworkError();
 
+  if (
+    cacheName !== null &&
+    !ArrayPrototypeIncludes(SAFE_METHODS, req.method) &&
+    resp.status < 400
+  ) {
+    PromisePrototypeCatch(
+      httpCache.invalidate(cacheName, req.currentUrl()),
+      () => {},
+    );
+  }
+
   processUrlList(req.urlList, req.urlListProcessed);
 
   /** @type {InnerResponse} */
@@ -248,6 +366,10 @@ async function mainFetch(req, recursive, terminator) {
     }
   }
 
+  if (cacheName !== null) {
+    storeInHttpCache(cacheName, req, response);
+  }
+
   if (recursive) return response;
 
   if (response.urlList.length === 0) {
@@ -258,6 +380,42 @@ async function mainFetch(req, recursive, terminator) {
   return response;
 }
 
+/**
+ * Builds a response from an entry of the HTTP cache.
+ * @param {InnerRequest} req
+ * @param {{ responseStatus: number, responseStatusText: string, responseHeaders: [string, string][], responseRid: number | null }} cached
+ * @param {boolean} recursive
+ * @param {AbortSignal} terminator
+ * @returns {InnerResponse}
+ */
+function cachedResponse(req, cached, recursive, terminator) {
+  processUrlList(req.urlList, req.urlListProcessed);
+
+  let body = null;
+  if (cached.responseRid !== null) {
+    if (nullBodyStatus(cached.responseStatus)) {
+      core.close(cached.responseRid);
+    } else {
+      body = new InnerBody(
+        createResponseBodyStream(cached.responseRid, terminator),
+      );
+    }
+  }
+
+  return {
+    headerList: cached.responseHeaders,
+    status: cached.responseStatus,
+    statusMessage: cached.responseStatusText,
+    body,
+    type: "basic",
+    url() {
+      if (this.urlList.length == 0) return null;
+      return this.urlList[this.urlList.length - 1];
+    },
+    urlList: recursive ? [] : [...new SafeArrayIterator(req.urlListProcessed)],
+  };
+}
+
 /**
  * @param {InnerRequest} request
  * @param {InnerResponse} response
@@ -605,4 +763,4 @@ function handleWasmStreaming(source, rid) {
   }
 }
 
-export { fetch, handleWasmStreaming, mainFetch };
+export { fetch, handleWasmStreaming, mainFetch, setHttpCache };
@@ -0,0 +1,268 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Record and replay of `fetch()` traffic, so that tests talking to external
+//! HTTP APIs can run deterministically and without network access.
+//!
+//! Every request/response pair is stored as a JSON file in the fixture
+//! directory, named after a hash of the request method, URL and body.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use bytes::Bytes;
+use http::Method;
+use http::StatusCode;
+use http::header::HeaderName;
+use http::header::HeaderValue;
+use http_body_util::BodyExt;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::Client;
+use crate::FetchError;
+use crate::ReqBody;
+use crate::ResBody;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FetchFixturesMode {
+  /// Send every request to the network and (re)record its fixture.
+  Record,
+  /// Replay recorded fixtures, recording the ones that are missing.
+  Replay,
+  /// Replay recorded fixtures, failing requests that have none.
+  Strict,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchFixtures {
+  dir: Arc<PathBuf>,
+  mode: FetchFixturesMode,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Fixture {
+  request: FixtureRequest,
+  response: FixtureResponse,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct FixtureRequest {
+  method: String,
+  url: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct FixtureResponse {
+  status: u16,
+  headers: Vec<(String, String)>,
+  /// The decompressed body, base64 encoded.
+  body: String,
+}
+
+impl FetchFixtures {
+  pub fn new(dir: PathBuf, mode: FetchFixturesMode) -> Self {
+    Self {
+      dir: Arc::new(dir),
+      mode,
+    }
+  }
+
+  pub fn mode(&self) -> FetchFixturesMode {
+    self.mode
+  }
+
+  fn fixture_path(&self, method: &Method, url: &str, body: &[u8]) -> PathBuf {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update(b" ");
+    hasher.update(url);
+    hasher.update(b"\n");
+    hasher.update(body);
+    self.dir.join(format!("{:x}.json", hasher.finalize()))
+  }
+
+  /// Sends a request, or answers it from a recorded fixture depending on the
+  /// mode. Request and response bodies are buffered in both cases.
+  pub async fn send(
+    &self,
+    client: Client,
+    request: http::Request<ReqBody>,
+  ) -> Result<http::Response<ResBody>, FetchError> {
+    let (parts, body) = request.into_parts();
+    let body = body.collect().await.map_err(FetchError::Other)?.to_bytes();
+    let url = parts.uri.to_string();
+    let path = self.fixture_path(&parts.method, &url, &body);
+
+    if self.mode != FetchFixturesMode::Record {
+      match tokio::fs::read(&path).await {
+        Ok(contents) => {
+          let fixture: Fixture =
+            serde_json::from_slice(&contents).map_err(|source| {
+              FetchError::FixtureParse {
+                path: path.clone(),
+                source,
+              }
+            })?;
+          return fixture.response.into_response(&path);
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+          if self.mode == FetchFixturesMode::Strict {
+            return Err(FetchError::FixtureNotFound {
+              method: parts.method,
+              url,
+            });
+          }
+        }
+        Err(source) => return Err(FetchError::FixtureIo { path, source }),
+      }
+    }
+
+    let method = parts.method.to_string();
+    let request = http::Request::from_parts(parts, ReqBody::full(body));
+    let (parts, body) = client.send(request).await?.into_parts();
+    let body = body.collect().await.map_err(FetchError::Other)?.to_bytes();
+    let fixture = Fixture {
+      request: FixtureRequest { method, url },
+      response: FixtureResponse::new(&parts, &body),
+    };
+    let contents = serde_json::to_vec_pretty(&fixture).unwrap();
+    tokio::fs::create_dir_all(&*self.dir)
+      .await
+      .map_err(|source| FetchError::FixtureIo {
+        path: self.dir.to_path_buf(),
+        source,
+      })?;
+    tokio::fs::write(&path, contents).await.map_err(|source| {
+      FetchError::FixtureIo {
+        path: path.clone(),
+        source,
+      }
+    })?;
+    Ok(http::Response::from_parts(parts, full_body(body)))
+  }
+}
+
+impl FixtureResponse {
+  fn new(parts: &http::response::Parts, body: &[u8]) -> Self {
+    Self {
+      status: parts.status.as_u16(),
+      headers: parts
+        .headers
+        .iter()
+        .map(|(name, value)| {
+          (
+            name.to_string(),
+            String::from_utf8_lossy(value.as_bytes()).into_owned(),
+          )
+        })
+        .collect(),
+      body: BASE64_STANDARD.encode(body),
+    }
+  }
+
+  fn into_response(
+    self,
+    path: &std::path::Path,
+  ) -> Result<http::Response<ResBody>, FetchError> {
+    let invalid = |message: String| FetchError::FixtureInvalid {
+      path: path.to_path_buf(),
+      message,
+    };
+    let body = BASE64_STANDARD
+      .decode(&self.body)
+      .map_err(|err| invalid(err.to_string()))?;
+    let mut response = http::Response::new(full_body(Bytes::from(body)));
+    *response.status_mut() = StatusCode::from_u16(self.status)
+      .map_err(|err| invalid(err.to_string()))?;
+    for (name, value) in self.headers {
+      response.headers_mut().append(
+        HeaderName::from_bytes(name.as_bytes())
+          .map_err(|err| invalid(err.to_string()))?,
+        HeaderValue::from_str(&value)
+          .map_err(|err| invalid(err.to_string()))?,
+      );
+    }
+    Ok(response)
+  }
+}
+
+fn full_body(body: Bytes) -> ResBody {
+  http_body_util::Full::new(body)
+    .map_err(|never| match never {})
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fixture_path() {
+    let fixtures =
+      FetchFixtures::new(PathBuf::from("fixtures"), FetchFixturesMode::Replay);
+    let path =
+      fixtures.fixture_path(&Method::GET, "http://example.com/data.json", b"");
+    assert_eq!(
+      path,
+      PathBuf::from("fixtures").join(
+        "345e6894a36d75c9b5c4bb913bc09acd75a0409cc20c7a9554391142c7718ca7.json"
+      )
+    );
+    // The method and body are part of the key.
+    assert_ne!(
+      path,
+      fixtures.fixture_path(&Method::POST, "http://example.com/data.json", b"")
+    );
+    assert_ne!(
+      path,
+      fixtures.fixture_path(&Method::GET, "http://example.com/data.json", b"1")
+    );
+  }
+
+  #[tokio::test]
+  async fn test_fixture_response_round_trip() {
+    let response = http::Response::builder()
+      .status(201)
+      .header("content-type", "application/json")
+      .header("set-cookie", "a=1")
+      .header("set-cookie", "b=2")
+      .body(())
+      .unwrap();
+    let (parts, ()) = response.into_parts();
+    let fixture = FixtureResponse::new(&parts, b"{\"ok\":true}");
+    assert_eq!(fixture.body, "eyJvayI6dHJ1ZX0=");
+
+    let response = fixture.into_response(std::path::Path::new("f")).unwrap();
+    assert_eq!(response.status(), 201);
+    assert_eq!(
+      response
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .collect::<Vec<_>>(),
+      vec!["a=1", "b=2"]
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(body, Bytes::from_static(b"{\"ok\":true}"));
+  }
+
+  #[test]
+  fn test_invalid_fixture_response() {
+    let fixture = FixtureResponse {
+      status: 1000,
+      headers: vec![],
+      body: String::new(),
+    };
+    let err = fixture
+      .into_response(std::path::Path::new("fixture.json"))
+      .unwrap_err();
+    assert!(matches!(err, FetchError::FixtureInvalid { .. }));
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
 pub mod dns;
+mod fixtures;
 mod fs_fetch_handler;
 mod proxy;
 #[cfg(test)]
@@ -44,7 +45,6 @@ use deno_core::convert::Uint8Array;
 use deno_core::futures::FutureExt;
 use deno_core::futures::Stream;
 use deno_core::futures::StreamExt;
-use deno_core::futures::TryFutureExt;
 use deno_core::futures::stream::Peekable;
 use deno_core::op2;
 use deno_core::url;
@@ -63,6 +63,8 @@ use deno_tls::TlsKey;
 use deno_tls::TlsKeys;
 use deno_tls::TlsKeysHolder;
 use deno_tls::rustls::RootCertStore;
+pub use fixtures::FetchFixtures;
+pub use fixtures::FetchFixturesMode;
 pub use fs_fetch_handler::FsFetchHandler;
 use http::Extensions;
 use http::HeaderMap;
@@ -117,6 +119,9 @@ pub struct Options {
   pub client_cert_chain_and_key: TlsKeys,
   pub file_fetch_handler: Rc<dyn FetchHandler>,
   pub resolver: dns::Resolver,
+  /// Record or replay HTTP(S) traffic from a fixture directory instead of
+  /// always going to the network.
+  pub fixtures: Option<FetchFixtures>,
 }
 
 impl Options {
@@ -140,6 +145,7 @@ impl Default for Options {
       client_cert_chain_and_key: TlsKeys::Null,
       file_fetch_handler: Rc::new(DefaultFileFetchHandler),
       resolver: dns::Resolver::default(),
+      fixtures: None,
     }
   }
 }
@@ -148,6 +154,7 @@ deno_core::extension!(deno_fetch,
   deps = [ deno_webidl, deno_web ],
   ops = [
     op_fetch,
+    op_fetch_check_net,
     op_fetch_send,
     op_utf8_to_byte_string,
     op_fetch_custom_client,
@@ -242,6 +249,26 @@ pub enum FetchError {
   #[class(generic)]
   #[error(transparent)]
   PermissionCheck(PermissionCheckError),
+  #[class(type)]
+  #[error("No recorded fetch fixture for {method} {url} in strict replay mode")]
+  FixtureNotFound { method: Method, url: String },
+  #[class(generic)]
+  #[error("Failed to access fetch fixture {}", .path.display())]
+  FixtureIo {
+    path: PathBuf,
+    #[source]
+    source: std::io::Error,
+  },
+  #[class(generic)]
+  #[error("Failed to parse fetch fixture {}", .path.display())]
+  FixtureParse {
+    path: PathBuf,
+    #[source]
+    source: serde_json::Error,
+  },
+  #[class(generic)]
+  #[error("Invalid fetch fixture {}: {message}", .path.display())]
+  FixtureInvalid { path: PathBuf, message: String },
   #[class(inherit)]
   #[error(transparent)]
   Other(JsErrorBox),
@@ -411,6 +438,23 @@ impl Drop for ResourceToBodyAdapter {
   }
 }
 
+/// Checks the net permission for a request that may be answered from the
+/// HTTP cache, which must not reveal stored responses to code that isn't
+/// allowed to fetch them.
+#[op2(fast, stack_trace)]
+pub fn op_fetch_check_net(
+  state: &mut OpState,
+  #[string] url: &str,
+) -> Result<(), FetchError> {
+  let url = Url::parse(url)?;
+  if matches!(url.scheme(), "http" | "https") {
+    state
+      .borrow_mut::<PermissionsContainer>()
+      .check_net_url(&url, "fetch()")?;
+  }
+  Ok(())
+}
+
 #[op2(stack_trace)]
 #[allow(clippy::too_many_arguments, reason = "op")]
 #[allow(clippy::large_enum_variant, reason = "TODO: investigate")]
@@ -532,16 +576,20 @@ pub fn op_fetch(
         request_builder_hook(&mut request)
           .map_err(FetchError::RequestBuilderHook)?;
       }
+      let fixtures = options.fixtures.clone();
 
       let cancel_handle = CancelHandle::new_rc();
       let cancel_handle_ = cancel_handle.clone();
 
       let fut = async move {
-        client
-          .send(request)
-          .map_err(Into::into)
-          .or_cancel(cancel_handle_)
-          .await
+        async move {
+          match fixtures {
+            Some(fixtures) => fixtures.send(client, request).await,
+            None => client.send(request).await.map_err(Into::into),
+          }
+        }
+        .or_cancel(cancel_handle_)
+        .await
       };
 
       let request_rid = state.resource_table.add(FetchRequestResource {
@@ -21,6 +21,7 @@ import {
   op_http_serve,
   op_http_serve_address_override,
   op_http_serve_on,
+  op_http_serve_quic,
   op_http_set_promise_complete,
   op_http_set_response_body_bytes,
   op_http_set_response_body_resource,
@@ -36,9 +37,12 @@ import {
   op_http_wait,
 } from "ext:core/ops";
 const {
+  ArrayIsArray,
   ArrayPrototypeFind,
+  ArrayPrototypeIncludes,
   ArrayPrototypeMap,
   ArrayPrototypePush,
+  NumberIsInteger,
   ObjectHasOwn,
   ObjectPrototypeIsPrototypeOf,
   PromisePrototypeCatch,
@@ -51,6 +55,7 @@ const {
   StringPrototypeStartsWith,
   Symbol,
   SymbolAsyncDispose,
+  RangeError,
   TypeError,
   TypedArrayPrototypeGetSymbolToStringTag,
   Uint8Array,
@@ -92,7 +97,11 @@ import {
   listenOptionApiName,
   UpgradedConn,
 } from "ext:deno_net/01_net.js";
-import { hasTlsKeyPairOptions, listenTls } from "ext:deno_net/02_tls.js";
+import {
+  hasTlsKeyPairOptions,
+  listenTls,
+  loadTlsKeyPair,
+} from "ext:deno_net/02_tls.js";
 import {
   builtinTracer,
   ContextManager,
@@ -347,7 +356,7 @@ class InnerRequest {
       };
     }
     return {
-      transport: "tcp",
+      transport: transport === "udp" ? "udp" : "tcp",
       hostname: this.#methodAndUri[3],
       port: this.#methodAndUri[4],
     };
@@ -721,11 +730,80 @@ type RawServeOptions = {
   reusePort?: boolean;
   key?: string;
   cert?: string;
+  http3?: boolean;
   onError?: (error: unknown) => Response | Promise<Response>;
   onListen?: (params: { hostname: string; port: number }) => void;
   handler?: RawHandler;
+  compression?: RawCompressionOptions | false;
+};
+
+type CompressionEncoding = "zstd" | "br" | "gzip";
+
+type RawCompressionOptions = {
+  encodings?: CompressionEncoding[];
+  levels?: { gzip?: number; br?: number; zstd?: number };
+};
+
+const COMPRESSION_ENCODINGS: CompressionEncoding[] = ["zstd", "br", "gzip"];
+const COMPRESSION_LEVEL_RANGES = {
+  __proto__: null,
+  gzip: [0, 9],
+  br: [0, 11],
+  zstd: [1, 22],
 };
 
+/**
+ * Validates the `compression` option of `Deno.serve` and converts it to the
+ * form expected by `op_http_serve`. Returns `undefined` for the defaults.
+ */
+function normalizeCompressionOptions(
+  compression: RawCompressionOptions | false | undefined,
+) {
+  if (compression === undefined) {
+    return undefined;
+  }
+  if (compression === false) {
+    return { encodings: [] };
+  }
+  const normalized = { __proto__: null };
+  const encodings = compression.encodings;
+  if (encodings !== undefined) {
+    if (!ArrayIsArray(encodings)) {
+      throw new TypeError(
+        "Invalid 'compression.encodings' option: expected an array",
+      );
+    }
+    for (let i = 0; i < encodings.length; ++i) {
+      if (!ArrayPrototypeIncludes(COMPRESSION_ENCODINGS, encodings[i])) {
+        throw new TypeError(
+          `Invalid 'compression.encodings' option: unsupported encoding '${
+            encodings[i]
+          }', expected one of "zstd", "br" or "gzip"`,
+        );
+      }
+    }
+    normalized.encodings = encodings;
+  }
+  const levels = compression.levels;
+  if (levels !== undefined) {
+    normalized.levels = { __proto__: null };
+    for (const encoding of new SafeArrayIterator(COMPRESSION_ENCODINGS)) {
+      const level = levels[encoding];
+      if (level === undefined) {
+        continue;
+      }
+      const { 0: min, 1: max } = COMPRESSION_LEVEL_RANGES[encoding];
+      if (!NumberIsInteger(level) || level < min || level > max) {
+        throw new RangeError(
+          `Invalid 'compression.levels.${encoding}' option: expected an integer between ${min} and ${max}, received ${level}`,
+        );
+      }
+      normalized.levels[encoding] = level;
+    }
+  }
+  return normalized;
+}
+
 const kLoadBalanced = Symbol("kLoadBalanced");
 
 function formatHostName(hostname: string): string {
@@ -789,7 +867,12 @@ function serve(arg1, arg2) {
     serveAddressOverrideConsumed = true;
 
     let envOptions = duplicateListener
-      ? { __proto__: null, signal: options.signal, onError: options.onError }
+      ? {
+        __proto__: null,
+        signal: options.signal,
+        onError: options.onError,
+        compression: options.compression,
+      }
       : options;
 
     switch (overrideKind) {
@@ -846,27 +929,7 @@ function serve(arg1, arg2) {
       const envListener = serveInner(envOptions, handler);
       const userListener = serveInner(options, handler);
 
-      return {
-        addr: userListener.addr,
-        finished: SafePromiseAll([envListener.finished, userListener.finished]),
-        shutdown() {
-          return SafePromiseAll([
-            envListener.shutdown(),
-            userListener.shutdown(),
-          ]);
-        },
-        ref() {
-          envListener.ref();
-          userListener.ref();
-        },
-        unref() {
-          envListener.unref();
-          userListener.unref();
-        },
-        [SymbolAsyncDispose]() {
-          return this.shutdown();
-        },
-      };
+      return combineServers(userListener, envListener);
     }
 
     options = envOptions;
@@ -875,7 +938,35 @@ function serve(arg1, arg2) {
   return serveInner(options, handler);
 }
 
+/**
+ * Combines two servers into one that reports the address of `primary`.
+ */
+function combineServers(primary, secondary) {
+  return {
+    addr: primary.addr,
+    finished: SafePromiseAll([secondary.finished, primary.finished]),
+    shutdown() {
+      return SafePromiseAll([
+        secondary.shutdown(),
+        primary.shutdown(),
+      ]);
+    },
+    ref() {
+      secondary.ref();
+      primary.ref();
+    },
+    unref() {
+      secondary.unref();
+      primary.unref();
+    },
+    [SymbolAsyncDispose]() {
+      return this.shutdown();
+    },
+  };
+}
+
 function serveInner(options, handler) {
+  const compression = normalizeCompressionOptions(options.compression);
   const wantsHttps = hasTlsKeyPairOptions(options);
   const wantsUnix = ObjectHasOwn(options, "path");
   const wantsVsock = ObjectHasOwn(options, "cid");
@@ -894,13 +985,21 @@ function serveInner(options, handler) {
       [listenOptionApiName]: "Deno.serve",
     });
     const path = listener.addr.path;
-    return serveHttpOnListener(listener, signal, handler, onError, () => {
+    const onListen = () => {
       if (options.onListen) {
         options.onListen(listener.addr);
       } else {
         import.meta.log("info", `Listening on ${path}`);
       }
-    });
+    };
+    return serveHttpOnListener(
+      listener,
+      signal,
+      handler,
+      onError,
+      onListen,
+      compression,
+    );
   }
 
   if (wantsVsock) {
@@ -911,13 +1010,21 @@ function serveInner(options, handler) {
       [listenOptionApiName]: "Deno.serve",
     });
     const { cid, port } = listener.addr;
-    return serveHttpOnListener(listener, signal, handler, onError, () => {
+    const onListen = () => {
       if (options.onListen) {
         options.onListen(listener.addr);
       } else {
         import.meta.log("info", `Listening on vsock:${cid}:${port}`);
       }
-    });
+    };
+    return serveHttpOnListener(
+      listener,
+      signal,
+      handler,
+      onError,
+      onListen,
+      compression,
+    );
   }
 
   if (wantsTunnel) {
@@ -925,7 +1032,7 @@ function serveInner(options, handler) {
       transport: "tunnel",
       [listenOptionApiName]: "Deno.serve",
     });
-    return serveHttpOnListener(listener, signal, handler, onError, () => {
+    const onListen = () => {
       if (options.onListen) {
         options.onListen(listener.addr);
       } else {
@@ -939,7 +1046,15 @@ function serveInner(options, handler) {
           }${additional}`,
         );
       }
-    });
+    };
+    return serveHttpOnListener(
+      listener,
+      signal,
+      handler,
+      onError,
+      onListen,
+      compression,
+    );
   }
 
   const listenOpts = {
@@ -961,6 +1076,12 @@ function serveInner(options, handler) {
     );
   }
 
+  if (options.http3 && !wantsHttps) {
+    throw new TypeError(
+      "Both 'cert' and 'key' must be provided to enable HTTP/3",
+    );
+  }
+
   let listener;
   if (wantsHttps) {
     if (!options.cert || !options.key) {
@@ -980,6 +1101,26 @@ function serveInner(options, handler) {
 
   const addr = listener.addr;
 
+  // HTTP/3 is served on the UDP port with the same number as the TCP
+  // listener, which then advertises it with an `Alt-Svc` header.
+  let quicServer;
+  if (options.http3) {
+    try {
+      quicServer = serveHttp3(
+        listenOpts.hostname,
+        addr,
+        loadTlsKeyPair("Deno.serve", { cert: options.cert, key: options.key }),
+        signal,
+        handler,
+        onError,
+        compression,
+      );
+    } catch (error) {
+      listener.close();
+      throw error;
+    }
+  }
+
   const onListen = (scheme) => {
     if (options.onListen) {
       options.onListen(addr);
@@ -996,16 +1137,36 @@ function serveInner(options, handler) {
     }
   };
 
-  return serveHttpOnListener(listener, signal, handler, onError, onListen);
+  const server = serveHttpOnListener(
+    listener,
+    signal,
+    handler,
+    onError,
+    onListen,
+    compression,
+    quicServer ? listenOpts.port : undefined,
+  );
+  if (quicServer === undefined) {
+    return server;
+  }
+  return combineServers(server, quicServer);
 }
 
 /**
  * Serve HTTP/1.1 and/or HTTP/2 on an arbitrary listener.
  */
-function serveHttpOnListener(listener, signal, handler, onError, onListen) {
+function serveHttpOnListener(
+  listener,
+  signal,
+  handler,
+  onError,
+  onListen,
+  compression = undefined,
+  http3Port = undefined,
+) {
   const context = new CallbackContext(
     signal,
-    op_http_serve(listener[internalRidSymbol]),
+    op_http_serve(listener[internalRidSymbol], compression, http3Port),
     listener,
   );
   const callback = mapToCallback(context, handler, onError);
@@ -1015,13 +1176,46 @@ function serveHttpOnListener(listener, signal, handler, onError, onListen) {
   return serveHttpOn(context, listener.addr, callback);
 }
 
+/**
+ * Serve HTTP/3 on the UDP port of `addr`.
+ */
+function serveHttp3(
+  hostname,
+  addr,
+  keyPair,
+  signal,
+  handler,
+  onError,
+  compression,
+) {
+  const listener = {
+    __proto__: null,
+    addr: { ...addr, transport: "udp" },
+  };
+  const context = new CallbackContext(
+    signal,
+    op_http_serve_quic(hostname, addr.port, keyPair, compression),
+    listener,
+  );
+  const callback = mapToCallback(context, handler, onError);
+
+  return serveHttpOn(context, addr, callback);
+}
+
 /**
  * Serve HTTP/1.1 and/or HTTP/2 on an arbitrary connection.
  */
-function serveHttpOnConnection(connection, signal, handler, onError, onListen) {
+function serveHttpOnConnection(
+  connection,
+  signal,
+  handler,
+  onError,
+  onListen,
+  compression = undefined,
+) {
   const context = new CallbackContext(
     signal,
-    op_http_serve_on(connection[internalRidSymbol]),
+    op_http_serve_on(connection[internalRidSymbol], compression),
     null,
   );
   const callback = mapToCallback(context, handler, onError);
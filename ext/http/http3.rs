@@ -0,0 +1,281 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! HTTP/3 support for `Deno.serve`, built on the QUIC endpoints of
+//! `deno_net`. Requests are handed to the same [`handle_request`] path as
+//! HTTP/1.1 and HTTP/2.
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+use bytes::Buf;
+use bytes::Bytes;
+use deno_core::CancelFuture;
+use deno_core::CancelTryFuture;
+use deno_core::unsync::JoinHandle;
+use deno_core::unsync::spawn;
+use deno_error::JsErrorBox;
+use deno_net::raw::NetworkStreamAddress;
+use deno_net::raw::NetworkStreamType;
+use h3::server::RequestResolver;
+use h3::server::RequestStream;
+use http_body_util::BodyExt;
+use hyper::body::Body;
+use hyper::body::Frame;
+use hyper::body::SizeHint;
+use hyper::header::CONNECTION;
+use hyper::header::CONTENT_LENGTH;
+use hyper::header::HeaderValue;
+use hyper::header::TRANSFER_ENCODING;
+use hyper::header::UPGRADE;
+
+use crate::Options;
+use crate::http_next::HttpLifetime;
+use crate::http_next::HttpNextError;
+use crate::request_body::IncomingBody;
+use crate::request_properties::HttpConnectionProperties;
+use crate::request_properties::HttpListenProperties;
+use crate::request_properties::listener_properties;
+use crate::service::HttpRecord;
+use crate::service::HttpServerState;
+use crate::service::SignallingRc;
+use crate::service::handle_request;
+
+/// How long clients may remember the advertised HTTP/3 endpoint, in seconds.
+const ALT_SVC_MAX_AGE: u32 = 86400;
+
+/// Builds the `Alt-Svc` header advertising HTTP/3 on `port` of the same host.
+pub(crate) fn alt_svc(port: u16) -> HeaderValue {
+  HeaderValue::from_str(&format!("h3=\":{port}\"; ma={ALT_SVC_MAX_AGE}"))
+    .unwrap()
+}
+
+/// HTTP/3 is always encrypted, so requests are treated like those received
+/// over TLS.
+pub(crate) fn listen_properties(
+  local_addr: SocketAddr,
+) -> Result<HttpListenProperties, std::io::Error> {
+  listener_properties(
+    NetworkStreamType::Tls,
+    NetworkStreamAddress::Ip(local_addr),
+  )
+}
+
+/// The body of an HTTP/3 request.
+pub struct Http3RequestBody {
+  stream: RequestStream<h3_quinn::RecvStream, Bytes>,
+  size_hint: SizeHint,
+  done: bool,
+}
+
+impl Http3RequestBody {
+  fn new(
+    stream: RequestStream<h3_quinn::RecvStream, Bytes>,
+    content_length: Option<u64>,
+  ) -> Self {
+    Self {
+      stream,
+      size_hint: content_length.map(SizeHint::with_exact).unwrap_or_default(),
+      done: false,
+    }
+  }
+}
+
+impl Body for Http3RequestBody {
+  type Data = Bytes;
+  type Error = JsErrorBox;
+
+  fn poll_frame(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+    let this = self.get_mut();
+    if this.done {
+      return Poll::Ready(None);
+    }
+    match ready!(this.stream.poll_recv_data(cx)) {
+      Ok(Some(mut data)) => {
+        let data = data.copy_to_bytes(data.remaining());
+        Poll::Ready(Some(Ok(Frame::data(data))))
+      }
+      Ok(None) => {
+        this.done = true;
+        Poll::Ready(None)
+      }
+      Err(err) => {
+        this.done = true;
+        Poll::Ready(Some(Err(JsErrorBox::new("Http", err.to_string()))))
+      }
+    }
+  }
+
+  fn is_end_stream(&self) -> bool {
+    self.done
+  }
+
+  fn size_hint(&self) -> SizeHint {
+    self.size_hint.clone()
+  }
+}
+
+/// Accepts an incoming QUIC connection and serves HTTP/3 requests on it
+/// until the client closes it or the server shuts down.
+pub(crate) fn serve_http3(
+  incoming: quinn::Incoming,
+  listen_properties: &HttpListenProperties,
+  lifetime: HttpLifetime,
+  tx: tokio::sync::mpsc::Sender<Rc<HttpRecord>>,
+  options: Options,
+) -> JoinHandle<Result<(), HttpNextError>> {
+  let HttpLifetime {
+    server_state,
+    connection_cancel_handle,
+    listen_cancel_handle,
+  } = lifetime;
+
+  let peer = incoming.remote_address();
+  let request_info = HttpConnectionProperties {
+    peer_address: Rc::from(peer.ip().to_string()),
+    peer_port: Some(peer.port() as _),
+    local_port: listen_properties.local_port,
+    stream_type: listen_properties.stream_type,
+  };
+  let legacy_abort = !options.no_legacy_abort;
+
+  spawn(
+    async move {
+      let connection = incoming.await?;
+      let mut connection: h3::server::Connection<_, Bytes> =
+        h3::server::builder()
+          .build(h3_quinn::Connection::new(connection))
+          .await?;
+      let mut shutting_down = false;
+      loop {
+        let accepted = if shutting_down {
+          connection.accept().await
+        } else {
+          match connection
+            .accept()
+            .or_cancel(listen_cancel_handle.clone())
+            .await
+          {
+            Ok(accepted) => accepted,
+            Err(_) => {
+              // Stop accepting new requests, but let the in-flight ones finish.
+              shutting_down = true;
+              connection.shutdown(0).await?;
+              continue;
+            }
+          }
+        };
+        let resolver = match accepted {
+          Ok(Some(resolver)) => resolver,
+          Ok(None) => return Ok(()),
+          Err(err) if err.is_h3_no_error() => return Ok(()),
+          Err(err) => return Err(err.into()),
+        };
+        let request_info = request_info.clone();
+        let server_state = server_state.clone();
+        let tx = tx.clone();
+        spawn(async move {
+          if let Err(err) = serve_http3_request(
+            resolver,
+            request_info,
+            server_state,
+            tx,
+            legacy_abort,
+          )
+          .await
+          {
+            log::debug!("HTTP/3 request failed: {err}");
+          }
+        });
+      }
+    }
+    .try_or_cancel(connection_cancel_handle),
+  )
+}
+
+async fn serve_http3_request(
+  resolver: RequestResolver<h3_quinn::Connection<Bytes>, Bytes>,
+  request_info: HttpConnectionProperties,
+  server_state: SignallingRc<HttpServerState>,
+  tx: tokio::sync::mpsc::Sender<Rc<HttpRecord>>,
+  legacy_abort: bool,
+) -> Result<(), HttpNextError> {
+  let (request, stream) = resolver.resolve_request().await?;
+  let (mut send, recv) = stream.split();
+  let content_length = request
+    .headers()
+    .get(CONTENT_LENGTH)
+    .and_then(|value| value.to_str().ok())
+    .and_then(|value| value.parse().ok());
+  let request = request
+    .map(|()| IncomingBody::Http3(Http3RequestBody::new(recv, content_length)));
+
+  let response =
+    handle_request(request, request_info, server_state, tx, legacy_abort)
+      .await
+      .map_err(|err| JsErrorBox::new("Http", err.to_string()))?;
+  let (mut parts, mut body) = response.into_parts();
+  // Connection-specific headers are not allowed in HTTP/3 (RFC 9114,
+  // section 4.2).
+  for name in [CONNECTION, TRANSFER_ENCODING, UPGRADE] {
+    parts.headers.remove(name);
+  }
+  parts.headers.remove("keep-alive");
+  parts.headers.remove("proxy-connection");
+  if let Some(length) = body.size_hint().exact()
+    && !parts.headers.contains_key(CONTENT_LENGTH)
+  {
+    parts
+      .headers
+      .insert(CONTENT_LENGTH, HeaderValue::from(length));
+  }
+  parts.version = http::Version::HTTP_3;
+  send
+    .send_response(http::Response::from_parts(parts, ()))
+    .await?;
+
+  while let Some(frame) = body.frame().await {
+    let frame = frame?;
+    match frame.into_data() {
+      Ok(mut data) => {
+        send.send_data(data.copy_to_bytes(data.remaining())).await?
+      }
+      Err(frame) => {
+        if let Ok(trailers) = frame.into_trailers() {
+          send.send_trailers(trailers).await?;
+        }
+      }
+    }
+  }
+  send.finish().await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_alt_svc() {
+    assert_eq!(alt_svc(443), "h3=\":443\"; ma=86400");
+    assert_eq!(alt_svc(8443), "h3=\":8443\"; ma=86400");
+  }
+
+  #[test]
+  fn test_listen_properties() {
+    let properties =
+      listen_properties("127.0.0.1:8443".parse().unwrap()).unwrap();
+    assert_eq!(properties.scheme, "https://");
+    assert_eq!(properties.fallback_host, "localhost:8443");
+    assert_eq!(properties.local_port, Some(8443));
+
+    let properties = listen_properties("0.0.0.0:443".parse().unwrap()).unwrap();
+    assert_eq!(properties.fallback_host, "localhost");
+  }
+}
@@ -8,6 +8,7 @@ use std::io;
 use std::pin::Pin;
 use std::ptr::null;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use bytes::Bytes;
 use bytes::BytesMut;
@@ -33,10 +34,12 @@ use deno_core::op2;
 use deno_core::unsync::JoinHandle;
 use deno_core::unsync::spawn;
 use deno_core::v8;
+use deno_features::FeatureChecker;
 use deno_net::ops_tls::TlsStream;
 use deno_net::raw::NetworkStream;
 use deno_net::raw::NetworkStreamReadHalf;
 use deno_net::raw::NetworkStreamWriteHalf;
+use deno_tls::TlsKeysHolder;
 use deno_websocket::ws_create_server_stream;
 use fly_accept_encoding::Encoding;
 use hyper::StatusCode;
@@ -68,12 +71,16 @@ use crate::LocalExecutor;
 use crate::Options;
 use crate::compressible::is_content_compressible;
 use crate::extract_network_stream;
+use crate::http3;
 use crate::network_buffered_stream::NetworkStreamPrefixCheck;
 use crate::request_body::HttpRequestBody;
+use crate::request_body::IncomingBody;
 use crate::request_properties::HttpConnectionProperties;
 use crate::request_properties::HttpListenProperties;
 use crate::request_properties::HttpPropertyExtractor;
 use crate::response_body::Compression;
+use crate::response_body::CompressionEncoding;
+use crate::response_body::CompressionOptions;
 use crate::response_body::ResponseBytesInner;
 use crate::service::HttpRecord;
 use crate::service::HttpRecordResponse;
@@ -192,6 +199,18 @@ pub enum HttpNextError {
   #[class("Http")]
   #[error("raw upgrade failed")]
   RawUpgradeFailed,
+  #[class(inherit)]
+  #[error(transparent)]
+  Quic(#[from] deno_net::QuicError),
+  #[class("Http")]
+  #[error("{0}")]
+  QuicConnection(#[from] quinn::ConnectionError),
+  #[class("Http")]
+  #[error("{0}")]
+  Http3Connection(#[from] h3::error::ConnectionError),
+  #[class("Http")]
+  #[error("{0}")]
+  Http3Stream(#[from] h3::error::StreamError),
 }
 
 #[op2(fast)]
@@ -592,7 +611,12 @@ pub fn op_http_set_response_trailers(
 fn is_request_compressible(
   length: Option<usize>,
   headers: &HeaderMap,
+  options: &CompressionOptions,
 ) -> Compression {
+  if options.encodings.is_empty() {
+    return Compression::None;
+  }
+
   if let Some(length) = length {
     // By the time we add compression headers and Accept-Encoding, it probably doesn't make sense
     // to compress stuff that's smaller than this.
@@ -605,31 +629,69 @@ fn is_request_compressible(
     return Compression::None;
   };
 
-  match accept_encoding.to_str() {
+  use CompressionEncoding::*;
+  let accepted: &[CompressionEncoding] = match accept_encoding.to_str() {
     // Firefox and Chrome send this -- no need to parse
-    Ok("gzip, deflate, br") => return Compression::Brotli,
-    Ok("gzip, deflate, br, zstd") => return Compression::Brotli,
-    Ok("gzip") => return Compression::GZip,
-    Ok("br") => return Compression::Brotli,
-    _ => (),
-  }
+    Ok("gzip, deflate, br") => &[GZip, Brotli],
+    Ok("gzip, deflate, br, zstd") => &[GZip, Brotli, Zstd],
+    Ok("gzip") => &[GZip],
+    Ok("br") => &[Brotli],
+    Ok("zstd") => &[Zstd],
+    // Fall back to the expensive parser
+    _ => {
+      return negotiate_compression(
+        fly_accept_encoding::encodings_iter_http_1(headers),
+        options,
+      );
+    }
+  };
+  options
+    .encodings
+    .iter()
+    .find(|encoding| accepted.contains(encoding))
+    .map(|encoding| options.compression(*encoding))
+    .unwrap_or(Compression::None)
+}
 
-  // Fall back to the expensive parser
-  let accepted =
-    fly_accept_encoding::encodings_iter_http_1(headers).filter(|r| {
-      matches!(
-        r,
-        Ok((
-          Some(Encoding::Identity | Encoding::Gzip | Encoding::Brotli),
-          _
-        ))
-      )
-    });
-  match fly_accept_encoding::preferred(accepted) {
-    Ok(Some(fly_accept_encoding::Encoding::Gzip)) => Compression::GZip,
-    Ok(Some(fly_accept_encoding::Encoding::Brotli)) => Compression::Brotli,
-    _ => Compression::None,
+/// Picks the encoding with the highest q-value, using the server's preference
+/// order to break ties. Returns `Compression::None` if the client prefers an
+/// uncompressed response or the header is malformed.
+fn negotiate_compression(
+  accepted: impl Iterator<
+    Item = Result<(Option<Encoding>, f32), fly_accept_encoding::EncodingError>,
+  >,
+  options: &CompressionOptions,
+) -> Compression {
+  let Ok(accepted) = accepted.collect::<Result<SmallVec<[_; 4]>, _>>() else {
+    return Compression::None;
+  };
+  let qval = |encoding: Encoding| {
+    accepted
+      .iter()
+      .find(|(accepted, _)| *accepted == Some(encoding))
+      .map(|(_, qval)| *qval)
+  };
+
+  let mut preferred = None;
+  let mut preferred_qval = 0.0;
+  for encoding in &options.encodings {
+    let qval = qval(match encoding {
+      CompressionEncoding::Zstd => Encoding::Zstd,
+      CompressionEncoding::Brotli => Encoding::Brotli,
+      CompressionEncoding::GZip => Encoding::Gzip,
+    })
+    .unwrap_or(0.0);
+    if qval > preferred_qval {
+      preferred = Some(*encoding);
+      preferred_qval = qval;
+    }
   }
+  if qval(Encoding::Identity).is_some_and(|qval| qval > preferred_qval) {
+    return Compression::None;
+  }
+  preferred
+    .map(|encoding| options.compression(encoding))
+    .unwrap_or(Compression::None)
 }
 
 fn is_response_compressible(headers: &HeaderMap) -> bool {
@@ -667,11 +729,7 @@ fn modify_compressibility_from_response(
   if !is_response_compressible(headers) {
     return Compression::None;
   }
-  let encoding = match compression {
-    Compression::Brotli => "br",
-    Compression::GZip => "gzip",
-    _ => unreachable!(),
-  };
+  let encoding = compression.content_encoding().unwrap();
   weaken_etag(headers);
   headers.remove(CONTENT_LENGTH);
   headers.insert(CONTENT_ENCODING, HeaderValue::from_static(encoding));
@@ -722,8 +780,11 @@ fn set_response(
   // The request may have been cancelled by this point and if so, there's no need for us to
   // do all of this work to send the response.
   if !http.cancelled() {
-    let compression =
-      is_request_compressible(length, &http.request_parts().headers);
+    let compression = is_request_compressible(
+      length,
+      &http.request_parts().headers,
+      &http.compression_options(),
+    );
     let mut response_headers =
       std::cell::RefMut::map(http.response_parts(), |this| &mut this.headers);
     let compression =
@@ -944,7 +1005,7 @@ fn serve_https(
   let legacy_abort = !options.no_legacy_abort;
   let svc = service_fn(move |req: Request| {
     handle_request(
-      req,
+      req.map(IncomingBody::from),
       request_info.clone(),
       server_state.clone(),
       tx.clone(),
@@ -999,7 +1060,7 @@ fn serve_http(
   let legacy_abort = !options.no_legacy_abort;
   let svc = service_fn(move |req: Request| {
     handle_request(
-      req,
+      req.map(IncomingBody::from),
       request_info.clone(),
       server_state.clone(),
       tx.clone(),
@@ -1057,10 +1118,10 @@ where
 }
 
 #[derive(Clone)]
-struct HttpLifetime {
-  connection_cancel_handle: Rc<CancelHandle>,
-  listen_cancel_handle: Rc<CancelHandle>,
-  server_state: SignallingRc<HttpServerState>,
+pub(crate) struct HttpLifetime {
+  pub(crate) connection_cancel_handle: Rc<CancelHandle>,
+  pub(crate) listen_cancel_handle: Rc<CancelHandle>,
+  pub(crate) server_state: SignallingRc<HttpServerState>,
 }
 
 struct HttpJoinHandle {
@@ -1072,13 +1133,17 @@ struct HttpJoinHandle {
 }
 
 impl HttpJoinHandle {
-  fn new(rx: tokio::sync::mpsc::Receiver<Rc<HttpRecord>>) -> Self {
+  fn new(
+    rx: tokio::sync::mpsc::Receiver<Rc<HttpRecord>>,
+    compression: CompressionOptions,
+    alt_svc: Option<HeaderValue>,
+  ) -> Self {
     Self {
       join_handle: AsyncRefCell::new(None),
       connection_cancel_handle: CancelHandle::new_rc(),
       listen_cancel_handle: CancelHandle::new_rc(),
       rx: AsyncRefCell::new(rx),
-      server_state: HttpServerState::new(),
+      server_state: HttpServerState::new(compression, alt_svc),
     }
   }
 
@@ -1119,10 +1184,15 @@ impl Drop for HttpJoinHandle {
   }
 }
 
+/// Serves HTTP/1.1 and HTTP/2 on a listener. If `http3_port` is set, every
+/// response advertises the HTTP/3 endpoint on that UDP port with an
+/// `Alt-Svc` header.
 #[op2]
 pub fn op_http_serve<HTTP>(
   state: Rc<RefCell<OpState>>,
   #[smi] listener_rid: ResourceId,
+  #[serde] compression: Option<CompressionOptions>,
+  #[serde] http3_port: Option<u16>,
 ) -> Result<(ResourceId, &'static str, String, bool), HttpNextError>
 where
   HTTP: HttpPropertyExtractor,
@@ -1133,7 +1203,11 @@ where
   let listen_properties = HTTP::listen_properties_from_listener(&listener)?;
 
   let (tx, rx) = tokio::sync::mpsc::channel(10);
-  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(rx));
+  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(
+    rx,
+    compression.unwrap_or_default(),
+    http3_port.map(http3::alt_svc),
+  ));
   let listen_cancel_clone = resource.listen_cancel_handle();
 
   let lifetime = resource.lifetime();
@@ -1178,6 +1252,7 @@ where
 pub fn op_http_serve_on<HTTP>(
   state: Rc<RefCell<OpState>>,
   #[smi] connection_rid: ResourceId,
+  #[serde] compression: Option<CompressionOptions>,
 ) -> Result<(ResourceId, &'static str, String, bool), HttpNextError>
 where
   HTTP: HttpPropertyExtractor,
@@ -1188,7 +1263,11 @@ where
   let listen_properties = HTTP::listen_properties_from_connection(&connection)?;
 
   let (tx, rx) = tokio::sync::mpsc::channel(10);
-  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(rx));
+  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(
+    rx,
+    compression.unwrap_or_default(),
+    None,
+  ));
 
   let options = {
     let state = state.borrow();
@@ -1216,6 +1295,92 @@ where
   ))
 }
 
+/// Serves HTTP/3 on a QUIC endpoint bound to `hostname:port`.
+///
+/// The endpoint is owned by the server, which closes it once it has shut down so
+/// that the UDP port is released right away.
+#[op2]
+pub fn op_http_serve_quic(
+  state: Rc<RefCell<OpState>>,
+  #[string] hostname: String,
+  #[smi] port: u16,
+  #[cppgc] keys: &TlsKeysHolder,
+  #[serde] compression: Option<CompressionOptions>,
+) -> Result<(ResourceId, &'static str, String, bool), HttpNextError> {
+  state
+    .borrow()
+    .borrow::<Arc<FeatureChecker>>()
+    .check_or_exit(
+      deno_net::UNSTABLE_FEATURE_NAME,
+      "Deno.serve({ http3: true })",
+    );
+
+  let endpoint = deno_net::listen_quic_endpoint(
+    &mut state.borrow_mut(),
+    &hostname,
+    port,
+    keys,
+    vec!["h3".to_string()],
+    "Deno.serve()",
+  )?;
+  let listen_properties = http3::listen_properties(endpoint.local_addr()?)?;
+
+  let (tx, rx) = tokio::sync::mpsc::channel(10);
+  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(
+    rx,
+    compression.unwrap_or_default(),
+    None,
+  ));
+  let listen_cancel_clone = resource.listen_cancel_handle();
+
+  let lifetime = resource.lifetime();
+
+  let options = {
+    let state = state.borrow();
+    *state.borrow::<Options>()
+  };
+
+  let listen_properties_clone = listen_properties.clone();
+  let handle = spawn(async move {
+    let result = async {
+      while let Some(incoming) = endpoint
+        .accept()
+        .or_cancel(listen_cancel_clone.clone())
+        .await?
+      {
+        http3::serve_http3(
+          incoming,
+          &listen_properties_clone,
+          lifetime.clone(),
+          tx.clone(),
+          options,
+        );
+      }
+      Ok::<_, HttpNextError>(())
+    }
+    .await;
+    // Refuse new connections and wait for the open ones, which finish their
+    // in-flight requests or are cancelled on a forced shutdown, before the
+    // endpoint and its socket are dropped.
+    endpoint.set_server_config(None);
+    endpoint.wait_idle().await;
+    endpoint.close(quinn::VarInt::from_u32(0), b"");
+    result
+  });
+
+  // Set the handle after we start the future
+  *RcRef::map(&resource, |this| &this.join_handle)
+    .try_borrow_mut()
+    .unwrap() = Some(handle);
+
+  Ok((
+    state.borrow_mut().resource_table.add_rc(resource),
+    listen_properties.scheme,
+    listen_properties.fallback_host,
+    options.no_legacy_abort,
+  ))
+}
+
 /// Synchronous, non-blocking call to see if there are any further HTTP requests. If anything
 /// goes wrong in this method we return null and let the async handler pick up the real error.
 #[op2(fast)]
@@ -1730,3 +1895,62 @@ pub fn op_http_copy_span_to_otel_info(
 
   http.copy_span_to_otel_info(span);
 }
+
+#[cfg(test)]
+mod tests {
+  use hyper::header::HeaderValue;
+
+  use super::*;
+
+  fn negotiate(
+    accept_encoding: &str,
+    options: &CompressionOptions,
+  ) -> &'static str {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+      ACCEPT_ENCODING,
+      HeaderValue::from_str(accept_encoding).unwrap(),
+    );
+    is_request_compressible(None, &headers, options)
+      .content_encoding()
+      .unwrap_or("identity")
+  }
+
+  #[test]
+  fn test_compression_negotiation() {
+    let options = CompressionOptions::default();
+    assert_eq!(negotiate("gzip, deflate, br, zstd", &options), "br");
+    assert_eq!(negotiate("gzip, deflate, zstd", &options), "zstd");
+    assert_eq!(negotiate("gzip, deflate, br", &options), "br");
+    assert_eq!(negotiate("gzip", &options), "gzip");
+    assert_eq!(negotiate("br;q=0.8, gzip;q=0.9", &options), "gzip");
+    assert_eq!(negotiate("zstd;q=0.5, gzip;q=0.5", &options), "zstd");
+    assert_eq!(negotiate("zstd;q=0, gzip", &options), "gzip");
+    assert_eq!(negotiate("identity, br;q=0.5", &options), "identity");
+    assert_eq!(negotiate("deflate", &options), "identity");
+    assert_eq!(negotiate("unknown", &options), "identity");
+
+    let options = CompressionOptions {
+      encodings: vec![CompressionEncoding::GZip],
+      ..Default::default()
+    };
+    assert_eq!(negotiate("gzip, deflate, br, zstd", &options), "gzip");
+    assert_eq!(negotiate("br;q=1, zstd;q=0.9", &options), "identity");
+
+    let options = CompressionOptions {
+      encodings: vec![],
+      ..Default::default()
+    };
+    assert_eq!(negotiate("gzip", &options), "identity");
+
+    let options = CompressionOptions {
+      encodings: vec![
+        CompressionEncoding::Zstd,
+        CompressionEncoding::Brotli,
+        CompressionEncoding::GZip,
+      ],
+      ..Default::default()
+    };
+    assert_eq!(negotiate("gzip, deflate, br, zstd", &options), "zstd");
+  }
+}
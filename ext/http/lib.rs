@@ -90,6 +90,7 @@ use crate::reader_stream::ShutdownHandle;
 
 pub mod compressible;
 mod fly_accept_encoding;
+mod http3;
 mod http_next;
 mod network_buffered_stream;
 mod reader_stream;
@@ -162,6 +163,7 @@ deno_core::extension!(
     http_next::op_http_read_request_body,
     http_next::op_http_serve_on<HTTP>,
     http_next::op_http_serve<HTTP>,
+    http_next::op_http_serve_quic,
     http_next::op_http_set_promise_complete,
     http_next::op_http_set_response_body_bytes,
     http_next::op_http_set_response_body_resource,
@@ -215,6 +217,7 @@ deno_core::extension!(
     http_next::op_http_read_request_body,
     http_next::op_http_serve_on<DefaultHttpPropertyExtractor>,
     http_next::op_http_serve<DefaultHttpPropertyExtractor>,
+    http_next::op_http_serve_quic,
     http_next::op_http_set_promise_complete,
     http_next::op_http_set_response_body_bytes,
     http_next::op_http_set_response_body_resource,
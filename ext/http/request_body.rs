@@ -13,24 +13,69 @@ use deno_core::RcRef;
 use deno_core::Resource;
 use deno_core::futures::Stream;
 use deno_core::futures::StreamExt;
-use deno_core::futures::TryFutureExt;
 use deno_core::futures::stream::Peekable;
 use deno_error::JsErrorBox;
 use hyper::body::Body;
+use hyper::body::Frame;
 use hyper::body::Incoming;
 use hyper::body::SizeHint;
 
-/// Converts a hyper incoming body stream into a stream of [`Bytes`] that we can use to read in V8.
-struct ReadFuture(Incoming);
+use crate::http3::Http3RequestBody;
+
+/// The body of a request received over HTTP/1.1, HTTP/2 or HTTP/3.
+pub enum IncomingBody {
+  Hyper(Incoming),
+  Http3(Http3RequestBody),
+}
+
+impl From<Incoming> for IncomingBody {
+  fn from(value: Incoming) -> Self {
+    IncomingBody::Hyper(value)
+  }
+}
+
+impl Body for IncomingBody {
+  type Data = Bytes;
+  type Error = JsErrorBox;
+
+  fn poll_frame(
+    self: Pin<&mut Self>,
+    cx: &mut std::task::Context<'_>,
+  ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+    match self.get_mut() {
+      IncomingBody::Hyper(body) => Pin::new(body)
+        .poll_frame(cx)
+        .map_err(|e| JsErrorBox::new("Http", e.to_string())),
+      IncomingBody::Http3(body) => Pin::new(body).poll_frame(cx),
+    }
+  }
+
+  fn is_end_stream(&self) -> bool {
+    match self {
+      IncomingBody::Hyper(body) => body.is_end_stream(),
+      IncomingBody::Http3(body) => body.is_end_stream(),
+    }
+  }
+
+  fn size_hint(&self) -> SizeHint {
+    match self {
+      IncomingBody::Hyper(body) => body.size_hint(),
+      IncomingBody::Http3(body) => body.size_hint(),
+    }
+  }
+}
+
+/// Converts an incoming body stream into a stream of [`Bytes`] that we can use to read in V8.
+struct ReadFuture(IncomingBody);
 
 impl Stream for ReadFuture {
-  type Item = Result<Bytes, hyper::Error>;
+  type Item = Result<Bytes, JsErrorBox>;
 
   fn poll_next(
     self: Pin<&mut Self>,
     cx: &mut std::task::Context<'_>,
   ) -> Poll<Option<Self::Item>> {
-    // Loop until we receive a non-empty frame from Hyper
+    // Loop until we receive a non-empty frame from the body
     let this = self.get_mut();
     loop {
       let res = ready!(Pin::new(&mut this.0).poll_frame(cx));
@@ -55,12 +100,12 @@ impl Stream for ReadFuture {
 pub struct HttpRequestBody(AsyncRefCell<Peekable<ReadFuture>>, SizeHint);
 
 impl HttpRequestBody {
-  pub fn new(body: Incoming) -> Self {
+  pub fn new(body: IncomingBody) -> Self {
     let size_hint = body.size_hint();
     Self(AsyncRefCell::new(ReadFuture(body).peekable()), size_hint)
   }
 
-  async fn read(self: Rc<Self>, limit: usize) -> Result<BufView, hyper::Error> {
+  async fn read(self: Rc<Self>, limit: usize) -> Result<BufView, JsErrorBox> {
     let peekable = RcRef::map(self, |this| &this.0);
     let mut peekable = peekable.borrow_mut().await;
     match Pin::new(&mut *peekable).peek_mut().await {
@@ -84,10 +129,7 @@ impl Resource for HttpRequestBody {
   }
 
   fn read(self: Rc<Self>, limit: usize) -> AsyncResult<BufView> {
-    Box::pin(
-      HttpRequestBody::read(self, limit)
-        .map_err(|e| JsErrorBox::new("Http", e.to_string())),
-    )
+    Box::pin(HttpRequestBody::read(self, limit))
   }
 
   fn size_hint(&self) -> (u64, Option<u64>) {
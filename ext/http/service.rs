@@ -22,9 +22,10 @@ use deno_error::JsErrorBox;
 use http::request::Parts;
 use hyper::body::Body;
 use hyper::body::Frame;
-use hyper::body::Incoming;
 use hyper::body::SizeHint;
+use hyper::header::ALT_SVC;
 use hyper::header::HeaderMap;
+use hyper::header::HeaderValue;
 use hyper::upgrade::OnUpgrade;
 use scopeguard::ScopeGuard;
 use scopeguard::guard;
@@ -32,11 +33,13 @@ use tokio::sync::oneshot;
 
 use crate::OtelInfo;
 use crate::OtelInfoAttributes;
+use crate::request_body::IncomingBody;
 use crate::request_properties::HttpConnectionProperties;
+use crate::response_body::CompressionOptions;
 use crate::response_body::ResponseBytesInner;
 use crate::response_body::ResponseStreamResult;
 
-pub type Request = hyper::Request<Incoming>;
+pub type Request = hyper::Request<IncomingBody>;
 pub type Response = hyper::Response<HttpRecordResponse>;
 
 #[cfg(feature = "__http_tracing")]
@@ -137,13 +140,24 @@ impl<T> std::ops::Deref for SignallingRc<T> {
   }
 }
 
-pub(crate) struct HttpServerState(RefCell<HttpServerStateInner>);
+pub(crate) struct HttpServerState {
+  inner: RefCell<HttpServerStateInner>,
+  compression: CompressionOptions,
+  /// Value of the `Alt-Svc` header added to every response, used to
+  /// advertise an HTTP/3 endpoint serving the same origin.
+  alt_svc: Option<HeaderValue>,
+}
 
 impl HttpServerState {
-  pub fn new() -> SignallingRc<Self> {
-    SignallingRc::new(Self(RefCell::new(HttpServerStateInner {
-      pool: Vec::new(),
-    })))
+  pub fn new(
+    compression: CompressionOptions,
+    alt_svc: Option<HeaderValue>,
+  ) -> SignallingRc<Self> {
+    SignallingRc::new(Self {
+      inner: RefCell::new(HttpServerStateInner { pool: Vec::new() }),
+      compression,
+      alt_svc,
+    })
   }
 }
 
@@ -151,20 +165,20 @@ impl std::ops::Deref for HttpServerState {
   type Target = RefCell<HttpServerStateInner>;
 
   fn deref(&self) -> &Self::Target {
-    &self.0
+    &self.inner
   }
 }
 
 enum RequestBodyState {
-  Incoming(Incoming),
+  Incoming(IncomingBody),
   Resource(
     #[allow(dead_code, reason = "prevent drop until variant is dropped")]
     HttpRequestBodyAutocloser,
   ),
 }
 
-impl From<Incoming> for RequestBodyState {
-  fn from(value: Incoming) -> Self {
+impl From<IncomingBody> for RequestBodyState {
+  fn from(value: IncomingBody) -> Self {
     RequestBodyState::Incoming(value)
   }
 }
@@ -469,8 +483,8 @@ impl HttpRecord {
       .ok_or(UpgradeUnavailableError)
   }
 
-  /// Take the Hyper body from this record.
-  pub fn take_request_body(&self) -> Option<Incoming> {
+  /// Take the request body from this record.
+  pub fn take_request_body(&self) -> Option<IncomingBody> {
     let body_holder = &mut self.self_mut().request_body;
     let body = body_holder.take();
     match body {
@@ -565,7 +579,13 @@ impl HttpRecord {
 
   /// Take the response.
   fn into_response(self: Rc<Self>) -> Response {
-    let parts = self.self_mut().response_parts.take().unwrap();
+    let mut parts = self.self_mut().response_parts.take().unwrap();
+    if let Some(alt_svc) = &self.self_ref().server_state.alt_svc {
+      parts
+        .headers
+        .entry(ALT_SVC)
+        .or_insert_with(|| alt_svc.clone());
+    }
     let body = HttpRecordResponse(Some(ManuallyDrop::new(self)));
     Response::from_parts(parts, body)
   }
@@ -580,6 +600,12 @@ impl HttpRecord {
     Ref::map(self.self_ref(), |inner| &inner.request_parts)
   }
 
+  /// Get the response compression settings of the server that received this
+  /// request.
+  pub fn compression_options(&self) -> Ref<'_, CompressionOptions> {
+    Ref::map(self.self_ref(), |inner| &inner.server_state.compression)
+  }
+
   /// Resolves when response head is ready.
   fn response_ready(&self) -> impl Future<Output = ()> + '_ {
     struct HttpRecordReady<'a>(&'a HttpRecord);
@@ -712,6 +738,9 @@ impl Body for HttpRecordResponse {
         ResponseBytesInner::BrotliStream(stm) => {
           ready!(Pin::new(stm.as_mut()).poll_frame(cx))
         }
+        ResponseBytesInner::ZstdStream(stm) => {
+          ready!(Pin::new(stm.as_mut()).poll_frame(cx))
+        }
       };
       // This is where we retry the NoData response
       if matches!(res, ResponseStreamResult::NoData) {
@@ -779,6 +808,7 @@ mod tests {
   use bytes::Buf;
   use deno_net::raw::NetworkStreamType;
   use hyper::body::Body;
+  use hyper::body::Incoming;
   use hyper::service::HttpService;
   use hyper::service::service_fn;
   use hyper_util::rt::TokioIo;
@@ -825,7 +855,10 @@ mod tests {
   #[tokio::test]
   async fn test_handle_request() -> Result<(), deno_core::error::AnyError> {
     let (tx, mut rx) = tokio::sync::mpsc::channel(10);
-    let server_state = HttpServerState::new();
+    let server_state = HttpServerState::new(
+      Default::default(),
+      Some(HeaderValue::from_static("h3=\":443\"; ma=86400")),
+    );
     let server_state_check = server_state.clone();
     let request_info = HttpConnectionProperties {
       peer_address: "".into(),
@@ -835,7 +868,7 @@ mod tests {
     };
     let svc = service_fn(move |req: hyper::Request<Incoming>| {
       handle_request(
-        req,
+        req.map(IncomingBody::from),
         request_info.clone(),
         server_state.clone(),
         tx.clone(),
@@ -863,6 +896,7 @@ mod tests {
           // Client reads the response
           use http_body_util::BodyExt;
           assert_eq!(res.status(), 200);
+          assert_eq!(res.headers()[ALT_SVC], "h3=\":443\"; ma=86400");
           let body = res.collect().await?.to_bytes();
           assert_eq!(body.chunk(), b"hello world");
           Ok(())
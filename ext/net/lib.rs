@@ -22,6 +22,7 @@ use deno_features::FeatureChecker;
 use deno_tls::RootCertStoreProvider;
 use deno_tls::rustls::RootCertStore;
 pub use quic::QuicError;
+pub use quic::listen_quic_endpoint;
 
 pub const UNSTABLE_FEATURE_NAME: &str = "net";
 
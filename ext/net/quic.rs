@@ -325,6 +325,19 @@ pub(crate) fn op_quic_endpoint_listen(
     return Err(QuicError::CannotListen);
   }
 
+  let server_config = server_crypto_config(keys, args.alpn_protocols)?;
+  let mut config = quinn::ServerConfig::with_crypto(server_config.clone());
+  apply_server_transport_config(&mut config, transport_config)?;
+
+  endpoint.endpoint.set_server_config(Some(config));
+
+  Ok(ListenerResource(endpoint.endpoint.clone(), server_config))
+}
+
+fn server_crypto_config(
+  keys: &TlsKeysHolder,
+  alpn_protocols: Option<Vec<String>>,
+) -> Result<Arc<QuicServerConfig>, QuicError> {
   let TlsKeys::Static(deno_tls::TlsKey(cert, key)) = keys.take() else {
     return Err(QuicError::MissingTlsKey);
   };
@@ -339,22 +352,48 @@ pub(crate) fn op_quic_endpoint_listen(
   // required by QUIC spec.
   crypto.max_early_data_size = u32::MAX;
 
-  if let Some(alpn_protocols) = args.alpn_protocols {
+  if let Some(alpn_protocols) = alpn_protocols {
     crypto.alpn_protocols = alpn_protocols
       .into_iter()
       .map(|alpn| alpn.into_bytes())
       .collect();
   }
 
-  let server_config = Arc::new(
+  Ok(Arc::new(
     QuicServerConfig::try_from(crypto).expect("TLS13 is explicitly configured"),
-  );
-  let mut config = quinn::ServerConfig::with_crypto(server_config.clone());
-  apply_server_transport_config(&mut config, transport_config)?;
+  ))
+}
 
-  endpoint.endpoint.set_server_config(Some(config));
+/// Binds an endpoint that accepts QUIC connections on `hostname:port`, for
+/// servers implemented on top of QUIC in other extensions.
+///
+/// Unlike the endpoints of `Deno.QuicEndpoint`, the returned endpoint is
+/// not referenced from JavaScript, so its UDP socket is released as soon as
+/// the caller drops it.
+pub fn listen_quic_endpoint(
+  state: &mut OpState,
+  hostname: &str,
+  port: u16,
+  keys: &TlsKeysHolder,
+  alpn_protocols: Vec<String>,
+  api_name: &str,
+) -> Result<quinn::Endpoint, QuicError> {
+  let addr = resolve_addr_sync(hostname, port)?
+    .next()
+    .ok_or_else(|| QuicError::UnableToResolve)?;
+  state
+    .borrow_mut::<PermissionsContainer>()
+    .check_net(&(&addr.ip().to_string(), Some(addr.port())), api_name)?;
 
-  Ok(ListenerResource(endpoint.endpoint.clone(), server_config))
+  let server_config = server_crypto_config(keys, Some(alpn_protocols))?;
+  let socket = std::net::UdpSocket::bind(addr)?;
+  let endpoint = quinn::Endpoint::new(
+    quinn::EndpointConfig::default(),
+    Some(quinn::ServerConfig::with_crypto(server_config)),
+    socket,
+    quinn::default_runtime().unwrap(),
+  )?;
+  Ok(endpoint)
 }
 
 struct ConnectionResource(
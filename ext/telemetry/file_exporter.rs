@@ -0,0 +1,233 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Exporters that append OTLP/JSON encoded export requests to a local file,
+//! one request per line, as described by the OpenTelemetry "OTLP File
+//! Exporter" specification.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use deno_core::futures::future::BoxFuture;
+use deno_core::serde_json;
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::transform::common::tonic::ResourceAttributesWithSchema;
+use opentelemetry_proto::transform::logs::tonic::group_logs_by_resource_and_scope;
+use opentelemetry_proto::transform::trace::tonic::group_spans_by_resource_and_scope;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::export::logs::LogBatch;
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::metrics::MetricError;
+use opentelemetry_sdk::metrics::MetricResult;
+use opentelemetry_sdk::metrics::Temporality;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use serde::Serialize;
+
+/// A shared handle to the output file. Traces, logs and metrics are all
+/// written to the same file, so writes of whole lines are serialized.
+#[derive(Debug, Clone)]
+pub struct OtlpFile(Arc<Mutex<File>>);
+
+impl OtlpFile {
+  pub fn open(path: &Path) -> std::io::Result<Self> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    Ok(Self(Arc::new(Mutex::new(file))))
+  }
+
+  fn write_line(&self, request: &impl Serialize) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(request)?;
+    line.push(b'\n');
+    let mut file = self.0.lock().unwrap();
+    file.write_all(&line)?;
+    file.flush()
+  }
+}
+
+// ---- Span Exporter ----
+
+#[derive(Debug)]
+pub struct FileSpanExporter {
+  file: OtlpFile,
+  resource: ResourceAttributesWithSchema,
+}
+
+impl FileSpanExporter {
+  pub fn new(file: OtlpFile) -> Self {
+    Self {
+      file,
+      resource: ResourceAttributesWithSchema::default(),
+    }
+  }
+}
+
+impl opentelemetry_sdk::export::trace::SpanExporter for FileSpanExporter {
+  fn export(
+    &mut self,
+    batch: Vec<SpanData>,
+  ) -> BoxFuture<'static, opentelemetry_sdk::export::trace::ExportResult> {
+    let request = ExportTraceServiceRequest {
+      resource_spans: group_spans_by_resource_and_scope(batch, &self.resource),
+    };
+    let result = self
+      .file
+      .write_line(&request)
+      .map_err(|err| opentelemetry::trace::TraceError::Other(Box::new(err)));
+    Box::pin(std::future::ready(result))
+  }
+
+  fn shutdown(&mut self) {}
+
+  fn set_resource(&mut self, resource: &Resource) {
+    self.resource = resource.into();
+  }
+}
+
+// ---- Log Exporter ----
+
+#[derive(Debug)]
+pub struct FileLogExporter {
+  file: OtlpFile,
+  resource: ResourceAttributesWithSchema,
+}
+
+impl FileLogExporter {
+  pub fn new(file: OtlpFile) -> Self {
+    Self {
+      file,
+      resource: ResourceAttributesWithSchema::default(),
+    }
+  }
+}
+
+#[async_trait]
+impl opentelemetry_sdk::export::logs::LogExporter for FileLogExporter {
+  async fn export(
+    &mut self,
+    batch: LogBatch<'_>,
+  ) -> opentelemetry_sdk::export::logs::ExportResult {
+    let request = ExportLogsServiceRequest {
+      resource_logs: group_logs_by_resource_and_scope(batch, &self.resource),
+    };
+    self
+      .file
+      .write_line(&request)
+      .map_err(|err| opentelemetry_sdk::logs::LogError::Other(Box::new(err)))
+  }
+
+  fn shutdown(&mut self) {}
+
+  fn set_resource(&mut self, resource: &Resource) {
+    self.resource = resource.into();
+  }
+}
+
+// ---- Metric Exporter ----
+
+#[derive(Debug)]
+pub struct FileMetricExporter {
+  file: OtlpFile,
+  temporality: Temporality,
+}
+
+impl FileMetricExporter {
+  pub fn new(file: OtlpFile, temporality: Temporality) -> Self {
+    Self { file, temporality }
+  }
+}
+
+#[async_trait]
+impl opentelemetry_sdk::metrics::exporter::PushMetricExporter
+  for FileMetricExporter
+{
+  async fn export(&self, metrics: &mut ResourceMetrics) -> MetricResult<()> {
+    let request = ExportMetricsServiceRequest::from(&*metrics);
+    self
+      .file
+      .write_line(&request)
+      .map_err(|err| MetricError::Other(err.to_string()))
+  }
+
+  async fn force_flush(&self) -> MetricResult<()> {
+    Ok(())
+  }
+
+  fn shutdown(&self) -> MetricResult<()> {
+    Ok(())
+  }
+
+  fn temporality(&self) -> Temporality {
+    self.temporality
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::borrow::Cow;
+  use std::time::SystemTime;
+
+  use opentelemetry::InstrumentationScope;
+  use opentelemetry::trace::SpanContext;
+  use opentelemetry::trace::SpanId;
+  use opentelemetry::trace::SpanKind;
+  use opentelemetry::trace::Status;
+  use opentelemetry::trace::TraceFlags;
+  use opentelemetry::trace::TraceId;
+  use opentelemetry::trace::TraceState;
+  use opentelemetry_sdk::export::trace::SpanExporter;
+  use opentelemetry_sdk::trace::SpanEvents;
+  use opentelemetry_sdk::trace::SpanLinks;
+
+  use super::*;
+
+  fn span(name: &'static str) -> SpanData {
+    SpanData {
+      span_context: SpanContext::new(
+        TraceId::from_u128(1),
+        SpanId::from_u64(1),
+        TraceFlags::SAMPLED,
+        false,
+        TraceState::NONE,
+      ),
+      parent_span_id: SpanId::INVALID,
+      span_kind: SpanKind::Internal,
+      name: Cow::Borrowed(name),
+      start_time: SystemTime::UNIX_EPOCH,
+      end_time: SystemTime::UNIX_EPOCH,
+      attributes: vec![],
+      dropped_attributes_count: 0,
+      events: SpanEvents::default(),
+      links: SpanLinks::default(),
+      status: Status::Unset,
+      instrumentation_scope: InstrumentationScope::builder("test").build(),
+    }
+  }
+
+  #[test]
+  fn test_span_exporter_writes_json_lines() {
+    let dir = std::env::temp_dir()
+      .join(format!("deno_otel_file_exporter_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("otel.jsonl");
+    let file = OtlpFile::open(&path).unwrap();
+    let mut exporter = FileSpanExporter::new(file);
+    deno_core::futures::executor::block_on(exporter.export(vec![span("a")]))
+      .unwrap();
+    deno_core::futures::executor::block_on(exporter.export(vec![span("b")]))
+      .unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let lines = contents.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    let value: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+    let span = &value["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+    assert_eq!(span["name"], "b");
+    assert_eq!(span["traceId"], "00000000000000000000000000000001");
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+}
@@ -14,6 +14,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::c_void;
 use std::fmt::Debug;
+use std::path::PathBuf;
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -72,6 +73,7 @@ use opentelemetry_otlp::HttpExporterBuilder;
 use opentelemetry_otlp::Protocol;
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_otlp::WithHttpConfig;
+use opentelemetry_otlp::WithTonicConfig;
 use opentelemetry_sdk::Resource;
 use opentelemetry_sdk::export::trace::SpanData;
 use opentelemetry_sdk::logs::BatchLogProcessor;
@@ -103,6 +105,10 @@ use tokio::sync::oneshot;
 use tokio::task::JoinSet;
 
 mod console_exporter;
+mod file_exporter;
+mod sampler;
+
+pub use sampler::OtelSampler;
 
 deno_core::extension!(
   deno_telemetry,
@@ -145,6 +151,7 @@ pub struct OtelConfig {
   pub console: OtelConsoleConfig,
   pub deterministic_prefix: Option<u8>,
   pub propagators: std::collections::HashSet<OtelPropagators>,
+  pub sampler: OtelSampler,
 }
 
 impl OtelConfig {
@@ -552,7 +559,7 @@ mod hyper_client {
   use super::OtelSharedRuntime;
 
   #[derive(Debug, thiserror::Error)]
-  enum Error {
+  pub enum Error {
     #[error(transparent)]
     StdIo(#[from] std::io::Error),
     #[error(transparent)]
@@ -562,7 +569,7 @@ mod hyper_client {
   }
 
   #[derive(Debug, Clone)]
-  enum Connector {
+  pub enum Connector {
     Http(HttpsConnector<HttpConnector>),
     Tunnel(TunnelConnection),
     #[cfg(any(
@@ -575,7 +582,7 @@ mod hyper_client {
 
   #[allow(clippy::large_enum_variant, reason = "TODO: investigate")]
   #[pin_project::pin_project(project = IOProj)]
-  enum IO {
+  pub enum IO {
     Tls(#[pin] TokioIo<MaybeHttpsStream<TokioIo<TcpStream>>>),
     Tunnel(#[pin] TunnelStream),
     #[cfg(any(
@@ -753,12 +760,7 @@ mod hyper_client {
     }
   }
 
-  #[derive(Debug, Clone)]
-  pub struct HyperClient {
-    inner: Client<Connector, Full<Bytes>>,
-  }
-
-  impl HyperClient {
+  impl Connector {
     pub fn new(sys: &impl FsRead) -> deno_core::anyhow::Result<Self> {
       let connector = if let Some(tunnel) = get_tunnel() {
         Connector::Tunnel(tunnel.clone())
@@ -823,13 +825,46 @@ mod hyper_client {
         let connector = HttpsConnector::from((http_connector, tls_config));
         Connector::Http(connector)
       };
+      Ok(connector)
+    }
+  }
+
+  #[derive(Debug, Clone)]
+  pub struct HyperClient {
+    inner: Client<Connector, Full<Bytes>>,
+  }
 
+  impl HyperClient {
+    pub fn new(sys: &impl FsRead) -> deno_core::anyhow::Result<Self> {
+      let connector = Connector::new(sys)?;
       Ok(Self {
         inner: Client::builder(OtelSharedRuntime).build(connector),
       })
     }
   }
 
+  /// Creates a lazily connected gRPC channel to the OTLP endpoint of a signal.
+  ///
+  /// The channel shares its connector (TLS configuration, tunnel and vsock
+  /// support) with the HTTP exporters and runs on the shared OpenTelemetry
+  /// runtime.
+  pub fn grpc_channel(
+    sys: &impl super::TelemetrySys,
+    connector: Connector,
+    signal_endpoint_var: &str,
+  ) -> deno_core::anyhow::Result<tonic::transport::Channel> {
+    // Per the spec, signal specific endpoints are used as-is for gRPC, the
+    // same as the generic endpoint.
+    let endpoint = sys
+      .env_var(signal_endpoint_var)
+      .or_else(|_| sys.env_var("OTEL_EXPORTER_OTLP_ENDPOINT"))
+      .unwrap_or_else(|_| "http://localhost:4317".to_string());
+    let channel = tonic::transport::Endpoint::from_shared(endpoint)?
+      .executor(OtelSharedRuntime)
+      .connect_with_connector_lazy(connector);
+    Ok(channel)
+  }
+
   #[async_trait::async_trait]
   impl opentelemetry_http::HttpClient for HyperClient {
     async fn send(
@@ -886,14 +921,22 @@ pub fn init(
 
   // Parse the `OTEL_EXPORTER_OTLP_PROTOCOL` variable. The opentelemetry_*
   // crates don't do this automatically.
-  // TODO(piscisaureus): enable GRPC support.
   let protocol_var = sys.env_var("OTEL_EXPORTER_OTLP_PROTOCOL");
-  let (use_console_exporter, protocol) = match protocol_var.as_deref() {
-    Ok("console") => (true, Protocol::HttpBinary),
+  let exporter = match protocol_var.as_deref() {
+    Ok("console") => OtelExporter::Console,
     Ok("http/protobuf") | Ok("") | Err(std::env::VarError::NotPresent) => {
-      (false, Protocol::HttpBinary)
+      OtelExporter::Http(Protocol::HttpBinary)
     }
-    Ok("http/json") => (false, Protocol::HttpJson),
+    Ok("http/json") => OtelExporter::Http(Protocol::HttpJson),
+    Ok("grpc") => OtelExporter::Grpc,
+    Ok("file") => match sys.env_var("OTEL_DENO_FILE") {
+      Ok(path) if !path.is_empty() => OtelExporter::File(PathBuf::from(path)),
+      _ => {
+        return Err(deno_core::anyhow::anyhow!(
+          "Env var OTEL_EXPORTER_OTLP_PROTOCOL is set to 'file', but OTEL_DENO_FILE is not set"
+        ));
+      }
+    },
     Ok(protocol) => {
       return Err(deno_core::anyhow::anyhow!(
         "Env var OTEL_EXPORTER_OTLP_PROTOCOL specifies an unsupported protocol: {}",
@@ -965,57 +1008,81 @@ pub fn init(
     }
   };
 
-  let (span_processor, meter_provider, log_processor) = if use_console_exporter
-  {
-    let span_exporter = console_exporter::ConsoleSpanExporter::new();
-    let mut span_processor =
-      BatchSpanProcessor::builder(span_exporter, OtelSharedRuntime).build();
-    span_processor.set_resource(&resource);
-
-    let metric_exporter =
-      console_exporter::ConsoleMetricExporter::new(temporality);
-    let metric_reader = DenoPeriodicReader::new(sys, metric_exporter);
-    let meter_provider = SdkMeterProvider::builder()
-      .with_reader(metric_reader)
-      .with_resource(resource.clone())
-      .build();
-
-    let log_exporter = console_exporter::ConsoleLogExporter::new();
-    let log_processor =
-      BatchLogProcessor::builder(log_exporter, OtelSharedRuntime).build();
-    log_processor.set_resource(&resource);
-
-    (span_processor, meter_provider, log_processor)
-  } else {
-    let client = hyper_client::HyperClient::new(sys)?;
-
-    let span_exporter = HttpExporterBuilder::default()
-      .with_http_client(client.clone())
-      .with_protocol(protocol)
-      .build_span_exporter()?;
-    let mut span_processor =
-      BatchSpanProcessor::builder(span_exporter, OtelSharedRuntime).build();
-    span_processor.set_resource(&resource);
-
-    let metric_exporter = HttpExporterBuilder::default()
-      .with_http_client(client.clone())
-      .with_protocol(protocol)
-      .build_metrics_exporter(temporality)?;
-    let metric_reader = DenoPeriodicReader::new(sys, metric_exporter);
-    let meter_provider = SdkMeterProvider::builder()
-      .with_reader(metric_reader)
-      .with_resource(resource.clone())
-      .build();
-
-    let log_exporter = HttpExporterBuilder::default()
-      .with_http_client(client)
-      .with_protocol(protocol)
-      .build_log_exporter()?;
-    let log_processor =
-      BatchLogProcessor::builder(log_exporter, OtelSharedRuntime).build();
-    log_processor.set_resource(&resource);
-
-    (span_processor, meter_provider, log_processor)
+  let (span_processor, meter_provider, log_processor) = match exporter {
+    OtelExporter::Console => build_processors(
+      sys,
+      &resource,
+      console_exporter::ConsoleSpanExporter::new(),
+      console_exporter::ConsoleMetricExporter::new(temporality),
+      console_exporter::ConsoleLogExporter::new(),
+    ),
+    OtelExporter::Http(protocol) => {
+      let client = hyper_client::HyperClient::new(sys)?;
+      build_processors(
+        sys,
+        &resource,
+        HttpExporterBuilder::default()
+          .with_http_client(client.clone())
+          .with_protocol(protocol)
+          .build_span_exporter()?,
+        HttpExporterBuilder::default()
+          .with_http_client(client.clone())
+          .with_protocol(protocol)
+          .build_metrics_exporter(temporality)?,
+        HttpExporterBuilder::default()
+          .with_http_client(client)
+          .with_protocol(protocol)
+          .build_log_exporter()?,
+      )
+    }
+    OtelExporter::Grpc => {
+      let connector = hyper_client::Connector::new(sys)?;
+      build_processors(
+        sys,
+        &resource,
+        opentelemetry_otlp::SpanExporter::builder()
+          .with_tonic()
+          .with_channel(hyper_client::grpc_channel(
+            sys,
+            connector.clone(),
+            "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+          )?)
+          .build()?,
+        opentelemetry_otlp::MetricExporter::builder()
+          .with_tonic()
+          .with_channel(hyper_client::grpc_channel(
+            sys,
+            connector.clone(),
+            "OTEL_EXPORTER_OTLP_METRICS_ENDPOINT",
+          )?)
+          .with_temporality(temporality)
+          .build()?,
+        opentelemetry_otlp::LogExporter::builder()
+          .with_tonic()
+          .with_channel(hyper_client::grpc_channel(
+            sys,
+            connector,
+            "OTEL_EXPORTER_OTLP_LOGS_ENDPOINT",
+          )?)
+          .build()?,
+      )
+    }
+    OtelExporter::File(path) => {
+      let file = file_exporter::OtlpFile::open(&path).map_err(|err| {
+        deno_core::anyhow::anyhow!(
+          "Failed to open OpenTelemetry export file '{}': {}",
+          path.display(),
+          err
+        )
+      })?;
+      build_processors(
+        sys,
+        &resource,
+        file_exporter::FileSpanExporter::new(file.clone()),
+        file_exporter::FileMetricExporter::new(file.clone(), temporality),
+        file_exporter::FileLogExporter::new(file),
+      )
+    }
   };
 
   let builtin_instrumentation_scope =
@@ -1046,6 +1113,44 @@ pub fn init(
   Ok(())
 }
 
+/// Where telemetry data is exported to, selected through the
+/// `OTEL_EXPORTER_OTLP_PROTOCOL` env var. The `file` protocol appends OTLP
+/// JSON lines to the path in the `OTEL_DENO_FILE` env var.
+enum OtelExporter {
+  Console,
+  Http(Protocol),
+  Grpc,
+  File(PathBuf),
+}
+
+fn build_processors(
+  sys: &impl TelemetrySys,
+  resource: &Resource,
+  span_exporter: impl opentelemetry_sdk::export::trace::SpanExporter + 'static,
+  metric_exporter: impl PushMetricExporter + 'static,
+  log_exporter: impl opentelemetry_sdk::export::logs::LogExporter + 'static,
+) -> (
+  BatchSpanProcessor<OtelSharedRuntime>,
+  SdkMeterProvider,
+  BatchLogProcessor<OtelSharedRuntime>,
+) {
+  let mut span_processor =
+    BatchSpanProcessor::builder(span_exporter, OtelSharedRuntime).build();
+  span_processor.set_resource(resource);
+
+  let metric_reader = DenoPeriodicReader::new(sys, metric_exporter);
+  let meter_provider = SdkMeterProvider::builder()
+    .with_reader(metric_reader)
+    .with_resource(resource.clone())
+    .build();
+
+  let log_processor =
+    BatchLogProcessor::builder(log_exporter, OtelSharedRuntime).build();
+  log_processor.set_resource(resource);
+
+  (span_processor, meter_provider, log_processor)
+}
+
 fn before_exit() {
   log::trace!("deno_telemetry::before_exit");
 
@@ -1613,7 +1718,11 @@ impl OtelTracer {
     start_time: Option<f64>,
     #[smi] attribute_count: usize,
   ) -> Result<OtelSpan, JsErrorBox> {
-    let OtelGlobals { id_generator, .. } = OTEL_GLOBALS
+    let OtelGlobals {
+      id_generator,
+      config,
+      ..
+    } = OTEL_GLOBALS
       .get()
       .ok_or_else(|| JsErrorBox::generic("otel not initialized"))?;
     let span_context;
@@ -1625,20 +1734,24 @@ impl OtelTracer {
           OtelSpanState::Recording(span) => &span.span_context,
           OtelSpanState::Done(span_context) => span_context,
         };
+        let trace_id = parent_span_context.trace_id();
         span_context = SpanContext::new(
-          parent_span_context.trace_id(),
+          trace_id,
           id_generator.new_span_id(),
-          TraceFlags::SAMPLED,
+          config
+            .sampler
+            .sample(Some(parent_span_context.is_sampled()), trace_id),
           false,
           parent_span_context.trace_state().clone(),
         );
         parent_span_id = parent_span_context.span_id();
       }
       None => {
+        let trace_id = id_generator.new_trace_id();
         span_context = SpanContext::new(
-          id_generator.new_trace_id(),
+          trace_id,
           id_generator.new_span_id(),
-          TraceFlags::SAMPLED,
+          config.sampler.sample(None, trace_id),
           false,
           TraceState::NONE,
         );
@@ -1691,6 +1804,7 @@ impl OtelTracer {
     scope: &mut v8::PinScope<'s, '_>,
     parent_trace_id: v8::Local<'s, v8::Value>,
     parent_span_id: v8::Local<'s, v8::Value>,
+    #[smi] parent_trace_flags: u8,
     name: v8::Local<'s, v8::Value>,
     #[smi] span_kind: u8,
     start_time: Option<f64>,
@@ -1704,13 +1818,18 @@ impl OtelTracer {
     if parent_span_id == SpanId::INVALID {
       return Err(JsErrorBox::generic("invalid span id"));
     };
-    let OtelGlobals { id_generator, .. } = OTEL_GLOBALS
+    let OtelGlobals {
+      id_generator,
+      config,
+      ..
+    } = OTEL_GLOBALS
       .get()
       .ok_or_else(|| JsErrorBox::generic("otel not initialized"))?;
+    let parent_sampled = TraceFlags::new(parent_trace_flags).is_sampled();
     let span_context = SpanContext::new(
       parent_trace_id,
       id_generator.new_span_id(),
-      TraceFlags::SAMPLED,
+      config.sampler.sample(Some(parent_sampled), parent_trace_id),
       false,
       TraceState::NONE,
     );
@@ -1885,6 +2004,11 @@ impl OtelSpan {
         &mut *state,
         Box::new(OtelSpanState::Done(span_context)),
       ) {
+        // Spans that were not sampled are still tracked so that their
+        // context propagates, but they are never exported.
+        if !span.span_context.is_sampled() {
+          return;
+        }
         span.end_time = end_time;
         let Some(OtelGlobals { span_processor, .. }) = OTEL_GLOBALS.get()
         else {
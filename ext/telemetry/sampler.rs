@@ -0,0 +1,176 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use opentelemetry::trace::TraceFlags;
+use opentelemetry::trace::TraceId;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Trace sampler, configured through the `OTEL_TRACES_SAMPLER` and
+/// `OTEL_TRACES_SAMPLER_ARG` environment variables.
+///
+/// See https://opentelemetry.io/docs/specs/otel/configuration/sdk-environment-variables/#general-sdk-configuration
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OtelSampler {
+  AlwaysOn,
+  AlwaysOff,
+  TraceIdRatio(f64),
+  ParentBasedAlwaysOn,
+  ParentBasedAlwaysOff,
+  ParentBasedTraceIdRatio(f64),
+}
+
+impl Default for OtelSampler {
+  fn default() -> Self {
+    Self::ParentBasedAlwaysOn
+  }
+}
+
+impl OtelSampler {
+  /// Parses the values of `OTEL_TRACES_SAMPLER` and `OTEL_TRACES_SAMPLER_ARG`.
+  ///
+  /// Returns the sampler to use together with a warning if a value was not
+  /// recognized, in which case the spec mandated fallback is used.
+  pub fn from_env_values(
+    sampler: Option<&str>,
+    arg: Option<&str>,
+  ) -> (Self, Option<String>) {
+    let parse_ratio = || -> (f64, Option<String>) {
+      match arg.map(|arg| arg.trim().parse::<f64>()) {
+        None => (1.0, None),
+        Some(Ok(ratio)) if (0.0..=1.0).contains(&ratio) => (ratio, None),
+        Some(_) => (
+          1.0,
+          Some(format!(
+            "'OTEL_TRACES_SAMPLER_ARG' env var value '{}' is not a ratio between 0 and 1, using 1.0",
+            arg.unwrap_or_default()
+          )),
+        ),
+      }
+    };
+    match sampler.map(|s| s.trim()) {
+      None | Some("") => (Self::default(), None),
+      Some("always_on") => (Self::AlwaysOn, None),
+      Some("always_off") => (Self::AlwaysOff, None),
+      Some("traceidratio") => {
+        let (ratio, warning) = parse_ratio();
+        (Self::TraceIdRatio(ratio), warning)
+      }
+      Some("parentbased_always_on") => (Self::ParentBasedAlwaysOn, None),
+      Some("parentbased_always_off") => (Self::ParentBasedAlwaysOff, None),
+      Some("parentbased_traceidratio") => {
+        let (ratio, warning) = parse_ratio();
+        (Self::ParentBasedTraceIdRatio(ratio), warning)
+      }
+      Some(other) => (
+        Self::default(),
+        Some(format!(
+          "'OTEL_TRACES_SAMPLER' env var value '{other}' not recognized, using 'parentbased_always_on'"
+        )),
+      ),
+    }
+  }
+
+  /// Decides whether a span is sampled and returns the trace flags for its
+  /// span context. `parent_sampled` is the sampled flag of the (local or
+  /// remote) parent span, if there is one.
+  pub fn sample(
+    &self,
+    parent_sampled: Option<bool>,
+    trace_id: TraceId,
+  ) -> TraceFlags {
+    let sampled = match (self, parent_sampled) {
+      (Self::AlwaysOn, _) => true,
+      (Self::AlwaysOff, _) => false,
+      (Self::TraceIdRatio(ratio), _) => trace_id_ratio(*ratio, trace_id),
+      (
+        Self::ParentBasedAlwaysOn
+        | Self::ParentBasedAlwaysOff
+        | Self::ParentBasedTraceIdRatio(_),
+        Some(sampled),
+      ) => sampled,
+      (Self::ParentBasedAlwaysOn, None) => true,
+      (Self::ParentBasedAlwaysOff, None) => false,
+      (Self::ParentBasedTraceIdRatio(ratio), None) => {
+        trace_id_ratio(*ratio, trace_id)
+      }
+    };
+    if sampled {
+      TraceFlags::SAMPLED
+    } else {
+      TraceFlags::default()
+    }
+  }
+}
+
+/// Same algorithm as the `TraceIdRatioBased` sampler of the OpenTelemetry
+/// SDK, so that sampling decisions are consistent across services.
+fn trace_id_ratio(ratio: f64, trace_id: TraceId) -> bool {
+  let bytes = trace_id.to_bytes();
+  let (_, low) = bytes.split_at(8);
+  let trace_id_low = u64::from_be_bytes(low.try_into().unwrap());
+  let rnd_from_trace_id = trace_id_low >> 1;
+  let prob_upper_bound = (ratio.max(0.0) * (1u64 << 63) as f64) as u64;
+  rnd_from_trace_id < prob_upper_bound
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn trace_id(low: u64) -> TraceId {
+    let mut bytes = [0u8; 16];
+    bytes[8..].copy_from_slice(&low.to_be_bytes());
+    TraceId::from_bytes(bytes)
+  }
+
+  #[test]
+  fn test_from_env_values() {
+    assert_eq!(
+      OtelSampler::from_env_values(None, None),
+      (OtelSampler::ParentBasedAlwaysOn, None)
+    );
+    assert_eq!(
+      OtelSampler::from_env_values(Some("always_off"), None),
+      (OtelSampler::AlwaysOff, None)
+    );
+    assert_eq!(
+      OtelSampler::from_env_values(Some("traceidratio"), Some("0.25")),
+      (OtelSampler::TraceIdRatio(0.25), None)
+    );
+    assert_eq!(
+      OtelSampler::from_env_values(Some("parentbased_traceidratio"), None),
+      (OtelSampler::ParentBasedTraceIdRatio(1.0), None)
+    );
+    let (sampler, warning) =
+      OtelSampler::from_env_values(Some("traceidratio"), Some("2"));
+    assert_eq!(sampler, OtelSampler::TraceIdRatio(1.0));
+    assert!(warning.is_some());
+    let (sampler, warning) =
+      OtelSampler::from_env_values(Some("jaeger_remote"), None);
+    assert_eq!(sampler, OtelSampler::ParentBasedAlwaysOn);
+    assert!(warning.is_some());
+  }
+
+  #[test]
+  fn test_sample() {
+    let low = trace_id(0);
+    let high = trace_id(u64::MAX);
+    let ratio = OtelSampler::TraceIdRatio(0.5);
+    assert!(ratio.sample(None, low).is_sampled());
+    assert!(!ratio.sample(None, high).is_sampled());
+    // Non parent-based samplers ignore the parent decision.
+    assert!(!ratio.sample(Some(true), high).is_sampled());
+
+    let parent_based = OtelSampler::ParentBasedTraceIdRatio(0.5);
+    assert!(parent_based.sample(Some(true), high).is_sampled());
+    assert!(!parent_based.sample(Some(false), low).is_sampled());
+    assert!(parent_based.sample(None, low).is_sampled());
+
+    assert!(
+      !OtelSampler::ParentBasedAlwaysOff
+        .sample(None, low)
+        .is_sampled()
+    );
+    assert!(!OtelSampler::AlwaysOff.sample(Some(true), low).is_sampled());
+  }
+}
@@ -29,6 +29,7 @@ use url::Url;
 
 use crate::UrlToFilePathError;
 use crate::glob::FilePatterns;
+use crate::glob::PathOrPattern;
 use crate::glob::PathOrPatternSet;
 use crate::import_map::imports_values;
 use crate::import_map::scope_values;
@@ -149,6 +150,12 @@ pub enum IntoResolvedErrorKind {
   #[class(inherit)]
   #[error(transparent)]
   UndefinedPermission(#[from] UndefinedPermissionError),
+  #[class(inherit)]
+  #[error("Invalid coverage threshold path: {0}")]
+  InvalidCoverageThresholdPath(crate::glob::PathOrPatternParseError),
+  #[class(type)]
+  #[error("Coverage thresholds must be between 0 and 100, got {0}")]
+  InvalidCoverageThreshold(u8),
 }
 
 #[derive(Debug, Error, JsError)]
@@ -598,6 +605,92 @@ impl ExportsConfig {
   }
 }
 
+/// Minimum coverage percentages enforced by `deno coverage`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CoverageThresholds {
+  pub lines: Option<u8>,
+  pub branches: Option<u8>,
+  pub functions: Option<u8>,
+}
+
+impl CoverageThresholds {
+  pub fn is_empty(&self) -> bool {
+    self.lines.is_none() && self.branches.is_none() && self.functions.is_none()
+  }
+
+  /// Returns these thresholds with the unset ones taken from `other`.
+  pub fn or(self, other: CoverageThresholds) -> CoverageThresholds {
+    CoverageThresholds {
+      lines: self.lines.or(other.lines),
+      branches: self.branches.or(other.branches),
+      functions: self.functions.or(other.functions),
+    }
+  }
+
+  fn validate(&self) -> Result<(), IntoResolvedErrorKind> {
+    for value in [self.lines, self.branches, self.functions]
+      .into_iter()
+      .flatten()
+    {
+      if value > 100 {
+        return Err(IntoResolvedErrorKind::InvalidCoverageThreshold(value));
+      }
+    }
+    Ok(())
+  }
+}
+
+/// `test.coverageThresholds` config representation for serde
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+struct SerializedCoverageThresholdsConfig {
+  pub lines: Option<u8>,
+  pub branches: Option<u8>,
+  pub functions: Option<u8>,
+  pub per_path: IndexMap<String, CoverageThresholds>,
+}
+
+impl SerializedCoverageThresholdsConfig {
+  pub fn into_resolved(
+    self,
+    config_file_specifier: &Url,
+  ) -> Result<CoverageThresholdsConfig, IntoResolvedError> {
+    let config_dir = url_to_file_path(&url_parent(config_file_specifier))?;
+    let global = CoverageThresholds {
+      lines: self.lines,
+      branches: self.branches,
+      functions: self.functions,
+    };
+    global.validate()?;
+    let per_path = self
+      .per_path
+      .into_iter()
+      .map(|(path, thresholds)| {
+        thresholds.validate()?;
+        let path = PathOrPattern::from_relative(&config_dir, &path)
+          .map_err(IntoResolvedErrorKind::InvalidCoverageThresholdPath)?;
+        Ok((path, thresholds))
+      })
+      .collect::<Result<Vec<_>, IntoResolvedError>>()?;
+    Ok(CoverageThresholdsConfig { global, per_path })
+  }
+}
+
+/// Coverage thresholds for all files and for the files matching a path or
+/// glob.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct CoverageThresholdsConfig {
+  pub global: CoverageThresholds,
+  pub per_path: Vec<(PathOrPattern, CoverageThresholds)>,
+}
+
+impl CoverageThresholdsConfig {
+  pub fn is_empty(&self) -> bool {
+    self.global.is_empty() && self.per_path.is_empty()
+  }
+}
+
 /// `test` config representation for serde
 ///
 /// fields `include` and `exclude` are expanded from [SerializedFilesConfig].
@@ -609,6 +702,8 @@ struct SerializedTestConfig {
   #[serde(rename = "files")]
   pub deprecated_files: serde_json::Value,
   pub permissions: Option<PermissionNameOrObject>,
+  #[serde(rename = "coverageThresholds")]
+  pub coverage_thresholds: Option<SerializedCoverageThresholdsConfig>,
 }
 
 impl SerializedTestConfig {
@@ -638,6 +733,10 @@ impl SerializedTestConfig {
         }
         None => None,
       },
+      coverage_thresholds: match self.coverage_thresholds {
+        Some(thresholds) => thresholds.into_resolved(config_file_specifier)?,
+        None => Default::default(),
+      },
     })
   }
 }
@@ -646,6 +745,7 @@ impl SerializedTestConfig {
 pub struct TestConfig {
   pub files: FilePatterns,
   pub permissions: Option<Box<PermissionsObjectWithBase>>,
+  pub coverage_thresholds: CoverageThresholdsConfig,
 }
 
 impl TestConfig {
@@ -653,6 +753,7 @@ impl TestConfig {
     Self {
       files: FilePatterns::new_with_base(base),
       permissions: None,
+      coverage_thresholds: Default::default(),
     }
   }
 }
@@ -1917,6 +2018,7 @@ impl ConfigFile {
       None => Ok(TestConfig {
         files: self.to_exclude_files_config()?,
         permissions: None,
+        coverage_thresholds: Default::default(),
       }),
     }
   }
@@ -2645,6 +2747,49 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_parse_config_coverage_thresholds() {
+    let config_text = r#"{
+      "test": {
+        "coverageThresholds": {
+          "lines": 80,
+          "branches": 70,
+          "perPath": {
+            "src/core/": { "lines": 95, "functions": 100 }
+          }
+        }
+      }
+    }"#;
+    let config_specifier = Url::parse("file:///deno/deno.json").unwrap();
+    let config_file = ConfigFile::new(config_text, config_specifier).unwrap();
+
+    let test_config = config_file.to_test_config(&Default::default()).unwrap();
+    assert_eq!(
+      test_config.coverage_thresholds,
+      CoverageThresholdsConfig {
+        global: CoverageThresholds {
+          lines: Some(80),
+          branches: Some(70),
+          functions: None,
+        },
+        per_path: vec![(
+          PathOrPattern::Path(PathBuf::from("/deno/src/core/")),
+          CoverageThresholds {
+            lines: Some(95),
+            branches: None,
+            functions: Some(100),
+          }
+        )],
+      }
+    );
+
+    let config_text =
+      r#"{ "test": { "coverageThresholds": { "lines": 101 } } }"#;
+    let config_specifier = Url::parse("file:///deno/deno.json").unwrap();
+    let config_file = ConfigFile::new(config_text, config_specifier).unwrap();
+    assert!(config_file.to_test_config(&Default::default()).is_err());
+  }
+
   #[test]
   fn test_parse_config_publish() {
     let config_text = r#"{
@@ -64,6 +64,19 @@ impl<'de> serde::Deserialize<'de> for PermissionConfigValue {
   }
 }
 
+impl serde::Serialize for PermissionConfigValue {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    match self {
+      PermissionConfigValue::All => serializer.serialize_bool(true),
+      PermissionConfigValue::Some(items) => items.serialize(serializer),
+      PermissionConfigValue::None => serializer.serialize_bool(false),
+    }
+  }
+}
+
 #[derive(Deserialize, Default, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(default, deny_unknown_fields)]
 pub struct AllowDenyPermissionConfig {
@@ -77,6 +90,29 @@ impl AllowDenyPermissionConfig {
   }
 }
 
+/// Serializes to the shorthand form (`true` or a list) when only `allow` is
+/// set, which is what `deserialize_allow_deny` accepts back.
+impl serde::Serialize for AllowDenyPermissionConfig {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    use serde::ser::SerializeMap;
+
+    if let (Some(allow), None) = (&self.allow, &self.deny) {
+      return allow.serialize(serializer);
+    }
+    let mut map = serializer.serialize_map(None)?;
+    if let Some(allow) = &self.allow {
+      map.serialize_entry("allow", allow)?;
+    }
+    if let Some(deny) = &self.deny {
+      map.serialize_entry("deny", deny)?;
+    }
+    map.end()
+  }
+}
+
 #[derive(Deserialize, Default, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(default, deny_unknown_fields)]
 pub struct AllowDenyIgnorePermissionConfig {
@@ -91,6 +127,30 @@ impl AllowDenyIgnorePermissionConfig {
   }
 }
 
+impl serde::Serialize for AllowDenyIgnorePermissionConfig {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    use serde::ser::SerializeMap;
+
+    if let (Some(allow), None, None) = (&self.allow, &self.deny, &self.ignore) {
+      return allow.serialize(serializer);
+    }
+    let mut map = serializer.serialize_map(None)?;
+    if let Some(allow) = &self.allow {
+      map.serialize_entry("allow", allow)?;
+    }
+    if let Some(deny) = &self.deny {
+      map.serialize_entry("deny", deny)?;
+    }
+    if let Some(ignore) = &self.ignore {
+      map.serialize_entry("ignore", ignore)?;
+    }
+    map.end()
+  }
+}
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 pub enum AllowDenyPermissionConfigValue {
@@ -177,26 +237,60 @@ pub struct PermissionsObjectWithBase {
   pub permissions: PermissionsObject,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Default, Hash)]
+#[derive(
+  Clone, Debug, PartialEq, Eq, Deserialize, serde::Serialize, Default, Hash,
+)]
 #[serde(default, deny_unknown_fields)]
 pub struct PermissionsObject {
-  #[serde(default)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
   pub all: Option<bool>,
-  #[serde(default, deserialize_with = "deserialize_allow_deny_ignore")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny_ignore",
+    skip_serializing_if = "AllowDenyIgnorePermissionConfig::is_none"
+  )]
   pub read: AllowDenyIgnorePermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny",
+    skip_serializing_if = "AllowDenyPermissionConfig::is_none"
+  )]
   pub write: AllowDenyPermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny",
+    skip_serializing_if = "AllowDenyPermissionConfig::is_none"
+  )]
   pub import: AllowDenyPermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny_ignore")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny_ignore",
+    skip_serializing_if = "AllowDenyIgnorePermissionConfig::is_none"
+  )]
   pub env: AllowDenyIgnorePermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny",
+    skip_serializing_if = "AllowDenyPermissionConfig::is_none"
+  )]
   pub net: AllowDenyPermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny",
+    skip_serializing_if = "AllowDenyPermissionConfig::is_none"
+  )]
   pub run: AllowDenyPermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny",
+    skip_serializing_if = "AllowDenyPermissionConfig::is_none"
+  )]
   pub ffi: AllowDenyPermissionConfig,
-  #[serde(default, deserialize_with = "deserialize_allow_deny")]
+  #[serde(
+    default,
+    deserialize_with = "deserialize_allow_deny",
+    skip_serializing_if = "AllowDenyPermissionConfig::is_none"
+  )]
   pub sys: AllowDenyPermissionConfig,
 }
 
@@ -441,4 +535,23 @@ mod test {
       }
     );
   }
+
+  #[test]
+  fn serialize_round_trips() {
+    let value = json!({
+      "read": ["./data"],
+      "env": {
+        "allow": ["HOME"],
+        "ignore": true,
+      },
+      "net": {
+        "allow": true,
+        "deny": ["example.com:443"],
+      },
+      "sys": false,
+    });
+    let object =
+      serde_json::from_value::<PermissionsObject>(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&object).unwrap(), value);
+  }
 }
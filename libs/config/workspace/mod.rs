@@ -48,6 +48,7 @@ use crate::deno_json::ConfigFile;
 use crate::deno_json::ConfigFileError;
 use crate::deno_json::ConfigFileRc;
 use crate::deno_json::ConfigFileReadError;
+use crate::deno_json::CoverageThresholdsConfig;
 use crate::deno_json::DeployConfig;
 use crate::deno_json::FmtConfig;
 use crate::deno_json::FmtOptionsConfig;
@@ -2339,6 +2340,7 @@ impl WorkspaceDirectory {
           url_to_file_path(&self.dir_url).unwrap(),
         ),
         permissions: None,
+        coverage_thresholds: Default::default(),
       },
     };
     let root_config = match &self.deno_json.root {
@@ -2353,6 +2355,18 @@ impl WorkspaceDirectory {
         (Some(r), _) => Some(r),
         (None, None) => None,
       },
+      coverage_thresholds: CoverageThresholdsConfig {
+        global: member_config
+          .coverage_thresholds
+          .global
+          .or(root_config.coverage_thresholds.global),
+        per_path: root_config
+          .coverage_thresholds
+          .per_path
+          .into_iter()
+          .chain(member_config.coverage_thresholds.per_path)
+          .collect(),
+      },
     })
   }
 
@@ -3792,6 +3806,7 @@ pub mod test {
           exclude: Default::default(),
         },
         permissions: None,
+        coverage_thresholds: Default::default(),
       }
     );
 
@@ -3815,6 +3830,7 @@ pub mod test {
           )])),
         },
         permissions: None,
+        coverage_thresholds: Default::default(),
       }
     );
   }
@@ -3937,6 +3953,7 @@ pub mod test {
         TestConfig {
           files: expected_files.clone(),
           permissions: None,
+          coverage_thresholds: Default::default(),
         }
       );
       assert_eq!(
@@ -64,3 +64,26 @@ pub enum DeserializationError {
   )]
   MissingPackage(StackString),
 }
+
+#[derive(Debug, Error)]
+#[error("Failed importing '{file_name}'")]
+pub struct NpmLockfileImportError {
+  pub file_name: &'static str,
+  #[source]
+  pub source: NpmLockfileImportErrorReason,
+}
+
+#[derive(Debug, Error)]
+pub enum NpmLockfileImportErrorReason {
+  #[error("Failed parsing. Lockfile may be corrupt")]
+  ParseError(#[source] serde_json::Error),
+  #[error("Failed parsing line {line}: {message}")]
+  SyntaxError { line: usize, message: String },
+  #[error("Unsupported lockfile version '{version}'")]
+  UnsupportedVersion { version: String },
+  #[error("Failed getting npm package info: {source}")]
+  FailedGettingNpmPackageInfo {
+    #[source]
+    source: Box<dyn std::error::Error + Send + Sync>,
+  },
+}
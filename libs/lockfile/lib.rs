@@ -5,6 +5,8 @@
 
 mod error;
 mod graphs;
+mod merge;
+mod npm_lockfiles;
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
@@ -30,6 +32,11 @@ mod transforms;
 pub use error::DeserializationError;
 pub use error::LockfileError;
 pub use error::LockfileErrorReason;
+pub use error::NpmLockfileImportError;
+pub use error::NpmLockfileImportErrorReason;
+pub use merge::LockfileMergeConflict;
+pub use npm_lockfiles::ImportedNpmLockfile;
+pub use npm_lockfiles::NpmLockfileKind;
 pub use transforms::Lockfile5NpmInfo;
 pub use transforms::NpmPackageInfoProvider;
 
@@ -198,7 +205,7 @@ pub struct NpmPackageDist {
   pub integrity: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JsrPackageInfo {
   pub integrity: String,
   /// List of package requirements found in the dependency.
@@ -213,7 +220,7 @@ impl JsrPackageInfo {
   }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct PackagesContent {
   /// Mapping between requests for jsr specifiers and resolved packages, eg.
   /// {
@@ -253,7 +260,7 @@ impl PackagesContent {
   }
 }
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
 pub(crate) struct LockfilePackageJsonContent {
   #[serde(default)]
   pub dependencies: HashSet<JsrDepPackageReq>,
@@ -268,7 +275,7 @@ impl LockfilePackageJsonContent {
   }
 }
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct WorkspaceMemberConfigContent {
   #[serde(default)]
@@ -318,7 +325,7 @@ impl LockfileLinkContent {
   }
 }
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct WorkspaceConfigContent {
   #[serde(default, flatten)]
@@ -349,7 +356,7 @@ impl WorkspaceConfigContent {
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct LockfileContent {
   pub packages: PackagesContent,
   pub redirects: BTreeMap<String, String>,
@@ -1012,6 +1019,36 @@ impl Lockfile {
     &self.content.remote
   }
 
+  /// Gets the dependencies of the workspace's configuration files.
+  pub fn workspace_dep_reqs(&self) -> impl Iterator<Item = &JsrDepPackageReq> {
+    self.content.workspace.get_all_dep_reqs()
+  }
+
+  /// Gets the dependencies of the configuration files of each workspace
+  /// member by the member's directory relative to the workspace root, which
+  /// is empty for the root.
+  pub fn workspace_members(
+    &self,
+  ) -> impl Iterator<Item = (&str, WorkspaceMemberConfig)> {
+    let workspace = &self.content.workspace;
+    std::iter::once(("", &workspace.root))
+      .chain(
+        workspace
+          .members
+          .iter()
+          .map(|(path, member)| (path.as_str(), member)),
+      )
+      .map(|(path, member)| {
+        (
+          path,
+          WorkspaceMemberConfig {
+            dependencies: member.dependencies.clone(),
+            package_json_deps: member.package_json.dependencies.clone(),
+          },
+        )
+      })
+  }
+
   /// Inserts a remote specifier into the lockfile replacing the existing package if it exists.
   ///
   /// WARNING: It is up to the caller to ensure checksums of remote modules are
@@ -1080,6 +1117,59 @@ impl Lockfile {
     }
   }
 
+  /// Inserts the packages of the lockfile of another package manager and
+  /// resolves the npm dependencies of the workspace to them.
+  ///
+  /// This should be called after [`Lockfile::set_workspace_config`] so that
+  /// the dependencies of the workspace are known.
+  pub fn import_npm_lockfile(&mut self, imported: ImportedNpmLockfile) {
+    for package in imported.packages {
+      self.insert_npm_package(package);
+    }
+    let npm_reqs = self
+      .content
+      .workspace
+      .get_all_dep_reqs()
+      .filter(|dep| dep.kind == PackageKind::Npm)
+      .cloned()
+      .collect::<Vec<_>>();
+    for dep in npm_reqs {
+      if self.content.packages.specifiers.contains_key(&dep) {
+        continue;
+      }
+      let nv = imported
+        .root_packages
+        .iter()
+        .filter(|nv| {
+          nv.name == dep.req.name && dep.req.version_req.matches(&nv.version)
+        })
+        .max_by(|a, b| a.version.cmp(&b.version));
+      if let Some(nv) = nv {
+        let version = SmallStackString::from_string(nv.version.to_string());
+        self.insert_package_specifier(dep, version);
+      }
+    }
+  }
+
+  /// Three-way merges the content of another lockfile into this one, where
+  /// `base` is the content both lockfiles were changed from.
+  ///
+  /// Packages and remote modules whose integrity differs keep the integrity
+  /// of this lockfile and are returned as conflicts.
+  pub fn merge(
+    &mut self,
+    base: &LockfileContent,
+    theirs: &LockfileContent,
+  ) -> Vec<LockfileMergeConflict> {
+    let (content, conflicts) =
+      merge::merge_content(base, &self.content, theirs);
+    if content != self.content {
+      self.content = content;
+      self.has_content_changed = true;
+    }
+    conflicts
+  }
+
   /// Inserts a package specifier into the lockfile.
   pub fn insert_package_specifier(
     &mut self,
@@ -1726,6 +1816,112 @@ mod tests {
     assert!(!lockfile.has_content_changed);
   }
 
+  #[test]
+  fn import_npm_lockfile() {
+    let mut lockfile = Lockfile::new_empty(PathBuf::from("/deno.lock"), false);
+    let npm_req =
+      |text: &str| JsrDepPackageReq::npm(PackageReq::from_str(text).unwrap());
+    lockfile.set_workspace_config(SetWorkspaceConfigOptions {
+      config: WorkspaceConfig {
+        root: WorkspaceMemberConfig {
+          dependencies: Default::default(),
+          package_json_deps: HashSet::from([
+            npm_req("a@^1.0.0"),
+            npm_req("b@^2.0.0"),
+            npm_req("c@^3.0.0"),
+          ]),
+        },
+        members: Default::default(),
+        links: Default::default(),
+        npm_overrides: None,
+      },
+      no_config: false,
+      no_npm: false,
+    });
+    let package = |id: &str, deps: &[(&str, &str)]| NpmPackageLockfileInfo {
+      serialized_id: id.into(),
+      integrity: Some(format!("sha512-{id}")),
+      dependencies: deps
+        .iter()
+        .map(|(name, id)| NpmPackageDependencyLockfileInfo {
+          name: (*name).into(),
+          id: (*id).into(),
+        })
+        .collect(),
+      optional_dependencies: Vec::new(),
+      optional_peers: Vec::new(),
+      os: Vec::new(),
+      cpu: Vec::new(),
+      tarball: None,
+      deprecated: false,
+      scripts: false,
+      bin: false,
+    };
+    lockfile.import_npm_lockfile(ImportedNpmLockfile {
+      packages: vec![
+        package("a@1.0.0", &[("b", "b@1.0.0")]),
+        package("a@1.2.0", &[]),
+        package("b@1.0.0", &[]),
+        package("b@2.0.0", &[]),
+      ],
+      root_packages: ["a@1.0.0", "a@1.2.0", "b@2.0.0"]
+        .into_iter()
+        .map(|nv| PackageNv::from_str(nv).unwrap())
+        .collect(),
+    });
+    assert!(lockfile.has_content_changed);
+    assert_eq!(
+      lockfile.content.packages.specifiers,
+      HashMap::from([
+        (npm_req("a@^1.0.0"), "1.2.0".into()),
+        (npm_req("b@^2.0.0"), "2.0.0".into()),
+      ])
+    );
+    assert_eq!(
+      lockfile
+        .content
+        .packages
+        .npm
+        .keys()
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>(),
+      vec!["a@1.0.0", "a@1.2.0", "b@1.0.0", "b@2.0.0"]
+    );
+  }
+
+  #[test]
+  fn workspace_members() {
+    let mut lockfile = Lockfile::new_empty(PathBuf::from("/deno.lock"), false);
+    let root = WorkspaceMemberConfig {
+      dependencies: HashSet::from([JsrDepPackageReq::jsr(
+        PackageReq::from_str("@std/path@1").unwrap(),
+      )]),
+      package_json_deps: HashSet::from([JsrDepPackageReq::npm(
+        PackageReq::from_str("chalk@5").unwrap(),
+      )]),
+    };
+    let member = WorkspaceMemberConfig {
+      dependencies: Default::default(),
+      package_json_deps: HashSet::from([JsrDepPackageReq::npm(
+        PackageReq::from_str("react@18").unwrap(),
+      )]),
+    };
+    lockfile.set_workspace_config(SetWorkspaceConfigOptions {
+      config: WorkspaceConfig {
+        root: root.clone(),
+        members: HashMap::from([("packages/a".to_string(), member.clone())]),
+        links: Default::default(),
+        npm_overrides: None,
+      },
+      no_config: false,
+      no_npm: false,
+    });
+    assert_eq!(
+      lockfile.workspace_members().collect::<Vec<_>>(),
+      vec![("", root), ("packages/a", member)]
+    );
+  }
+
   #[test]
   fn empty_lockfile_nicer_error() {
     let content: &str = r#"  "#;
@@ -0,0 +1,513 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Three-way merging of lockfiles, which resolves the conflicts version
+//! control systems report when branches change the same lockfile.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+
+use deno_semver::StackString;
+use deno_semver::Version;
+use deno_semver::jsr::JsrDepPackageReq;
+use deno_semver::package::PackageKind;
+use deno_semver::package::PackageNv;
+
+use crate::JsrPackageInfo;
+use crate::LockfileContent;
+use crate::NpmPackageInfo;
+use crate::PackagesContent;
+
+/// A package or remote module whose integrity differs between the merged
+/// lockfiles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockfileMergeConflict {
+  /// The package or remote module, ex. `npm:chalk@5.3.0`.
+  pub specifier: String,
+  pub ours: String,
+  pub theirs: String,
+}
+
+impl std::fmt::Display for LockfileMergeConflict {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} has the integrity {} in ours and {} in theirs",
+      self.specifier, self.ours, self.theirs
+    )
+  }
+}
+
+pub fn merge_content(
+  base: &LockfileContent,
+  ours: &LockfileContent,
+  theirs: &LockfileContent,
+) -> (LockfileContent, Vec<LockfileMergeConflict>) {
+  let mut conflicts = Vec::new();
+
+  let specifiers = {
+    let (base, ours, theirs) = (
+      &base.packages.specifiers,
+      &ours.packages.specifiers,
+      &theirs.packages.specifiers,
+    );
+    let reqs = ours
+      .keys()
+      .chain(theirs.keys())
+      .chain(base.keys())
+      .collect::<HashSet<_>>();
+    reqs
+      .into_iter()
+      .filter_map(|req| {
+        let version = merge_value(
+          base.get(req),
+          ours.get(req),
+          theirs.get(req),
+          |ours, theirs| {
+            // both sides resolved the requirement again, so use the newer
+            // version
+            match (parse_version(ours), parse_version(theirs)) {
+              (Some(ours_version), Some(theirs_version))
+                if theirs_version > ours_version =>
+              {
+                theirs.clone()
+              }
+              _ => ours.clone(),
+            }
+          },
+        )?;
+        Some((req.clone(), version))
+      })
+      .collect()
+  };
+
+  let jsr = merge_maps(
+    &base.packages.jsr,
+    &ours.packages.jsr,
+    &theirs.packages.jsr,
+    |nv, ours, theirs| {
+      if ours.integrity != theirs.integrity {
+        conflicts.push(LockfileMergeConflict {
+          specifier: format!("jsr:{}", nv),
+          ours: ours.integrity.clone(),
+          theirs: theirs.integrity.clone(),
+        });
+        return ours.clone();
+      }
+      JsrPackageInfo {
+        integrity: ours.integrity.clone(),
+        dependencies: ours
+          .dependencies
+          .union(&theirs.dependencies)
+          .cloned()
+          .collect(),
+      }
+    },
+  );
+
+  let npm = merge_maps(
+    &base.packages.npm,
+    &ours.packages.npm,
+    &theirs.packages.npm,
+    |id, ours, theirs| {
+      if let (Some(ours_integrity), Some(theirs_integrity)) =
+        (&ours.integrity, &theirs.integrity)
+        && ours_integrity != theirs_integrity
+      {
+        conflicts.push(LockfileMergeConflict {
+          specifier: format!("npm:{}", id),
+          ours: ours_integrity.clone(),
+          theirs: theirs_integrity.clone(),
+        });
+        return ours.clone();
+      }
+      let mut info = ours.clone();
+      let dependencies = [
+        (&mut info.dependencies, &theirs.dependencies),
+        (
+          &mut info.optional_dependencies,
+          &theirs.optional_dependencies,
+        ),
+        (&mut info.optional_peers, &theirs.optional_peers),
+      ];
+      for (ours, theirs) in dependencies {
+        for (name, dep_id) in theirs {
+          ours.entry(name.clone()).or_insert_with(|| dep_id.clone());
+        }
+      }
+      info
+    },
+  );
+
+  let remote = merge_maps(
+    &base.remote,
+    &ours.remote,
+    &theirs.remote,
+    |url, ours, theirs| {
+      conflicts.push(LockfileMergeConflict {
+        specifier: url.clone(),
+        ours: ours.clone(),
+        theirs: theirs.clone(),
+      });
+      ours.clone()
+    },
+  );
+
+  let redirects = merge_maps(
+    &base.redirects,
+    &ours.redirects,
+    &theirs.redirects,
+    |_, ours, _| ours.clone(),
+  );
+
+  // the workspace configuration is derived from the configuration files,
+  // which the caller should set again once they're merged
+  let workspace = if ours.workspace == base.workspace {
+    theirs.workspace.clone()
+  } else {
+    ours.workspace.clone()
+  };
+
+  let mut content = LockfileContent {
+    packages: PackagesContent {
+      specifiers,
+      jsr,
+      npm,
+    },
+    redirects,
+    remote,
+    workspace,
+  };
+  remove_unreachable_packages(&mut content.packages);
+  (content, conflicts)
+}
+
+/// Merges a value that might've been changed by both sides, where
+/// `merge_changed` is called when both changed it to different values.
+fn merge_value<T: Clone + PartialEq>(
+  base: Option<&T>,
+  ours: Option<&T>,
+  theirs: Option<&T>,
+  merge_changed: impl FnOnce(&T, &T) -> T,
+) -> Option<T> {
+  if ours == theirs || theirs == base {
+    return ours.cloned();
+  }
+  if ours == base {
+    return theirs.cloned();
+  }
+  match (ours, theirs) {
+    (Some(ours), Some(theirs)) => Some(merge_changed(ours, theirs)),
+    // one side removed what the other changed, so keep the change
+    (Some(value), None) | (None, Some(value)) => Some(value.clone()),
+    (None, None) => None,
+  }
+}
+
+fn merge_maps<K: Ord + Clone, V: Clone + PartialEq>(
+  base: &BTreeMap<K, V>,
+  ours: &BTreeMap<K, V>,
+  theirs: &BTreeMap<K, V>,
+  mut merge_changed: impl FnMut(&K, &V, &V) -> V,
+) -> BTreeMap<K, V> {
+  let keys = ours
+    .keys()
+    .chain(theirs.keys())
+    .chain(base.keys())
+    .collect::<BTreeSet<_>>();
+  keys
+    .into_iter()
+    .filter_map(|key| {
+      let value =
+        merge_value(base.get(key), ours.get(key), theirs.get(key), |o, t| {
+          merge_changed(key, o, t)
+        })?;
+      Some((key.clone(), value))
+    })
+    .collect()
+}
+
+/// Parses the version of a resolved specifier, which for npm packages might
+/// be followed by the peer dependencies.
+fn parse_version(text: &str) -> Option<Version> {
+  let version = text.split_once('_').map(|(v, _)| v).unwrap_or(text);
+  Version::parse_standard(version).ok()
+}
+
+/// Removes the packages that no specifier resolves to anymore, such as the
+/// previous version of a package that both sides upgraded.
+fn remove_unreachable_packages(packages: &mut PackagesContent) {
+  let mut reachable_jsr = HashSet::new();
+  let mut reachable_npm = HashSet::new();
+  let mut pending_jsr = Vec::new();
+  let mut pending_npm = Vec::new();
+  let resolve = |req: &JsrDepPackageReq,
+                 pending_jsr: &mut Vec<PackageNv>,
+                 pending_npm: &mut Vec<StackString>| {
+    let Some(version) = packages.specifiers.get(req) else {
+      return;
+    };
+    match req.kind {
+      PackageKind::Jsr => {
+        if let Ok(version) = Version::parse_standard(version) {
+          pending_jsr.push(PackageNv {
+            name: req.req.name.clone(),
+            version,
+          });
+        }
+      }
+      PackageKind::Npm => {
+        let mut id = StackString::from_str(&req.req.name);
+        id.push('@');
+        id.push_str(version);
+        pending_npm.push(id);
+      }
+    }
+  };
+  for req in packages.specifiers.keys() {
+    resolve(req, &mut pending_jsr, &mut pending_npm);
+  }
+  loop {
+    if let Some(nv) = pending_jsr.pop() {
+      if !reachable_jsr.insert(nv.clone()) {
+        continue;
+      }
+      if let Some(info) = packages.jsr.get(&nv) {
+        for req in &info.dependencies {
+          resolve(req, &mut pending_jsr, &mut pending_npm);
+        }
+      }
+    } else if let Some(id) = pending_npm.pop() {
+      if !reachable_npm.insert(id.clone()) {
+        continue;
+      }
+      if let Some(info) = packages.npm.get(&id) {
+        pending_npm.extend(npm_dependency_ids(info).cloned());
+      }
+    } else {
+      break;
+    }
+  }
+  packages.jsr.retain(|nv, _| reachable_jsr.contains(nv));
+  packages.npm.retain(|id, _| reachable_npm.contains(id));
+}
+
+fn npm_dependency_ids(
+  info: &NpmPackageInfo,
+) -> impl Iterator<Item = &StackString> {
+  info
+    .dependencies
+    .values()
+    .chain(info.optional_dependencies.values())
+    .chain(info.optional_peers.values())
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn content(json: serde_json::Value) -> LockfileContent {
+    LockfileContent::from_json(json).unwrap()
+  }
+
+  fn print(content: &LockfileContent) -> serde_json::Value {
+    let text = crate::printer::print_v5_content(content);
+    serde_json::from_str(&text).unwrap()
+  }
+
+  fn merge(
+    base: serde_json::Value,
+    ours: serde_json::Value,
+    theirs: serde_json::Value,
+  ) -> (serde_json::Value, Vec<LockfileMergeConflict>) {
+    let (merged, conflicts) =
+      merge_content(&content(base), &content(ours), &content(theirs));
+    (print(&merged), conflicts)
+  }
+
+  #[test]
+  fn merges_changes_of_both_sides() {
+    let base = serde_json::json!({
+      "version": "5",
+      "specifiers": {
+        "npm:a@1": "1.0.0",
+        "npm:b@1": "1.0.0"
+      },
+      "npm": {
+        "a@1.0.0": { "integrity": "sha512-a1" },
+        "b@1.0.0": { "integrity": "sha512-b1" }
+      },
+      "remote": {
+        "https://deno.land/x/mod.ts": "abc"
+      }
+    });
+    // ours adds a package and upgrades `a`
+    let ours = serde_json::json!({
+      "version": "5",
+      "specifiers": {
+        "npm:a@1": "1.1.0",
+        "npm:b@1": "1.0.0",
+        "npm:c@1": "1.0.0"
+      },
+      "npm": {
+        "a@1.1.0": { "integrity": "sha512-a11" },
+        "b@1.0.0": { "integrity": "sha512-b1" },
+        "c@1.0.0": { "integrity": "sha512-c1" }
+      },
+      "remote": {
+        "https://deno.land/x/mod.ts": "abc"
+      }
+    });
+    // theirs upgrades `a` further, removes `b` and adds a remote module
+    let theirs = serde_json::json!({
+      "version": "5",
+      "specifiers": {
+        "jsr:@std/path@1": "1.0.8",
+        "npm:a@1": "1.2.0"
+      },
+      "jsr": {
+        "@std/path@1.0.8": {
+          "integrity": "def"
+        }
+      },
+      "npm": {
+        "a@1.2.0": { "integrity": "sha512-a12" }
+      },
+      "remote": {
+        "https://deno.land/x/mod.ts": "abc",
+        "https://deno.land/x/other.ts": "ghi"
+      }
+    });
+    let (merged, conflicts) = merge(base, ours, theirs);
+    assert_eq!(conflicts, Vec::new());
+    assert_eq!(
+      merged,
+      serde_json::json!({
+        "version": "5",
+        "specifiers": {
+          "jsr:@std/path@1": "1.0.8",
+          "npm:a@1": "1.2.0",
+          "npm:c@1": "1.0.0"
+        },
+        "jsr": {
+          "@std/path@1.0.8": {
+            "integrity": "def"
+          }
+        },
+        "npm": {
+          "a@1.2.0": { "integrity": "sha512-a12" },
+          "c@1.0.0": { "integrity": "sha512-c1" }
+        },
+        "remote": {
+          "https://deno.land/x/mod.ts": "abc",
+          "https://deno.land/x/other.ts": "ghi"
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn reports_integrity_conflicts() {
+    let base = serde_json::json!({ "version": "5" });
+    let ours = serde_json::json!({
+      "version": "5",
+      "specifiers": { "npm:a@1": "1.0.0" },
+      "npm": {
+        "a@1.0.0": { "integrity": "sha512-ours", "dependencies": ["b"] },
+        "b@1.0.0": { "integrity": "sha512-b" }
+      },
+      "remote": {
+        "https://deno.land/x/mod.ts": "ours"
+      }
+    });
+    let theirs = serde_json::json!({
+      "version": "5",
+      "specifiers": { "npm:a@1": "1.0.0" },
+      "npm": {
+        "a@1.0.0": { "integrity": "sha512-theirs" }
+      },
+      "remote": {
+        "https://deno.land/x/mod.ts": "theirs"
+      }
+    });
+    let (merged, conflicts) = merge(base, ours.clone(), theirs);
+    let ours = print(&content(ours));
+    assert_eq!(
+      conflicts,
+      vec![
+        LockfileMergeConflict {
+          specifier: "npm:a@1.0.0".to_string(),
+          ours: "sha512-ours".to_string(),
+          theirs: "sha512-theirs".to_string(),
+        },
+        LockfileMergeConflict {
+          specifier: "https://deno.land/x/mod.ts".to_string(),
+          ours: "ours".to_string(),
+          theirs: "theirs".to_string(),
+        },
+      ]
+    );
+    assert_eq!(merged, ours);
+    assert_eq!(
+      conflicts[0].to_string(),
+      "npm:a@1.0.0 has the integrity sha512-ours in ours and sha512-theirs in theirs"
+    );
+  }
+
+  #[test]
+  fn combines_dependencies_of_same_package() {
+    let base = serde_json::json!({ "version": "5" });
+    let ours = serde_json::json!({
+      "version": "5",
+      "specifiers": {
+        "jsr:@scope/a@1": "1.0.0",
+        "npm:b@1": "1.0.0"
+      },
+      "jsr": {
+        "@scope/a@1.0.0": {
+          "integrity": "abc",
+          "dependencies": ["npm:b@1"]
+        }
+      },
+      "npm": {
+        "b@1.0.0": { "integrity": "sha512-b" }
+      }
+    });
+    let theirs = serde_json::json!({
+      "version": "5",
+      "specifiers": {
+        "jsr:@scope/a@1": "1.0.0",
+        "npm:c@1": "1.0.0"
+      },
+      "jsr": {
+        "@scope/a@1.0.0": {
+          "integrity": "abc",
+          "dependencies": ["npm:c@1"]
+        }
+      },
+      "npm": {
+        "c@1.0.0": { "integrity": "sha512-c" }
+      }
+    });
+    let (merged, conflicts) = merge(base, ours, theirs);
+    assert_eq!(conflicts, Vec::new());
+    assert_eq!(
+      merged["jsr"],
+      serde_json::json!({
+        "@scope/a@1.0.0": {
+          "integrity": "abc",
+          "dependencies": ["npm:b@1", "npm:c@1"]
+        }
+      })
+    );
+    assert_eq!(
+      merged["npm"],
+      serde_json::json!({
+        "b@1.0.0": { "integrity": "sha512-b" },
+        "c@1.0.0": { "integrity": "sha512-c" }
+      })
+    );
+  }
+}
@@ -0,0 +1,437 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Imports the packages resolved in the lockfiles of npm, pnpm and yarn, so
+//! that migrating a project keeps its pinned versions and integrity hashes.
+
+mod package_lock;
+mod pnpm;
+mod yaml;
+mod yarn;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use deno_semver::SmallStackString;
+use deno_semver::StackString;
+use deno_semver::Version;
+use deno_semver::package::PackageNv;
+
+use crate::NpmPackageDependencyLockfileInfo;
+use crate::NpmPackageLockfileInfo;
+use crate::error::NpmLockfileImportError;
+use crate::error::NpmLockfileImportErrorReason;
+use crate::transforms::MissingNpmPackageInfo;
+use crate::transforms::NpmPackageInfoProvider;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NpmLockfileKind {
+  Npm,
+  Pnpm,
+  Yarn,
+}
+
+impl NpmLockfileKind {
+  /// All the kinds in the order they should be looked for.
+  pub const ALL: [NpmLockfileKind; 3] = [
+    NpmLockfileKind::Npm,
+    NpmLockfileKind::Pnpm,
+    NpmLockfileKind::Yarn,
+  ];
+
+  pub fn file_name(&self) -> &'static str {
+    match self {
+      NpmLockfileKind::Npm => "package-lock.json",
+      NpmLockfileKind::Pnpm => "pnpm-lock.yaml",
+      NpmLockfileKind::Yarn => "yarn.lock",
+    }
+  }
+}
+
+/// The npm packages of a lockfile of another package manager.
+#[derive(Debug, Clone)]
+pub struct ImportedNpmLockfile {
+  pub packages: Vec<NpmPackageLockfileInfo>,
+  /// Candidates for resolving the npm dependencies of the workspace.
+  pub root_packages: Vec<PackageNv>,
+}
+
+impl ImportedNpmLockfile {
+  /// Parses the lockfile and fills in the package information that it
+  /// doesn't have, such as the optional dependencies and whether a package
+  /// has install scripts, from the registry.
+  ///
+  /// Packages that aren't from the npm registry, and the packages depending
+  /// on them, are left out.
+  pub async fn parse(
+    kind: NpmLockfileKind,
+    text: &str,
+    provider: &dyn NpmPackageInfoProvider,
+  ) -> Result<ImportedNpmLockfile, NpmLockfileImportError> {
+    Self::parse_inner(kind, text, provider)
+      .await
+      .map_err(|source| NpmLockfileImportError {
+        file_name: kind.file_name(),
+        source,
+      })
+  }
+
+  async fn parse_inner(
+    kind: NpmLockfileKind,
+    text: &str,
+    provider: &dyn NpmPackageInfoProvider,
+  ) -> Result<ImportedNpmLockfile, NpmLockfileImportErrorReason> {
+    let mut lockfile = match kind {
+      NpmLockfileKind::Npm => package_lock::parse(text)?,
+      NpmLockfileKind::Pnpm => pnpm::parse(text)?,
+      NpmLockfileKind::Yarn => yarn::parse(text)?,
+    };
+    lockfile.retain_supported_packages();
+
+    let nvs = lockfile.packages.keys().cloned().collect::<Vec<_>>();
+    let infos = if nvs.is_empty() {
+      Vec::new()
+    } else {
+      provider
+        .get_npm_package_info(&nvs)
+        .await
+        .map_err(|source| {
+          NpmLockfileImportErrorReason::FailedGettingNpmPackageInfo { source }
+        })?
+    };
+    if infos.len() != nvs.len() {
+      return Err(NpmLockfileImportErrorReason::FailedGettingNpmPackageInfo {
+        source: Box::new(MissingNpmPackageInfo),
+      });
+    }
+
+    let packages = lockfile
+      .packages
+      .into_iter()
+      .zip(infos)
+      .map(|((nv, package), info)| {
+        let mut dependencies = Vec::new();
+        let mut optional_dependencies = Vec::new();
+        let mut optional_peers = Vec::new();
+        for (name, dep_nv) in package.dependencies {
+          let dep = NpmPackageDependencyLockfileInfo {
+            id: StackString::from_string(dep_nv.to_string()),
+            name,
+          };
+          if info.optional_dependencies.contains_key(dep.name.as_str()) {
+            optional_dependencies.push(dep);
+          } else {
+            // resolved optional peers are also regular dependencies
+            if info.optional_peers.contains_key(dep.name.as_str()) {
+              optional_peers.push(dep.clone());
+            }
+            dependencies.push(dep);
+          }
+        }
+        NpmPackageLockfileInfo {
+          serialized_id: StackString::from_string(nv.to_string()),
+          integrity: package.integrity,
+          dependencies,
+          optional_dependencies,
+          optional_peers,
+          os: info
+            .os
+            .iter()
+            .map(|s| SmallStackString::from_str(s))
+            .collect(),
+          cpu: info
+            .cpu
+            .iter()
+            .map(|s| SmallStackString::from_str(s))
+            .collect(),
+          tarball: info.tarball_url.map(StackString::from_string),
+          deprecated: info.deprecated,
+          scripts: info.scripts,
+          bin: info.bin,
+        }
+      })
+      .collect();
+
+    Ok(ImportedNpmLockfile {
+      packages,
+      root_packages: lockfile.root_packages.into_iter().collect(),
+    })
+  }
+}
+
+/// A package as resolved by another package manager.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ForeignPackage {
+  /// `None` when the package can't be imported, for example because it's
+  /// from a git repository or depends on a workspace package.
+  integrity: Option<String>,
+  /// The resolved dependencies, including optional and peer dependencies,
+  /// by the name they're imported with.
+  dependencies: BTreeMap<StackString, PackageNv>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ForeignLockfile {
+  packages: BTreeMap<PackageNv, ForeignPackage>,
+  root_packages: BTreeSet<PackageNv>,
+}
+
+impl ForeignLockfile {
+  /// Removes the packages that can't be imported along with the packages
+  /// that depend on them.
+  fn retain_supported_packages(&mut self) {
+    self
+      .packages
+      .retain(|_, package| package.integrity.is_some());
+    loop {
+      let unresolved = self
+        .packages
+        .iter()
+        .filter(|(_, package)| {
+          package
+            .dependencies
+            .values()
+            .any(|dep| !self.packages.contains_key(dep))
+        })
+        .map(|(nv, _)| nv.clone())
+        .collect::<Vec<_>>();
+      if unresolved.is_empty() {
+        break;
+      }
+      for nv in unresolved {
+        self.packages.remove(&nv);
+      }
+    }
+    let packages = &self.packages;
+    self.root_packages.retain(|nv| packages.contains_key(nv));
+  }
+}
+
+/// Splits a `name@version` id, where the name may be scoped.
+fn split_nv(id: &str) -> Option<(&str, &str)> {
+  if id.is_empty() {
+    return None;
+  }
+  let at_index = id[1..].find('@')? + 1;
+  Some((&id[..at_index], &id[at_index + 1..]))
+}
+
+fn parse_nv(name: &str, version: &str) -> Option<PackageNv> {
+  Some(PackageNv {
+    name: StackString::from_str(name),
+    version: Version::parse_from_npm(version).ok()?,
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use futures::FutureExt;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::Lockfile5NpmInfo;
+
+  struct TestNpmPackageInfoProvider {
+    packages: HashMap<PackageNv, Lockfile5NpmInfo>,
+  }
+
+  #[async_trait::async_trait(?Send)]
+  impl NpmPackageInfoProvider for TestNpmPackageInfoProvider {
+    async fn get_npm_package_info(
+      &self,
+      values: &[PackageNv],
+    ) -> Result<Vec<Lockfile5NpmInfo>, Box<dyn std::error::Error + Send + Sync>>
+    {
+      Ok(
+        values
+          .iter()
+          .map(|v| {
+            self
+              .packages
+              .get(v)
+              .cloned()
+              .unwrap_or_else(|| panic!("no info for {v}"))
+          })
+          .collect(),
+      )
+    }
+  }
+
+  fn nv(name_and_version: &str) -> PackageNv {
+    PackageNv::from_str(name_and_version).unwrap()
+  }
+
+  pub fn foreign_package(
+    integrity: &str,
+    dependencies: &[(&str, &str)],
+  ) -> ForeignPackage {
+    ForeignPackage {
+      integrity: Some(integrity.to_string()),
+      dependencies: dependencies
+        .iter()
+        .map(|(name, id)| (StackString::from_str(name), nv(id)))
+        .collect(),
+    }
+  }
+
+  pub fn foreign_lockfile(
+    packages: Vec<(&str, ForeignPackage)>,
+    root_packages: &[&str],
+  ) -> ForeignLockfile {
+    ForeignLockfile {
+      packages: packages
+        .into_iter()
+        .map(|(id, package)| (nv(id), package))
+        .collect(),
+      root_packages: root_packages.iter().map(|id| nv(id)).collect(),
+    }
+  }
+
+  #[test]
+  fn removes_unsupported_packages() {
+    let mut lockfile = foreign_lockfile(
+      vec![
+        ("a@1.0.0", foreign_package("sha512-a", &[("b", "b@1.0.0")])),
+        ("b@1.0.0", foreign_package("sha512-b", &[("c", "c@1.0.0")])),
+        (
+          "c@1.0.0",
+          ForeignPackage {
+            integrity: None,
+            dependencies: Default::default(),
+          },
+        ),
+        ("d@1.0.0", foreign_package("sha512-d", &[])),
+      ],
+      &["a@1.0.0", "d@1.0.0"],
+    );
+    lockfile.retain_supported_packages();
+    assert_eq!(
+      lockfile,
+      foreign_lockfile(
+        vec![("d@1.0.0", foreign_package("sha512-d", &[]))],
+        &["d@1.0.0"],
+      )
+    );
+  }
+
+  #[test]
+  fn fills_in_registry_info() {
+    let text = r#"{
+  "name": "project",
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "dependencies": { "a": "^1.0.0" }
+    },
+    "node_modules/a": {
+      "version": "1.0.0",
+      "integrity": "sha512-a",
+      "dependencies": { "b": "^1.0.0" },
+      "optionalDependencies": { "c": "^1.0.0" },
+      "peerDependencies": { "d": "^1.0.0" }
+    },
+    "node_modules/b": { "version": "1.0.0", "integrity": "sha512-b" },
+    "node_modules/c": { "version": "1.0.0", "integrity": "sha512-c" },
+    "node_modules/d": { "version": "1.0.0", "integrity": "sha512-d" }
+  }
+}"#;
+    let provider = TestNpmPackageInfoProvider {
+      packages: HashMap::from([
+        (
+          nv("a@1.0.0"),
+          Lockfile5NpmInfo {
+            optional_dependencies: BTreeMap::from([(
+              "c".to_string(),
+              "^1.0.0".to_string(),
+            )]),
+            optional_peers: BTreeMap::from([(
+              "d".to_string(),
+              "^1.0.0".to_string(),
+            )]),
+            scripts: true,
+            ..Default::default()
+          },
+        ),
+        (
+          nv("b@1.0.0"),
+          Lockfile5NpmInfo {
+            tarball_url: Some("https://example.com/b.tgz".to_string()),
+            bin: true,
+            ..Default::default()
+          },
+        ),
+        (
+          nv("c@1.0.0"),
+          Lockfile5NpmInfo {
+            os: vec!["darwin".to_string()],
+            cpu: vec!["arm64".to_string()],
+            ..Default::default()
+          },
+        ),
+        (
+          nv("d@1.0.0"),
+          Lockfile5NpmInfo {
+            deprecated: true,
+            ..Default::default()
+          },
+        ),
+      ]),
+    };
+    let imported =
+      ImportedNpmLockfile::parse(NpmLockfileKind::Npm, text, &provider)
+        .now_or_never()
+        .unwrap()
+        .unwrap();
+
+    fn dep(name: &str, id: &str) -> NpmPackageDependencyLockfileInfo {
+      NpmPackageDependencyLockfileInfo {
+        name: name.into(),
+        id: id.into(),
+      }
+    }
+    fn package(id: &str, integrity: &str) -> NpmPackageLockfileInfo {
+      NpmPackageLockfileInfo {
+        serialized_id: id.into(),
+        integrity: Some(integrity.to_string()),
+        dependencies: Vec::new(),
+        optional_dependencies: Vec::new(),
+        optional_peers: Vec::new(),
+        os: Vec::new(),
+        cpu: Vec::new(),
+        tarball: None,
+        deprecated: false,
+        scripts: false,
+        bin: false,
+      }
+    }
+
+    assert_eq!(imported.root_packages, vec![nv("a@1.0.0")]);
+    assert_eq!(
+      imported.packages,
+      vec![
+        NpmPackageLockfileInfo {
+          dependencies: vec![dep("b", "b@1.0.0"), dep("d", "d@1.0.0")],
+          optional_dependencies: vec![dep("c", "c@1.0.0")],
+          optional_peers: vec![dep("d", "d@1.0.0")],
+          scripts: true,
+          ..package("a@1.0.0", "sha512-a")
+        },
+        NpmPackageLockfileInfo {
+          tarball: Some("https://example.com/b.tgz".into()),
+          bin: true,
+          ..package("b@1.0.0", "sha512-b")
+        },
+        NpmPackageLockfileInfo {
+          os: vec![SmallStackString::from_str("darwin")],
+          cpu: vec![SmallStackString::from_str("arm64")],
+          ..package("c@1.0.0", "sha512-c")
+        },
+        NpmPackageLockfileInfo {
+          deprecated: true,
+          ..package("d@1.0.0", "sha512-d")
+        },
+      ]
+    );
+  }
+}
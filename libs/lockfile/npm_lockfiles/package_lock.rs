@@ -0,0 +1,237 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Reads `package-lock.json` files of lockfile versions 2 and 3, which list
+//! the packages by their path in the `node_modules` folder.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use deno_semver::StackString;
+use deno_semver::package::PackageNv;
+use serde::Deserialize;
+
+use super::ForeignLockfile;
+use super::ForeignPackage;
+use super::parse_nv;
+use crate::error::NpmLockfileImportErrorReason;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageLock {
+  #[serde(default)]
+  lockfile_version: u64,
+  #[serde(default)]
+  packages: BTreeMap<String, PackageLockEntry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct PackageLockEntry {
+  /// The real name of the package when it's installed under an alias.
+  name: Option<String>,
+  version: Option<String>,
+  integrity: Option<String>,
+  /// Whether this is a symlink to a workspace package.
+  link: bool,
+  dependencies: BTreeMap<String, String>,
+  dev_dependencies: BTreeMap<String, String>,
+  optional_dependencies: BTreeMap<String, String>,
+  peer_dependencies: BTreeMap<String, String>,
+}
+
+pub fn parse(
+  text: &str,
+) -> Result<ForeignLockfile, NpmLockfileImportErrorReason> {
+  let package_lock: PackageLock = serde_json::from_str(text)
+    .map_err(NpmLockfileImportErrorReason::ParseError)?;
+  if !matches!(package_lock.lockfile_version, 2 | 3) {
+    return Err(NpmLockfileImportErrorReason::UnsupportedVersion {
+      version: package_lock.lockfile_version.to_string(),
+    });
+  }
+
+  // `None` for the installed packages that can't be imported
+  let installed = package_lock
+    .packages
+    .iter()
+    .filter_map(|(path, entry)| {
+      let (_, folder_name) = path.rsplit_once("node_modules/")?;
+      let nv = if entry.link {
+        None
+      } else {
+        let name = entry.name.as_deref().unwrap_or(folder_name);
+        entry
+          .version
+          .as_deref()
+          .and_then(|version| parse_nv(name, version))
+      };
+      Some((path.as_str(), nv))
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut lockfile = ForeignLockfile::default();
+  for (path, entry) in &package_lock.packages {
+    match installed.get(path.as_str()) {
+      Some(Some(nv)) => {
+        if lockfile.packages.contains_key(nv) {
+          // the same package installed in another folder
+          continue;
+        }
+        let mut package = ForeignPackage {
+          integrity: entry.integrity.clone(),
+          dependencies: Default::default(),
+        };
+        let dep_names = entry
+          .dependencies
+          .keys()
+          .chain(entry.optional_dependencies.keys())
+          .chain(entry.peer_dependencies.keys());
+        for name in dep_names {
+          match resolve(&installed, path, name) {
+            Some(Some(dep_nv)) => {
+              package
+                .dependencies
+                .insert(StackString::from_str(name), dep_nv.clone());
+            }
+            // depends on a workspace package or one that can't be imported
+            Some(None) => package.integrity = None,
+            // optional and peer dependencies might not be installed
+            None => {}
+          }
+        }
+        lockfile.packages.insert(nv.clone(), package);
+      }
+      Some(None) => {}
+      None => {
+        // the root package or a workspace package
+        let dep_names = entry
+          .dependencies
+          .keys()
+          .chain(entry.dev_dependencies.keys())
+          .chain(entry.optional_dependencies.keys());
+        for name in dep_names {
+          if let Some(Some(dep_nv)) = resolve(&installed, path, name) {
+            lockfile.root_packages.insert(dep_nv.clone());
+          }
+        }
+      }
+    }
+  }
+  Ok(lockfile)
+}
+
+/// Resolves a dependency the way Node.js does, by looking for it in the
+/// `node_modules` folders of the package and its ancestors.
+fn resolve<'a>(
+  installed: &'a HashMap<&str, Option<PackageNv>>,
+  package_path: &str,
+  name: &str,
+) -> Option<&'a Option<PackageNv>> {
+  let mut dir = package_path;
+  loop {
+    let candidate = if dir.is_empty() {
+      format!("node_modules/{name}")
+    } else {
+      format!("{dir}/node_modules/{name}")
+    };
+    if let Some(nv) = installed.get(candidate.as_str()) {
+      return Some(nv);
+    }
+    if dir.is_empty() {
+      return None;
+    }
+    dir = match dir.rfind("node_modules/") {
+      Some(index) => dir[..index].trim_end_matches('/'),
+      None => "",
+    };
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::npm_lockfiles::test::foreign_lockfile;
+  use crate::npm_lockfiles::test::foreign_package;
+
+  #[test]
+  fn parses_package_lock() {
+    let text = r#"{
+  "name": "project",
+  "version": "1.0.0",
+  "lockfileVersion": 3,
+  "requires": true,
+  "packages": {
+    "": {
+      "name": "project",
+      "workspaces": ["packages/*"],
+      "dependencies": { "a": "^1.0.0", "alias": "npm:b@^2.0.0" },
+      "devDependencies": { "@scope/c": "^1.0.0" }
+    },
+    "node_modules/a": {
+      "version": "1.0.0",
+      "resolved": "https://registry.npmjs.org/a/-/a-1.0.0.tgz",
+      "integrity": "sha512-a",
+      "dependencies": { "b": "^1.0.0" },
+      "peerDependencies": { "missing-peer": "*" }
+    },
+    "node_modules/a/node_modules/b": {
+      "version": "1.0.0",
+      "integrity": "sha512-b1"
+    },
+    "node_modules/alias": {
+      "name": "b",
+      "version": "2.0.0",
+      "integrity": "sha512-b2"
+    },
+    "node_modules/@scope/c": {
+      "version": "1.0.0",
+      "integrity": "sha512-c",
+      "dependencies": { "a": "^1.0.0" }
+    },
+    "node_modules/d": {
+      "version": "1.0.0",
+      "integrity": "sha512-d",
+      "dependencies": { "member": "*" }
+    },
+    "node_modules/member": {
+      "resolved": "packages/member",
+      "link": true
+    },
+    "packages/member": {
+      "version": "1.0.0",
+      "dependencies": { "d": "^1.0.0" }
+    }
+  }
+}"#;
+    assert_eq!(
+      parse(text).unwrap(),
+      foreign_lockfile(
+        vec![
+          (
+            "@scope/c@1.0.0",
+            foreign_package("sha512-c", &[("a", "a@1.0.0")])
+          ),
+          ("a@1.0.0", foreign_package("sha512-a", &[("b", "b@1.0.0")])),
+          ("b@1.0.0", foreign_package("sha512-b1", &[])),
+          ("b@2.0.0", foreign_package("sha512-b2", &[])),
+          (
+            "d@1.0.0",
+            ForeignPackage {
+              integrity: None,
+              dependencies: Default::default(),
+            }
+          ),
+        ],
+        &["@scope/c@1.0.0", "a@1.0.0", "b@2.0.0", "d@1.0.0"],
+      )
+    );
+  }
+
+  #[test]
+  fn errors_on_unsupported_version() {
+    let err = parse(r#"{ "lockfileVersion": 1 }"#).unwrap_err();
+    assert_eq!(err.to_string(), "Unsupported lockfile version '1'");
+  }
+}
@@ -0,0 +1,316 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Reads `pnpm-lock.yaml` files of lockfile versions 6 and 9.
+//!
+//! In version 6, the `packages` section has both the metadata and the
+//! resolved dependencies of each package, keyed by `/name@version` with the
+//! resolved peer dependencies in parentheses. Version 9 moved the resolved
+//! dependencies to a `snapshots` section and dropped the leading slash.
+
+use deno_semver::StackString;
+use deno_semver::package::PackageNv;
+
+use super::ForeignLockfile;
+use super::ForeignPackage;
+use super::parse_nv;
+use super::split_nv;
+use super::yaml;
+use crate::error::NpmLockfileImportErrorReason;
+
+pub fn parse(
+  text: &str,
+) -> Result<ForeignLockfile, NpmLockfileImportErrorReason> {
+  let doc = yaml::parse(text).map_err(|err| {
+    NpmLockfileImportErrorReason::SyntaxError {
+      line: err.line,
+      message: err.message,
+    }
+  })?;
+  let version = doc
+    .get("lockfileVersion")
+    .and_then(|version| version.as_str())
+    .unwrap_or_default();
+  let is_v6 = match version.split('.').next() {
+    Some("6") => true,
+    Some("9") => false,
+    _ => {
+      return Err(NpmLockfileImportErrorReason::UnsupportedVersion {
+        version: version.to_string(),
+      });
+    }
+  };
+  let snapshots = if is_v6 {
+    doc.get("packages")
+  } else {
+    doc.get("snapshots")
+  };
+  // single package projects have their dependencies at the top level
+  let importers = doc.get("importers").unwrap_or(&doc);
+
+  let mut lockfile = ForeignLockfile::default();
+  for (key, snapshot) in snapshots.map(|s| s.entries()).unwrap_or_default() {
+    let id = strip_peers(key.strip_prefix('/').unwrap_or(key));
+    let Some(nv) = split_nv(id).and_then(|(name, version)| {
+      // packages from git repositories or tarballs have a URL as version
+      parse_nv(name, version)
+    }) else {
+      continue;
+    };
+    if lockfile.packages.contains_key(&nv) {
+      // the same package resolved with other peer dependencies
+      continue;
+    }
+    let metadata = if is_v6 {
+      Some(snapshot)
+    } else {
+      doc.get("packages").and_then(|packages| packages.get(id))
+    };
+    let mut package = ForeignPackage {
+      integrity: metadata
+        .and_then(|metadata| metadata.get("resolution"))
+        .and_then(|resolution| resolution.get("integrity"))
+        .and_then(|integrity| integrity.as_str())
+        .map(|integrity| integrity.to_string()),
+      dependencies: Default::default(),
+    };
+    let deps = snapshot
+      .get("dependencies")
+      .into_iter()
+      .chain(snapshot.get("optionalDependencies"))
+      .flat_map(|deps| deps.entries());
+    for (name, value) in deps {
+      match value.as_str().and_then(|value| resolve_dep(name, value)) {
+        Some(dep_nv) => {
+          package
+            .dependencies
+            .insert(StackString::from_str(name), dep_nv);
+        }
+        // a workspace package or a package from a git repository
+        None => package.integrity = None,
+      }
+    }
+    lockfile.packages.insert(nv, package);
+  }
+
+  let importers = match importers.get(".") {
+    // a workspace, where the importers are keyed by their folder
+    Some(_) => importers.entries().iter().map(|(_, i)| i).collect(),
+    None => vec![importers],
+  };
+  for importer in importers {
+    let deps = ["dependencies", "devDependencies", "optionalDependencies"]
+      .into_iter()
+      .filter_map(|section| importer.get(section))
+      .flat_map(|deps| deps.entries());
+    for (name, dep) in deps {
+      let dep_nv = dep
+        .get("version")
+        .and_then(|version| version.as_str())
+        .and_then(|version| resolve_dep(name, version));
+      if let Some(dep_nv) = dep_nv {
+        lockfile.root_packages.insert(dep_nv);
+      }
+    }
+  }
+  Ok(lockfile)
+}
+
+/// Resolves a dependency from its version, which is `name@version` when the
+/// package is installed under an alias.
+fn resolve_dep(name: &str, value: &str) -> Option<PackageNv> {
+  let value = strip_peers(value.strip_prefix('/').unwrap_or(value));
+  match split_nv(value) {
+    Some((name, version)) => parse_nv(name, version),
+    None => parse_nv(name, value),
+  }
+}
+
+/// Removes the resolved peer dependencies, ex. `a@1.0.0(b@2.0.0)`.
+fn strip_peers(id: &str) -> &str {
+  id.split_once('(').map(|(id, _)| id).unwrap_or(id)
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::npm_lockfiles::test::foreign_lockfile;
+  use crate::npm_lockfiles::test::foreign_package;
+
+  #[test]
+  fn parses_v9() {
+    let text = r#"lockfileVersion: '9.0'
+
+settings:
+  autoInstallPeers: true
+  excludeLinksFromLockfile: false
+
+importers:
+
+  .:
+    dependencies:
+      a:
+        specifier: ^1.0.0
+        version: 1.0.0(c@1.0.0)
+      alias:
+        specifier: npm:b@^2.0.0
+        version: b@2.0.0
+
+  packages/member:
+    devDependencies:
+      '@scope/d':
+        specifier: ^1.0.0
+        version: 1.0.0
+      project:
+        specifier: workspace:*
+        version: link:../..
+
+packages:
+
+  '@scope/d@1.0.0':
+    resolution: {integrity: sha512-d}
+
+  a@1.0.0:
+    resolution: {integrity: sha512-a}
+    peerDependencies:
+      c: ^1.0.0
+
+  b@2.0.0:
+    resolution: {integrity: sha512-b}
+    hasBin: true
+
+  c@1.0.0:
+    resolution: {integrity: sha512-c}
+
+  e@1.0.0:
+    resolution: {tarball: https://example.com/e.tgz}
+
+  f@1.0.0:
+    resolution: {integrity: sha512-f}
+
+  g@https://codeload.github.com/g/g/tar.gz/abc:
+    resolution: {tarball: https://codeload.github.com/g/g/tar.gz/abc}
+    version: 1.0.0
+
+snapshots:
+
+  '@scope/d@1.0.0':
+    dependencies:
+      e: 1.0.0
+
+  a@1.0.0(c@1.0.0):
+    dependencies:
+      b: 2.0.0
+      c: 1.0.0
+    optionalDependencies:
+      '@scope/d': 1.0.0
+
+  b@2.0.0: {}
+
+  c@1.0.0: {}
+
+  e@1.0.0: {}
+
+  f@1.0.0:
+    dependencies:
+      g: https://codeload.github.com/g/g/tar.gz/abc
+
+  g@https://codeload.github.com/g/g/tar.gz/abc: {}
+"#;
+    assert_eq!(
+      parse(text).unwrap(),
+      foreign_lockfile(
+        vec![
+          (
+            "@scope/d@1.0.0",
+            foreign_package("sha512-d", &[("e", "e@1.0.0")])
+          ),
+          (
+            "a@1.0.0",
+            foreign_package(
+              "sha512-a",
+              &[
+                ("@scope/d", "@scope/d@1.0.0"),
+                ("b", "b@2.0.0"),
+                ("c", "c@1.0.0"),
+              ]
+            )
+          ),
+          ("b@2.0.0", foreign_package("sha512-b", &[])),
+          ("c@1.0.0", foreign_package("sha512-c", &[])),
+          (
+            "e@1.0.0",
+            ForeignPackage {
+              integrity: None,
+              dependencies: Default::default(),
+            }
+          ),
+          (
+            "f@1.0.0",
+            ForeignPackage {
+              integrity: None,
+              dependencies: Default::default(),
+            }
+          ),
+        ],
+        &["@scope/d@1.0.0", "a@1.0.0", "b@2.0.0"],
+      )
+    );
+  }
+
+  #[test]
+  fn parses_v6() {
+    let text = r#"lockfileVersion: '6.0'
+
+dependencies:
+  a:
+    specifier: ^1.0.0
+    version: 1.0.0(c@1.0.0)
+
+devDependencies:
+  alias:
+    specifier: npm:b@^2.0.0
+    version: /b@2.0.0
+
+packages:
+
+  /a@1.0.0(c@1.0.0):
+    resolution: {integrity: sha512-a}
+    peerDependencies:
+      c: ^1.0.0
+    dependencies:
+      b: 2.0.0
+      c: 1.0.0
+    dev: false
+
+  /b@2.0.0:
+    resolution: {integrity: sha512-b}
+    dev: false
+
+  /c@1.0.0:
+    resolution: {integrity: sha512-c}
+    dev: false
+"#;
+    assert_eq!(
+      parse(text).unwrap(),
+      foreign_lockfile(
+        vec![
+          (
+            "a@1.0.0",
+            foreign_package("sha512-a", &[("b", "b@2.0.0"), ("c", "c@1.0.0")])
+          ),
+          ("b@2.0.0", foreign_package("sha512-b", &[])),
+          ("c@1.0.0", foreign_package("sha512-c", &[])),
+        ],
+        &["a@1.0.0", "b@2.0.0"],
+      )
+    );
+  }
+
+  #[test]
+  fn errors_on_unsupported_version() {
+    let err = parse("lockfileVersion: 5.4\n").unwrap_err();
+    assert_eq!(err.to_string(), "Unsupported lockfile version '5.4'");
+  }
+}
@@ -0,0 +1,552 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! A parser for the subset of YAML that package managers write to their
+//! lockfiles: block mappings and sequences, flow mappings and sequences, and
+//! plain or quoted scalars.
+//!
+//! Anchors, aliases, tags, block scalars and multiple documents aren't
+//! supported and are reported as errors instead of being read as plain
+//! scalars, so that a lockfile using them is never silently misread.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum YamlValue {
+  Null,
+  Scalar(String),
+  Sequence(Vec<YamlValue>),
+  Mapping(Vec<(String, YamlValue)>),
+}
+
+impl YamlValue {
+  pub fn get(&self, key: &str) -> Option<&YamlValue> {
+    self
+      .entries()
+      .iter()
+      .find(|(entry_key, _)| entry_key == key)
+      .map(|(_, value)| value)
+  }
+
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      YamlValue::Scalar(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  /// The entries of a mapping, or nothing for other values.
+  pub fn entries(&self) -> &[(String, YamlValue)] {
+    match self {
+      YamlValue::Mapping(entries) => entries,
+      _ => &[],
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct YamlError {
+  /// 1-based line number.
+  pub line: usize,
+  pub message: String,
+}
+
+pub fn parse(text: &str) -> Result<YamlValue, YamlError> {
+  let mut lines = Vec::new();
+  for (index, line) in text.lines().enumerate() {
+    let line = line.trim_end();
+    let content = line.trim_start_matches(' ');
+    if content.is_empty() || content.starts_with('#') {
+      continue;
+    }
+    let line = Line {
+      number: index + 1,
+      indent: line.len() - content.len(),
+      text: content,
+    };
+    if content == "---" || content == "..." {
+      // only the start marker of a single document is allowed
+      if lines.is_empty() && content == "---" {
+        continue;
+      }
+      return Err(line.error("Multiple documents aren't supported"));
+    }
+    lines.push(line);
+  }
+  let mut parser = BlockParser { lines, index: 0 };
+  let Some(first) = parser.peek() else {
+    return Ok(YamlValue::Null);
+  };
+  let value = parser.parse_block(first.indent)?;
+  match parser.peek() {
+    Some(line) => Err(line.error("Unexpected indentation")),
+    None => Ok(value),
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+  number: usize,
+  indent: usize,
+  text: &'a str,
+}
+
+impl Line<'_> {
+  fn error(&self, message: impl Into<String>) -> YamlError {
+    YamlError {
+      line: self.number,
+      message: message.into(),
+    }
+  }
+
+  fn is_sequence_item(&self) -> bool {
+    self.text == "-" || self.text.starts_with("- ")
+  }
+}
+
+struct BlockParser<'a> {
+  lines: Vec<Line<'a>>,
+  index: usize,
+}
+
+impl<'a> BlockParser<'a> {
+  fn peek(&self) -> Option<Line<'a>> {
+    self.lines.get(self.index).copied()
+  }
+
+  fn parse_block(&mut self, indent: usize) -> Result<YamlValue, YamlError> {
+    match self.peek() {
+      Some(line) if line.is_sequence_item() => self.parse_sequence(indent),
+      _ => self.parse_mapping(indent),
+    }
+  }
+
+  fn parse_mapping(&mut self, indent: usize) -> Result<YamlValue, YamlError> {
+    let mut entries = Vec::new();
+    while let Some(line) = self.peek() {
+      if line.indent < indent {
+        break;
+      }
+      if line.indent > indent || line.is_sequence_item() {
+        return Err(line.error("Unexpected indentation"));
+      }
+      self.index += 1;
+      let (key, rest) = split_mapping_entry(line)?;
+      let value = if rest.is_empty() {
+        self.parse_nested(indent)?
+      } else {
+        self.parse_inline(line, rest, indent)?
+      };
+      entries.push((key, value));
+    }
+    Ok(YamlValue::Mapping(entries))
+  }
+
+  fn parse_sequence(&mut self, indent: usize) -> Result<YamlValue, YamlError> {
+    let mut items = Vec::new();
+    while let Some(line) = self.peek() {
+      if line.indent != indent || !line.is_sequence_item() {
+        break;
+      }
+      self.index += 1;
+      let rest = line.text[1..].trim_start();
+      let item = if rest.is_empty() {
+        self.parse_nested(indent)?
+      } else {
+        self.parse_inline(line, rest, indent)?
+      };
+      items.push(item);
+    }
+    Ok(YamlValue::Sequence(items))
+  }
+
+  /// Parses the value of a mapping entry or sequence item that starts on the
+  /// following lines.
+  fn parse_nested(&mut self, indent: usize) -> Result<YamlValue, YamlError> {
+    match self.peek() {
+      Some(next) if next.indent > indent => self.parse_block(next.indent),
+      // sequences may have the same indentation as their key
+      Some(next) if next.indent == indent && next.is_sequence_item() => {
+        self.parse_sequence(indent)
+      }
+      _ => Ok(YamlValue::Null),
+    }
+  }
+
+  fn parse_inline(
+    &mut self,
+    line: Line,
+    text: &str,
+    indent: usize,
+  ) -> Result<YamlValue, YamlError> {
+    let is_plain = !text.starts_with(['{', '[', '\'', '"']);
+    let mut parser = FlowParser { line, text, pos: 0 };
+    let value = parser.parse_value(false)?;
+    parser.skip_whitespace();
+    if !parser.is_at_end() && !parser.rest().starts_with('#') {
+      return Err(line.error(format!("Unexpected '{}'", parser.rest())));
+    }
+    match value {
+      YamlValue::Scalar(mut value) if is_plain => {
+        // plain scalars continue on more indented lines
+        while let Some(next) = self.peek() {
+          if next.indent <= indent {
+            break;
+          }
+          self.index += 1;
+          value.push(' ');
+          value.push_str(next.text);
+        }
+        Ok(YamlValue::Scalar(value))
+      }
+      value => Ok(value),
+    }
+  }
+}
+
+fn split_mapping_entry(line: Line) -> Result<(String, &str), YamlError> {
+  let text = line.text;
+  if text.starts_with(['\'', '"']) {
+    let mut parser = FlowParser { line, text, pos: 0 };
+    let key = parser.parse_quoted()?;
+    parser.skip_whitespace();
+    let Some(rest) = parser.rest().strip_prefix(':') else {
+      return Err(line.error("Expected ':' after the key"));
+    };
+    return Ok((key, rest.trim_start()));
+  }
+  check_plain_scalar(line, text)?;
+  let colon_index = text
+    .find(": ")
+    .or_else(|| text.ends_with(':').then(|| text.len() - 1))
+    .ok_or_else(|| line.error("Expected a mapping entry"))?;
+  Ok((
+    text[..colon_index].trim_end().to_string(),
+    text[colon_index + 1..].trim_start(),
+  ))
+}
+
+/// Errors for the unsupported features that start with an indicator that a
+/// plain scalar can't start with.
+fn check_plain_scalar(line: Line, value: &str) -> Result<(), YamlError> {
+  let message = match value.chars().next() {
+    Some('&' | '*') => "Anchors and aliases aren't supported",
+    Some('!') => "Tags aren't supported",
+    Some('|' | '>') => "Block scalars aren't supported",
+    Some('?') if value == "?" || value.starts_with("? ") => {
+      "Complex mapping keys aren't supported"
+    }
+    Some(c @ ('%' | '@' | '`')) => {
+      return Err(line.error(format!("Unexpected '{c}'")));
+    }
+    _ => return Ok(()),
+  };
+  Err(line.error(message))
+}
+
+struct FlowParser<'a> {
+  line: Line<'a>,
+  text: &'a str,
+  pos: usize,
+}
+
+impl<'a> FlowParser<'a> {
+  fn rest(&self) -> &'a str {
+    &self.text[self.pos..]
+  }
+
+  fn is_at_end(&self) -> bool {
+    self.pos >= self.text.len()
+  }
+
+  fn peek_char(&self) -> Option<char> {
+    self.rest().chars().next()
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.pos += rest.len() - rest.trim_start().len();
+  }
+
+  fn error(&self, message: impl Into<String>) -> YamlError {
+    self.line.error(message)
+  }
+
+  fn parse_value(&mut self, in_flow: bool) -> Result<YamlValue, YamlError> {
+    self.skip_whitespace();
+    match self.peek_char() {
+      Some('{') => self.parse_flow_mapping(),
+      Some('[') => self.parse_flow_sequence(),
+      Some('\'' | '"') => self.parse_quoted().map(YamlValue::Scalar),
+      _ => {
+        let value = self.parse_plain(in_flow);
+        check_plain_scalar(self.line, value)?;
+        if value.is_empty() {
+          Ok(YamlValue::Null)
+        } else {
+          Ok(YamlValue::Scalar(value.to_string()))
+        }
+      }
+    }
+  }
+
+  /// Parses a plain scalar, which in flow collections ends at an indicator.
+  fn parse_plain(&mut self, in_flow: bool) -> &'a str {
+    let rest = self.rest();
+    let end = if in_flow {
+      rest.find([',', '}', ']']).unwrap_or(rest.len())
+    } else {
+      rest.find(" #").unwrap_or(rest.len())
+    };
+    self.pos += end;
+    rest[..end].trim()
+  }
+
+  fn parse_quoted(&mut self) -> Result<String, YamlError> {
+    let quote = self.peek_char().unwrap();
+    self.pos += 1;
+    let mut value = String::new();
+    let mut chars = self.rest().char_indices();
+    while let Some((index, c)) = chars.next() {
+      match c {
+        '\'' if quote == '\'' => {
+          if self.rest()[index + 1..].starts_with('\'') {
+            chars.next();
+            value.push('\'');
+          } else {
+            self.pos += index + 1;
+            return Ok(value);
+          }
+        }
+        '"' if quote == '"' => {
+          self.pos += index + 1;
+          return Ok(value);
+        }
+        '\\' if quote == '"' => match chars.next() {
+          Some((_, 'n')) => value.push('\n'),
+          Some((_, 't')) => value.push('\t'),
+          Some((_, c @ ('"' | '\\' | '/'))) => value.push(c),
+          Some((_, c)) => {
+            return Err(self.error(format!("Unsupported escape '\\{c}'")));
+          }
+          None => break,
+        },
+        c => value.push(c),
+      }
+    }
+    Err(self.error("Unterminated string"))
+  }
+
+  fn parse_flow_mapping(&mut self) -> Result<YamlValue, YamlError> {
+    self.pos += 1;
+    let mut entries = Vec::new();
+    loop {
+      self.skip_whitespace();
+      match self.peek_char() {
+        Some('}') => {
+          self.pos += 1;
+          return Ok(YamlValue::Mapping(entries));
+        }
+        Some(_) => {}
+        None => return Err(self.error("Unterminated flow mapping")),
+      }
+      let key = match self.peek_char() {
+        Some('\'' | '"') => self.parse_quoted()?,
+        _ => {
+          let rest = self.rest();
+          let end = rest.find([':', ',', '}']).unwrap_or(rest.len());
+          self.pos += end;
+          rest[..end].trim().to_string()
+        }
+      };
+      self.skip_whitespace();
+      let value = if self.rest().starts_with(':') {
+        self.pos += 1;
+        self.parse_value(true)?
+      } else {
+        YamlValue::Null
+      };
+      entries.push((key, value));
+      self.skip_flow_separator('}')?;
+    }
+  }
+
+  fn parse_flow_sequence(&mut self) -> Result<YamlValue, YamlError> {
+    self.pos += 1;
+    let mut items = Vec::new();
+    loop {
+      self.skip_whitespace();
+      match self.peek_char() {
+        Some(']') => {
+          self.pos += 1;
+          return Ok(YamlValue::Sequence(items));
+        }
+        Some(_) => {}
+        None => return Err(self.error("Unterminated flow sequence")),
+      }
+      items.push(self.parse_value(true)?);
+      self.skip_flow_separator(']')?;
+    }
+  }
+
+  fn skip_flow_separator(&mut self, end: char) -> Result<(), YamlError> {
+    self.skip_whitespace();
+    match self.peek_char() {
+      Some(',') => {
+        self.pos += 1;
+        Ok(())
+      }
+      // a missing end is reported by the caller
+      Some(c) if c == end => Ok(()),
+      None => Ok(()),
+      _ => Err(self.error(format!("Expected ',' or '{end}'"))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn scalar(value: &str) -> YamlValue {
+    YamlValue::Scalar(value.to_string())
+  }
+
+  fn mapping(entries: Vec<(&str, YamlValue)>) -> YamlValue {
+    YamlValue::Mapping(
+      entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect(),
+    )
+  }
+
+  #[test]
+  fn parses_pnpm_subset() {
+    let text = r#"lockfileVersion: '9.0'
+
+# comment
+importers:
+
+  .:
+    dependencies:
+      '@scope/a':
+        specifier: ^1.0.0
+        version: 1.0.0(b@2.0.0)
+
+packages:
+
+  '@scope/a@1.0.0':
+    resolution: {integrity: sha512-abc==, tarball: 'https://x.test/a.tgz'}
+    os: [darwin, linux]
+    deprecated: this package
+      is deprecated
+    empty: {}
+    list:
+    - "a\"b"
+    - c
+"#;
+    assert_eq!(
+      parse(text).unwrap(),
+      mapping(vec![
+        ("lockfileVersion", scalar("9.0")),
+        (
+          "importers",
+          mapping(vec![(
+            ".",
+            mapping(vec![(
+              "dependencies",
+              mapping(vec![(
+                "@scope/a",
+                mapping(vec![
+                  ("specifier", scalar("^1.0.0")),
+                  ("version", scalar("1.0.0(b@2.0.0)")),
+                ]),
+              )]),
+            )]),
+          )]),
+        ),
+        (
+          "packages",
+          mapping(vec![(
+            "@scope/a@1.0.0",
+            mapping(vec![
+              (
+                "resolution",
+                mapping(vec![
+                  ("integrity", scalar("sha512-abc==")),
+                  ("tarball", scalar("https://x.test/a.tgz")),
+                ]),
+              ),
+              (
+                "os",
+                YamlValue::Sequence(vec![scalar("darwin"), scalar("linux")]),
+              ),
+              ("deprecated", scalar("this package is deprecated")),
+              ("empty", mapping(vec![])),
+              (
+                "list",
+                YamlValue::Sequence(vec![scalar("a\"b"), scalar("c")]),
+              ),
+            ]),
+          )]),
+        ),
+      ])
+    );
+  }
+
+  #[test]
+  fn errors_on_invalid_yaml() {
+    assert_eq!(
+      parse("a:\n  b: 1\n c: 2\n").unwrap_err(),
+      YamlError {
+        line: 3,
+        message: "Unexpected indentation".to_string(),
+      }
+    );
+    assert_eq!(
+      parse("a: {b: 1\n").unwrap_err(),
+      YamlError {
+        line: 1,
+        message: "Unterminated flow mapping".to_string(),
+      }
+    );
+    assert_eq!(
+      parse("a: 'b\n").unwrap_err(),
+      YamlError {
+        line: 1,
+        message: "Unterminated string".to_string(),
+      }
+    );
+  }
+
+  #[test]
+  fn errors_on_unsupported_features() {
+    let cases = [
+      ("a: &b c\n", 1, "Anchors and aliases aren't supported"),
+      ("a: b\nc: *b\n", 2, "Anchors and aliases aren't supported"),
+      ("a: [b, *c]\n", 1, "Anchors and aliases aren't supported"),
+      ("a: !!str b\n", 1, "Tags aren't supported"),
+      ("a: |\n  b\n", 1, "Block scalars aren't supported"),
+      ("a: >-\n  b\n", 1, "Block scalars aren't supported"),
+      ("? a\n: b\n", 1, "Complex mapping keys aren't supported"),
+      ("&a b: c\n", 1, "Anchors and aliases aren't supported"),
+      ("a: @b\n", 1, "Unexpected '@'"),
+      (
+        "---\na: b\n---\nc: d\n",
+        3,
+        "Multiple documents aren't supported",
+      ),
+      ("a: b\n...\n", 2, "Multiple documents aren't supported"),
+    ];
+    for (text, line, message) in cases {
+      assert_eq!(
+        parse(text).unwrap_err(),
+        YamlError {
+          line,
+          message: message.to_string(),
+        },
+        "{text}"
+      );
+    }
+  }
+}
@@ -0,0 +1,307 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Reads `yarn.lock` files of yarn v1.
+//!
+//! Lockfiles of later yarn versions are not supported because they store a
+//! checksum of yarn's own archive of a package instead of the integrity of
+//! its npm tarball.
+
+use std::collections::HashMap;
+
+use deno_semver::StackString;
+use deno_semver::VersionReq;
+use deno_semver::package::PackageNv;
+
+use super::ForeignLockfile;
+use super::ForeignPackage;
+use super::parse_nv;
+use super::split_nv;
+use super::yaml;
+use crate::error::NpmLockfileImportErrorReason;
+
+#[derive(Debug, Default)]
+struct Entry {
+  /// The `name@range` descriptors that resolve to this entry.
+  descriptors: Vec<String>,
+  fields: HashMap<String, String>,
+  /// Dependencies by name and range, and whether they're optional.
+  dependencies: Vec<(String, String, bool)>,
+}
+
+pub fn parse(
+  text: &str,
+) -> Result<ForeignLockfile, NpmLockfileImportErrorReason> {
+  if text.lines().any(|line| line == "__metadata:") {
+    let version = yaml::parse(text)
+      .ok()
+      .and_then(|doc| {
+        let version = doc.get("__metadata")?.get("version")?.as_str()?;
+        Some(version.to_string())
+      })
+      .unwrap_or_default();
+    return Err(NpmLockfileImportErrorReason::UnsupportedVersion { version });
+  }
+
+  let entries = parse_entries(text)?;
+  let mut nv_by_descriptor = HashMap::new();
+  for entry in &entries {
+    let nv = entry_nv(entry);
+    for descriptor in &entry.descriptors {
+      nv_by_descriptor.insert(descriptor.as_str(), nv.clone());
+    }
+  }
+
+  let mut lockfile = ForeignLockfile::default();
+  for entry in &entries {
+    let Some(nv) = entry_nv(entry) else {
+      continue;
+    };
+    if lockfile.packages.contains_key(&nv) {
+      continue;
+    }
+    let mut package = ForeignPackage {
+      integrity: entry_integrity(entry),
+      dependencies: Default::default(),
+    };
+    for (name, range, optional) in &entry.dependencies {
+      match nv_by_descriptor.get(format!("{name}@{range}").as_str()) {
+        Some(Some(dep_nv)) => {
+          package
+            .dependencies
+            .insert(StackString::from_str(name), dep_nv.clone());
+        }
+        // optional dependencies might not be installed
+        None if *optional => {}
+        _ => package.integrity = None,
+      }
+    }
+    // the lockfile doesn't say which packages are direct dependencies
+    lockfile.root_packages.insert(nv.clone());
+    lockfile.packages.insert(nv, package);
+  }
+  Ok(lockfile)
+}
+
+/// Gets the package of an entry, which is `None` when it's not from the npm
+/// registry.
+fn entry_nv(entry: &Entry) -> Option<PackageNv> {
+  let version = entry.fields.get("version")?;
+  let mut name = None;
+  for descriptor in &entry.descriptors {
+    let (alias, range) = split_nv(descriptor)?;
+    let (package_name, range) = match range.strip_prefix("npm:") {
+      Some(range) => split_nv(range).unwrap_or((alias, range)),
+      None => (alias, range),
+    };
+    // git repositories, tarballs and local folders have other ranges
+    if range.contains([':', '/']) {
+      return None;
+    }
+    VersionReq::parse_from_npm(range).ok()?;
+    name = Some(package_name);
+  }
+  parse_nv(name?, version)
+}
+
+fn entry_integrity(entry: &Entry) -> Option<String> {
+  if let Some(integrity) = entry.fields.get("integrity") {
+    return Some(integrity.clone());
+  }
+  // older lockfiles only have the shasum of the tarball
+  let (url, hash) = entry.fields.get("resolved")?.split_once('#')?;
+  let is_shasum =
+    hash.len() == 40 && hash.chars().all(|c| c.is_ascii_hexdigit());
+  (url.ends_with(".tgz") && is_shasum).then(|| hash.to_string())
+}
+
+fn parse_entries(
+  text: &str,
+) -> Result<Vec<Entry>, NpmLockfileImportErrorReason> {
+  let mut entries: Vec<Entry> = Vec::new();
+  let mut section = None;
+  for (index, line) in text.lines().enumerate() {
+    let syntax_error =
+      |message: &str| NpmLockfileImportErrorReason::SyntaxError {
+        line: index + 1,
+        message: message.to_string(),
+      };
+    let content = line.trim_start_matches(' ');
+    if content.trim().is_empty() || content.starts_with('#') {
+      continue;
+    }
+    let content = content.trim_end();
+    match line.len() - line.trim_start_matches(' ').len() {
+      0 => {
+        let descriptors = content
+          .strip_suffix(':')
+          .ok_or_else(|| syntax_error("Expected ':' after the entry"))?;
+        entries.push(Entry {
+          descriptors: split_descriptors(descriptors)
+            .ok_or_else(|| syntax_error("Invalid entry"))?,
+          ..Default::default()
+        });
+        section = None;
+      }
+      2 => {
+        let entry = entries
+          .last_mut()
+          .ok_or_else(|| syntax_error("Unexpected indentation"))?;
+        if let Some(name) = content.strip_suffix(':') {
+          section = Some(name.to_string());
+        } else {
+          let (key, value) = split_field(content)
+            .ok_or_else(|| syntax_error("Expected a field"))?;
+          entry.fields.insert(key, value);
+          section = None;
+        }
+      }
+      4 => {
+        let entry = entries
+          .last_mut()
+          .ok_or_else(|| syntax_error("Unexpected indentation"))?;
+        let (name, range) = split_field(content)
+          .ok_or_else(|| syntax_error("Expected a field"))?;
+        match section.as_deref() {
+          Some("dependencies") => entry.dependencies.push((name, range, false)),
+          Some("optionalDependencies") => {
+            entry.dependencies.push((name, range, true))
+          }
+          Some(_) => {}
+          None => return Err(syntax_error("Unexpected indentation")),
+        }
+      }
+      _ => return Err(syntax_error("Unexpected indentation")),
+    }
+  }
+  Ok(entries)
+}
+
+/// Splits `"a@^1.0.0", a@^1.1.0` into its descriptors.
+fn split_descriptors(text: &str) -> Option<Vec<String>> {
+  let mut descriptors = Vec::new();
+  let mut rest = text;
+  loop {
+    let (descriptor, after) = parse_token(rest, ',')?;
+    descriptors.push(descriptor);
+    match after.trim_start().strip_prefix(',') {
+      Some(after) => rest = after.trim_start(),
+      None if after.trim().is_empty() => return Some(descriptors),
+      None => return None,
+    }
+  }
+}
+
+/// Splits a field line like `resolved "https://..."`.
+fn split_field(text: &str) -> Option<(String, String)> {
+  let (key, rest) = parse_token(text, ' ')?;
+  let (value, rest) = parse_token(rest.trim_start(), '\0')?;
+  rest.trim().is_empty().then_some((key, value))
+}
+
+/// Parses a quoted string, or an unquoted one up to the delimiter.
+fn parse_token(text: &str, delimiter: char) -> Option<(String, &str)> {
+  if text.starts_with('"') {
+    let mut escaped = false;
+    let end = text[1..].find(|c| {
+      let is_end = c == '"' && !escaped;
+      escaped = c == '\\' && !escaped;
+      is_end
+    })?
+      + 1;
+    let value = serde_json::from_str(&text[..=end]).ok()?;
+    Some((value, &text[end + 1..]))
+  } else {
+    let end = text.find(delimiter).unwrap_or(text.len());
+    let value = text[..end].trim();
+    (!value.is_empty()).then(|| (value.to_string(), &text[end..]))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::npm_lockfiles::test::foreign_lockfile;
+  use crate::npm_lockfiles::test::foreign_package;
+
+  #[test]
+  fn parses_v1() {
+    let text = r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"@scope/a@^1.0.0", "@scope/a@^1.1.0":
+  version "1.1.0"
+  resolved "https://registry.yarnpkg.com/@scope/a/-/a-1.1.0.tgz#0123456789abcdef0123456789abcdef01234567"
+  integrity sha512-a
+  dependencies:
+    b "^2.0.0"
+  optionalDependencies:
+    fsevents "~2.3.1"
+
+alias@npm:b@^2.0.0, b@^2.0.0:
+  version "2.0.0"
+  resolved "https://registry.yarnpkg.com/b/-/b-2.0.0.tgz#0123456789abcdef0123456789abcdef01234567"
+
+c@^1.0.0:
+  version "1.0.0"
+  resolved "https://registry.yarnpkg.com/c/-/c-1.0.0.tgz#abc"
+  dependencies:
+    d "github:d/d"
+
+"d@github:d/d":
+  version "1.0.0"
+  resolved "https://codeload.github.com/d/d/tar.gz/0123456789abcdef0123456789abcdef01234567"
+"#;
+    assert_eq!(
+      parse(text).unwrap(),
+      foreign_lockfile(
+        vec![
+          (
+            "@scope/a@1.1.0",
+            foreign_package("sha512-a", &[("b", "b@2.0.0")])
+          ),
+          (
+            "b@2.0.0",
+            foreign_package("0123456789abcdef0123456789abcdef01234567", &[])
+          ),
+          (
+            "c@1.0.0",
+            ForeignPackage {
+              integrity: None,
+              dependencies: Default::default(),
+            }
+          ),
+        ],
+        &["@scope/a@1.1.0", "b@2.0.0", "c@1.0.0"],
+      )
+    );
+  }
+
+  #[test]
+  fn errors_on_berry() {
+    let text = r#"__metadata:
+  version: 8
+  cacheKey: 10c0
+
+"a@npm:^1.0.0":
+  version: 1.0.0
+  resolution: "a@npm:1.0.0"
+  checksum: 10c0/abc
+  languageName: node
+  linkType: hard
+"#;
+    let err = parse(text).unwrap_err();
+    assert_eq!(err.to_string(), "Unsupported lockfile version '8'");
+  }
+
+  #[test]
+  fn errors_on_invalid_syntax() {
+    let err = parse("a@^1.0.0\n  version \"1.0.0\"\n").unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Failed parsing line 1: Expected ':' after the entry"
+    );
+  }
+}
@@ -0,0 +1,109 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Protocol types and a reference server for the Deno permission broker.
+//!
+//! When `DENO_PERMISSION_BROKER_PATH` is set, Deno sends every permission
+//! check to the broker listening on that Unix socket (or Windows named pipe)
+//! instead of consulting its own permission state. Each request and response
+//! is a single JSON object terminated by a newline.
+
+mod policy;
+mod server;
+
+pub use policy::Decision;
+pub use policy::Policy;
+pub use policy::PolicyParseError;
+pub use policy::Rule;
+pub use server::BrokerListener;
+pub use server::BrokerStream;
+pub use server::PermissionBroker;
+
+/// Version of the JSON lines protocol spoken between Deno and the broker.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A permission check sent by a Deno process to the broker.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionBrokerRequest {
+  pub v: u32,
+  pub pid: u32,
+  /// Identifier of the request, unique per connection. The response must
+  /// carry the same id.
+  pub id: u32,
+  /// RFC 3339 timestamp of when the check was made.
+  pub datetime: String,
+  /// Permission kind, e.g. `read`, `net` or `env`.
+  pub permission: String,
+  /// The value being checked, or `None` when the whole permission is
+  /// requested (e.g. `Deno.env.toObject()`).
+  pub value: Option<String>,
+}
+
+/// The broker's answer to a [`PermissionBrokerRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionBrokerResponse {
+  pub id: u32,
+  pub result: PermissionBrokerResult,
+  /// Shown to the user when the permission is denied.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub reason: Option<String>,
+}
+
+#[derive(
+  Debug,
+  Default,
+  Clone,
+  Copy,
+  PartialEq,
+  Eq,
+  serde::Serialize,
+  serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PermissionBrokerResult {
+  Allow,
+  #[default]
+  Deny,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_wire_format() {
+    let request: PermissionBrokerRequest = serde_json::from_value(json!({
+      "v": 1,
+      "pid": 42,
+      "id": 3,
+      "datetime": "2025-01-01T00:00:00+00:00",
+      "permission": "env",
+      "value": null,
+    }))
+    .unwrap();
+    assert_eq!(request.permission, "env");
+    assert_eq!(request.value, None);
+
+    let response = PermissionBrokerResponse {
+      id: 3,
+      result: PermissionBrokerResult::Deny,
+      reason: Some("no env".to_string()),
+    };
+    assert_eq!(
+      serde_json::to_value(&response).unwrap(),
+      json!({ "id": 3, "result": "deny", "reason": "no env" })
+    );
+    let response = PermissionBrokerResponse {
+      id: 4,
+      result: PermissionBrokerResult::Allow,
+      reason: None,
+    };
+    assert_eq!(
+      serde_json::to_string(&response).unwrap(),
+      r#"{"id":4,"result":"allow"}"#
+    );
+  }
+}
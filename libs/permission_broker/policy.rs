@@ -0,0 +1,243 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use serde::Deserialize;
+
+use crate::PermissionBrokerRequest;
+use crate::PermissionBrokerResult;
+
+/// A set of rules deciding which permission requests are allowed.
+///
+/// ```json
+/// {
+///   "default": "deny",
+///   "rules": [
+///     { "permission": "read", "allow": ["./data/*"], "deny": ["./data/secret*"] },
+///     { "permission": "env", "deny": ["*"], "reason": "Reading env vars is not allowed." },
+///     { "permission": "*", "pids": [4242], "allow": ["*"] }
+///   ]
+/// }
+/// ```
+///
+/// Rules are evaluated in order and the first one with a matching pattern
+/// decides. Within a rule, `deny` patterns take precedence over `allow`
+/// patterns. Requests not matched by any rule get the `default` decision.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+  #[serde(default)]
+  pub default: PermissionBrokerResult,
+  #[serde(default)]
+  pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+  /// Permission kind the rule applies to, or `*` for every kind.
+  pub permission: String,
+  /// Only apply the rule to requests from these processes.
+  #[serde(default)]
+  pub pids: Option<Vec<u32>>,
+  #[serde(default)]
+  pub allow: Vec<String>,
+  #[serde(default)]
+  pub deny: Vec<String>,
+  /// Reason reported to the process when the rule denies a request.
+  #[serde(default)]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PolicyParseError {
+  #[error("Invalid permission broker rules: {0}")]
+  Json(#[from] serde_json::Error),
+  #[error("Rule {index} for \"{permission}\" has no allow or deny patterns")]
+  EmptyRule { index: usize, permission: String },
+}
+
+/// Outcome of evaluating a request against a [`Policy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+  pub result: PermissionBrokerResult,
+  pub reason: Option<String>,
+  /// Index of the rule that made the decision, `None` for the default.
+  pub rule: Option<usize>,
+}
+
+impl Policy {
+  pub fn parse(text: &str) -> Result<Self, PolicyParseError> {
+    let policy: Policy = serde_json::from_str(text)?;
+    for (index, rule) in policy.rules.iter().enumerate() {
+      if rule.allow.is_empty() && rule.deny.is_empty() {
+        return Err(PolicyParseError::EmptyRule {
+          index,
+          permission: rule.permission.clone(),
+        });
+      }
+    }
+    Ok(policy)
+  }
+
+  pub fn decide(&self, request: &PermissionBrokerRequest) -> Decision {
+    let value = request.value.as_deref();
+    for (index, rule) in self.rules.iter().enumerate() {
+      if !rule.applies_to(request) {
+        continue;
+      }
+      if rule.deny.iter().any(|p| matches_pattern(p, value)) {
+        return Decision {
+          result: PermissionBrokerResult::Deny,
+          reason: rule.reason.clone(),
+          rule: Some(index),
+        };
+      }
+      if rule.allow.iter().any(|p| matches_pattern(p, value)) {
+        return Decision {
+          result: PermissionBrokerResult::Allow,
+          reason: None,
+          rule: Some(index),
+        };
+      }
+    }
+    Decision {
+      result: self.default,
+      reason: None,
+      rule: None,
+    }
+  }
+}
+
+impl Rule {
+  fn applies_to(&self, request: &PermissionBrokerRequest) -> bool {
+    (self.permission == "*" || self.permission == request.permission)
+      && self
+        .pids
+        .as_ref()
+        .is_none_or(|pids| pids.contains(&request.pid))
+  }
+}
+
+/// Matches a value against a pattern where `*` matches any sequence of
+/// characters. A request for the whole permission (no value) is only
+/// matched by the `*` pattern.
+fn matches_pattern(pattern: &str, value: Option<&str>) -> bool {
+  let Some(value) = value else {
+    return pattern == "*";
+  };
+  let pattern = pattern.as_bytes();
+  let value = value.as_bytes();
+  let (mut p, mut v) = (0, 0);
+  // Position of the last `*` in the pattern and the value position it
+  // was tried at, to backtrack to on a mismatch.
+  let mut backtrack = None;
+  while v < value.len() {
+    if p < pattern.len() && pattern[p] == b'*' {
+      backtrack = Some((p, v));
+      p += 1;
+    } else if p < pattern.len() && pattern[p] == value[v] {
+      p += 1;
+      v += 1;
+    } else if let Some((star_p, star_v)) = backtrack {
+      p = star_p + 1;
+      v = star_v + 1;
+      backtrack = Some((star_p, star_v + 1));
+    } else {
+      return false;
+    }
+  }
+  pattern[p..].iter().all(|c| *c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn request(
+    pid: u32,
+    permission: &str,
+    value: Option<&str>,
+  ) -> PermissionBrokerRequest {
+    PermissionBrokerRequest {
+      v: 1,
+      pid,
+      id: 1,
+      datetime: String::new(),
+      permission: permission.to_string(),
+      value: value.map(|v| v.to_string()),
+    }
+  }
+
+  #[test]
+  fn test_matches_pattern() {
+    assert!(matches_pattern("*", None));
+    assert!(matches_pattern("*", Some("")));
+    assert!(!matches_pattern("HOME", None));
+    assert!(matches_pattern("HOME", Some("HOME")));
+    assert!(!matches_pattern("HOME", Some("HOMEPATH")));
+    assert!(matches_pattern("./data/*", Some("./data/a/b.json")));
+    assert!(!matches_pattern("./data/*", Some("./other/a.json")));
+    assert!(matches_pattern(
+      "*.example.com:*",
+      Some("api.example.com:443")
+    ));
+    assert!(matches_pattern("a*b*c", Some("aXbYbZc")));
+    assert!(!matches_pattern("a*b*c", Some("aXbYbZ")));
+  }
+
+  #[test]
+  fn test_decide() {
+    let policy = Policy::parse(
+      r#"{
+        "rules": [
+          { "permission": "*", "pids": [7], "allow": ["*"] },
+          { "permission": "read", "allow": ["./data/*"], "deny": ["./data/secret*"] },
+          { "permission": "env", "allow": ["PORT"], "deny": ["*"], "reason": "No env." }
+        ]
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(policy.default, PermissionBrokerResult::Deny);
+
+    let decide = |pid, permission, value| {
+      let decision = policy.decide(&request(pid, permission, value));
+      (decision.result, decision.reason, decision.rule)
+    };
+    use PermissionBrokerResult::*;
+    assert_eq!(
+      decide(1, "read", Some("./data/a.json")),
+      (Allow, None, Some(1))
+    );
+    assert_eq!(
+      decide(1, "read", Some("./data/secret.key")),
+      (Deny, None, Some(1))
+    );
+    assert_eq!(decide(1, "read", Some("/etc/passwd")), (Deny, None, None));
+    assert_eq!(
+      decide(1, "env", None),
+      (Deny, Some("No env.".to_string()), Some(2))
+    );
+    // Deny patterns win over allow patterns of the same rule.
+    assert_eq!(
+      decide(1, "env", Some("PORT")),
+      (Deny, Some("No env.".to_string()), Some(2))
+    );
+    assert_eq!(decide(7, "env", None), (Allow, None, Some(0)));
+    assert_eq!(decide(7, "run", Some("git")), (Allow, None, Some(0)));
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert!(matches!(
+      Policy::parse(r#"{ "rules": [{ "permission": "read" }] }"#),
+      Err(PolicyParseError::EmptyRule { index: 0, .. })
+    ));
+    assert!(matches!(
+      Policy::parse(r#"{ "default": "maybe" }"#),
+      Err(PolicyParseError::Json(_))
+    ));
+    assert_eq!(
+      Policy::parse(r#"{ "default": "allow" }"#).unwrap().default,
+      PermissionBrokerResult::Allow
+    );
+  }
+}
@@ -0,0 +1,424 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::ffi::OsStr;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::Decision;
+use crate::PROTOCOL_VERSION;
+use crate::PermissionBrokerRequest;
+use crate::PermissionBrokerResponse;
+use crate::PermissionBrokerResult;
+use crate::Policy;
+
+/// A broker answering permission requests according to a [`Policy`].
+pub struct PermissionBroker {
+  policy: Policy,
+  decision_log: Option<Mutex<Box<dyn Write + Send>>>,
+}
+
+/// A single line of the decision log.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DecisionLogEntry<'a> {
+  datetime: &'a str,
+  pid: u32,
+  id: u32,
+  permission: &'a str,
+  value: Option<&'a str>,
+  result: PermissionBrokerResult,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  reason: Option<&'a str>,
+  rule: Option<usize>,
+}
+
+impl PermissionBroker {
+  pub fn new(policy: Policy) -> Self {
+    Self {
+      policy,
+      decision_log: None,
+    }
+  }
+
+  /// Writes every decision as a JSON line to `writer`.
+  pub fn with_decision_log(mut self, writer: Box<dyn Write + Send>) -> Self {
+    self.decision_log = Some(Mutex::new(writer));
+    self
+  }
+
+  pub fn decide(
+    &self,
+    request: &PermissionBrokerRequest,
+  ) -> PermissionBrokerResponse {
+    let decision = if request.v == PROTOCOL_VERSION {
+      self.policy.decide(request)
+    } else {
+      Decision {
+        result: PermissionBrokerResult::Deny,
+        reason: Some(format!(
+          "Unsupported permission broker protocol version {}",
+          request.v
+        )),
+        rule: None,
+      }
+    };
+    self.log_decision(request, &decision);
+    PermissionBrokerResponse {
+      id: request.id,
+      result: decision.result,
+      reason: decision.reason,
+    }
+  }
+
+  fn log_decision(
+    &self,
+    request: &PermissionBrokerRequest,
+    decision: &Decision,
+  ) {
+    let Some(decision_log) = &self.decision_log else {
+      return;
+    };
+    let entry = DecisionLogEntry {
+      datetime: &request.datetime,
+      pid: request.pid,
+      id: request.id,
+      permission: &request.permission,
+      value: request.value.as_deref(),
+      result: decision.result,
+      reason: decision.reason.as_deref(),
+      rule: decision.rule,
+    };
+    let mut line = serde_json::to_vec(&entry).unwrap();
+    line.push(b'\n');
+    let mut writer = decision_log.lock();
+    if let Err(err) = writer.write_all(&line).and_then(|_| writer.flush()) {
+      log::error!("Failed writing permission broker decision log: {}", err);
+    }
+  }
+
+  /// Answers requests on a connection until the client disconnects.
+  pub fn handle_connection(&self, stream: impl Read + Write) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    loop {
+      line.clear();
+      if reader.read_line(&mut line)? == 0 {
+        return Ok(());
+      }
+      let request = line.trim();
+      if request.is_empty() {
+        continue;
+      }
+      let request = serde_json::from_str::<PermissionBrokerRequest>(request)
+        .map_err(|err| {
+          io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+        })?;
+      let response = self.decide(&request);
+      let mut out = serde_json::to_vec(&response).unwrap();
+      out.push(b'\n');
+      let stream = reader.get_mut();
+      stream.write_all(&out)?;
+      stream.flush()?;
+    }
+  }
+
+  /// Accepts connections until the listener fails, serving each client on
+  /// its own thread.
+  pub fn serve(self: Arc<Self>, listener: BrokerListener) -> io::Result<()> {
+    loop {
+      let stream = listener.accept()?;
+      let broker = self.clone();
+      std::thread::spawn(move || {
+        if let Err(err) = broker.handle_connection(stream) {
+          log::error!("Permission broker connection error: {}", err);
+        }
+      });
+    }
+  }
+}
+
+#[cfg(unix)]
+pub type BrokerStream = std::os::unix::net::UnixStream;
+
+#[cfg(not(unix))]
+pub type BrokerStream = std::fs::File;
+
+/// The server side of `IpcPipe`: a Unix socket or a Windows named pipe.
+pub struct BrokerListener(ListenerInner);
+
+#[cfg(unix)]
+type ListenerInner = std::os::unix::net::UnixListener;
+
+#[cfg(windows)]
+type ListenerInner = PipeListener;
+
+/// A named pipe that always has an instance waiting for the next client, so
+/// that clients can connect as soon as the listener is bound.
+#[cfg(windows)]
+struct PipeListener {
+  /// The UTF-16 name of the pipe, terminated by a NUL.
+  name: Vec<u16>,
+  next: Mutex<std::fs::File>,
+}
+
+#[cfg(all(not(unix), not(windows)))]
+type ListenerInner = ();
+
+impl BrokerListener {
+  /// Listens on a local IPC endpoint.
+  /// - Unix: `addr` like `/tmp/deno.sock`
+  /// - Windows: `addr` like `\\.\pipe\deno-permission-broker`
+  pub fn bind(addr: impl AsRef<OsStr>) -> io::Result<Self> {
+    Self::bind_impl(addr.as_ref())
+  }
+}
+
+#[cfg(unix)]
+impl BrokerListener {
+  fn bind_impl(addr: &OsStr) -> io::Result<Self> {
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixListener;
+    use std::path::Path;
+    let path = Path::new(addr);
+    // Remove a socket left behind by a previous broker, but never a file
+    // that isn't a socket.
+    match std::fs::symlink_metadata(path) {
+      Ok(metadata) if metadata.file_type().is_socket() => {
+        std::fs::remove_file(path)?;
+      }
+      Ok(_) => {
+        return Err(io::Error::new(
+          io::ErrorKind::AlreadyExists,
+          format!("{} already exists and is not a socket", path.display()),
+        ));
+      }
+      Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+      Err(err) => return Err(err),
+    }
+    Ok(Self(UnixListener::bind(path)?))
+  }
+
+  pub fn accept(&self) -> io::Result<BrokerStream> {
+    let (stream, _) = self.0.accept()?;
+    Ok(stream)
+  }
+}
+
+#[cfg(windows)]
+impl BrokerListener {
+  fn bind_impl(addr: &OsStr) -> io::Result<Self> {
+    use std::os::windows::ffi::OsStrExt;
+    // OsStr -> UTF-16 + NUL
+    let mut name: Vec<u16> = addr.encode_wide().collect();
+    name.push(0);
+    let next = create_pipe_instance(&name)?;
+    Ok(Self(PipeListener {
+      name,
+      next: Mutex::new(next),
+    }))
+  }
+
+  /// Waits for a client to connect to the pending pipe instance.
+  pub fn accept(&self) -> io::Result<BrokerStream> {
+    use std::os::windows::io::AsRawHandle;
+
+    use windows_sys::Win32::Foundation::ERROR_PIPE_CONNECTED;
+    use windows_sys::Win32::System::Pipes::ConnectNamedPipe;
+
+    let mut next = self.0.next.lock();
+    // SAFETY: WinAPI call
+    if unsafe {
+      ConnectNamedPipe(next.as_raw_handle() as _, std::ptr::null_mut())
+    } == 0
+    {
+      let err = io::Error::last_os_error();
+      // ERROR_PIPE_CONNECTED means the client connected between creating
+      // the pipe and waiting for it, which is fine.
+      if err.raw_os_error() != Some(ERROR_PIPE_CONNECTED as i32) {
+        return Err(err);
+      }
+    }
+    // create the instance for the next client before handing out this one,
+    // so that there's no moment where clients can't find the pipe
+    let instance = create_pipe_instance(&self.0.name)?;
+    Ok(std::mem::replace(&mut *next, instance))
+  }
+}
+
+#[cfg(windows)]
+fn create_pipe_instance(name: &[u16]) -> io::Result<std::fs::File> {
+  use std::os::windows::io::FromRawHandle;
+
+  use windows_sys::Win32::Foundation::INVALID_HANDLE_VALUE;
+  use windows_sys::Win32::Storage::FileSystem::PIPE_ACCESS_DUPLEX;
+  use windows_sys::Win32::System::Pipes::CreateNamedPipeW;
+  use windows_sys::Win32::System::Pipes::PIPE_READMODE_BYTE;
+  use windows_sys::Win32::System::Pipes::PIPE_TYPE_BYTE;
+  use windows_sys::Win32::System::Pipes::PIPE_UNLIMITED_INSTANCES;
+  use windows_sys::Win32::System::Pipes::PIPE_WAIT;
+
+  // SAFETY: WinAPI call
+  let handle = unsafe {
+    CreateNamedPipeW(
+      name.as_ptr(),
+      PIPE_ACCESS_DUPLEX,
+      PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+      PIPE_UNLIMITED_INSTANCES,
+      65536,
+      65536,
+      0,
+      std::ptr::null(),
+    )
+  };
+  if handle == INVALID_HANDLE_VALUE {
+    return Err(io::Error::last_os_error());
+  }
+  // SAFETY: Passing WinAPI handle
+  Ok(unsafe { std::fs::File::from_raw_handle(handle as _) })
+}
+
+#[cfg(all(not(unix), not(windows)))]
+impl BrokerListener {
+  fn bind_impl(_addr: &OsStr) -> io::Result<Self> {
+    Err(io::Error::new(
+      io::ErrorKind::Unsupported,
+      "Platform not supported.",
+    ))
+  }
+
+  pub fn accept(&self) -> io::Result<BrokerStream> {
+    Err(io::Error::new(
+      io::ErrorKind::Unsupported,
+      "Platform not supported.",
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A writer that can be inspected after being moved into the broker.
+  #[derive(Clone, Default)]
+  struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+  impl Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      self.0.lock().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  /// In-memory duplex stream: reads from `input`, writes to `output`.
+  struct Duplex {
+    input: io::Cursor<Vec<u8>>,
+    output: Vec<u8>,
+  }
+
+  impl Read for Duplex {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+      self.input.read(buf)
+    }
+  }
+
+  impl Write for Duplex {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_handle_connection() {
+    let policy = Policy::parse(
+      r#"{ "rules": [
+        { "permission": "read", "allow": ["./*"] },
+        { "permission": "env", "deny": ["*"], "reason": "No env." }
+      ] }"#,
+    )
+    .unwrap();
+    let log = SharedBuf::default();
+    let broker =
+      PermissionBroker::new(policy).with_decision_log(Box::new(log.clone()));
+    let mut stream = Duplex {
+      input: io::Cursor::new(
+        concat!(
+          r#"{"v":1,"pid":10,"id":1,"datetime":"d","permission":"read","value":"./a.txt"}"#,
+          "\n",
+          r#"{"v":1,"pid":10,"id":2,"datetime":"d","permission":"env","value":null}"#,
+          "\n",
+          r#"{"v":2,"pid":10,"id":3,"datetime":"d","permission":"read","value":"./a.txt"}"#,
+          "\n",
+        )
+        .as_bytes()
+        .to_vec(),
+      ),
+      output: Vec::new(),
+    };
+    broker.handle_connection(&mut stream).unwrap();
+    assert_eq!(
+      String::from_utf8(stream.output).unwrap(),
+      concat!(
+        r#"{"id":1,"result":"allow"}"#,
+        "\n",
+        r#"{"id":2,"result":"deny","reason":"No env."}"#,
+        "\n",
+        r#"{"id":3,"result":"deny","reason":"Unsupported permission broker protocol version 2"}"#,
+        "\n",
+      )
+    );
+    let log = String::from_utf8(log.0.lock().clone()).unwrap();
+    assert_eq!(
+      log.lines().next().unwrap(),
+      r#"{"datetime":"d","pid":10,"id":1,"permission":"read","value":"./a.txt","result":"allow","rule":0}"#
+    );
+    assert_eq!(log.lines().count(), 3);
+  }
+
+  #[test]
+  fn test_handle_connection_invalid_request() {
+    let broker = PermissionBroker::new(Policy::default());
+    let mut stream = Duplex {
+      input: io::Cursor::new(b"not json\n".to_vec()),
+      output: Vec::new(),
+    };
+    let err = broker.handle_connection(&mut stream).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(stream.output.is_empty());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_bind_only_replaces_sockets() {
+    let dir = std::env::temp_dir().join(format!(
+      "deno_permission_broker_bind_{}",
+      std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("broker.sock");
+
+    std::fs::write(&path, "not a socket").unwrap();
+    let err = BrokerListener::bind(&path).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+
+    std::fs::remove_file(&path).unwrap();
+    drop(BrokerListener::bind(&path).unwrap());
+    // the socket left behind by the previous listener is replaced
+    drop(BrokerListener::bind(&path).unwrap());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+}
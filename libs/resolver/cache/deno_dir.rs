@@ -124,6 +124,18 @@ impl<TSys: DiskCacheSys> DenoDir<TSys> {
     self.root.join("check_cache_v2")
   }
 
+  /// Path for the cache of failed tests used by `deno test --failed`.
+  pub fn test_results_cache_db_file_path(&self) -> PathBuf {
+    // bump this version name to invalidate the entire cache
+    self.root.join("test_results_cache_v1")
+  }
+
+  /// Folder of the baselines saved by `deno bench --save-baseline`, which
+  /// has a subfolder for each workspace.
+  pub fn bench_baselines_folder_path(&self) -> PathBuf {
+    self.root.join("bench_baselines")
+  }
+
   /// Path to the registries cache, used for the lps.
   pub fn registries_folder_path(&self) -> PathBuf {
     self.root.join("registries")
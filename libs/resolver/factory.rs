@@ -203,6 +203,9 @@ pub struct WorkspaceFactoryOptions {
   pub config_discovery: ConfigDiscoveryOption,
   pub is_package_manager_subcommand: bool,
   pub frozen_lockfile: Option<bool>,
+  /// Whether to seed an empty lockfile from a package-lock.json,
+  /// pnpm-lock.yaml or yarn.lock file.
+  pub import_npm_lockfile: bool,
   pub lock_arg: Option<PathBuf>,
   /// Whether to skip writing to the lockfile.
   pub lockfile_skip_write: bool,
@@ -504,6 +507,7 @@ impl<TSys: WorkspaceFactorySys> WorkspaceFactory<TSys> {
               ConfigDiscoveryOption::Disabled
             ),
             no_npm: self.options.no_npm,
+            import_npm_lockfile: self.options.import_npm_lockfile,
           },
           &workspace_directory.workspace,
           maybe_external_import_map.as_ref().map(|v| &v.value),
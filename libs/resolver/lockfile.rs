@@ -1,6 +1,7 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
 use std::collections::HashSet;
+use std::path::Path;
 use std::path::PathBuf;
 
 use anyhow::Context;
@@ -8,7 +9,9 @@ use anyhow::Error as AnyError;
 use capacity_builder::StringBuilder;
 use deno_config::workspace::Workspace;
 use deno_error::JsErrorBox;
+use deno_lockfile::ImportedNpmLockfile;
 use deno_lockfile::Lockfile;
+use deno_lockfile::NpmLockfileKind;
 use deno_lockfile::NpmPackageInfoProvider;
 use deno_lockfile::WorkspaceMemberConfig;
 use deno_maybe_sync::MaybeSend;
@@ -178,6 +181,8 @@ pub struct LockfileFlags {
   pub skip_write: bool,
   pub no_config: bool,
   pub no_npm: bool,
+  /// Seed an empty lockfile from the lockfile of another package manager.
+  pub import_npm_lockfile: bool,
 }
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
@@ -463,9 +468,54 @@ impl<TSys: LockfileSys> LockfileLock<TSys> {
       no_config: flags.no_config,
       config,
     });
+    if flags.import_npm_lockfile && !frozen && root_folder.pkg_json.is_some() {
+      lockfile
+        .import_npm_lockfile(&workspace.root_dir_path(), api)
+        .await;
+    }
     Ok(Some(lockfile))
   }
 
+  /// Imports the packages of the first lockfile of another package manager
+  /// found in the directory when the lockfile has no npm packages yet.
+  async fn import_npm_lockfile(
+    &self,
+    dir_path: &Path,
+    api: &dyn NpmPackageInfoProvider,
+  ) {
+    if !self.lockfile.lock().content.packages.npm.is_empty() {
+      return;
+    }
+    for kind in NpmLockfileKind::ALL {
+      let Ok(text) =
+        self.sys.fs_read_to_string(&dir_path.join(kind.file_name()))
+      else {
+        continue;
+      };
+      match ImportedNpmLockfile::parse(kind, &text, api).await {
+        Ok(imported) => {
+          let count = imported.packages.len();
+          self.lockfile.lock().import_npm_lockfile(imported);
+          log::info!(
+            "{} {} npm packages from {}",
+            deno_terminal::colors::green("Imported"),
+            count,
+            kind.file_name()
+          );
+        }
+        Err(err) => {
+          log::warn!(
+            "{} {}: {}",
+            deno_terminal::colors::yellow("Warning"),
+            err,
+            err.source
+          );
+        }
+      }
+      return;
+    }
+  }
+
   pub async fn read_from_path(
     sys: TSys,
     opts: LockfileReadFromPathOptions,
@@ -52,6 +52,13 @@ pub static FEATURE_DESCRIPTIONS: &[UnstableFeatureDescription] = &[
     kind: UnstableFeatureKind::Cli,
     env_var: None,
   },
+  UnstableFeatureDescription {
+    name: "fetch-cache",
+    help_text: "Enable an RFC 9111 HTTP cache for all `fetch()` calls",
+    show_in_help: true,
+    kind: UnstableFeatureKind::Runtime,
+    env_var: None,
+  },
   UnstableFeatureDescription {
     name: "ffi",
     help_text: "Enable unstable FFI APIs",
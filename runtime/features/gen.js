@@ -10,19 +10,20 @@ export const unstableIds = {
   broadcastChannel: 1,
   bundle: 2,
   cron: 4,
-  ffi: 6,
-  fs: 7,
-  http: 8,
-  kv: 9,
-  net: 12,
-  noLegacyAbort: 13,
-  nodeGlobals: 14,
-  otel: 16,
-  process: 17,
-  rawImports: 18,
-  temporal: 21,
-  unsafeProto: 23,
-  vsock: 24,
-  webgpu: 25,
-  workerOptions: 26,
+  fetchCache: 6,
+  ffi: 7,
+  fs: 8,
+  http: 9,
+  kv: 10,
+  net: 13,
+  noLegacyAbort: 14,
+  nodeGlobals: 15,
+  otel: 17,
+  process: 18,
+  rawImports: 19,
+  temporal: 22,
+  unsafeProto: 24,
+  vsock: 25,
+  webgpu: 26,
+  workerOptions: 27,
 };
@@ -68,6 +68,7 @@ import {
 import * as performance from "ext:deno_web/15_performance.js";
 import * as url from "ext:deno_web/00_url.js";
 import * as fetch from "ext:deno_fetch/26_fetch.js";
+import { httpCache } from "ext:deno_cache/02_http_cache.js";
 import * as messagePort from "ext:deno_web/13_message_port.js";
 import {
   denoNs,
@@ -752,6 +753,10 @@ function bootstrapMainRuntime(runtimeOptions, warmup = false) {
       closed: core.propGetterOnly(() => windowIsClosing),
     });
     exposeUnstableFeaturesForWindowOrWorkerGlobalScope(unstableFeatures);
+    fetch.setHttpCache(
+      httpCache,
+      ArrayPrototypeIncludes(unstableFeatures, unstableIds.fetchCache),
+    );
     ObjectSetPrototypeOf(globalThis, Window.prototype);
 
     bootstrapOtel(otelConfig);
@@ -876,6 +881,10 @@ function bootstrapWorkerRuntime(
       );
     }
     exposeUnstableFeaturesForWindowOrWorkerGlobalScope(unstableFeatures);
+    fetch.setHttpCache(
+      httpCache,
+      ArrayPrototypeIncludes(unstableFeatures, unstableIds.fetchCache),
+    );
     ObjectSetPrototypeOf(globalThis, DedicatedWorkerGlobalScope.prototype);
 
     bootstrapOtel(otelConfig);
@@ -7,6 +7,10 @@ use std::path::PathBuf;
 use std::sync::OnceLock;
 use std::sync::atomic::AtomicU32;
 
+use deno_permission_broker::PROTOCOL_VERSION;
+use deno_permission_broker::PermissionBrokerRequest;
+use deno_permission_broker::PermissionBrokerResponse;
+use deno_permission_broker::PermissionBrokerResult;
 use parking_lot::Mutex;
 
 use super::BrokerResponse;
@@ -28,25 +32,6 @@ pub fn has_broker() -> bool {
   PERMISSION_BROKER.get().is_some()
 }
 
-#[derive(serde::Serialize)]
-#[serde(rename_all = "camelCase")]
-struct PermissionBrokerRequest<'a> {
-  v: u32,
-  pid: u32,
-  id: u32,
-  datetime: String,
-  permission: &'a str,
-  value: Option<String>,
-}
-
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct PermissionBrokerResponse {
-  id: u32,
-  result: String,
-  reason: Option<String>,
-}
-
 pub struct PermissionBroker {
   stream: Mutex<IpcPipe>,
   next_id: AtomicU32,
@@ -78,12 +63,12 @@ impl PermissionBroker {
       .next_id
       .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     let request = PermissionBrokerRequest {
-      v: 1,
+      v: PROTOCOL_VERSION,
       pid: *PID.get().unwrap(),
       id,
       #[allow(clippy::disallowed_methods, reason = "TODO: use sys_traits")]
       datetime: chrono::Utc::now().to_rfc3339(),
-      permission,
+      permission: permission.to_string(),
       value: stringified_value,
     };
 
@@ -108,16 +93,11 @@ impl PermissionBroker {
       ));
     }
 
-    let prompt_response = match response.result.as_str() {
-      "allow" => BrokerResponse::Allow,
-      "deny" => BrokerResponse::Deny {
+    let prompt_response = match response.result {
+      PermissionBrokerResult::Allow => BrokerResponse::Allow,
+      PermissionBrokerResult::Deny => BrokerResponse::Deny {
         message: response.reason,
       },
-      _ => {
-        return Err(std::io::Error::other(
-          "Permission broker unknown result variant",
-        ));
-      }
     };
 
     Ok(prompt_response)
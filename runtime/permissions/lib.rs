@@ -61,6 +61,19 @@ pub type OtelAuditFn =
 pub enum AuditSink {
   File(Mutex<std::fs::File>),
   Otel(OtelAuditFn),
+  /// Keeps every distinct granted permission access in memory, so that it
+  /// can be turned into a permission set once the process is done.
+  Collect(Mutex<std::collections::BTreeSet<AuditEntry>>),
+}
+
+/// A distinct permission access recorded by [`AuditSink::Collect`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AuditEntry {
+  /// The permission flag name, e.g. `read` or `net`.
+  pub permission: String,
+  /// The accessed descriptor, or `None` when the whole permission was
+  /// requested (e.g. `Deno.env.toObject()`).
+  pub value: Option<String>,
 }
 
 pub static AUDIT_SINK: OnceLock<AuditSink> = OnceLock::new();
@@ -93,9 +106,6 @@ where
     return;
   };
 
-  let get_stack = MAYBE_CURRENT_STACKTRACE.lock();
-  let stack = get_stack.as_ref().map(|s| s());
-
   match sink {
     AuditSink::File(file) => {
       let mut file = file.lock();
@@ -118,7 +128,7 @@ where
       );
       let _ = map.insert("value".into(), serde_json::to_value(&value).unwrap());
 
-      if let Some(ref stack) = stack {
+      if let Some(stack) = current_stack() {
         let _ =
           map.insert("stack".into(), serde_json::to_value(stack).unwrap());
       }
@@ -135,8 +145,60 @@ where
         })
         .unwrap_or_default();
 
-      report_fn(flag_name, &value_str, stack.as_deref());
+      report_fn(flag_name, &value_str, current_stack().as_deref());
     }
+    // Accesses are only collected once they were granted, see
+    // `collect_granted`.
+    AuditSink::Collect(_) => {}
+  }
+}
+
+fn current_stack() -> Option<Vec<String>> {
+  let get_stack = MAYBE_CURRENT_STACKTRACE.lock();
+  get_stack.as_ref().map(|s| s())
+}
+
+/// Records a granted permission access in the [`AuditSink::Collect`] sink.
+/// Denied accesses are never recorded, so that the generated permission set
+/// does not allow more than the program was allowed to do.
+fn collect_granted<T>(flag_name: &str, value: T)
+where
+  T: Serialize,
+{
+  let Some(AuditSink::Collect(entries)) = AUDIT_SINK.get() else {
+    return;
+  };
+
+  let value = match serde_json::to_value(&value) {
+    Ok(serde_json::Value::Null) | Err(_) => None,
+    Ok(serde_json::Value::String(s)) => Some(s),
+    Ok(other) => Some(other.to_string()),
+  };
+  entries.lock().insert(AuditEntry {
+    permission: flag_name.to_string(),
+    value,
+  });
+}
+
+/// Audits a permission access that is already known to be granted.
+fn write_audit_granted<T>(flag_name: &str, value: T)
+where
+  T: Serialize,
+{
+  write_audit(flag_name, &value);
+  collect_granted(flag_name, &value);
+}
+
+/// Audits an open of `path` that is already known to be granted. Only the
+/// kinds of access that were requested are collected.
+fn write_audit_open(path: &Path, access_kind: OpenAccessKind) {
+  write_audit(ReadQueryDescriptor::flag_name(), path);
+  write_audit(WriteQueryDescriptor::flag_name(), path);
+  if access_kind.is_read() {
+    collect_granted(ReadQueryDescriptor::flag_name(), path);
+  }
+  if access_kind.is_write() {
+    collect_granted(WriteQueryDescriptor::flag_name(), path);
   }
 }
 
@@ -144,9 +206,11 @@ where
 /// is in the "fully-granted" state.
 macro_rules! audit_and_skip_check_if_is_permission_fully_granted {
   ($this:expr, $flag_name:expr, $value:expr) => {
-    write_audit($flag_name, $value);
+    let value = $value;
+    write_audit($flag_name, &value);
 
     if $this.is_allow_all() {
+      collect_granted($flag_name, &value);
       return Ok(());
     }
   };
@@ -1010,6 +1074,12 @@ impl<
         || desc.map(|d| format_display_name(d.display_name()).into_owned()),
         self.prompt,
       );
+    if result.is_ok() {
+      collect_granted(
+        TAllowDesc::QueryDesc::flag_name(),
+        desc.map(|d| d.display_name()),
+      );
+    }
     if prompted {
       if result.is_ok() {
         if is_allow_all {
@@ -1446,7 +1516,7 @@ impl QueryDescriptor for ReadQueryDescriptor<'_> {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), true, api_name)
   }
@@ -1649,7 +1719,7 @@ impl QueryDescriptor for WriteQueryDescriptor<'_> {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), true, api_name)
   }
@@ -1850,7 +1920,7 @@ impl QueryDescriptor for NetDescriptor {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), false, api_name)
   }
@@ -2141,7 +2211,7 @@ impl QueryDescriptor for ImportDescriptor {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), false, api_name)
   }
@@ -2329,7 +2399,7 @@ impl QueryDescriptor for EnvQueryDescriptor<'_> {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), false, api_name)
   }
@@ -2554,7 +2624,7 @@ impl QueryDescriptor for RunQueryDescriptor<'_> {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), false, api_name)
   }
@@ -2866,7 +2936,7 @@ impl QueryDescriptor for SysDescriptor {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), false, api_name)
   }
@@ -2946,7 +3016,7 @@ impl QueryDescriptor for FfiQueryDescriptor<'_> {
     audit_and_skip_check_if_is_permission_fully_granted!(
       perm,
       Self::flag_name(),
-      ()
+      self.display_name()
     );
     perm.check_desc(Some(self), true, api_name)
   }
@@ -3286,6 +3356,11 @@ impl UnaryPermission<AllowRunDescriptor> {
     cmd: &RunQueryDescriptor,
     api_name: Option<&str>,
   ) -> Result<(), PermissionDeniedError> {
+    audit_and_skip_check_if_is_permission_fully_granted!(
+      self,
+      RunQueryDescriptor::flag_name(),
+      cmd.display_name()
+    );
     self.check_desc(Some(cmd), false, api_name)
   }
 
@@ -3293,6 +3368,11 @@ impl UnaryPermission<AllowRunDescriptor> {
     &mut self,
     api_name: Option<&str>,
   ) -> Result<(), PermissionDeniedError> {
+    audit_and_skip_check_if_is_permission_fully_granted!(
+      self,
+      RunQueryDescriptor::flag_name(),
+      ()
+    );
     self.check_desc(None, false, api_name)
   }
 
@@ -3919,7 +3999,7 @@ impl PermissionsContainer {
       "file" => {
         if inner.read.is_allow_all() {
           if kind != CheckSpecifierKind::Static {
-            write_audit(ReadQueryDescriptor::flag_name(), specifier);
+            write_audit_granted(ReadQueryDescriptor::flag_name(), specifier);
           }
 
           return Ok(());
@@ -3947,7 +4027,7 @@ impl PermissionsContainer {
       "blob" => Ok(()),
       _ => {
         if inner.import.is_allow_all() {
-          write_audit(ImportDescriptor::flag_name(), specifier);
+          write_audit_granted(ImportDescriptor::flag_name(), specifier);
 
           return Ok(()); // avoid allocation below
         }
@@ -3997,8 +4077,7 @@ impl PermissionsContainer {
     let path = {
       let mut inner = self.inner.lock();
       if inner.all_granted() {
-        write_audit(ReadQueryDescriptor::flag_name(), &path);
-        write_audit(WriteQueryDescriptor::flag_name(), &path);
+        write_audit_open(&path, access_kind);
         return Ok(CheckedPath {
           path: PathWithRequested {
             path,
@@ -4020,8 +4099,7 @@ impl PermissionsContainer {
         None => path_descriptor,
       };
       if !should_check_read && !should_check_write {
-        write_audit(ReadQueryDescriptor::flag_name(), &path);
-        write_audit(WriteQueryDescriptor::flag_name(), &path);
+        write_audit_open(&path, access_kind);
         drop(inner);
         path_descriptor
       } else {
@@ -4094,7 +4172,7 @@ impl PermissionsContainer {
     let mut inner = self.inner.lock();
     let inner = &mut inner.write;
     if inner.is_allow_all() {
-      write_audit(WriteQueryDescriptor::flag_name(), &path);
+      write_audit_granted(WriteQueryDescriptor::flag_name(), &path);
       Ok(CheckedPath {
         path: PathWithRequested {
           path,
@@ -4419,7 +4497,7 @@ impl PermissionsContainer {
     let mut inner = self.inner.lock();
     let inner = &mut inner.ffi;
     if inner.is_allow_all() {
-      write_audit(FfiQueryDescriptor::flag_name(), &path);
+      write_audit_granted(FfiQueryDescriptor::flag_name(), &path);
       Ok(path)
     } else {
       let desc = self.descriptor_parser.parse_path_query(path)?.into_ffi();
@@ -4438,7 +4516,7 @@ impl PermissionsContainer {
     if !inner.is_allow_all() {
       inner.check_partial(None)?;
     } else {
-      write_audit(FfiQueryDescriptor::flag_name(), ());
+      write_audit_granted(FfiQueryDescriptor::flag_name(), ());
     }
     Ok(())
   }
@@ -4452,7 +4530,7 @@ impl PermissionsContainer {
     let mut inner = self.inner.lock();
     let inner = &mut inner.ffi;
     if inner.is_allow_all() {
-      write_audit(FfiQueryDescriptor::flag_name(), &path);
+      write_audit_granted(FfiQueryDescriptor::flag_name(), &path);
       Ok(path)
     } else {
       let desc = self.descriptor_parser.parse_path_query(path)?.into_ffi();
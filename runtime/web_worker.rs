@@ -390,6 +390,8 @@ pub struct WebWorkerOptions {
   pub extensions: Vec<Extension>,
   pub startup_snapshot: Option<&'static [u8]>,
   pub unsafely_ignore_certificate_errors: Option<Vec<String>>,
+  /// Record or replay `fetch()` traffic from a fixture directory.
+  pub fetch_fixtures: Option<deno_fetch::FetchFixtures>,
   /// Optional isolate creation parameters, such as heap limits.
   pub create_params: Option<v8::CreateParams>,
   pub seed: Option<u64>,
@@ -536,6 +538,7 @@ impl WebWorker {
           .unsafely_ignore_certificate_errors
           .clone(),
         file_fetch_handler: Rc::new(deno_fetch::FsFetchHandler),
+        fixtures: options.fetch_fixtures.clone(),
         ..Default::default()
       }),
       deno_cache::deno_cache::init(create_cache),
@@ -235,6 +235,8 @@ pub struct WorkerOptions {
   pub create_params: Option<v8::CreateParams>,
 
   pub unsafely_ignore_certificate_errors: Option<Vec<String>>,
+  /// Record or replay `fetch()` traffic from a fixture directory.
+  pub fetch_fixtures: Option<deno_fetch::FetchFixtures>,
   pub seed: Option<u64>,
 
   // Callbacks invoked when creating new instance of WebWorker
@@ -269,6 +271,7 @@ impl Default for WorkerOptions {
       skip_op_registration: false,
       seed: None,
       unsafely_ignore_certificate_errors: Default::default(),
+      fetch_fixtures: Default::default(),
       should_break_on_first_statement: Default::default(),
       should_wait_for_inspector_session: Default::default(),
       trace_ops: Default::default(),
@@ -532,6 +535,7 @@ impl MainWorker {
             .clone(),
           file_fetch_handler: Rc::new(deno_fetch::FsFetchHandler),
           resolver: services.fetch_dns_resolver,
+          fixtures: options.fetch_fixtures.clone(),
           ..Default::default()
         }),
         deno_cache::deno_cache::args(create_cache),
@@ -501,6 +501,84 @@ fn permissions_audit_with_traces() {
   );
 }
 
+#[test]
+fn permissions_generate() {
+  let ctx = TestContext::default();
+  let dir = ctx.temp_dir();
+  let path = dir.path().join("permissions.json");
+
+  ctx
+    .new_command()
+    .env("DENO_GENERATE_PERMISSIONS", &path)
+    .args_vec(["run", "-A", "run/permissions_audit.ts"])
+    .run()
+    .assert_matches_text(
+      "Wrote permission set \"default\" to [WILDCARD]permissions.json\n",
+    )
+    .assert_exit_code(0);
+
+  let file = std::fs::read_to_string(path).unwrap();
+  test_util::assertions::assert_wildcard_match(
+    &file,
+    r#"{
+  "permissions": {
+    "default": {
+      "write": [
+        "[WILDCARD]"
+      ],
+      "env": [
+        "FOO"
+      ],
+      "sys": [
+        "hostname"
+      ]
+    }
+  }
+}
+"#,
+  );
+}
+
+#[test]
+fn permissions_generate_skips_denied() {
+  let ctx = TestContext::default();
+  let dir = ctx.temp_dir();
+  let path = dir.path().join("permissions.json");
+
+  ctx
+    .new_command()
+    .env("DENO_GENERATE_PERMISSIONS", &path)
+    .args_vec([
+      "run",
+      "--allow-sys=hostname",
+      "--allow-env=FOO",
+      "run/permissions_generate_denied.ts",
+    ])
+    .run()
+    .assert_matches_text(
+      "Wrote permission set \"default\" to [WILDCARD]permissions.json\n",
+    )
+    .assert_exit_code(0);
+
+  let file = std::fs::read_to_string(path).unwrap();
+  assert_eq!(
+    file,
+    r#"{
+  "permissions": {
+    "default": {
+      "env": [
+        "FOO"
+      ],
+      "sys": [
+        "hostname"
+      ]
+    }
+  }
+}
+"#,
+  );
+}
+
 #[test]
 fn lock_redirects() {
   let context = TestContextBuilder::new()
@@ -3525,6 +3603,68 @@ fn test_permission_broker() {
   );
 }
 
+#[test]
+fn test_permission_broker_subcommand() {
+  use std::io::BufRead;
+  use std::io::BufReader;
+
+  let context = TestContext::default();
+  let socket_path = if cfg!(windows) {
+    PathRef::new(r"\\.\pipe\deno-permission-broker-subcommand")
+  } else {
+    context.temp_dir().path().join("broker.sock")
+  };
+  let log_path = context.temp_dir().path().join("decisions.jsonl");
+
+  let mut broker = context
+    .new_command()
+    .arg("permission-broker")
+    .arg("--rules=run/permission_broker/rules.json")
+    .arg("--log")
+    .arg(&log_path)
+    .arg(&socket_path)
+    .stderr(Stdio::piped())
+    .spawn()
+    .unwrap();
+
+  let broker_stderr = broker.stderr.take().unwrap();
+  let mut broker_reader = BufReader::new(broker_stderr);
+  let mut line = String::new();
+  loop {
+    line.clear();
+    match broker_reader.read_line(&mut line) {
+      Ok(0) => break, // EOF
+      Ok(_) => {
+        if line.starts_with("Permission broker listening on") {
+          break;
+        }
+      }
+      Err(err) => panic!("{}", err),
+    }
+  }
+
+  let output = context
+    .new_command()
+    .env("DENO_PERMISSION_BROKER_PATH", &socket_path)
+    .args("run run/permission_broker/test1.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "Warning Permission broker is an experimental feature\nerror:[WILDCARD]NotCapable: Make sure to enable reading env vars.[WILDCARD]",
+  );
+  let _ = broker.kill();
+
+  test_util::assertions::assert_wildcard_match(
+    &log_path.read_to_string(),
+    r#"{"datetime":"[WILDCARD]","pid":[WILDCARD],"id":1,"permission":"read","value":"./run/permission_broker/scratch.txt","result":"allow","rule":0}
+{"datetime":"[WILDCARD]","pid":[WILDCARD],"id":2,"permission":"read","value":"./run/permission_broker/scratch.txt","result":"allow","rule":0}
+{"datetime":"[WILDCARD]","pid":[WILDCARD],"id":3,"permission":"read","value":"./run/permission_broker/log.txt","result":"allow","rule":0}
+{"datetime":"[WILDCARD]","pid":[WILDCARD],"id":4,"permission":"write","value":"./run/permission_broker/log.txt","result":"allow","rule":1}
+{"datetime":"[WILDCARD]","pid":[WILDCARD],"id":5,"permission":"env","value":null,"result":"deny","reason":"Make sure to enable reading env vars.","rule":2}
+"#,
+  );
+}
+
 // Regression test for https://github.com/denoland/deno/issues/32473
 // Verifies that process.stdout.write() and console.log() produce output
 // in the correct order when stdout is a TTY (uses PTY).
@@ -0,0 +1,5 @@
+Deno.bench("noop", () => {});
+Deno.bench("loop", { group: "work" }, () => {
+  let sum = 0;
+  for (let i = 0; i < 100; i++) sum += i;
+});
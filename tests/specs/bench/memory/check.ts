@@ -0,0 +1,17 @@
+// runs the benchmarks and checks that only the allocating one reports heap
+// allocations
+const { stdout } = await new Deno.Command(Deno.execPath(), {
+  args: ["bench", "--memory", "--json", "memory.ts"],
+  stderr: "null",
+}).output();
+const report = JSON.parse(new TextDecoder().decode(stdout));
+interface Bench {
+  name: string;
+  results: { ok: { heap: { allocatedPerIter: number } } }[];
+}
+const heap = Object.fromEntries(
+  report.benches.map((bench: Bench) => [bench.name, bench.results[0].ok.heap]),
+);
+// `new Array(100)` needs at least 100 pointer sized elements
+console.log(heap.allocate.allocatedPerIter >= 400);
+console.log(heap.no_heap_use.allocatedPerIter < 8);
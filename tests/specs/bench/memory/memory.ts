@@ -0,0 +1,4 @@
+Deno.bench("allocate", () => {
+  new Array(100).fill(0);
+});
+Deno.bench("no_heap_use", () => {});
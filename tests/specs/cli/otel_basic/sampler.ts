@@ -0,0 +1,28 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+import { context, trace } from "npm:@opentelemetry/api@1.9.0";
+
+const tracer = trace.getTracer("example-tracer");
+
+// Root spans and their children are not sampled with
+// `parentbased_always_off`, so none of these are exported.
+tracer.startActiveSpan("root span", (span) => {
+  tracer.startSpan("child span").end();
+  span.end();
+});
+
+// A remote parent that was sampled is honored.
+const remote = trace.setSpanContext(context.active(), {
+  traceId: "1234567890abcdef1234567890abcdef",
+  spanId: "1234567890abcdef",
+  traceFlags: 1,
+});
+tracer.startSpan("sampled child span", {}, remote).end();
+
+// A remote parent that was not sampled is honored too.
+const unsampledRemote = trace.setSpanContext(context.active(), {
+  traceId: "1234567890abcdef1234567890abcdef",
+  spanId: "1234567890abcdef",
+  traceFlags: 0,
+});
+tracer.startSpan("unsampled child span", {}, unsampledRemote).end();
@@ -0,0 +1,19 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+// Prints the spans of an OTLP JSON lines file, one per line.
+const spans = [];
+for (const line of Deno.readTextFileSync(Deno.args[0]).split("\n")) {
+  if (line === "") continue;
+  const request = JSON.parse(line);
+  for (const resourceSpans of request.resourceSpans ?? []) {
+    for (const scopeSpans of resourceSpans.scopeSpans) {
+      spans.push(...scopeSpans.spans);
+    }
+  }
+}
+spans.sort((a, b) => a.spanId.localeCompare(b.spanId));
+for (const span of spans) {
+  console.log(
+    `${span.name} ${span.traceId}/${span.spanId} parent=${span.parentSpanId}`,
+  );
+}
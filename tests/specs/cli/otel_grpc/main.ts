@@ -0,0 +1,53 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+// A minimal OTLP gRPC collector, which records the called methods and the
+// span names found in the exported protobuf messages.
+const methods = new Set<string>();
+const spanNames = ["outer span", "inner span"];
+const foundSpanNames = new Set<string>();
+
+async function handler(req: Request) {
+  const { pathname } = new URL(req.url);
+  methods.add(`${pathname} ${req.headers.get("content-type")}`);
+  const body = new TextDecoder("latin1").decode(await req.arrayBuffer());
+  for (const name of spanNames) {
+    if (body.includes(name)) {
+      foundSpanNames.add(name);
+    }
+  }
+  // an empty length-prefixed export response message
+  return new Response(new Uint8Array(5), {
+    headers: {
+      "content-type": "application/grpc",
+      "grpc-status": "0",
+    },
+  });
+}
+
+const server = Deno.serve({
+  hostname: "127.0.0.1",
+  port: 0,
+  onListen({ port }) {
+    const child = new Deno.Command(Deno.execPath(), {
+      args: ["run", "-A", "-q", Deno.args[0]],
+      env: {
+        OTEL_DENO: "true",
+        OTEL_DENO_METRICS: "false",
+        OTEL_EXPORTER_OTLP_PROTOCOL: "grpc",
+        OTEL_EXPORTER_OTLP_ENDPOINT: `http://127.0.0.1:${port}`,
+      },
+      stdout: "null",
+    }).spawn();
+    child.status
+      .then(() => server.shutdown())
+      .then(() => {
+        for (const method of [...methods].sort()) {
+          console.log(method);
+        }
+        for (const name of spanNames) {
+          console.log(`${name}: ${foundSpanNames.has(name)}`);
+        }
+      });
+  },
+  handler,
+});
@@ -0,0 +1,10 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+import { trace } from "npm:@opentelemetry/api@1.9.0";
+
+const tracer = trace.getTracer("example-tracer");
+
+tracer.startActiveSpan("outer span", (outer) => {
+  tracer.startSpan("inner span").end();
+  outer.end();
+});
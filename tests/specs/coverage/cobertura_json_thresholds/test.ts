@@ -0,0 +1,5 @@
+import { f } from "./mod.ts";
+
+Deno.test("f", () => {
+  f(true);
+});
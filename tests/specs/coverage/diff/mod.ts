@@ -0,0 +1,3 @@
+export function covered() {
+  return 1;
+}
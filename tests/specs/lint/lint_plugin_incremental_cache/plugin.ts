@@ -0,0 +1,13 @@
+import { MESSAGE } from "./message.ts";
+
+export default {
+  name: "test-plugin",
+  rules: {
+    "log-file": {
+      create(context) {
+        console.log(MESSAGE, context.filename.split(/[\\/]/).pop());
+        return {};
+      },
+    },
+  },
+};
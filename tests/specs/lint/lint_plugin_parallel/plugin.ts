@@ -0,0 +1,25 @@
+console.log("loading plugin");
+
+// every host of the pool loads the plugin in its own isolate
+let filesLinted = 0;
+
+export default {
+  name: "test-plugin",
+  rules: {
+    "my-rule": {
+      create(context) {
+        const count = ++filesLinted;
+        return {
+          Identifier(node) {
+            if (node.name === "_a") {
+              context.report({
+                node,
+                message: `should be _b (file ${count} of its host)`,
+              });
+            }
+          },
+        };
+      },
+    },
+  },
+} satisfies Deno.lint.Plugin;
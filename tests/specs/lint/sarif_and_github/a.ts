@@ -0,0 +1,2 @@
+const _a = 1;
+if (_a) {}
@@ -0,0 +1 @@
+export const _a = 2;
@@ -0,0 +1,7 @@
+const url = Deno.readTextFileSync("url.txt");
+try {
+  await fetch(url);
+  console.log("served from the cache");
+} catch (err) {
+  console.log((err as Error).name);
+}
@@ -0,0 +1,17 @@
+const started = Promise.withResolvers<number>();
+const server = Deno.serve({
+  port: 0,
+  onListen: ({ port }) => started.resolve(port),
+}, () =>
+  new Response("cached", {
+    headers: { "cache-control": "max-age=3600" },
+  }));
+const url = `http://localhost:${await started.promise}/`;
+await (await fetch(url)).text();
+const cache = await caches.open("deno:fetch");
+while (!(await cache.match(url))) {
+  await new Promise((resolve) => setTimeout(resolve, 10));
+}
+Deno.writeTextFileSync("url.txt", url);
+await server.shutdown();
+console.log("stored");
@@ -0,0 +1,4 @@
+import { add } from "jsr:@denotest/add@1";
+import { getValue } from "npm:@denotest/sbom-licensed@1.0.0";
+
+console.log(add(1, getValue()));
@@ -0,0 +1,7 @@
+import { add } from "./mod.ts";
+
+Deno.test("adds", () => {
+  if (add(1, 2) !== 3) {
+    throw new Error("wrong sum");
+  }
+});
@@ -0,0 +1,7 @@
+Deno.test("passes", () => {});
+
+Deno.test("fails", () => {
+  if (!Deno.env.get("FIXED")) {
+    throw new Error("broken");
+  }
+});
@@ -0,0 +1,10 @@
+Deno.test("also passes", () => {});
+
+// same name as the failing test of a_test.ts, but it passes
+Deno.test("fails", () => {});
+
+Deno.test("also fails", () => {
+  if (!Deno.env.get("FIXED")) {
+    throw new Error("broken too");
+  }
+});
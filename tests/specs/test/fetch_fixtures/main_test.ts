@@ -0,0 +1,20 @@
+Deno.test("replays a recorded response", async () => {
+  const res = await fetch("http://fixtures.example/data.json");
+  if (res.status !== 200) throw new Error(`unexpected status ${res.status}`);
+  if (res.headers.get("content-type") !== "application/json") {
+    throw new Error("unexpected content-type");
+  }
+  const data = await res.json();
+  if (data.name !== "deno") throw new Error("unexpected body");
+});
+
+Deno.test("fails requests without a fixture in strict mode", async () => {
+  try {
+    await fetch("http://fixtures.example/missing.json");
+  } catch (err) {
+    if (!(err instanceof TypeError)) throw err;
+    if (!err.message.includes("No recorded fetch fixture")) throw err;
+    return;
+  }
+  throw new Error("expected the request to fail");
+});
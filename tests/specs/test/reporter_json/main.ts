@@ -0,0 +1,10 @@
+Deno.test("passes", () => {});
+
+Deno.test("fails", () => {
+  throw new Error("boom");
+});
+
+Deno.test("steps", async (t) => {
+  console.log("hello");
+  await t.step("inner", () => {});
+});
@@ -0,0 +1,21 @@
+let flakyAttempts = 0;
+Deno.test("flaky", () => {
+  flakyAttempts++;
+  if (flakyAttempts < 2) {
+    throw new Error("failed on attempt " + flakyAttempts);
+  }
+});
+
+let retryAttempts = 0;
+Deno.test({
+  name: "retry option",
+  retry: 2,
+  fn() {
+    retryAttempts++;
+    if (retryAttempts < 3) {
+      throw new Error("failed on attempt " + retryAttempts);
+    }
+  },
+});
+
+Deno.test("passes", () => {});
@@ -0,0 +1,10 @@
+let attempts = 0;
+Deno.test("flaky step", async (t) => {
+  attempts++;
+  await t.step("setup", () => {});
+  await t.step("request", () => {
+    if (attempts < 2) {
+      throw new Error("failed on attempt " + attempts);
+    }
+  });
+});
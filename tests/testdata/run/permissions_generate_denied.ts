@@ -0,0 +1,7 @@
+Deno.hostname();
+Deno.env.get("FOO");
+try {
+  Deno.env.get("BAR");
+} catch {
+  // denied
+}
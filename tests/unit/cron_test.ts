@@ -1,5 +1,5 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
-import { assertEquals, assertThrows } from "./test_util.ts";
+import { assert, assertEquals, assertThrows } from "./test_util.ts";
 
 // @ts-ignore This is not publicly typed namespace, but it's there for sure.
 const {
@@ -317,6 +317,48 @@ Deno.test(async function retriesWithBackoffScheduleOldApi() {
   assertEquals(count, 3);
 });
 
+Deno.test(async function listAndTrigger() {
+  Deno.env.delete("DENO_CRON_TEST_SCHEDULE_OFFSET");
+
+  const { promise, resolve } = Promise.withResolvers<void>();
+  const ac = new AbortController();
+  const c = Deno.cron(
+    "listed",
+    "0 0 1 1 *",
+    { signal: ac.signal, backoffSchedule: [10] },
+    () => resolve(),
+  );
+  try {
+    await sleep(100);
+    const [status] = Deno.cron.list();
+    assertEquals(status.name, "listed");
+    assertEquals(status.schedule, "0 0 1 1 *");
+    assertEquals(status.backoffSchedule, [10]);
+    assertEquals(status.running, false);
+    assertEquals(status.lastRun, null);
+    assertEquals(status.lastSuccess, null);
+    assert(status.nextDeadline! > Date.now());
+
+    Deno.cron.trigger("listed");
+    await promise;
+    await sleep(100);
+    const [after] = Deno.cron.list();
+    assertEquals(after.lastSuccess, true);
+    assert(after.lastRun! <= Date.now());
+    assert(after.nextDeadline! > Date.now());
+
+    assertThrows(
+      () => Deno.cron.trigger("unknown"),
+      Deno.errors.NotFound,
+      "Cron not found: unknown",
+    );
+  } finally {
+    ac.abort();
+    await c;
+  }
+  assertEquals(Deno.cron.list(), []);
+});
+
 Deno.test("formatToCronSchedule - undefined value", () => {
   const result = formatToCronSchedule();
   assertEquals(result, "*");
@@ -2416,3 +2416,165 @@ Deno.test(
     assertEquals(await resp3.text(), "Not found");
   },
 );
+
+async function waitForHttpCacheEntry(cacheName: string, url: string) {
+  const cache = await caches.open(cacheName);
+  while (!(await cache.match(url))) {
+    await delay(10);
+  }
+}
+
+Deno.test(
+  { permissions: { net: true } },
+  async function fetchHttpCacheServesFreshResponse() {
+    let requests = 0;
+    const started = Promise.withResolvers<number>();
+    await using _server = Deno.serve({
+      port: 0,
+      onListen: ({ port }) => started.resolve(port),
+    }, () => {
+      requests++;
+      return new Response(`response ${requests}`, {
+        headers: { "cache-control": "max-age=60" },
+      });
+    });
+    const url = `http://localhost:${await started.promise}/`;
+    const cacheName = "fetch-http-cache-fresh";
+    using client = Deno.createHttpClient({ cache: { name: cacheName } });
+
+    const resp1 = await fetch(url, { client });
+    assertEquals(await resp1.text(), "response 1");
+    await waitForHttpCacheEntry(cacheName, url);
+
+    const resp2 = await fetch(url, { client });
+    assertEquals(resp2.status, 200);
+    assertEquals(resp2.headers.get("cache-control"), "max-age=60");
+    assertEquals(resp2.headers.has("x-deno-cache-stored-at"), false);
+    assertEquals(await resp2.text(), "response 1");
+
+    const resp3 = await fetch(url, {
+      client,
+      headers: { "cache-control": "no-store" },
+    });
+    assertEquals(await resp3.text(), "response 2");
+    assertEquals(requests, 2);
+    await caches.delete(cacheName);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function fetchHttpCacheRevalidatesStaleResponse() {
+    const conditionalRequests: (string | null)[] = [];
+    const started = Promise.withResolvers<number>();
+    await using _server = Deno.serve({
+      port: 0,
+      onListen: ({ port }) => started.resolve(port),
+    }, (req) => {
+      const ifNoneMatch = req.headers.get("if-none-match");
+      conditionalRequests.push(ifNoneMatch);
+      if (ifNoneMatch === '"v1"') {
+        return new Response(null, {
+          status: 304,
+          headers: { etag: '"v1"', "x-revalidated": "true" },
+        });
+      }
+      return new Response("cached body", {
+        headers: { "cache-control": "no-cache", etag: '"v1"' },
+      });
+    });
+    const url = `http://localhost:${await started.promise}/`;
+    const cacheName = "fetch-http-cache-revalidate";
+    using client = Deno.createHttpClient({ cache: { name: cacheName } });
+
+    const resp1 = await fetch(url, { client });
+    assertEquals(await resp1.text(), "cached body");
+    await waitForHttpCacheEntry(cacheName, url);
+
+    const resp2 = await fetch(url, { client });
+    assertEquals(resp2.status, 200);
+    assertEquals(resp2.headers.get("etag"), '"v1"');
+    assertEquals(resp2.headers.get("x-revalidated"), "true");
+    assertEquals(await resp2.text(), "cached body");
+    assertEquals(conditionalRequests, [null, '"v1"']);
+    await caches.delete(cacheName);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function fetchHttpCacheSkipsResponsesToAuthorizedRequests() {
+    let requests = 0;
+    const started = Promise.withResolvers<number>();
+    await using _server = Deno.serve({
+      port: 0,
+      onListen: ({ port }) => started.resolve(port),
+    }, (req) => {
+      requests++;
+      const authorization = req.headers.get("authorization");
+      return new Response(authorization ? "private" : "public", {
+        headers: { "cache-control": "max-age=60" },
+      });
+    });
+    const url = `http://localhost:${await started.promise}/`;
+    const cacheName = "fetch-http-cache-authorization";
+    using client = Deno.createHttpClient({ cache: { name: cacheName } });
+
+    const resp1 = await fetch(url, {
+      client,
+      headers: { authorization: "Bearer secret" },
+    });
+    assertEquals(await resp1.text(), "private");
+    // a request without credentials must not get the private response
+    const resp2 = await fetch(url, { client });
+    assertEquals(await resp2.text(), "public");
+    await waitForHttpCacheEntry(cacheName, url);
+
+    const resp3 = await fetch(url, { client });
+    assertEquals(await resp3.text(), "public");
+    assertEquals(requests, 2);
+    await caches.delete(cacheName);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function fetchHttpCacheDisabledForClient() {
+    let requests = 0;
+    const started = Promise.withResolvers<number>();
+    await using _server = Deno.serve({
+      port: 0,
+      onListen: ({ port }) => started.resolve(port),
+    }, () => {
+      requests++;
+      return new Response("ok", {
+        headers: { "cache-control": "max-age=60" },
+      });
+    });
+    const url = `http://localhost:${await started.promise}/`;
+    using client = Deno.createHttpClient({ cache: false });
+
+    await (await fetch(url, { client })).text();
+    await (await fetch(url, { client })).text();
+    assertEquals(requests, 2);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  function createHttpClientInvalidCacheOption() {
+    assertThrows(
+      () => {
+        // @ts-expect-error testing invalid option
+        Deno.createHttpClient({ cache: "yes" });
+      },
+      TypeError,
+      `Invalid value for 'cache' option: "yes"`,
+    );
+    assertThrows(
+      () => Deno.createHttpClient({ cache: { name: "" } }),
+      TypeError,
+      `Invalid value for 'cache.name' option: ""`,
+    );
+  },
+);
@@ -2789,6 +2789,13 @@ const compressionTestCases = [
     out: { "Content-Type": "text/plain" },
     expect: "br",
   },
+  {
+    name: "CompressibleType4",
+    length: 1024,
+    in: { "Accept-Encoding": "zstd" },
+    out: { "Content-Type": "text/plain" },
+    expect: "zstd",
+  },
   {
     name: "IncompressibleRange",
     length: 1024,
@@ -2877,6 +2884,174 @@ for (const testCase of compressionTestCases) {
   );
 }
 
+async function readContentEncoding(acceptEncoding: string) {
+  const conn = await Deno.connect({ port: servePort });
+  const encoder = new TextEncoder();
+  await conn.write(encoder.encode(
+    `GET / HTTP/1.1\r\nHost: example.domain\r\nAccept-Encoding: ${acceptEncoding}\r\nConnection: close\r\n\r\n`,
+  ));
+  const decoder = new TextDecoder();
+  let msg = "";
+  while (!msg.includes("\r\n\r\n")) {
+    const buf = new Uint8Array(1024);
+    const readResult = await conn.read(buf);
+    if (!readResult) {
+      break;
+    }
+    msg += decoder.decode(buf.subarray(0, readResult));
+  }
+  conn.close();
+  const head = msg.slice(0, msg.indexOf("\r\n\r\n")).toLowerCase();
+  const match = head.match(/\r\ncontent-encoding: ([^\r]*)/);
+  return match ? match[1] : null;
+}
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerCompressionNegotiation() {
+    const listeningDeferred = Promise.withResolvers<void>();
+    const ac = new AbortController();
+    await using server = Deno.serve({
+      handler: () =>
+        new Response("a".repeat(1024), {
+          headers: { "Content-Type": "text/plain" },
+        }),
+      port: servePort,
+      signal: ac.signal,
+      onListen: onListen(listeningDeferred.resolve),
+      onError: createOnErrorCb(ac),
+    });
+    await listeningDeferred.promise;
+    try {
+      assertEquals(
+        await readContentEncoding("gzip, deflate, br, zstd"),
+        "br",
+      );
+      assertEquals(await readContentEncoding("gzip, deflate, zstd"), "zstd");
+      assertEquals(await readContentEncoding("gzip, deflate, br"), "br");
+      assertEquals(await readContentEncoding("gzip;q=1, zstd;q=0.5"), "gzip");
+      assertEquals(await readContentEncoding("zstd;q=0, gzip"), "gzip");
+      assertEquals(await readContentEncoding("identity;q=1, br;q=0.5"), null);
+      assertEquals(await readContentEncoding("deflate"), null);
+    } finally {
+      ac.abort();
+      await server.finished;
+    }
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerCompressionOptions() {
+    const listeningDeferred = Promise.withResolvers<void>();
+    const ac = new AbortController();
+    await using server = Deno.serve({
+      handler: () =>
+        new Response("a".repeat(1024), {
+          headers: { "Content-Type": "text/plain" },
+        }),
+      port: servePort,
+      signal: ac.signal,
+      onListen: onListen(listeningDeferred.resolve),
+      onError: createOnErrorCb(ac),
+      compression: { encodings: ["gzip", "br"], levels: { gzip: 9 } },
+    });
+    await listeningDeferred.promise;
+    try {
+      assertEquals(
+        await readContentEncoding("gzip, deflate, br, zstd"),
+        "gzip",
+      );
+      assertEquals(await readContentEncoding("br, zstd"), "br");
+      assertEquals(await readContentEncoding("zstd"), null);
+    } finally {
+      ac.abort();
+      await server.finished;
+    }
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerCompressionPreferZstd() {
+    const listeningDeferred = Promise.withResolvers<void>();
+    const ac = new AbortController();
+    await using server = Deno.serve({
+      handler: () =>
+        new Response("a".repeat(1024), {
+          headers: { "Content-Type": "text/plain" },
+        }),
+      port: servePort,
+      signal: ac.signal,
+      onListen: onListen(listeningDeferred.resolve),
+      onError: createOnErrorCb(ac),
+      compression: { encodings: ["zstd", "br", "gzip"] },
+    });
+    await listeningDeferred.promise;
+    try {
+      assertEquals(
+        await readContentEncoding("gzip, deflate, br, zstd"),
+        "zstd",
+      );
+      assertEquals(await readContentEncoding("gzip, deflate, br"), "br");
+    } finally {
+      ac.abort();
+      await server.finished;
+    }
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerCompressionDisabled() {
+    const listeningDeferred = Promise.withResolvers<void>();
+    const ac = new AbortController();
+    await using server = Deno.serve({
+      handler: () =>
+        new Response("a".repeat(1024), {
+          headers: { "Content-Type": "text/plain" },
+        }),
+      port: servePort,
+      signal: ac.signal,
+      onListen: onListen(listeningDeferred.resolve),
+      onError: createOnErrorCb(ac),
+      compression: false,
+    });
+    await listeningDeferred.promise;
+    try {
+      assertEquals(await readContentEncoding("gzip, deflate, br, zstd"), null);
+    } finally {
+      ac.abort();
+      await server.finished;
+    }
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  function httpServerCompressionInvalidOptions() {
+    assertThrows(
+      () =>
+        Deno.serve({
+          port: servePort,
+          // @ts-expect-error: testing an invalid encoding
+          compression: { encodings: ["deflate"] },
+        }, () => new Response()),
+      TypeError,
+      "unsupported encoding 'deflate'",
+    );
+    assertThrows(
+      () =>
+        Deno.serve({
+          port: servePort,
+          compression: { levels: { zstd: 23 } },
+        }, () => new Response()),
+      RangeError,
+      "expected an integer between 1 and 22, received 23",
+    );
+  },
+);
+
 Deno.test(
   { permissions: { net: true, write: true, read: true } },
   async function httpServerPostFile() {
@@ -3142,6 +3317,188 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { read: true, net: true } },
+  async function httpServerWithHttp3() {
+    const ac = new AbortController();
+    const { promise, resolve } = Promise.withResolvers<void>();
+    const hostname = "127.0.0.1";
+
+    await using server = Deno.serve({
+      handler: () => new Response("Hello World"),
+      hostname,
+      port: servePort,
+      signal: ac.signal,
+      onListen: onListen(resolve),
+      onError: createOnErrorCb(ac),
+      cert: Deno.readTextFileSync("tests/testdata/tls/localhost.crt"),
+      key: Deno.readTextFileSync("tests/testdata/tls/localhost.key"),
+      http3: true,
+    });
+
+    await promise;
+    const caCert = Deno.readTextFileSync("tests/testdata/tls/RootCA.pem");
+    const client = Deno.createHttpClient({ caCerts: [caCert] });
+    const resp = await fetch(`https://localhost:${servePort}/`, {
+      client,
+      headers: { "connection": "close" },
+    });
+    assertEquals(resp.headers.get("alt-svc"), `h3=":${servePort}"; ma=86400`);
+    assertEquals(await resp.text(), "Hello World");
+    client.close();
+
+    // The advertised QUIC endpoint negotiates HTTP/3.
+    const conn = await Deno.connectQuic({
+      hostname,
+      port: servePort,
+      serverName: "localhost",
+      caCerts: [caCert],
+      alpnProtocols: ["h3"],
+    });
+    assertEquals(conn.protocol, "h3");
+    conn.close();
+
+    ac.abort();
+    await server.finished;
+  },
+);
+
+function encodeQuicVarInt(value: number): number[] {
+  if (value < 0x40) return [value];
+  if (value < 0x4000) return [0x40 | (value >> 8), value & 0xff];
+  throw new RangeError("Value is too large for this test");
+}
+
+/** Sends a GET request over HTTP/3 and returns the response status and body. */
+async function http3Get(
+  conn: Deno.QuicConn,
+  authority: string,
+  path: string,
+): Promise<{ status: number; body: string }> {
+  const encoder = new TextEncoder();
+  // the control stream, which starts with an empty SETTINGS frame
+  const control = await conn.createUnidirectionalStream();
+  await control.getWriter().write(new Uint8Array([0x00, 0x04, 0x00]));
+
+  // QPACK field section without dynamic table references, using the static
+  // table entries for `:method: GET`, `:scheme: https` and `:authority`
+  const literal = (text: string) => [text.length, ...encoder.encode(text)];
+  const fields = [
+    0x00,
+    0x00,
+    0xc0 | 17,
+    0xc0 | 23,
+    0x50,
+    ...literal(authority),
+    0x51,
+    ...literal(path),
+  ];
+  const { readable, writable } = await conn.createBidirectionalStream();
+  const writer = writable.getWriter();
+  await writer.write(
+    new Uint8Array([0x01, ...encodeQuicVarInt(fields.length), ...fields]),
+  );
+  await writer.close();
+
+  const chunks = [];
+  for await (const chunk of readable) {
+    chunks.push(...chunk);
+  }
+  const bytes = new Uint8Array(chunks);
+  const readVarInt = (offset: number) => {
+    const length = 1 << (bytes[offset] >> 6);
+    let value = bytes[offset] & 0x3f;
+    for (let i = 1; i < length; i++) {
+      value = value * 256 + bytes[offset + i];
+    }
+    return { value, offset: offset + length };
+  };
+
+  let status = 0;
+  const body = [];
+  let offset = 0;
+  while (offset < bytes.length) {
+    const type = readVarInt(offset);
+    const length = readVarInt(type.offset);
+    const payload = bytes.subarray(
+      length.offset,
+      length.offset + length.value,
+    );
+    offset = length.offset + length.value;
+    if (type.value === 0x01 && status === 0) {
+      // the status is the first field line, encoded as an indexed static
+      // table entry
+      const statuses: Record<number, number> = {
+        24: 103,
+        25: 200,
+        26: 304,
+        27: 404,
+        28: 503,
+      };
+      assertEquals(payload[2] & 0xc0, 0xc0);
+      status = statuses[payload[2] & 0x3f];
+    } else if (type.value === 0x00) {
+      body.push(...payload);
+    }
+  }
+  return { status, body: new TextDecoder().decode(new Uint8Array(body)) };
+}
+
+Deno.test(
+  { permissions: { net: true, read: true } },
+  async function httpServerHttp3Request() {
+    const { promise, resolve } = Promise.withResolvers<void>();
+    const hostname = "127.0.0.1";
+    const server = Deno.serve({
+      handler: (request) =>
+        new Response(`${request.method} ${new URL(request.url).pathname}`),
+      hostname,
+      port: servePort,
+      onListen: onListen(resolve),
+      cert: Deno.readTextFileSync("tests/testdata/tls/localhost.crt"),
+      key: Deno.readTextFileSync("tests/testdata/tls/localhost.key"),
+      http3: true,
+    });
+    await promise;
+
+    const conn = await Deno.connectQuic({
+      hostname,
+      port: servePort,
+      serverName: "localhost",
+      caCerts: [Deno.readTextFileSync("tests/testdata/tls/RootCA.pem")],
+      alpnProtocols: ["h3"],
+    });
+    const response = await http3Get(conn, `localhost:${servePort}`, "/hello");
+    assertEquals(response, { status: 200, body: "GET /hello" });
+    conn.close();
+
+    await server.shutdown();
+
+    // the UDP port is released once the server shut down
+    const socket = Deno.listenDatagram({
+      hostname,
+      port: servePort,
+      transport: "udp",
+    });
+    socket.close();
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  function httpServerHttp3RequiresTls() {
+    assertThrows(
+      () =>
+        Deno.serve({
+          port: servePort,
+          http3: true,
+        }, () => new Response()),
+      TypeError,
+      "Both 'cert' and 'key' must be provided to enable HTTP/3",
+    );
+  },
+);
+
 Deno.test(
   { permissions: { net: true, write: true, read: true } },
   async function httpServerRequestCLTE() {